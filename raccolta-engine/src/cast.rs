@@ -0,0 +1,234 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the conversions of the `<cast specification>`. The
+//! target of a `CAST` is the column container its data type would be stored
+//! in, such that a value is converted the same way as it would be stored.
+//!
+//! The conversions between the types are:
+//!
+//! | From \ To           | `INTEGER` | `BOOLEAN` | `CHARACTER VARYING` | `BINARY` | `BIT`   |
+//! |---------------------|-----------|-----------|---------------------|----------|---------|
+//! | `INTEGER`           | yes       | yes       | yes                 |          |         |
+//! | `DOUBLE PRECISION`  | rounded   |           | yes                 |          |         |
+//! | `BOOLEAN`           | yes       | yes       | yes                 |          |         |
+//! | `CHARACTER VARYING` | parsed    | parsed    | truncated           |          |         |
+//! | `BINARY`            |           |           |                     | resized  |         |
+//! | `BIT`               |           |           |                     |          | resized |
+//!
+//! There is no `DOUBLE PRECISION` data type yet, but integers are converted
+//! to it when they are mixed with e.g. the result of `SQRT`.
+
+use crate::{
+    EngineMessage,
+    EngineResult,
+    EngineRowColumnValue,
+    expression::EngineValueType,
+    table::{
+        EngineColumnContainer,
+        truncate_characters,
+    },
+};
+
+/// The type a value is converted to by a `CAST`, including its length.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CastTarget {
+    /// `BINARY(n)`, where shorter values are padded with zero octets.
+    BinaryFixed {
+        length: usize,
+    },
+
+    /// `BINARY VARYING(n)`, `VARBINARY(n)` and `BLOB`
+    BinaryVarying {
+        maximum_length: usize,
+    },
+
+    /// `BIT(n)`, where shorter values are padded with zero bits.
+    BitsFixed {
+        length: usize,
+    },
+
+    /// `BIT VARYING(n)`
+    BitsVarying {
+        maximum_length: usize,
+    },
+
+    Boolean,
+
+    /// `CHARACTER VARYING(n)`, `CLOB` and `NCLOB`
+    CharacterVarying {
+        maximum_length: usize,
+    },
+
+    /// `DOUBLE PRECISION`, which is only the target of integers mixed with
+    /// doubles, e.g. the `1` of `1 + 0.5`.
+    Double,
+
+    Integer,
+}
+
+impl CastTarget {
+    /// Returns the target of the values stored in the column container.
+    pub fn of_container(container: &EngineColumnContainer) -> Self {
+        match container {
+            EngineColumnContainer::BinariesFixed { length, .. } => Self::BinaryFixed { length: *length },
+            EngineColumnContainer::BinariesVarying { maximum_length, .. }
+                | EngineColumnContainer::BinaryLargeObjects { maximum_length, .. } => Self::BinaryVarying {
+                    maximum_length: *maximum_length,
+                },
+            EngineColumnContainer::BitsFixed { length, .. } => Self::BitsFixed { length: *length },
+            EngineColumnContainer::BitsVarying { maximum_length, .. } => Self::BitsVarying { maximum_length: *maximum_length },
+            EngineColumnContainer::Booleans(..) => Self::Boolean,
+            EngineColumnContainer::CharacterLargeObjects { maximum_length, .. }
+                | EngineColumnContainer::StringsVarying { maximum_length, .. } => Self::CharacterVarying {
+                    maximum_length: *maximum_length,
+                },
+            EngineColumnContainer::Integers(..) => Self::Integer,
+        }
+    }
+
+    /// The type of the values this target produces.
+    pub fn value_type(&self) -> EngineValueType {
+        match self {
+            Self::BinaryFixed { .. } | Self::BinaryVarying { .. } => EngineValueType::Binary,
+            Self::BitsFixed { .. } | Self::BitsVarying { .. } => EngineValueType::Bits,
+            Self::Boolean => EngineValueType::Boolean,
+            Self::CharacterVarying { .. } => EngineValueType::CharacterString,
+            Self::Double => EngineValueType::Double,
+            Self::Integer => EngineValueType::Integer,
+        }
+    }
+
+    /// Checks whether values of the `source` type can be converted to this
+    /// target at all. The conversion itself can still fail for some values,
+    /// e.g. `CAST('abc' AS INTEGER)`.
+    pub fn accepts(&self, source: EngineValueType) -> bool {
+        use EngineValueType as Type;
+
+        matches!(
+            (source, self.value_type()),
+            (Type::Binary, Type::Binary)
+                | (Type::Bits, Type::Bits)
+                | (
+                    Type::Boolean | Type::CharacterString | Type::Integer,
+                    Type::Boolean | Type::CharacterString | Type::Integer,
+                )
+                | (Type::Double | Type::Integer, Type::Double)
+                | (Type::Double, Type::CharacterString | Type::Integer)
+        )
+    }
+
+    /// Converts the `value` to this target, which must be of a type this
+    /// target [accepts](Self::accepts). The null value stays null.
+    pub fn cast(&self, value: EngineRowColumnValue) -> Result<EngineRowColumnValue, EngineResult> {
+        use EngineRowColumnValue as Value;
+
+        Ok(match (self, value) {
+            (_, Value::Null) => Value::Null,
+
+            (Self::BinaryFixed { length }, value) => {
+                let mut bytes = binary_value(value);
+                bytes.resize(*length, 0);
+                Value::Binary(bytes)
+            }
+
+            (Self::BinaryVarying { maximum_length }, value) => {
+                let mut bytes = binary_value(value);
+                bytes.truncate(*maximum_length);
+                Value::Binary(bytes)
+            }
+
+            (Self::BitsFixed { length }, Value::Bits(mut bits)) => {
+                bits.resize(*length, false);
+                Value::Bits(bits)
+            }
+
+            (Self::BitsVarying { maximum_length }, Value::Bits(mut bits)) => {
+                bits.truncate(*maximum_length);
+                Value::Bits(bits)
+            }
+
+            (Self::Boolean, Value::Bool(value)) => Value::Bool(value),
+            (Self::Boolean, Value::I32(value)) => Value::Bool(value != 0),
+            (Self::Boolean, value) => {
+                let string = string_value(value);
+                match string.trim().to_ascii_uppercase().as_str() {
+                    "TRUE" => Value::Bool(true),
+                    "FALSE" => Value::Bool(false),
+                    "UNKNOWN" => Value::Null,
+                    _ => return Err(invalid_input(self.value_type(), &string)),
+                }
+            }
+
+            (Self::CharacterVarying { maximum_length }, value @ (Value::Bool(..) | Value::F64(..) | Value::I32(..))) => {
+                let string = value.to_string().to_ascii_uppercase();
+                if string.chars().count() > *maximum_length {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!(
+                            "value {string} is too long for type CHARACTER VARYING({maximum_length})"
+                        ).into())
+                    ]));
+                }
+
+                Value::String(string)
+            }
+
+            (Self::CharacterVarying { maximum_length }, value) => {
+                let mut string = string_value(value);
+                truncate_characters(&mut string, *maximum_length);
+                Value::String(string)
+            }
+
+            (Self::Double, Value::F64(value)) => Value::F64(value),
+            (Self::Double, Value::I32(value)) => Value::F64(value.into()),
+
+            (Self::Integer, Value::Bool(value)) => Value::I32(value.into()),
+            (Self::Integer, Value::F64(value)) => {
+                let rounded = value.round();
+                if rounded < f64::from(i32::MIN) || rounded > f64::from(i32::MAX) {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!("value {value} is out of range for type INTEGER").into())
+                    ]));
+                }
+
+                Value::I32(rounded as i32)
+            }
+            (Self::Integer, Value::I32(value)) => Value::I32(value),
+            (Self::Integer, value) => {
+                let string = string_value(value);
+                let trimmed = string.trim();
+                match trimmed.parse::<i32>() {
+                    Ok(value) => Value::I32(value),
+                    Err(..) if trimmed.parse::<i128>().is_ok() => return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!("value {trimmed} is out of range for type INTEGER").into())
+                    ])),
+                    Err(..) => return Err(invalid_input(self.value_type(), &string)),
+                }
+            }
+
+            (_, value) => unreachable!("type-checked as castable to {self:?}, but got {value:?}"),
+        })
+    }
+}
+
+/// Returns the bytes of a binary string value.
+fn binary_value(value: EngineRowColumnValue) -> Vec<u8> {
+    match value.materialize() {
+        EngineRowColumnValue::Binary(bytes) => bytes,
+        value => unreachable!("type-checked as BINARY VARYING, but got {value:?}"),
+    }
+}
+
+/// Returns the contents of a character string value.
+fn string_value(value: EngineRowColumnValue) -> String {
+    match value.materialize() {
+        EngineRowColumnValue::String(string) => string,
+        value => unreachable!("type-checked as CHARACTER VARYING, but got {value:?}"),
+    }
+}
+
+fn invalid_input(value_type: EngineValueType, input: &str) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("invalid input syntax for type {value_type}: '{input}'").into())
+    ])
+}
@@ -0,0 +1,1483 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the compiler and evaluator of value expressions. A
+//! [`ValueExpression`] is compiled once per query, which binds the column
+//! references to column indices and checks the types of the operands. The
+//! resulting [`CompiledExpression`] is then evaluated for every row.
+//!
+//! Null values propagate through operators, and boolean values follow the
+//! three-valued logic of SQL, where the null value is the truth value
+//! `UNKNOWN`. `AND` and `OR` short-circuit, such that e.g. the right-hand side
+//! of `FALSE AND 1 / 0 = 1` is never evaluated.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+};
+
+use raccolta_syntax::{
+    common::Identifier,
+    expression::{
+        boolean_expression::TruthValue,
+        BooleanExpression,
+        case_expression::CaseResult,
+        CaseExpression,
+        cast_specification::CastOperand,
+        CastSpecification,
+        ColumnReference,
+        NumericOperator,
+        NumericValueExpression,
+        numeric_value_function::NumericValueFunction,
+        RoutineInvocation,
+        string_value_expression::StringValueExpression,
+        string_value_function::{
+            StringValueFunction,
+            TrimSpecification,
+        },
+        ValueExpression,
+        WindowFunction,
+    },
+    predicate::{
+        comparison_predicate::ComparisonOperator,
+        Predicate,
+        quantified_comparison_predicate::Quantifier,
+    },
+    Span,
+    Spanned,
+};
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::{
+    EngineMessage,
+    EngineResult,
+    EngineRow,
+    EngineRowColumnValue,
+    cast::CastTarget,
+    function::{
+        Function,
+        Overload,
+    },
+    pattern::PatternKind,
+    table::{
+        EngineColumn,
+        EngineColumnContainer,
+    },
+    window::WindowOperator,
+};
+
+/// The type of the values a [`CompiledExpression`] produces.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EngineValueType {
+    /// `BINARY`, `BINARY VARYING` and `BLOB`
+    Binary,
+
+    /// `BIT` and `BIT VARYING`
+    Bits,
+
+    Boolean,
+
+    /// `CHARACTER VARYING`, `CLOB` and `NCLOB`
+    CharacterString,
+
+    /// `DOUBLE PRECISION`, `FLOAT` and `REAL`
+    Double,
+
+    Integer,
+}
+
+impl EngineValueType {
+    /// Returns the type of the values stored in the column container.
+    pub fn of_column(container: &EngineColumnContainer) -> Self {
+        match container {
+            EngineColumnContainer::BinariesFixed { .. }
+                | EngineColumnContainer::BinariesVarying { .. }
+                | EngineColumnContainer::BinaryLargeObjects { .. } => Self::Binary,
+            EngineColumnContainer::BitsFixed { .. } | EngineColumnContainer::BitsVarying { .. } => Self::Bits,
+            EngineColumnContainer::Booleans(..) => Self::Boolean,
+            EngineColumnContainer::CharacterLargeObjects { .. }
+                | EngineColumnContainer::StringsVarying { .. } => Self::CharacterString,
+            EngineColumnContainer::Integers(..) => Self::Integer,
+        }
+    }
+
+    /// Checks whether values of this type can be compared with values of the
+    /// `other` type, which is when they are of the same type or both numeric.
+    pub fn is_comparable_with(&self, other: Self) -> bool {
+        *self == other || (self.is_numeric() && other.is_numeric())
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Self::Double | Self::Integer)
+    }
+}
+
+impl Display for EngineValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Binary => "BINARY VARYING",
+            Self::Bits => "BIT VARYING",
+            Self::Boolean => "BOOLEAN",
+            Self::CharacterString => "CHARACTER VARYING",
+            Self::Double => "DOUBLE PRECISION",
+            Self::Integer => "INTEGER",
+        })
+    }
+}
+
+/// The names an expression can refer to when it is compiled.
+#[derive(Copy, Clone)]
+pub struct CompileScope<'a> {
+    /// The name by which the table is referred to in the query, i.e. its
+    /// correlation name, or its table name when it has none. Column references
+    /// qualified by another name don't resolve.
+    pub table_name: Option<&'a Identifier>,
+
+    /// The columns of the table. Queries without a `FROM` clause have no
+    /// columns.
+    pub columns: &'a [EngineColumn],
+
+    /// The operator computing the window functions of the select list, of
+    /// which the values are appended to the row after the values of the
+    /// `columns`. This is [`None`] where window functions aren't allowed.
+    pub window_operator: Option<&'a WindowOperator<'a>>,
+}
+
+impl<'a> CompileScope<'a> {
+    /// A scope with only the `table_name` and `columns` of the table.
+    pub fn new(table_name: Option<&'a Identifier>, columns: &'a [EngineColumn]) -> Self {
+        Self {
+            table_name,
+            columns,
+            window_operator: None,
+        }
+    }
+}
+
+/// A value expression that is type-checked and of which the column references
+/// are bound to the columns of the table.
+#[derive(Clone, Debug)]
+pub struct CompiledExpression {
+    kind: CompiledExpressionKind,
+    value_type: EngineValueType,
+}
+
+#[derive(Clone, Debug)]
+enum CompiledExpressionKind {
+    And(Box<CompiledExpression>, Box<CompiledExpression>),
+
+    Between {
+        operand: Box<CompiledExpression>,
+        negated: bool,
+        symmetric: bool,
+        lower_bound: Box<CompiledExpression>,
+        upper_bound: Box<CompiledExpression>,
+    },
+
+    /// A `<searched case>`, or a `<simple case>` when the `operand` is set, of
+    /// which the `<when operand>`s are compared with the operand.
+    Case {
+        operand: Option<Box<CompiledExpression>>,
+        when_clauses: Vec<(CompiledExpression, CompiledExpression)>,
+        else_clause: Box<CompiledExpression>,
+    },
+
+    Cast {
+        operand: Box<CompiledExpression>,
+        target: CastTarget,
+        span: Span,
+    },
+
+    Coalesce(Vec<CompiledExpression>),
+
+    /// The value of the column at this index in the row.
+    Column(usize),
+
+    Comparison {
+        left_hand_side: Box<CompiledExpression>,
+        operator: ComparisonOperator,
+        right_hand_side: Box<CompiledExpression>,
+    },
+
+    Concatenation(Box<CompiledExpression>, Box<CompiledExpression>),
+
+    Constant(EngineRowColumnValue),
+
+    Distinct {
+        left_hand_side: Box<CompiledExpression>,
+        negated: bool,
+        right_hand_side: Box<CompiledExpression>,
+    },
+
+    DoubleArithmetic {
+        left_hand_side: Box<CompiledExpression>,
+        operator: NumericOperator,
+        right_hand_side: Box<CompiledExpression>,
+        span: Span,
+    },
+
+    DoubleNegation(Box<CompiledExpression>),
+
+    /// A call of a built-in function, of which the `arguments` are of the
+    /// types of the parameters of the `overload`.
+    Function {
+        overload: &'static Overload,
+        arguments: Vec<CompiledExpression>,
+        span: Span,
+    },
+
+    In {
+        operand: Box<CompiledExpression>,
+        negated: bool,
+        values: Vec<CompiledExpression>,
+    },
+
+    IntegerArithmetic {
+        left_hand_side: Box<CompiledExpression>,
+        operator: NumericOperator,
+        right_hand_side: Box<CompiledExpression>,
+        span: Span,
+    },
+
+    IntegerNegation(Box<CompiledExpression>, Span),
+
+    /// `<operand> IS [ NOT ] <truth value>`, where the truth value `UNKNOWN` is
+    /// represented by [`None`].
+    Is {
+        operand: Box<CompiledExpression>,
+        negated: bool,
+        truth_value: Option<bool>,
+    },
+
+    IsNull {
+        operand: Box<CompiledExpression>,
+        negated: bool,
+    },
+
+    Not(Box<CompiledExpression>),
+
+    NullIf(Box<CompiledExpression>, Box<CompiledExpression>),
+
+    Or(Box<CompiledExpression>, Box<CompiledExpression>),
+
+    /// `LIKE` and `SIMILAR TO`
+    PatternMatch {
+        operand: Box<CompiledExpression>,
+        negated: bool,
+        matcher: PatternMatcher,
+        span: Span,
+    },
+
+    QuantifiedComparison {
+        left_hand_side: Box<CompiledExpression>,
+        operator: ComparisonOperator,
+        quantifier: Quantifier,
+        values: Vec<CompiledExpression>,
+    },
+}
+
+/// The pattern of a `LIKE` or `SIMILAR TO` predicate.
+#[derive(Clone, Debug)]
+enum PatternMatcher {
+    /// The pattern and escape character are constant, so the pattern is
+    /// compiled once per query.
+    Compiled(Regex),
+
+    /// The pattern depends on the row, so it is compiled for every evaluation.
+    Dynamic {
+        kind: PatternKind,
+        pattern: Box<CompiledExpression>,
+        escape: Option<Box<CompiledExpression>>,
+    },
+}
+
+impl CompiledExpression {
+    /// Compiles the `expression`, resolving column references and window
+    /// functions against the `scope`.
+    pub fn compile(expression: &ValueExpression, scope: CompileScope<'_>) -> Result<Self, EngineResult> {
+        match expression {
+            ValueExpression::Boolean(boolean_expression) => Self::compile_boolean(expression, boolean_expression, scope),
+            ValueExpression::Case(case_expression) => Self::compile_case(expression, case_expression, scope),
+            ValueExpression::Cast(cast_specification) => Self::compile_cast(expression, cast_specification, scope),
+            ValueExpression::ColumnReference(column_reference) => Self::compile_column_reference(column_reference, scope),
+            ValueExpression::Numeric(numeric_expression) => Self::compile_numeric(expression, numeric_expression, scope),
+            ValueExpression::RoutineInvocation(routine_invocation) => Self::compile_routine_invocation(expression, routine_invocation, scope),
+            ValueExpression::SetFunctionSpecification(..) => Err(unsupported_expression(expression)),
+            ValueExpression::StringValueExpression(string_expression) => Self::compile_string(expression, string_expression, scope),
+            ValueExpression::WindowFunction(window_function) => Self::compile_window_function(window_function, scope),
+        }
+    }
+
+    fn compile_boolean(
+        expression: &ValueExpression,
+        boolean_expression: &BooleanExpression,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let kind = match boolean_expression {
+            BooleanExpression::And { left_hand_side, right_hand_side } => CompiledExpressionKind::And(
+                Box::new(Self::compile_operand(expression, "AND", left_hand_side, EngineValueType::Boolean, scope)?),
+                Box::new(Self::compile_operand(expression, "AND", right_hand_side, EngineValueType::Boolean, scope)?),
+            ),
+
+            BooleanExpression::Is { operand, negated, truth_value, .. } => CompiledExpressionKind::Is {
+                operand: Box::new(Self::compile_operand(expression, "IS", operand, EngineValueType::Boolean, scope)?),
+                negated: *negated,
+                truth_value: match truth_value {
+                    TruthValue::False => Some(false),
+                    TruthValue::True => Some(true),
+                    TruthValue::Unknown => None,
+                },
+            },
+
+            BooleanExpression::Literal(value, _) => CompiledExpressionKind::Constant(EngineRowColumnValue::Bool(*value)),
+
+            BooleanExpression::Not(operand, _) => CompiledExpressionKind::Not(
+                Box::new(Self::compile_operand(expression, "NOT", operand, EngineValueType::Boolean, scope)?),
+            ),
+
+            BooleanExpression::Or { left_hand_side, right_hand_side } => CompiledExpressionKind::Or(
+                Box::new(Self::compile_operand(expression, "OR", left_hand_side, EngineValueType::Boolean, scope)?),
+                Box::new(Self::compile_operand(expression, "OR", right_hand_side, EngineValueType::Boolean, scope)?),
+            ),
+
+            BooleanExpression::Predicate(predicate) => Self::compile_predicate(expression, predicate, scope)?,
+
+            BooleanExpression::Unknown(_) => CompiledExpressionKind::Constant(EngineRowColumnValue::Null),
+        };
+
+        Ok(Self { kind, value_type: EngineValueType::Boolean })
+    }
+
+    fn compile_case(
+        expression: &ValueExpression,
+        case_expression: &CaseExpression,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let compile_all = |values: &mut dyn Iterator<Item = &ValueExpression>| values
+            .map(|value| Self::compile(value, scope))
+            .collect::<Result<Vec<_>, _>>();
+
+        match case_expression {
+            CaseExpression::Coalesce(values, _) => {
+                let values = compile_all(&mut values.iter())?;
+                let value_type = common_type(expression, "COALESCE", values.iter().map(Some))?
+                    .expect("COALESCE should have at least one argument");
+
+                Ok(Self { kind: CompiledExpressionKind::Coalesce(values), value_type })
+            }
+
+            CaseExpression::NullIf { left_hand_side, right_hand_side, .. } => {
+                let left_hand_side = Self::compile(left_hand_side, scope)?;
+                let right_hand_side = Self::compile(right_hand_side, scope)?;
+                check_comparable(expression, "NULLIF", &left_hand_side, &[&right_hand_side])?;
+
+                Ok(Self {
+                    value_type: left_hand_side.value_type,
+                    kind: CompiledExpressionKind::NullIf(Box::new(left_hand_side), Box::new(right_hand_side)),
+                })
+            }
+
+            CaseExpression::Searched { when_clauses, else_clause, .. } => {
+                let conditions = when_clauses.iter()
+                    .map(|clause| Self::compile_search_condition("WHEN", &clause.search_condition, scope))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let results = when_clauses.iter().map(|clause| &clause.result);
+
+                Self::compile_case_results(expression, None, conditions, results, else_clause.as_deref(), scope)
+            }
+
+            CaseExpression::Simple { operand, when_clauses, else_clause, .. } => {
+                let operand = Self::compile(operand, scope)?;
+                let when_operands = compile_all(&mut when_clauses.iter().map(|clause| &clause.when_operand))?;
+                check_comparable(expression, "=", &operand, &when_operands.iter().collect::<Vec<_>>())?;
+                let results = when_clauses.iter().map(|clause| &clause.result);
+
+                Self::compile_case_results(expression, Some(operand), when_operands, results, else_clause.as_deref(), scope)
+            }
+        }
+    }
+
+    /// Compiles the `<result>`s of a `CASE`, which must all be of the same
+    /// type. The `NULL` results take the type of the other results.
+    fn compile_case_results<'a>(
+        expression: &ValueExpression,
+        operand: Option<Self>,
+        conditions: Vec<Self>,
+        results: impl Iterator<Item = &'a CaseResult>,
+        else_clause: Option<&'a CaseResult>,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let results = results
+            .map(Some)
+            .chain(std::iter::once(else_clause))
+            .map(|result| match result {
+                None | Some(CaseResult::Null(_)) => Ok(None),
+                Some(CaseResult::ValueExpression(result)) => Self::compile(result, scope).map(Some),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let Some(value_type) = common_type(expression, "CASE", results.iter().map(Option::as_ref))? else {
+            return Err(type_error(expression, "`CASE` needs at least one result that isn't NULL".to_owned()));
+        };
+
+        let mut results = results.into_iter().map(|result| result.unwrap_or(Self {
+            kind: CompiledExpressionKind::Constant(EngineRowColumnValue::Null),
+            value_type,
+        }));
+
+        let when_clauses = conditions.into_iter().zip(results.by_ref()).collect();
+        let else_clause = Box::new(results.next().unwrap());
+
+        Ok(Self {
+            kind: CompiledExpressionKind::Case {
+                operand: operand.map(Box::new),
+                when_clauses,
+                else_clause,
+            },
+            value_type,
+        })
+    }
+
+    fn compile_cast(
+        expression: &ValueExpression,
+        cast_specification: &CastSpecification,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let Some(container) = EngineColumnContainer::for_data_type(&cast_specification.target) else {
+            return Err(unsupported_expression(expression));
+        };
+
+        let target = CastTarget::of_container(&container);
+        let value_type = target.value_type();
+
+        let operand = match &cast_specification.operand {
+            CastOperand::Null => return Ok(Self {
+                kind: CompiledExpressionKind::Constant(EngineRowColumnValue::Null),
+                value_type,
+            }),
+            CastOperand::ValueExpression(operand) => Self::compile(operand, scope)?,
+        };
+
+        if !target.accepts(operand.value_type) {
+            return Err(type_error(expression, format!("can't cast {} to {value_type}", operand.value_type)));
+        }
+
+        Ok(Self {
+            kind: CompiledExpressionKind::Cast { operand: Box::new(operand), target, span: expression.span() },
+            value_type,
+        })
+    }
+
+    fn compile_predicate(
+        expression: &ValueExpression,
+        predicate: &Predicate,
+        scope: CompileScope<'_>,
+    ) -> Result<CompiledExpressionKind, EngineResult> {
+        let compile_all = |values: &[ValueExpression]| values.iter()
+            .map(|value| Self::compile(value, scope))
+            .collect::<Result<Vec<_>, _>>();
+
+        Ok(match predicate {
+            Predicate::Between(between) => {
+                let operand = Self::compile(&between.operand, scope)?;
+                let lower_bound = Self::compile(&between.lower_bound, scope)?;
+                let upper_bound = Self::compile(&between.upper_bound, scope)?;
+                check_comparable(expression, "BETWEEN", &operand, &[&lower_bound, &upper_bound])?;
+
+                CompiledExpressionKind::Between {
+                    operand: Box::new(operand),
+                    negated: between.negated,
+                    symmetric: between.symmetric,
+                    lower_bound: Box::new(lower_bound),
+                    upper_bound: Box::new(upper_bound),
+                }
+            }
+
+            Predicate::Comparison(comparison) => {
+                let left_hand_side = Self::compile(&comparison.left_hand_side, scope)?;
+                let right_hand_side = Self::compile(&comparison.right_hand_side, scope)?;
+                check_comparable(expression, comparison.operator.symbol(), &left_hand_side, &[&right_hand_side])?;
+
+                CompiledExpressionKind::Comparison {
+                    left_hand_side: Box::new(left_hand_side),
+                    operator: comparison.operator,
+                    right_hand_side: Box::new(right_hand_side),
+                }
+            }
+
+            Predicate::Distinct(distinct) => {
+                let left_hand_side = Self::compile(&distinct.left_hand_side, scope)?;
+                let right_hand_side = Self::compile(&distinct.right_hand_side, scope)?;
+                check_comparable(expression, "IS DISTINCT FROM", &left_hand_side, &[&right_hand_side])?;
+
+                CompiledExpressionKind::Distinct {
+                    left_hand_side: Box::new(left_hand_side),
+                    negated: distinct.negated,
+                    right_hand_side: Box::new(right_hand_side),
+                }
+            }
+
+            Predicate::In(in_predicate) => {
+                let operand = Self::compile(&in_predicate.operand, scope)?;
+                let values = compile_all(&in_predicate.values)?;
+                check_comparable(expression, "IN", &operand, &values.iter().collect::<Vec<_>>())?;
+
+                CompiledExpressionKind::In {
+                    operand: Box::new(operand),
+                    negated: in_predicate.negated,
+                    values,
+                }
+            }
+
+            Predicate::Like(like) => Self::compile_pattern_match(
+                expression, PatternKind::Like, &like.operand, like.negated, &like.pattern, like.escape.as_ref(), scope,
+            )?,
+
+            Predicate::Null(null) => CompiledExpressionKind::IsNull {
+                operand: Box::new(Self::compile(&null.operand, scope)?),
+                negated: null.negated,
+            },
+
+            Predicate::QuantifiedComparison(comparison) => {
+                let left_hand_side = Self::compile(&comparison.left_hand_side, scope)?;
+                let values = compile_all(&comparison.values)?;
+                check_comparable(expression, comparison.operator.symbol(), &left_hand_side, &values.iter().collect::<Vec<_>>())?;
+
+                CompiledExpressionKind::QuantifiedComparison {
+                    left_hand_side: Box::new(left_hand_side),
+                    operator: comparison.operator,
+                    quantifier: comparison.quantifier,
+                    values,
+                }
+            }
+
+            Predicate::Similar(similar) => Self::compile_pattern_match(
+                expression, PatternKind::Similar, &similar.operand, similar.negated, &similar.pattern, similar.escape.as_ref(), scope,
+            )?,
+        })
+    }
+
+    /// Compiles a `LIKE` or `SIMILAR TO` predicate. When the pattern and
+    /// escape character are constant, the pattern is compiled right away.
+    fn compile_pattern_match(
+        expression: &ValueExpression,
+        kind: PatternKind,
+        operand: &ValueExpression,
+        negated: bool,
+        pattern: &ValueExpression,
+        escape: Option<&ValueExpression>,
+        scope: CompileScope<'_>,
+    ) -> Result<CompiledExpressionKind, EngineResult> {
+        let operator = kind.to_string();
+        let operand = Self::compile_operand(expression, &operator, operand, EngineValueType::CharacterString, scope)?;
+        let pattern = Self::compile_operand(expression, &operator, pattern, EngineValueType::CharacterString, scope)?;
+        let escape = escape
+            .map(|escape| Self::compile_operand(expression, "ESCAPE", escape, EngineValueType::CharacterString, scope))
+            .transpose()?;
+
+        let constant_escape = match &escape {
+            None => Some(None),
+            Some(escape) => escape.constant_str().map(Some),
+        };
+
+        let matcher = match (pattern.constant_str(), constant_escape) {
+            (Some(pattern), Some(escape)) => PatternMatcher::Compiled(
+                kind.compile(pattern, escape).map_err(|result| locate_errors(result, expression.span()))?
+            ),
+            _ => PatternMatcher::Dynamic {
+                kind,
+                pattern: Box::new(pattern),
+                escape: escape.map(Box::new),
+            },
+        };
+
+        Ok(CompiledExpressionKind::PatternMatch {
+            operand: Box::new(operand),
+            negated,
+            matcher,
+            span: expression.span(),
+        })
+    }
+
+    fn compile_column_reference(column_reference: &ColumnReference, scope: CompileScope<'_>) -> Result<Self, EngineResult> {
+        let ColumnReference::BasicIdentifierChain(chain) = column_reference else {
+            return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error(format!("column not resolvable: {column_reference}").into())
+                    .at(column_reference.span())
+            ]));
+        };
+
+        let (name, qualifier) = chain.split_last().unwrap();
+        if !qualifier.is_empty() {
+            let is_table = match (qualifier, scope.table_name) {
+                ([qualifier], Some(table_name)) => qualifier.matches(table_name),
+                _ => false,
+            };
+
+            if !is_table {
+                let qualifier_span = qualifier[0].span().to(qualifier[qualifier.len() - 1].span());
+                let qualifier = qualifier.iter().join(".");
+                return Err(EngineResult::with_messages(vec![
+                    EngineMessage::Error(format!("Unknown table named \"{qualifier}\"").into())
+                        .at(qualifier_span)
+                ]));
+            }
+        }
+
+        let Some((column_index, column)) = scope.columns.iter()
+            .enumerate()
+            .find(|(_, column)| name.matches(&column.descriptor.name)) else {
+            return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error(format!("failed to find column: \"{name}\"").into())
+                    .at(column_reference.span())
+            ]));
+        };
+
+        Ok(Self {
+            kind: CompiledExpressionKind::Column(column_index),
+            value_type: EngineValueType::of_column(&column.values),
+        })
+    }
+
+    fn compile_numeric(
+        expression: &ValueExpression,
+        numeric_expression: &NumericValueExpression,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let kind = match numeric_expression {
+            // There is no `DECIMAL` type yet, so exact numeric literals with a
+            // fraction are `DOUBLE PRECISION` as well.
+            NumericValueExpression::ApproximateNumericLiteral(literal, _) | NumericValueExpression::ExactNumericLiteral(literal, _) => {
+                return Ok(Self {
+                    kind: CompiledExpressionKind::Constant(EngineRowColumnValue::F64(double_literal(expression, literal)?)),
+                    value_type: EngineValueType::Double,
+                });
+            }
+
+            NumericValueExpression::BinaryOperation { left_hand_side, operator, right_hand_side } => {
+                let left_hand_side = Self::compile(left_hand_side, scope)?;
+                let right_hand_side = Self::compile(right_hand_side, scope)?;
+
+                if left_hand_side.value_type == EngineValueType::Double || right_hand_side.value_type == EngineValueType::Double {
+                    return Ok(Self {
+                        kind: CompiledExpressionKind::DoubleArithmetic {
+                            left_hand_side: Box::new(left_hand_side.into_double(expression, operator.symbol())?),
+                            operator: *operator,
+                            right_hand_side: Box::new(right_hand_side.into_double(expression, operator.symbol())?),
+                            span: expression.span(),
+                        },
+                        value_type: EngineValueType::Double,
+                    });
+                }
+
+                CompiledExpressionKind::IntegerArithmetic {
+                    left_hand_side: Box::new(left_hand_side.expect_operand_type(expression, operator.symbol(), EngineValueType::Integer)?),
+                    operator: *operator,
+                    right_hand_side: Box::new(right_hand_side.expect_operand_type(expression, operator.symbol(), EngineValueType::Integer)?),
+                    span: expression.span(),
+                }
+            }
+
+            NumericValueExpression::Function(NumericValueFunction::Position { needle, haystack, .. }) => {
+                let arguments = vec![Self::compile(needle, scope)?, Self::compile(haystack, scope)?];
+                return Self::compile_built_in_call(expression, "POSITION", arguments);
+            }
+
+            NumericValueExpression::Negation(operand, _) => {
+                let operand = Self::compile(operand, scope)?;
+                if operand.value_type == EngineValueType::Double {
+                    return Ok(Self {
+                        kind: CompiledExpressionKind::DoubleNegation(Box::new(operand)),
+                        value_type: EngineValueType::Double,
+                    });
+                }
+
+                CompiledExpressionKind::IntegerNegation(
+                    Box::new(operand.expect_operand_type(expression, "-", EngineValueType::Integer)?),
+                    expression.span(),
+                )
+            }
+
+            NumericValueExpression::SimpleI64(value, _) => CompiledExpressionKind::Constant(
+                EngineRowColumnValue::I32(integer_literal(expression, *value)?)
+            ),
+
+            NumericValueExpression::SimpleU64(value, _) => CompiledExpressionKind::Constant(
+                EngineRowColumnValue::I32(integer_literal(expression, *value)?)
+            ),
+        };
+
+        Ok(Self { kind, value_type: EngineValueType::Integer })
+    }
+
+    /// Compiles a `<routine invocation>` of a built-in function.
+    fn compile_routine_invocation(
+        expression: &ValueExpression,
+        routine_invocation: &RoutineInvocation,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let name = &routine_invocation.name;
+        let Some(function) = Function::lookup(name) else {
+            let mut messages = vec![
+                EngineMessage::Error(format!("unknown function: {name}").into()).at(name.span)
+            ];
+
+            if let Some(suggestion) = Function::suggest(&name.value) {
+                messages.push(EngineMessage::Hint(format!("did you mean `{suggestion}`?").into()));
+            }
+
+            return Err(EngineResult::with_messages(messages));
+        };
+
+        let arguments = routine_invocation.arguments.iter()
+            .map(|argument| Self::compile(argument, scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::compile_call(expression, function, arguments)
+    }
+
+    /// Compiles a call of the built-in function called `name`, which is used
+    /// by the functions with keywords between their arguments.
+    fn compile_built_in_call(expression: &ValueExpression, name: &str, arguments: Vec<Self>) -> Result<Self, EngineResult> {
+        let function = Function::lookup(&Identifier::regular(name))
+            .expect("function should be in the registry");
+
+        Self::compile_call(expression, function, arguments)
+    }
+
+    /// Compiles a call of the `function`, choosing the overload for the types
+    /// of the `arguments`.
+    fn compile_call(expression: &ValueExpression, function: &Function, arguments: Vec<Self>) -> Result<Self, EngineResult> {
+        let argument_types = arguments.iter().map(|argument| argument.value_type).collect::<Vec<_>>();
+        let Some(overload) = function.resolve(&argument_types) else {
+            let mut result = type_error(
+                expression,
+                format!("`{}` doesn't accept arguments of type ({})", function.name, argument_types.iter().join(", ")),
+            );
+
+            result.messages.extend(function.overloads.iter().map(|overload| {
+                EngineMessage::Hint(format!("`{}` accepts {overload}", function.name).into())
+            }));
+
+            return Err(result);
+        };
+
+        let arguments = arguments.into_iter()
+            .zip(overload.parameters)
+            .map(|(argument, parameter)| match parameter {
+                EngineValueType::Double => argument.into_double(expression, function.name),
+                _ => Ok(argument),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            kind: CompiledExpressionKind::Function { overload, arguments, span: expression.span() },
+            value_type: overload.return_type,
+        })
+    }
+
+    fn compile_string(
+        expression: &ValueExpression,
+        string_expression: &StringValueExpression,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        Ok(match string_expression {
+            StringValueExpression::BinaryLiteral(literal, _) => Self {
+                kind: CompiledExpressionKind::Constant(EngineRowColumnValue::Binary(literal.clone())),
+                value_type: EngineValueType::Binary,
+            },
+
+            StringValueExpression::BitLiteral(literal, _) => Self {
+                kind: CompiledExpressionKind::Constant(EngineRowColumnValue::Bits(literal.iter().collect())),
+                value_type: EngineValueType::Bits,
+            },
+
+            StringValueExpression::Concatenation { left_hand_side, right_hand_side } => {
+                let left_hand_side = Self::compile(left_hand_side, scope)?;
+                let right_hand_side = Self::compile(right_hand_side, scope)?;
+
+                let value_type = left_hand_side.value_type;
+                let is_string_type = matches!(
+                    value_type,
+                    EngineValueType::Binary | EngineValueType::Bits | EngineValueType::CharacterString
+                );
+
+                if !is_string_type || right_hand_side.value_type != value_type {
+                    return Err(type_error(
+                        expression,
+                        format!(
+                            "can't concatenate {} with {} using `||`",
+                            left_hand_side.value_type,
+                            right_hand_side.value_type,
+                        ),
+                    ));
+                }
+
+                Self {
+                    kind: CompiledExpressionKind::Concatenation(Box::new(left_hand_side), Box::new(right_hand_side)),
+                    value_type,
+                }
+            }
+
+            StringValueExpression::Function(function) => Self::compile_string_function(expression, function, scope)?,
+
+            StringValueExpression::Literal(literal, _, _) => Self {
+                kind: CompiledExpressionKind::Constant(EngineRowColumnValue::String(literal.clone())),
+                value_type: EngineValueType::CharacterString,
+            },
+        })
+    }
+
+    /// Compiles a `<string value function>` as a call of the built-in
+    /// function with positional arguments.
+    fn compile_string_function(
+        expression: &ValueExpression,
+        function: &StringValueFunction,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let compile_all = |values: &[Option<&ValueExpression>]| values.iter()
+            .flatten()
+            .map(|value| Self::compile(value, scope))
+            .collect::<Result<Vec<_>, _>>();
+
+        match function {
+            StringValueFunction::Overlay { operand, placing, start_position, string_length, .. } => Self::compile_built_in_call(
+                expression,
+                "OVERLAY",
+                compile_all(&[Some(operand), Some(placing), Some(start_position), string_length.as_deref()])?,
+            ),
+
+            StringValueFunction::Substring { operand, start_position, string_length, .. } => Self::compile_built_in_call(
+                expression,
+                "SUBSTRING",
+                compile_all(&[Some(operand), Some(start_position), string_length.as_deref()])?,
+            ),
+
+            StringValueFunction::Trim { specification, character, source, .. } => Self::compile_built_in_call(
+                expression,
+                match specification {
+                    TrimSpecification::Both => "BTRIM",
+                    TrimSpecification::Leading => "LTRIM",
+                    TrimSpecification::Trailing => "RTRIM",
+                },
+                compile_all(&[Some(source), character.as_deref()])?,
+            ),
+        }
+    }
+
+    /// Compiles the operand of the `operator` in `expression`, which must be
+    /// of the `expected_type`.
+    fn compile_operand(
+        expression: &ValueExpression,
+        operator: &str,
+        operand: &ValueExpression,
+        expected_type: EngineValueType,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        Self::compile(operand, scope)?.expect_operand_type(expression, operator, expected_type)
+    }
+
+    /// Checks that this operand of the `operator` in `expression` is of the
+    /// `expected_type`.
+    fn expect_operand_type(self, expression: &ValueExpression, operator: &str, expected_type: EngineValueType) -> Result<Self, EngineResult> {
+        if self.value_type != expected_type {
+            return Err(type_error(
+                expression,
+                format!("operator `{operator}` expects an operand of type {expected_type}, but got {}", self.value_type),
+            ));
+        }
+
+        Ok(self)
+    }
+
+    /// Converts this numeric operand of the `operator` in `expression` to
+    /// `DOUBLE PRECISION`, e.g. for `1 + 0.5`.
+    fn into_double(self, expression: &ValueExpression, operator: &str) -> Result<Self, EngineResult> {
+        if self.value_type != EngineValueType::Integer {
+            return self.expect_operand_type(expression, operator, EngineValueType::Double);
+        }
+
+        Ok(Self {
+            kind: CompiledExpressionKind::Cast { operand: Box::new(self), target: CastTarget::Double, span: expression.span() },
+            value_type: EngineValueType::Double,
+        })
+    }
+
+    /// Binds the `window_function` to its value, which is computed by the
+    /// window operator before the select list is evaluated.
+    fn compile_window_function(window_function: &WindowFunction, scope: CompileScope<'_>) -> Result<Self, EngineResult> {
+        let Some(window_operator) = scope.window_operator else {
+            return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error("window functions are not allowed here".into()).at(window_function.span),
+                EngineMessage::Hint("Window functions can only be used in the select list, and can't be nested.".into()),
+            ]));
+        };
+
+        let (slot, value_type) = window_operator.bind(window_function)?;
+        Ok(Self {
+            kind: CompiledExpressionKind::Column(scope.columns.len() + slot),
+            value_type,
+        })
+    }
+
+    /// Compiles the `<search condition>` of e.g. a `WHERE` clause, which must
+    /// be a boolean value expression.
+    pub fn compile_search_condition(
+        clause_name: &str,
+        search_condition: &ValueExpression,
+        scope: CompileScope<'_>,
+    ) -> Result<Self, EngineResult> {
+        let condition = Self::compile(search_condition, scope)?;
+        if condition.value_type != EngineValueType::Boolean {
+            return Err(type_error(
+                search_condition,
+                format!("{clause_name} clause expects a condition of type BOOLEAN, but got {}", condition.value_type),
+            ));
+        }
+
+        Ok(condition)
+    }
+
+    /// Returns the string if this expression is a constant character string.
+    fn constant_str(&self) -> Option<&str> {
+        match &self.kind {
+            CompiledExpressionKind::Constant(EngineRowColumnValue::String(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn value_type(&self) -> EngineValueType {
+        self.value_type
+    }
+
+    /// Returns the index of the column if this expression is a plain column
+    /// reference.
+    pub fn column_index(&self) -> Option<usize> {
+        match self.kind {
+            CompiledExpressionKind::Column(column_index) => Some(column_index),
+            _ => None,
+        }
+    }
+
+    /// Evaluates this expression for the given `row`, which contains the
+    /// values of all the columns of the table.
+    pub fn evaluate(&self, row: &EngineRow) -> Result<EngineRowColumnValue, EngineResult> {
+        Ok(match &self.kind {
+            CompiledExpressionKind::And(left_hand_side, right_hand_side) => {
+                let left_hand_side = left_hand_side.evaluate_boolean(row)?;
+                if left_hand_side == Some(false) {
+                    return Ok(EngineRowColumnValue::Bool(false));
+                }
+
+                truth_value(and(left_hand_side, right_hand_side.evaluate_boolean(row)?))
+            }
+
+            CompiledExpressionKind::Case { operand, when_clauses, else_clause } => {
+                let operand = operand.as_ref().map(|operand| operand.evaluate(row)).transpose()?;
+
+                for (condition, result) in when_clauses {
+                    let is_match = match &operand {
+                        Some(operand) => compare(ComparisonOperator::EqualTo, operand, &condition.evaluate(row)?),
+                        None => condition.evaluate_boolean(row)?,
+                    };
+
+                    if is_match == Some(true) {
+                        return result.evaluate(row);
+                    }
+                }
+
+                else_clause.evaluate(row)?
+            }
+
+            CompiledExpressionKind::Cast { operand, target, span } => {
+                target.cast(operand.evaluate(row)?).map_err(|result| locate_errors(result, *span))?
+            }
+
+            CompiledExpressionKind::Coalesce(values) => {
+                for value in values {
+                    let value = value.evaluate(row)?;
+                    if value != EngineRowColumnValue::Null {
+                        return Ok(value);
+                    }
+                }
+
+                EngineRowColumnValue::Null
+            }
+
+            CompiledExpressionKind::Column(column_index) => row.values[*column_index].clone(),
+
+            CompiledExpressionKind::Between { operand, negated, symmetric, lower_bound, upper_bound } => {
+                let operand = operand.evaluate(row)?;
+                let lower_bound = lower_bound.evaluate(row)?;
+                let upper_bound = upper_bound.evaluate(row)?;
+
+                let is_between = |lower_bound, upper_bound| and(
+                    compare(ComparisonOperator::GreaterThanOrEqualTo, &operand, lower_bound),
+                    compare(ComparisonOperator::LessThanOrEqualTo, &operand, upper_bound),
+                );
+
+                let mut result = is_between(&lower_bound, &upper_bound);
+                if *symmetric {
+                    result = or(result, is_between(&upper_bound, &lower_bound));
+                }
+
+                truth_value(negate(result, *negated))
+            }
+
+            CompiledExpressionKind::Comparison { left_hand_side, operator, right_hand_side } => {
+                truth_value(compare(*operator, &left_hand_side.evaluate(row)?, &right_hand_side.evaluate(row)?))
+            }
+
+            CompiledExpressionKind::Concatenation(left_hand_side, right_hand_side) => {
+                match (left_hand_side.evaluate(row)?, right_hand_side.evaluate(row)?) {
+                    (EngineRowColumnValue::Null, _) | (_, EngineRowColumnValue::Null) => EngineRowColumnValue::Null,
+                    (left_hand_side, right_hand_side) => concatenate_values(left_hand_side, right_hand_side),
+                }
+            }
+
+            CompiledExpressionKind::Constant(value) => value.clone(),
+
+            CompiledExpressionKind::Distinct { left_hand_side, negated, right_hand_side } => {
+                let is_distinct = match (left_hand_side.evaluate(row)?, right_hand_side.evaluate(row)?) {
+                    (EngineRowColumnValue::Null, EngineRowColumnValue::Null) => false,
+                    (EngineRowColumnValue::Null, _) | (_, EngineRowColumnValue::Null) => true,
+                    (left_hand_side, right_hand_side) => compare_values(&left_hand_side, &right_hand_side) != Ordering::Equal,
+                };
+
+                EngineRowColumnValue::Bool(is_distinct != *negated)
+            }
+
+            CompiledExpressionKind::DoubleArithmetic { left_hand_side, operator, right_hand_side, span } => {
+                let (Some(left_hand_side), Some(right_hand_side)) = (
+                    left_hand_side.evaluate_double(row)?,
+                    right_hand_side.evaluate_double(row)?,
+                ) else {
+                    return Ok(EngineRowColumnValue::Null);
+                };
+
+                if *operator == NumericOperator::Division && right_hand_side == 0.0 {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error("division by zero".into()).at(*span)
+                    ]));
+                }
+
+                let result = match operator {
+                    NumericOperator::Addition => left_hand_side + right_hand_side,
+                    NumericOperator::Division => left_hand_side / right_hand_side,
+                    NumericOperator::Multiplication => left_hand_side * right_hand_side,
+                    NumericOperator::Subtraction => left_hand_side - right_hand_side,
+                };
+
+                if !result.is_finite() {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!(
+                            "double precision out of range: {} {} {}",
+                            approximate_numeric_literal(left_hand_side),
+                            operator.symbol(),
+                            approximate_numeric_literal(right_hand_side),
+                        ).into()).at(*span)
+                    ]));
+                }
+
+                EngineRowColumnValue::F64(result)
+            }
+
+            CompiledExpressionKind::DoubleNegation(operand) => match operand.evaluate_double(row)? {
+                Some(operand) => EngineRowColumnValue::F64(-operand),
+                None => EngineRowColumnValue::Null,
+            },
+
+            CompiledExpressionKind::Function { overload, arguments, span } => {
+                let arguments = arguments.iter()
+                    .map(|argument| argument.evaluate(row))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                overload.call(&arguments).map_err(|result| locate_errors(result, *span))?
+            }
+
+            CompiledExpressionKind::In { operand, negated, values } => {
+                let operand = operand.evaluate(row)?;
+                let result = any(values, |value| Ok(compare(ComparisonOperator::EqualTo, &operand, &value.evaluate(row)?)))?;
+                truth_value(negate(result, *negated))
+            }
+
+            CompiledExpressionKind::IntegerArithmetic { left_hand_side, operator, right_hand_side, span } => {
+                let (Some(left_hand_side), Some(right_hand_side)) = (
+                    left_hand_side.evaluate_integer(row)?,
+                    right_hand_side.evaluate_integer(row)?,
+                ) else {
+                    return Ok(EngineRowColumnValue::Null);
+                };
+
+                if *operator == NumericOperator::Division && right_hand_side == 0 {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error("division by zero".into()).at(*span)
+                    ]));
+                }
+
+                let result = match operator {
+                    NumericOperator::Addition => left_hand_side.checked_add(right_hand_side),
+                    NumericOperator::Division => left_hand_side.checked_div(right_hand_side),
+                    NumericOperator::Multiplication => left_hand_side.checked_mul(right_hand_side),
+                    NumericOperator::Subtraction => left_hand_side.checked_sub(right_hand_side),
+                };
+
+                EngineRowColumnValue::I32(result.ok_or_else(|| integer_out_of_range(
+                    format!("{left_hand_side} {} {right_hand_side}", operator.symbol()),
+                    *span,
+                ))?)
+            }
+
+            CompiledExpressionKind::IntegerNegation(operand, span) => {
+                let Some(operand) = operand.evaluate_integer(row)? else {
+                    return Ok(EngineRowColumnValue::Null);
+                };
+
+                EngineRowColumnValue::I32(operand.checked_neg().ok_or_else(|| integer_out_of_range(format!("-({operand})"), *span))?)
+            }
+
+            CompiledExpressionKind::Is { operand, negated, truth_value } => {
+                EngineRowColumnValue::Bool((operand.evaluate_boolean(row)? == *truth_value) != *negated)
+            }
+
+            CompiledExpressionKind::IsNull { operand, negated } => {
+                EngineRowColumnValue::Bool((operand.evaluate(row)? == EngineRowColumnValue::Null) != *negated)
+            }
+
+            CompiledExpressionKind::Not(operand) => truth_value(negate(operand.evaluate_boolean(row)?, true)),
+
+            CompiledExpressionKind::NullIf(left_hand_side, right_hand_side) => {
+                let left_hand_side = left_hand_side.evaluate(row)?;
+                if compare(ComparisonOperator::EqualTo, &left_hand_side, &right_hand_side.evaluate(row)?) == Some(true) {
+                    return Ok(EngineRowColumnValue::Null);
+                }
+
+                left_hand_side
+            }
+
+            CompiledExpressionKind::Or(left_hand_side, right_hand_side) => {
+                let left_hand_side = left_hand_side.evaluate_boolean(row)?;
+                if left_hand_side == Some(true) {
+                    return Ok(EngineRowColumnValue::Bool(true));
+                }
+
+                truth_value(or(left_hand_side, right_hand_side.evaluate_boolean(row)?))
+            }
+
+            CompiledExpressionKind::PatternMatch { operand, negated, matcher, span } => {
+                let operand = operand.evaluate(row)?;
+                if operand == EngineRowColumnValue::Null {
+                    return Ok(EngineRowColumnValue::Null);
+                }
+
+                let is_match = match matcher {
+                    PatternMatcher::Compiled(regex) => regex.is_match(as_str(&operand)),
+                    PatternMatcher::Dynamic { kind, pattern, escape } => {
+                        let pattern = pattern.evaluate(row)?;
+                        let escape = escape.as_ref().map(|escape| escape.evaluate(row)).transpose()?;
+                        if pattern == EngineRowColumnValue::Null || escape == Some(EngineRowColumnValue::Null) {
+                            return Ok(EngineRowColumnValue::Null);
+                        }
+
+                        kind.compile(as_str(&pattern), escape.as_ref().map(as_str))
+                            .map_err(|result| locate_errors(result, *span))?
+                            .is_match(as_str(&operand))
+                    }
+                };
+
+                EngineRowColumnValue::Bool(is_match != *negated)
+            }
+
+            CompiledExpressionKind::QuantifiedComparison { left_hand_side, operator, quantifier, values } => {
+                let left_hand_side = left_hand_side.evaluate(row)?;
+                let compare_with = |value: &CompiledExpression| Ok(compare(*operator, &left_hand_side, &value.evaluate(row)?));
+
+                truth_value(match quantifier {
+                    Quantifier::All => all(values, compare_with)?,
+                    Quantifier::Any => any(values, compare_with)?,
+                })
+            }
+        })
+    }
+
+    /// Evaluates this search condition for the given `row`. Only rows for
+    /// which the condition is `TRUE` qualify, i.e. not when it is `UNKNOWN`.
+    pub fn evaluate_search_condition(&self, row: &EngineRow) -> Result<bool, EngineResult> {
+        Ok(self.evaluate_boolean(row)? == Some(true))
+    }
+
+    /// Evaluates this boolean expression, where `UNKNOWN` is [`None`].
+    fn evaluate_boolean(&self, row: &EngineRow) -> Result<Option<bool>, EngineResult> {
+        match self.evaluate(row)? {
+            EngineRowColumnValue::Bool(value) => Ok(Some(value)),
+            EngineRowColumnValue::Null => Ok(None),
+            value => unreachable!("type-checked as BOOLEAN, but evaluated to {value:?}"),
+        }
+    }
+
+    /// Evaluates this `DOUBLE PRECISION` expression, where the null value is
+    /// [`None`].
+    fn evaluate_double(&self, row: &EngineRow) -> Result<Option<f64>, EngineResult> {
+        match self.evaluate(row)? {
+            EngineRowColumnValue::F64(value) => Ok(Some(value)),
+            EngineRowColumnValue::Null => Ok(None),
+            value => unreachable!("type-checked as DOUBLE PRECISION, but evaluated to {value:?}"),
+        }
+    }
+
+    /// Evaluates this integer expression, where the null value is [`None`].
+    fn evaluate_integer(&self, row: &EngineRow) -> Result<Option<i32>, EngineResult> {
+        match self.evaluate(row)? {
+            EngineRowColumnValue::I32(value) => Ok(Some(value)),
+            EngineRowColumnValue::Null => Ok(None),
+            value => unreachable!("type-checked as INTEGER, but evaluated to {value:?}"),
+        }
+    }
+}
+
+/// Converts a truth value to a boolean value, where `UNKNOWN` is [`None`].
+fn truth_value(value: Option<bool>) -> EngineRowColumnValue {
+    value.map_or(EngineRowColumnValue::Null, EngineRowColumnValue::Bool)
+}
+
+/// The `AND` of two truth values, where `UNKNOWN` is [`None`].
+fn and(left_hand_side: Option<bool>, right_hand_side: Option<bool>) -> Option<bool> {
+    match (left_hand_side, right_hand_side) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+/// The `OR` of two truth values, where `UNKNOWN` is [`None`].
+fn or(left_hand_side: Option<bool>, right_hand_side: Option<bool>) -> Option<bool> {
+    negate(and(negate(left_hand_side, true), negate(right_hand_side, true)), true)
+}
+
+/// Negates the truth value if `negated` is set, where `UNKNOWN` stays
+/// `UNKNOWN`.
+fn negate(value: Option<bool>, negated: bool) -> Option<bool> {
+    value.map(|value| value != negated)
+}
+
+/// Checks whether the `predicate` holds for all `values`, which is `UNKNOWN`
+/// when it is `UNKNOWN` for some value and `FALSE` for none.
+fn all<F>(values: &[CompiledExpression], mut predicate: F) -> Result<Option<bool>, EngineResult>
+        where F: FnMut(&CompiledExpression) -> Result<Option<bool>, EngineResult> {
+    let mut result = Some(true);
+    for value in values {
+        result = and(result, predicate(value)?);
+        if result == Some(false) {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Checks whether the `predicate` holds for any of the `values`, which is
+/// `UNKNOWN` when it is `UNKNOWN` for some value and `TRUE` for none.
+fn any<F>(values: &[CompiledExpression], mut predicate: F) -> Result<Option<bool>, EngineResult>
+        where F: FnMut(&CompiledExpression) -> Result<Option<bool>, EngineResult> {
+    let mut result = Some(false);
+    for value in values {
+        result = or(result, predicate(value)?);
+        if result == Some(true) {
+            break;
+        }
+    }
+
+    Ok(result)
+}
+
+/// Compares two values of the same type using the `operator`, which is
+/// `UNKNOWN` when either of them is null.
+fn compare(
+    operator: ComparisonOperator,
+    left_hand_side: &EngineRowColumnValue,
+    right_hand_side: &EngineRowColumnValue,
+) -> Option<bool> {
+    if *left_hand_side == EngineRowColumnValue::Null || *right_hand_side == EngineRowColumnValue::Null {
+        return None;
+    }
+
+    let ordering = compare_values(left_hand_side, right_hand_side);
+    Some(match operator {
+        ComparisonOperator::LessThan => ordering == Ordering::Less,
+        ComparisonOperator::LessThanOrEqualTo => ordering != Ordering::Greater,
+        ComparisonOperator::GreaterThan => ordering == Ordering::Greater,
+        ComparisonOperator::GreaterThanOrEqualTo => ordering != Ordering::Less,
+        ComparisonOperator::EqualTo => ordering == Ordering::Equal,
+        ComparisonOperator::NotEqualTo => ordering != Ordering::Equal,
+    })
+}
+
+/// Compares two values of comparable types, see
+/// [`EngineValueType::is_comparable_with`].
+fn compare_values(left_hand_side: &EngineRowColumnValue, right_hand_side: &EngineRowColumnValue) -> Ordering {
+    use EngineRowColumnValue as Value;
+
+    // Double values are never NaN, so they are always ordered.
+    let compare_doubles = |left_hand_side: f64, right_hand_side: f64| left_hand_side.partial_cmp(&right_hand_side)
+        .expect("double values should never be NaN");
+
+    match (left_hand_side, right_hand_side) {
+        (Value::Bool(left_hand_side), Value::Bool(right_hand_side)) => left_hand_side.cmp(right_hand_side),
+        (Value::Bits(left_hand_side), Value::Bits(right_hand_side)) => left_hand_side.cmp(right_hand_side),
+        (Value::F64(left_hand_side), Value::F64(right_hand_side)) => compare_doubles(*left_hand_side, *right_hand_side),
+        (Value::F64(left_hand_side), Value::I32(right_hand_side)) => compare_doubles(*left_hand_side, (*right_hand_side).into()),
+        (Value::I32(left_hand_side), Value::F64(right_hand_side)) => compare_doubles((*left_hand_side).into(), *right_hand_side),
+        (Value::I32(left_hand_side), Value::I32(right_hand_side)) => left_hand_side.cmp(right_hand_side),
+        _ => match (as_binary(left_hand_side), as_binary(right_hand_side)) {
+            (Some(left_hand_side), Some(right_hand_side)) => left_hand_side.cmp(right_hand_side),
+            _ => unreachable!("type-checked as comparable, but got {left_hand_side:?} and {right_hand_side:?}"),
+        },
+    }
+}
+
+/// Concatenates two string values of the same type.
+fn concatenate_values(left_hand_side: EngineRowColumnValue, right_hand_side: EngineRowColumnValue) -> EngineRowColumnValue {
+    use EngineRowColumnValue as Value;
+
+    match (left_hand_side, right_hand_side) {
+        (Value::Bits(mut left_hand_side), Value::Bits(right_hand_side)) => {
+            left_hand_side.extend_from_bitslice(&right_hand_side);
+            Value::Bits(left_hand_side)
+        }
+
+        (left_hand_side @ (Value::String(..) | Value::CharacterLargeObject(..)), right_hand_side) => {
+            let mut result = as_str(&left_hand_side).to_owned();
+            result.push_str(as_str(&right_hand_side));
+            Value::String(result)
+        }
+
+        (left_hand_side, right_hand_side) => {
+            let mut result = as_binary(&left_hand_side).unwrap().to_vec();
+            result.extend_from_slice(as_binary(&right_hand_side).unwrap());
+            Value::Binary(result)
+        }
+    }
+}
+
+/// Returns the contents of a character string value.
+pub fn as_str(value: &EngineRowColumnValue) -> &str {
+    match value {
+        EngineRowColumnValue::CharacterLargeObject(value) => value,
+        EngineRowColumnValue::String(value) => value,
+        _ => unreachable!("type-checked as CHARACTER VARYING, but got {value:?}"),
+    }
+}
+
+/// Returns the bytes of a binary or character string value, which are ordered
+/// the same as the values themselves.
+fn as_binary(value: &EngineRowColumnValue) -> Option<&[u8]> {
+    match value {
+        EngineRowColumnValue::Binary(value) => Some(value),
+        EngineRowColumnValue::BinaryLargeObject(value) => Some(value),
+        EngineRowColumnValue::CharacterLargeObject(value) => Some(value.as_bytes()),
+        EngineRowColumnValue::String(value) => Some(value.as_bytes()),
+        _ => None,
+    }
+}
+
+/// Converts an integer literal to an `INTEGER` value.
+fn integer_literal<T>(expression: &ValueExpression, value: T) -> Result<i32, EngineResult>
+        where T: Copy + Display + TryInto<i32> {
+    value.try_into().map_err(|_| EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("value {value} is out of range for type INTEGER").into()).at(expression.span()),
+        EngineMessage::Informational(format!("in expression: {expression}").into()),
+    ]))
+}
+
+/// Converts a numeric literal with a fraction or exponent to a
+/// `DOUBLE PRECISION` value.
+fn double_literal(expression: &ValueExpression, literal: &str) -> Result<f64, EngineResult> {
+    match literal.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(EngineResult::with_messages(vec![
+            EngineMessage::Error(format!("value {literal} is out of range for type DOUBLE PRECISION").into()).at(expression.span()),
+            EngineMessage::Informational(format!("in expression: {expression}").into()),
+        ])),
+    }
+}
+
+/// Attaches the `span` to the errors of the `result` that aren't located yet,
+/// e.g. the errors of a function, which doesn't know where it was called.
+fn locate_errors(mut result: EngineResult, span: Span) -> EngineResult {
+    for message in &mut result.messages {
+        if let EngineMessage::Error(error) = message {
+            *message = EngineMessage::Error(std::mem::take(error)).at(span);
+        }
+    }
+
+    result
+}
+
+fn integer_out_of_range(operation: String, span: Span) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("integer out of range: {operation}").into()).at(span)
+    ])
+}
+
+/// Writes the `value` as an `<approximate numeric literal>`, e.g. `1E308`,
+/// instead of its full decimal expansion.
+fn approximate_numeric_literal(value: f64) -> String {
+    format!("{value:E}")
+}
+
+/// Checks that the `operand` can be compared with each of the `values` using
+/// the `operator`, i.e. that they are of the same type or both numeric.
+fn check_comparable(
+    expression: &ValueExpression,
+    operator: &str,
+    operand: &CompiledExpression,
+    values: &[&CompiledExpression],
+) -> Result<(), EngineResult> {
+    match values.iter().find(|value| !value.value_type.is_comparable_with(operand.value_type)) {
+        Some(value) => Err(type_error(
+            expression,
+            format!("can't compare {} with {} using `{operator}`", operand.value_type, value.value_type),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Determines the type of the `values` of e.g. `COALESCE`, which must all be of
+/// the same type. Values that are [`None`] are `NULL`, which takes any type,
+/// and the type is [`None`] when all values are.
+fn common_type<'a>(
+    expression: &ValueExpression,
+    operator: &str,
+    mut values: impl Iterator<Item = Option<&'a CompiledExpression>>,
+) -> Result<Option<EngineValueType>, EngineResult> {
+    let mut value_type = None;
+    values.try_for_each(|value| match (value_type, value) {
+        (Some(value_type), Some(value)) if value.value_type != value_type => Err(type_error(
+            expression,
+            format!("`{operator}` expects values of the same type, but got {value_type} and {}", value.value_type),
+        )),
+        (None, Some(value)) => {
+            value_type = Some(value.value_type);
+            Ok(())
+        }
+        _ => Ok(()),
+    })?;
+
+    Ok(value_type)
+}
+
+pub fn type_error(expression: &ValueExpression, message: String) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("type error: {message}").into()).at(expression.span()),
+        EngineMessage::Informational(format!("in expression: {expression}").into()),
+    ])
+}
+
+fn unsupported_expression(expression: &ValueExpression) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("This expression is not yet supported by the engine: {expression}").into())
+            .at(expression.span()),
+        EngineMessage::Help("You can create an issue at: https://github.com/usadson/raccolta/issues/new?template=bug_report.md".into()),
+    ])
+}
@@ -0,0 +1,439 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the registry of the built-in scalar functions, e.g.
+//! `UPPER` and `MOD`. A function has one or more overloads with typed
+//! parameters, of which the one matching the types of the arguments is
+//! chosen when the expression is compiled.
+//!
+//! The functions with keywords between their arguments, e.g.
+//! `SUBSTRING(s FROM 2 FOR 3)`, are compiled as calls of the functions in
+//! this registry with positional arguments, e.g. `SUBSTRING(s, 2, 3)`.
+//!
+//! All functions return the null value when any of their arguments is null,
+//! so the implementations only receive values that aren't null.
+
+use std::fmt::Display;
+
+use raccolta_syntax::common::Identifier;
+
+use crate::{
+    EngineMessage,
+    EngineResult,
+    EngineRowColumnValue,
+    expression::{
+        as_str,
+        EngineValueType,
+    },
+};
+
+use EngineValueType::{
+    Binary,
+    CharacterString,
+    Double,
+    Integer,
+};
+
+/// A built-in scalar function.
+#[derive(Debug)]
+pub struct Function {
+    pub name: &'static str,
+    pub overloads: &'static [Overload],
+}
+
+/// A signature of a [`Function`], with the implementation for arguments of
+/// exactly these types.
+#[derive(Debug)]
+pub struct Overload {
+    pub parameters: &'static [EngineValueType],
+    pub return_type: EngineValueType,
+    pub implementation: fn(&[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult>,
+}
+
+/// The built-in scalar functions, ordered by name.
+static FUNCTIONS: &[Function] = &[
+    Function {
+        name: "ABS",
+        overloads: &[
+            Overload { parameters: &[Integer], return_type: Integer, implementation: abs_integer },
+            Overload { parameters: &[Double], return_type: Double, implementation: |arguments| double(as_f64(&arguments[0]).abs()) },
+        ],
+    },
+    Function {
+        name: "BTRIM",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, true, true) },
+            Overload { parameters: &[CharacterString, CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, true, true) },
+        ],
+    },
+    Function {
+        name: "CEIL",
+        overloads: CEILING_OVERLOADS,
+    },
+    Function {
+        name: "CEILING",
+        overloads: CEILING_OVERLOADS,
+    },
+    Function {
+        name: "CHARACTER_LENGTH",
+        overloads: CHARACTER_LENGTH_OVERLOADS,
+    },
+    Function {
+        name: "CHAR_LENGTH",
+        overloads: CHARACTER_LENGTH_OVERLOADS,
+    },
+    Function {
+        name: "EXP",
+        overloads: &[
+            Overload { parameters: &[Double], return_type: Double, implementation: |arguments| double(as_f64(&arguments[0]).exp()) },
+        ],
+    },
+    Function {
+        name: "FLOOR",
+        overloads: &[
+            Overload { parameters: &[Integer], return_type: Integer, implementation: |arguments| Ok(arguments[0].clone()) },
+            Overload { parameters: &[Double], return_type: Double, implementation: |arguments| double(as_f64(&arguments[0]).floor()) },
+        ],
+    },
+    Function {
+        name: "LN",
+        overloads: &[
+            Overload { parameters: &[Double], return_type: Double, implementation: |arguments| logarithm(as_f64(&arguments[0]), f64::ln) },
+        ],
+    },
+    Function {
+        name: "LOG10",
+        overloads: &[
+            Overload { parameters: &[Double], return_type: Double, implementation: |arguments| logarithm(as_f64(&arguments[0]), f64::log10) },
+        ],
+    },
+    Function {
+        name: "LOWER",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: CharacterString, implementation: |arguments| Ok(EngineRowColumnValue::String(as_str(&arguments[0]).to_lowercase())) },
+        ],
+    },
+    Function {
+        name: "LTRIM",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, true, false) },
+            Overload { parameters: &[CharacterString, CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, true, false) },
+        ],
+    },
+    Function {
+        name: "MOD",
+        overloads: &[
+            Overload { parameters: &[Integer, Integer], return_type: Integer, implementation: modulo },
+        ],
+    },
+    Function {
+        name: "OCTET_LENGTH",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: Integer, implementation: |arguments| length(as_str(&arguments[0]).len()) },
+            Overload { parameters: &[Binary], return_type: Integer, implementation: |arguments| length(as_bytes(&arguments[0]).len()) },
+        ],
+    },
+    Function {
+        name: "OVERLAY",
+        overloads: &[
+            Overload { parameters: &[CharacterString, CharacterString, Integer], return_type: CharacterString, implementation: overlay },
+            Overload { parameters: &[CharacterString, CharacterString, Integer, Integer], return_type: CharacterString, implementation: overlay },
+        ],
+    },
+    Function {
+        name: "POSITION",
+        overloads: &[
+            Overload { parameters: &[CharacterString, CharacterString], return_type: Integer, implementation: position },
+        ],
+    },
+    Function {
+        name: "POWER",
+        overloads: &[
+            Overload { parameters: &[Double, Double], return_type: Double, implementation: power },
+        ],
+    },
+    Function {
+        name: "RTRIM",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, false, true) },
+            Overload { parameters: &[CharacterString, CharacterString], return_type: CharacterString, implementation: |arguments| trim(arguments, false, true) },
+        ],
+    },
+    Function {
+        name: "SQRT",
+        overloads: &[
+            Overload { parameters: &[Double], return_type: Double, implementation: square_root },
+        ],
+    },
+    Function {
+        name: "SUBSTRING",
+        overloads: &[
+            Overload { parameters: &[CharacterString, Integer], return_type: CharacterString, implementation: substring },
+            Overload { parameters: &[CharacterString, Integer, Integer], return_type: CharacterString, implementation: substring },
+        ],
+    },
+    Function {
+        name: "UPPER",
+        overloads: &[
+            Overload { parameters: &[CharacterString], return_type: CharacterString, implementation: |arguments| Ok(EngineRowColumnValue::String(as_str(&arguments[0]).to_uppercase())) },
+        ],
+    },
+];
+
+static CEILING_OVERLOADS: &[Overload] = &[
+    Overload { parameters: &[Integer], return_type: Integer, implementation: |arguments| Ok(arguments[0].clone()) },
+    Overload { parameters: &[Double], return_type: Double, implementation: |arguments| double(as_f64(&arguments[0]).ceil()) },
+];
+
+static CHARACTER_LENGTH_OVERLOADS: &[Overload] = &[
+    Overload { parameters: &[CharacterString], return_type: Integer, implementation: |arguments| length(as_str(&arguments[0]).chars().count()) },
+];
+
+impl Function {
+    /// Looks up the built-in function called `name`.
+    pub fn lookup(name: &Identifier) -> Option<&'static Self> {
+        FUNCTIONS.iter().find(|function| name.normalized() == function.name)
+    }
+
+    /// Finds the name of the built-in function that is the closest to `name`,
+    /// if it is close enough to likely be a typo.
+    pub fn suggest(name: &str) -> Option<&'static str> {
+        let name = name.to_ascii_uppercase();
+        let maximum_distance = (name.chars().count() / 3).max(1);
+
+        FUNCTIONS.iter()
+            .map(|function| (edit_distance(&name, function.name), function.name))
+            .filter(|(distance, _)| *distance <= maximum_distance)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, name)| name)
+    }
+
+    /// Chooses the overload for arguments of the `argument_types`. An
+    /// `INTEGER` argument is accepted for a `DOUBLE PRECISION` parameter, but
+    /// the overload needing the fewest of these promotions is preferred.
+    pub fn resolve(&self, argument_types: &[EngineValueType]) -> Option<&'static Overload> {
+        self.overloads.iter()
+            .filter(|overload| overload.parameters.len() == argument_types.len())
+            .filter_map(|overload| {
+                let mut promotions = 0;
+                for (parameter, argument) in overload.parameters.iter().zip(argument_types) {
+                    match (parameter, argument) {
+                        (parameter, argument) if parameter == argument => (),
+                        (Double, Integer) => promotions += 1,
+                        _ => return None,
+                    }
+                }
+
+                Some((promotions, overload))
+            })
+            .min_by_key(|(promotions, _)| *promotions)
+            .map(|(_, overload)| overload)
+    }
+}
+
+impl Overload {
+    /// Calls the implementation, or returns the null value if any of the
+    /// `arguments` is null.
+    pub fn call(&self, arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+        if arguments.contains(&EngineRowColumnValue::Null) {
+            return Ok(EngineRowColumnValue::Null);
+        }
+
+        (self.implementation)(arguments)
+    }
+}
+
+/// Formats the parameters of the overload, e.g. `(INTEGER, INTEGER)`.
+impl Display for Overload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(")?;
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            Display::fmt(parameter, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+fn as_bytes(value: &EngineRowColumnValue) -> &[u8] {
+    match value {
+        EngineRowColumnValue::Binary(value) => value,
+        EngineRowColumnValue::BinaryLargeObject(value) => value,
+        _ => unreachable!("type-checked as BINARY VARYING, but got {value:?}"),
+    }
+}
+
+fn as_f64(value: &EngineRowColumnValue) -> f64 {
+    match value {
+        EngineRowColumnValue::F64(value) => *value,
+        _ => unreachable!("type-checked as DOUBLE PRECISION, but got {value:?}"),
+    }
+}
+
+fn as_i32(value: &EngineRowColumnValue) -> i32 {
+    match value {
+        EngineRowColumnValue::I32(value) => *value,
+        _ => unreachable!("type-checked as INTEGER, but got {value:?}"),
+    }
+}
+
+fn abs_integer(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let value = as_i32(&arguments[0]);
+    value.checked_abs()
+        .map(EngineRowColumnValue::I32)
+        .ok_or_else(|| error(format!("integer out of range: ABS({value})")))
+}
+
+fn modulo(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let (dividend, divisor) = (as_i32(&arguments[0]), as_i32(&arguments[1]));
+    if divisor == 0 {
+        return Err(error("division by zero".to_owned()));
+    }
+
+    // `i32::MIN % -1` overflows, even though the remainder is simply zero.
+    Ok(EngineRowColumnValue::I32(dividend.wrapping_rem(divisor)))
+}
+
+/// Replaces the `length` characters at the 1-based `start` position with the
+/// replacement, which defaults to as many characters as the replacement has.
+fn overlay(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let string = as_str(&arguments[0]);
+    let replacement = as_str(&arguments[1]);
+    let start = i64::from(as_i32(&arguments[2]));
+    let length = match arguments.get(3) {
+        Some(length) => i64::from(as_i32(length)),
+        None => replacement.chars().count() as i64,
+    };
+
+    let mut result = substring_of(string, 1, Some(start - 1))?;
+    result.push_str(replacement);
+    result.push_str(&substring_of(string, start + length, None)?);
+    Ok(EngineRowColumnValue::String(result))
+}
+
+/// Returns the 1-based position of the first argument in the second, or zero
+/// if it doesn't occur in it.
+fn position(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let (needle, haystack) = (as_str(&arguments[0]), as_str(&arguments[1]));
+    match haystack.find(needle) {
+        Some(byte_index) => length(haystack[..byte_index].chars().count() + 1),
+        None => Ok(EngineRowColumnValue::I32(0)),
+    }
+}
+
+fn power(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let (base, exponent) = (as_f64(&arguments[0]), as_f64(&arguments[1]));
+    if base == 0.0 && exponent < 0.0 {
+        return Err(error("zero raised to a negative power is undefined".to_owned()));
+    }
+
+    if base < 0.0 && exponent.fract() != 0.0 {
+        return Err(error("a negative number raised to a non-integer power yields a complex result".to_owned()));
+    }
+
+    double(base.powf(exponent))
+}
+
+fn square_root(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let value = as_f64(&arguments[0]);
+    if value < 0.0 {
+        return Err(error("can't take the square root of a negative number".to_owned()));
+    }
+
+    double(value.sqrt())
+}
+
+fn substring(arguments: &[EngineRowColumnValue]) -> Result<EngineRowColumnValue, EngineResult> {
+    let length = arguments.get(2).map(|length| i64::from(as_i32(length)));
+    substring_of(as_str(&arguments[0]), as_i32(&arguments[1]).into(), length).map(EngineRowColumnValue::String)
+}
+
+/// Returns the `length` characters starting at the 1-based `start` position,
+/// or all characters after it when the length is [`None`]. The positions
+/// before the first and after the last character are empty, so e.g.
+/// `SUBSTRING('abc' FROM 0 FOR 2)` is `'a'`.
+fn substring_of(string: &str, start: i64, length: Option<i64>) -> Result<String, EngineResult> {
+    let end = match length {
+        Some(length) if length < 0 => return Err(error("negative substring length not allowed".to_owned())),
+        Some(length) => start + length,
+        None => i64::MAX,
+    };
+
+    let skip = (start - 1).max(0) as usize;
+    let take = (end - start.max(1)).max(0) as usize;
+    Ok(string.chars().skip(skip).take(take).collect())
+}
+
+/// Removes the characters of the second argument, which default to a space,
+/// from the start and/or end of the first.
+fn trim(arguments: &[EngineRowColumnValue], leading: bool, trailing: bool) -> Result<EngineRowColumnValue, EngineResult> {
+    let string = as_str(&arguments[0]);
+    let characters = arguments.get(1).map_or(" ", as_str);
+    let is_trimmed = |character| characters.contains(character);
+
+    let mut result = string;
+    if leading {
+        result = result.trim_start_matches(is_trimmed);
+    }
+    if trailing {
+        result = result.trim_end_matches(is_trimmed);
+    }
+
+    Ok(EngineRowColumnValue::String(result.to_owned()))
+}
+
+fn logarithm(value: f64, function: fn(f64) -> f64) -> Result<EngineRowColumnValue, EngineResult> {
+    if value == 0.0 {
+        return Err(error("can't take the logarithm of zero".to_owned()));
+    }
+
+    if value < 0.0 {
+        return Err(error("can't take the logarithm of a negative number".to_owned()));
+    }
+
+    double(function(value))
+}
+
+/// Converts the result of a function to a `DOUBLE PRECISION` value, which
+/// can't be infinite.
+fn double(value: f64) -> Result<EngineRowColumnValue, EngineResult> {
+    if !value.is_finite() {
+        return Err(error("value out of range for type DOUBLE PRECISION".to_owned()));
+    }
+
+    Ok(EngineRowColumnValue::F64(value))
+}
+
+/// Converts the length of a string to an `INTEGER` value.
+fn length(length: usize) -> Result<EngineRowColumnValue, EngineResult> {
+    i32::try_from(length)
+        .map(EngineRowColumnValue::I32)
+        .map_err(|_| error(format!("value {length} is out of range for type INTEGER")))
+}
+
+fn error(message: String) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(message.into())
+    ])
+}
+
+/// The Levenshtein distance between two strings, i.e. the number of
+/// insertions, deletions and substitutions of characters to get from one to
+/// the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_character) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_character) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_character != *b_character);
+            row.push(substitution.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+        previous_row = row;
+    }
+
+    previous_row[b.len()]
+}
@@ -9,6 +9,7 @@ use std::sync::{
 };
 
 use raccolta_syntax::expression::{
+    BooleanExpression,
     data_type::{
         DataType,
         NumericType,
@@ -17,6 +18,7 @@ use raccolta_syntax::expression::{
     NumericValueExpression,
     row_value_expression::ContextuallyTypedRowValueExpression,
     row_value_constructor::ContextuallyTypedRowValueConstructorElement,
+    string_value_expression::StringValueExpression,
     table_value_constructor::ContextuallyTypedTableValueConstructor,
     ValueExpression,
 };
@@ -75,20 +77,46 @@ fn execute_from_contextually_typed_table_value_constructor_impl(
 fn is_column_value_trivially_convertible_to(column_value: &ContextuallyTypedRowValueConstructorElement, data_type: &DataType) -> bool {
     match column_value {
         ContextuallyTypedRowValueConstructorElement::ValueExpression(expression) => match expression {
-            ValueExpression::Boolean(..) => match data_type {
-                DataType::Predefined(PredefinedType::Boolean) => true,
-                DataType::Predefined(PredefinedType::Numeric(..)) => true,
-                _ => false,
-            },
+            ValueExpression::Boolean(BooleanExpression::Literal(..)) => matches!(
+                data_type,
+                DataType::Predefined(PredefinedType::Boolean) | DataType::Predefined(PredefinedType::Numeric(..))
+            ),
+
+            // Null values and expressions aren't supported yet.
+            ValueExpression::Boolean(..) => false,
+
             ValueExpression::Numeric(numeric_expression) => match numeric_expression {
-                NumericValueExpression::SimpleU64(..) => match data_type {
-                    DataType::Predefined(PredefinedType::Numeric(NumericType::Integer)) => true,
-                    _ => false,
-                }
+                NumericValueExpression::SimpleI64(..) | NumericValueExpression::SimpleU64(..) => matches!(
+                    data_type,
+                    DataType::Predefined(PredefinedType::Numeric(NumericType::Integer))
+                ),
+
+                // There are no columns for decimal or floating-point numbers
+                // yet.
+                NumericValueExpression::ApproximateNumericLiteral(..) | NumericValueExpression::ExactNumericLiteral(..) => false,
+
+                // Expressions aren't evaluated yet.
+                NumericValueExpression::BinaryOperation { .. }
+                    | NumericValueExpression::Function(..)
+                    | NumericValueExpression::Negation(..) => false,
             }
-            ValueExpression::StringValueExpression(..) => match data_type {
-                DataType::Predefined(PredefinedType::CharacterString { .. }) => true,
-                _ => false,
+            ValueExpression::StringValueExpression(string_expression) => match string_expression {
+                StringValueExpression::BinaryLiteral(..) => matches!(
+                    data_type,
+                    DataType::Predefined(PredefinedType::BinaryString(..))
+                ),
+                StringValueExpression::BitLiteral(..) => matches!(
+                    data_type,
+                    DataType::Predefined(PredefinedType::BitString(..))
+                ),
+                StringValueExpression::Literal(..) => matches!(
+                    data_type,
+                    DataType::Predefined(PredefinedType::CharacterString { .. })
+                        | DataType::Predefined(PredefinedType::NationalCharacterString(..))
+                ),
+
+                // Expressions aren't evaluated yet.
+                StringValueExpression::Concatenation { .. } | StringValueExpression::Function(..) => false,
             }
 
             // TODO
@@ -0,0 +1,82 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the out-of-line storage of large object values, i.e.
+//! the values of `CLOB`, `NCLOB` and `BLOB` columns.
+
+use std::{
+    fmt::{
+        Debug,
+        Display,
+    },
+    ops::Deref,
+    sync::Arc,
+};
+
+/// A handle to a large object value. The value itself is stored out of line,
+/// so producing rows, sorting them or passing them around only copies this
+/// handle, not the value. Use [`LargeObject::materialize`] to obtain an owned
+/// copy of the value when it is actually needed.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LargeObject<T: ?Sized> {
+    value: Arc<T>,
+}
+
+impl<T: ?Sized> Clone for LargeObject<T> {
+    fn clone(&self) -> Self {
+        Self { value: Arc::clone(&self.value) }
+    }
+}
+
+impl<T: ?Sized> LargeObject<T> {
+    /// Returns `true` if both handles refer to the same stored value.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl LargeObject<str> {
+    /// Copies the value out of the large object storage.
+    pub fn materialize(&self) -> String {
+        self.value.as_ref().to_owned()
+    }
+}
+
+impl LargeObject<[u8]> {
+    /// Copies the value out of the large object storage.
+    pub fn materialize(&self) -> Vec<u8> {
+        self.value.as_ref().to_owned()
+    }
+}
+
+impl<T: ?Sized> Deref for LargeObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl From<String> for LargeObject<str> {
+    fn from(value: String) -> Self {
+        Self { value: Arc::from(value) }
+    }
+}
+
+impl From<Vec<u8>> for LargeObject<[u8]> {
+    fn from(value: Vec<u8>) -> Self {
+        Self { value: Arc::from(value) }
+    }
+}
+
+impl<T: ?Sized + Debug> Debug for LargeObject<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.value, f)
+    }
+}
+
+impl Display for LargeObject<str> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
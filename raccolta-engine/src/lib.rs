@@ -4,10 +4,16 @@
 //! This crate contains the SQL/RDBMS engine for Raccolta, which executes the
 //! parsed statements by `raccolta-syntax`.
 
+mod cast;
+mod expression;
+mod function;
 mod insert;
+mod large_object;
+mod pattern;
 mod select;
 mod sorting;
 mod table;
+mod window;
 
 use std::{
     borrow::Cow,
@@ -23,7 +29,8 @@ use std::{
 };
 
 use bitvec::prelude::*;
-use unicase::UniCase;
+
+pub use large_object::LargeObject;
 
 use raccolta_syntax::{
     clause::{
@@ -32,12 +39,14 @@ use raccolta_syntax::{
             OrderByClause,
             OrderingSpecification,
         },
+        result_offset_clause::ResultOffsetClause,
     },
+    common::Identifier,
     expression::{
         data_type::{
+            CharacterStringType,
             DataType,
-            NumericType,
-            PredefinedType, CharacterStringType,
+            PredefinedType,
         },
         QueryExpression,
         QuerySpecification,
@@ -48,7 +57,6 @@ use raccolta_syntax::{
     },
     schema::definition::table_definition::{
         TableDefinition,
-        ColumnDefinition,
         TableElement,
     },
     statement::{
@@ -62,6 +70,8 @@ use raccolta_syntax::{
         SqlSchemaDefinitionStatement,
         SqlSchemaStatement,
     },
+    Span,
+    Spanned,
 };
 
 use table::{
@@ -73,9 +83,10 @@ use table::{
 
 /// The main entrypoint for the SQL/RDBMS engine for Raccolta, which executes
 /// the parsed statements by `raccolta-syntax`.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Engine {
-    tables: HashMap<UniCase<Arc<str>>, Arc<RwLock<EngineTable>>>,
+    /// The tables, keyed by their [normalized](Identifier::normalized) names.
+    tables: HashMap<Arc<str>, Arc<RwLock<EngineTable>>>,
 }
 
 impl Engine {
@@ -87,12 +98,12 @@ impl Engine {
     }
 
     pub fn new_debug() -> Self {
-        let table_name = Arc::from("People");
+        let table_name = Identifier::regular("People");
         let mut tables = HashMap::new();
         tables.insert(
-            UniCase::from(Arc::clone(&table_name)),
+            Arc::from(table_name.normalized()),
             Arc::new(RwLock::new(EngineTable {
-                name: table_name,
+                name: Arc::from(table_name.value),
                 columns: vec![
                     EngineColumn {
                         descriptor: EngineColumnDescriptor {
@@ -131,29 +142,6 @@ impl Engine {
         }
     }
 
-    fn create_value_container_for_column(&self, column: &ColumnDefinition) -> Option<EngineColumnContainer> {
-        match &column.data_type {
-            DataType::Predefined(PredefinedType::Boolean) => {
-                Some(EngineColumnContainer::Booleans(BitVec::new()))
-            }
-            DataType::Predefined(PredefinedType::Numeric(NumericType::Integer)) => {
-                Some(EngineColumnContainer::Integers(Vec::new()))
-            }
-            DataType::Predefined(PredefinedType::CharacterString { definition, .. }) => {
-                match definition {
-                    CharacterStringType::Varying { length } => Some(
-                        EngineColumnContainer::StringsVarying {
-                            values: Vec::new(),
-                            maximum_length: *length
-                        }
-                    ),
-                    _ => None
-                }
-            }
-            _ => None
-        }
-    }
-
     /// Executes a parsed statement.
     pub fn execute_statement(&mut self, statement: SqlExecutableStatement) -> EngineResult {
         _ = statement;
@@ -167,7 +155,7 @@ impl Engine {
     fn execute_statement_data(&mut self, statement: SqlDataStatement) -> EngineResult {
         match statement {
             SqlDataStatement::ChangeStatement(statement) => self.execute_statement_data_change(statement),
-            SqlDataStatement::SelectStatement(statement) => self.execute_statement_select(statement),
+            SqlDataStatement::SelectStatement(statement) => self.execute_statement_select(*statement),
 
             _ => self.execute_unsupported_statement(),
         }
@@ -187,12 +175,10 @@ impl Engine {
             ]);
         }
 
-        let table_name = UniCase::new(Arc::from(statement.table_name.table_qualifier.as_ref()));
-        let table_ref = self.tables.get(&table_name);
-
-        let Some(table_ref) = table_ref else {
+        let Some(table_ref) = self.find_table(&statement.table_name.table_qualifier) else {
             return EngineResult::with_messages(vec![
                 EngineMessage::Error(format!("Unknown table named \"{}\"", statement.table_name.table_qualifier).into())
+                    .at(statement.table_name.span())
             ]);
         };
 
@@ -220,10 +206,11 @@ impl Engine {
     /// Executes the [`TableDefinition`] statement, which is colloquially known
     /// as the `CREATE TABLE` statement.
     fn execute_statement_schema_definition_table(&mut self, statement: TableDefinition) -> EngineResult {
-        if self.tables.contains_key(&UniCase::new(Arc::from(statement.table_name.as_ref()))) {
+        let normalized_table_name = Arc::from(statement.table_name.normalized());
+        if self.tables.contains_key(&normalized_table_name) {
             return EngineResult::with_messages(vec![
-                EngineMessage::Error("A table with this name already exists".into()),
-                EngineMessage::Hint("Table names are case-insensitive, try to come up with a different name! :)".into())
+                EngineMessage::Error("A table with this name already exists".into()).at(statement.span),
+                EngineMessage::Hint("Table names are case-insensitive unless they are quoted, try to come up with a different name! :)".into())
             ]);
         }
 
@@ -235,9 +222,9 @@ impl Engine {
                 continue;
             };
 
-            let Some(values) = self.create_value_container_for_column(&column) else {
+            let Some(values) = EngineColumnContainer::for_data_type(&column.data_type) else {
                 return EngineResult::with_messages(vec![
-                    EngineMessage::Error(format!("Failed to create column container for \"{}\"", column.column_name).into()),
+                    EngineMessage::Error(format!("Failed to create column container for \"{}\"", column.column_name).into()).at(column.span),
                     EngineMessage::Error(format!("DataType is unsupported at the moment: {:#?}", column.data_type).into()),
                     EngineMessage::Help("You can create an issue at: https://github.com/usadson/raccolta/issues/new?template=bug_report.md".into()),
                 ]);
@@ -252,14 +239,13 @@ impl Engine {
             });
         }
 
-        let table_name = Arc::from(statement.table_name.as_ref());
         let table = EngineTable {
-            name: Arc::clone(&table_name),
+            name: Arc::from(statement.table_name.value),
             columns
         };
 
         self.tables.insert(
-            UniCase::new(table_name),
+            normalized_table_name,
             Arc::new(RwLock::new(table))
         );
 
@@ -273,7 +259,12 @@ impl Engine {
             raccolta_syntax::expression::query_expression::QueryExpressionBody::SimpleTable(simple_table) => {
                 match simple_table {
                     raccolta_syntax::expression::query_expression::SimpleTable::QuerySpecification(query_specification) => {
-                        self.execute_statement_select_simple_table_query_specification(query_specification, statement.order_by, statement.fetch)
+                        self.execute_statement_select_simple_table_query_specification(
+                            query_specification,
+                            statement.order_by,
+                            statement.offset,
+                            statement.fetch,
+                        )
                     }
                 }
             }
@@ -285,10 +276,13 @@ impl Engine {
         &self,
         query_specification: QuerySpecification,
         order_by_clause: Option<OrderByClause>,
+        result_offset_clause: Option<ResultOffsetClause>,
         fetch_first_clause: Option<FetchFirstClause>,
     ) -> EngineResult {
+        // Queries without a `FROM` clause are evaluated against a single
+        // implicit row, e.g. `SELECT 1 + 1`.
         let Some(table_expression) = &query_specification.table_expression else {
-            return self.execute_unsupported_statement();
+            return select::execute(query_specification, None, order_by_clause, result_offset_clause, fetch_first_clause);
         };
 
         if table_expression.from_clause.table_references.len() != 1 {
@@ -301,13 +295,19 @@ impl Engine {
             }
         };
 
-        let Some(table_ref) = self.tables.get(&UniCase::new(Arc::from(table_name.clone()))) else {
+        let Some(table_ref) = self.find_table(table_name) else {
             return EngineResult::with_messages(vec![
-                EngineMessage::Error(format!("Unknown table named \"{}\"", table_name).into())
+                EngineMessage::Error(format!("Unknown table named \"{}\"", table_name).into()).at(table_name.span())
             ]);
         };
 
-        select::execute(query_specification, table_ref.clone(), order_by_clause, fetch_first_clause)
+        select::execute(query_specification, Some(table_ref.clone()), order_by_clause, result_offset_clause, fetch_first_clause)
+    }
+
+    /// Finds the table referred to by the given `name`. Table names are
+    /// case-insensitive, unless the name was a delimited identifier.
+    fn find_table(&self, name: &Identifier) -> Option<&Arc<RwLock<EngineTable>>> {
+        self.tables.get(name.normalized().as_ref())
     }
 
     /// Returns a message describing that this statement is not yet supported
@@ -323,14 +323,13 @@ impl Engine {
     /// Get the names of all tables.
     pub fn get_table_names(&self) -> Vec<Arc<str>> {
         self.tables.values()
-            .map(|table| {
+            .filter_map(|table| {
                 if let Ok(table) = table.read() {
                     Some(Arc::clone(&table.name))
                 } else {
                     None
                 }
             })
-            .flatten()
             .collect()
     }
 }
@@ -341,6 +340,42 @@ pub enum EngineMessage {
     Help(Cow<'static, str>),
     Hint(Cow<'static, str>),
     Informational(Cow<'static, str>),
+
+    /// A message about the part of the statement at the `span`, e.g. the
+    /// column reference that couldn't be found.
+    Located {
+        message: Box<EngineMessage>,
+        span: Span,
+    },
+}
+
+impl EngineMessage {
+    /// Attaches the `span` of the statement this message is about.
+    pub fn at(self, span: Span) -> Self {
+        let message = match self {
+            Self::Located { message, .. } => message,
+            message => Box::new(message),
+        };
+
+        Self::Located { message, span }
+    }
+
+    /// Returns the message without its location.
+    pub fn message(&self) -> &EngineMessage {
+        match self {
+            Self::Located { message, .. } => message.message(),
+            message => message,
+        }
+    }
+
+    /// Returns the span of the statement this message is about, if it is
+    /// known.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Self::Located { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
 }
 
 /// The result of invoked statements.
@@ -374,18 +409,90 @@ pub struct EngineRow {
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum EngineRowColumnValue {
+    Binary(Vec<u8>),
+
+    /// A `BLOB` value, which isn't copied until it is materialized.
+    BinaryLargeObject(LargeObject<[u8]>),
+
+    Bits(BitVec),
     Bool(bool),
+
+    /// A `CLOB` or `NCLOB` value, which isn't copied until it is materialized.
+    CharacterLargeObject(LargeObject<str>),
+
+    /// A `DOUBLE PRECISION` value, which is never NaN or infinite.
+    F64(f64),
+
     I32(i32),
+
+    /// The null value, e.g. the truth value `UNKNOWN`.
+    Null,
+
     String(String),
 }
 
 impl EngineRowColumnValue {
+    /// Copies large object values out of their storage, converting them to
+    /// their inline counterparts. Other values are returned as is.
+    pub fn materialize(self) -> Self {
+        match self {
+            Self::BinaryLargeObject(value) => Self::Binary(value.materialize()),
+            Self::CharacterLargeObject(value) => Self::String(value.materialize()),
+            _ => self,
+        }
+    }
+
     fn compare_ordering(
         &self,
         other: &EngineRowColumnValue,
         ordering_specification: OrderingSpecification
     ) -> std::cmp::Ordering {
+        // Null values are sorted after all other values, like PostgreSQL.
+        let null_ordering = match (self, other) {
+            (Self::Null, Self::Null) => Some(std::cmp::Ordering::Equal),
+            (Self::Null, _) => Some(std::cmp::Ordering::Greater),
+            (_, Self::Null) => Some(std::cmp::Ordering::Less),
+            _ => None,
+        };
+
+        if let Some(ordering) = null_ordering {
+            return match ordering_specification {
+                OrderingSpecification::Ascending => ordering,
+                OrderingSpecification::Descending => ordering.reverse(),
+            };
+        }
+
         match &self {
+            Self::Binary(self_value) => match &other {
+                Self::Binary(other_value) => {
+                    let ordering = self_value.cmp(other_value);
+                    match ordering_specification {
+                        OrderingSpecification::Ascending => ordering,
+                        OrderingSpecification::Descending => ordering.reverse(),
+                    }
+                }
+                _ => std::cmp::Ordering::Equal,
+            }
+            Self::BinaryLargeObject(self_value) => match &other {
+                Self::BinaryLargeObject(other_value) => {
+                    let ordering = self_value.cmp(other_value);
+                    match ordering_specification {
+                        OrderingSpecification::Ascending => ordering,
+                        OrderingSpecification::Descending => ordering.reverse(),
+                    }
+                }
+                _ => std::cmp::Ordering::Equal,
+            }
+            Self::Bits(self_value) => match &other {
+                Self::Bits(other_value) => {
+                    let ordering = self_value.cmp(other_value);
+                    match ordering_specification {
+                        OrderingSpecification::Ascending => ordering,
+                        OrderingSpecification::Descending => ordering.reverse(),
+                    }
+                }
+                _ => std::cmp::Ordering::Equal,
+            }
             Self::Bool(self_value) => match &other {
                 Self::Bool(other_value) => {
                     let ordering = self_value.cmp(other_value);
@@ -396,6 +503,26 @@ impl EngineRowColumnValue {
                 }
                 _ => std::cmp::Ordering::Equal,
             }
+            Self::CharacterLargeObject(self_value) => match &other {
+                Self::CharacterLargeObject(other_value) => {
+                    let ordering = self_value.cmp(other_value);
+                    match ordering_specification {
+                        OrderingSpecification::Ascending => ordering,
+                        OrderingSpecification::Descending => ordering.reverse(),
+                    }
+                }
+                _ => std::cmp::Ordering::Equal,
+            }
+            Self::F64(self_value) => match &other {
+                Self::F64(other_value) => {
+                    let ordering = self_value.total_cmp(other_value);
+                    match ordering_specification {
+                        OrderingSpecification::Ascending => ordering,
+                        OrderingSpecification::Descending => ordering.reverse(),
+                    }
+                }
+                _ => std::cmp::Ordering::Equal,
+            }
             Self::I32(self_value) => match &other {
                 Self::I32(other_value) => {
                     let ordering = self_value.cmp(other_value);
@@ -406,6 +533,7 @@ impl EngineRowColumnValue {
                 }
                 _ => std::cmp::Ordering::Equal,
             }
+            Self::Null => std::cmp::Ordering::Equal,
             Self::String(self_value) => match &other {
                 Self::String(other_value) => {
                     let ordering = self_value.cmp(other_value);
@@ -423,9 +551,30 @@ impl EngineRowColumnValue {
 impl Display for EngineRowColumnValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
+            Self::Binary(bytes) => fmt_binary(bytes, f),
+            Self::BinaryLargeObject(bytes) => fmt_binary(bytes, f),
+            Self::Bits(bits) => {
+                f.write_str("B'")?;
+                for bit in bits {
+                    f.write_str(if *bit { "1" } else { "0" })?;
+                }
+                f.write_str("'")
+            }
             Self::Bool(b) => Display::fmt(&b, f),
+            Self::CharacterLargeObject(s) => Display::fmt(&s, f),
+            Self::F64(d) => Display::fmt(&d, f),
             Self::I32(i) => Display::fmt(&i, f),
+            Self::Null => f.write_str("NULL"),
             Self::String(s) => Display::fmt(&s, f),
         }
     }
 }
+
+/// Formats a binary string as a `<binary string literal>`, e.g. `X'DEADBEEF'`.
+fn fmt_binary(bytes: &[u8], f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("X'")?;
+    for byte in bytes {
+        write!(f, "{byte:02X}")?;
+    }
+    f.write_str("'")
+}
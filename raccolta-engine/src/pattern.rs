@@ -0,0 +1,204 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the compiler of the patterns of the `LIKE` and
+//! `SIMILAR TO` predicates. Both are translated to a [`Regex`] which matches
+//! the complete string.
+
+use regex::Regex;
+
+use crate::{
+    EngineMessage,
+    EngineResult,
+};
+
+/// The predicate a pattern belongs to, which defines its syntax.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PatternKind {
+    /// `LIKE`, where `%` matches any sequence of characters and `_` matches a
+    /// single character.
+    Like,
+
+    /// `SIMILAR TO`, which extends the syntax of `LIKE` with the regular
+    /// expression operators `|`, `*`, `+`, `?`, `{m,n}`, `()` and `[...]`.
+    Similar,
+}
+
+impl PatternKind {
+    /// Compiles the `pattern`, where the `escape` character makes the following
+    /// special character match itself. An empty escape string means that there
+    /// is no escape character.
+    pub fn compile(self, pattern: &str, escape: Option<&str>) -> Result<Regex, EngineResult> {
+        let escape = match escape.map(|escape| {
+            let mut chars = escape.chars();
+            (chars.next(), chars.next())
+        }) {
+            None | Some((None, _)) => None,
+            Some((Some(escape), None)) => Some(escape),
+            Some((Some(_), Some(_))) => return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error(format!("invalid escape string: '{}'", escape.unwrap_or_default()).into()),
+                EngineMessage::Hint("The escape string must be empty or a single character.".into()),
+            ])),
+        };
+
+        let mut regex = String::from("(?s)^(?:");
+        match self {
+            Self::Like => translate_like(pattern, escape, &mut regex)?,
+            Self::Similar => translate_similar(pattern, escape, &mut regex)?,
+        }
+        regex.push_str(")$");
+
+        Regex::new(&regex).map_err(|error| EngineResult::with_messages(vec![
+            EngineMessage::Error(format!("invalid {self} pattern: '{pattern}'").into()),
+            EngineMessage::Informational(error.to_string().into()),
+        ]))
+    }
+}
+
+impl std::fmt::Display for PatternKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Like => "LIKE",
+            Self::Similar => "SIMILAR TO",
+        })
+    }
+}
+
+fn translate_like(pattern: &str, escape: Option<char>, regex: &mut String) -> Result<(), EngineResult> {
+    let mut chars = pattern.chars();
+    while let Some(character) = chars.next() {
+        if Some(character) == escape {
+            match chars.next() {
+                Some(escaped @ ('%' | '_')) => push_literal(regex, escaped),
+                Some(escaped) if Some(escaped) == escape => push_literal(regex, escaped),
+                _ => return Err(invalid_escape_sequence(PatternKind::Like, pattern)),
+            }
+
+            continue;
+        }
+
+        match character {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            _ => push_literal(regex, character),
+        }
+    }
+
+    Ok(())
+}
+
+fn translate_similar(pattern: &str, escape: Option<char>, regex: &mut String) -> Result<(), EngineResult> {
+    let mut is_in_bracket_expression = false;
+
+    let mut chars = pattern.chars();
+    while let Some(character) = chars.next() {
+        if Some(character) == escape {
+            let Some(escaped) = chars.next() else {
+                return Err(invalid_escape_sequence(PatternKind::Similar, pattern));
+            };
+
+            push_literal(regex, escaped);
+            continue;
+        }
+
+        if is_in_bracket_expression {
+            match character {
+                ']' => {
+                    is_in_bracket_expression = false;
+                    regex.push(']');
+                }
+
+                // These are the character class set operators of the regex
+                // crate, which don't exist in SQL.
+                '[' | '&' | '~' | '\\' => push_literal(regex, character),
+
+                _ => regex.push(character),
+            }
+
+            continue;
+        }
+
+        match character {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            '(' => regex.push_str("(?:"),
+            '[' => {
+                is_in_bracket_expression = true;
+                regex.push('[');
+            }
+            '|' | '*' | '+' | '?' | '{' | '}' | ')' => regex.push(character),
+            _ => push_literal(regex, character),
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends the `character` to the `regex`, such that it only matches itself.
+fn push_literal(regex: &mut String, character: char) {
+    regex.push_str(&regex::escape(character.encode_utf8(&mut [0; 4])));
+}
+
+fn invalid_escape_sequence(kind: PatternKind, pattern: &str) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!("invalid escape sequence in {kind} pattern: '{pattern}'").into()),
+        EngineMessage::Hint(match kind {
+            PatternKind::Like => "The escape character must be followed by `%`, `_` or the escape character itself.",
+            PatternKind::Similar => "The escape character must be followed by the character to match.",
+        }.into()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(PatternKind::Like, "abc", None, "abc", true)]
+    #[case(PatternKind::Like, "abc", None, "abcd", false)]
+    #[case(PatternKind::Like, "a%", None, "a", true)]
+    #[case(PatternKind::Like, "a%", None, "ab\ncd", true)]
+    #[case(PatternKind::Like, "%b%", None, "abc", true)]
+    #[case(PatternKind::Like, "a_c", None, "abc", true)]
+    #[case(PatternKind::Like, "a_c", None, "ac", false)]
+    #[case(PatternKind::Like, "a.c", None, "abc", false)]
+    #[case(PatternKind::Like, "(a)", None, "(a)", true)]
+    #[case(PatternKind::Like, "100!%", Some("!"), "100%", true)]
+    #[case(PatternKind::Like, "100!%", Some("!"), "1000", false)]
+    #[case(PatternKind::Like, "a!!b", Some("!"), "a!b", true)]
+    #[case(PatternKind::Like, "a%", Some(""), "abc", true)]
+    #[case(PatternKind::Similar, "abc", None, "abc", true)]
+    #[case(PatternKind::Similar, "a|b", None, "b", true)]
+    #[case(PatternKind::Similar, "(a|b)%", None, "banana", true)]
+    #[case(PatternKind::Similar, "(a|b)%", None, "cherry", false)]
+    #[case(PatternKind::Similar, "a+b*c?", None, "aaab", true)]
+    #[case(PatternKind::Similar, "[0-9]{3}", None, "123", true)]
+    #[case(PatternKind::Similar, "[0-9]{3}", None, "12", false)]
+    #[case(PatternKind::Similar, "a.c", None, "abc", false)]
+    #[case(PatternKind::Similar, "a#%", Some("#"), "a%", true)]
+    #[case(PatternKind::Similar, "a#|b", Some("#"), "a|b", true)]
+    fn pattern_matching(
+        #[case] kind: PatternKind,
+        #[case] pattern: &str,
+        #[case] escape: Option<&str>,
+        #[case] input: &str,
+        #[case] expected: bool,
+    ) {
+        let Ok(regex) = kind.compile(pattern, escape) else {
+            panic!("failed to compile {kind} pattern '{pattern}'");
+        };
+
+        assert_eq!(regex.is_match(input), expected);
+    }
+
+    #[rstest]
+    #[case(PatternKind::Like, "a!", Some("!"))]
+    #[case(PatternKind::Like, "a!b", Some("!"))]
+    #[case(PatternKind::Like, "a", Some("!!"))]
+    #[case(PatternKind::Similar, "a#", Some("#"))]
+    #[case(PatternKind::Similar, "(a", None)]
+    fn pattern_erroneous(#[case] kind: PatternKind, #[case] pattern: &str, #[case] escape: Option<&str>) {
+        assert!(kind.compile(pattern, escape).is_err());
+    }
+}
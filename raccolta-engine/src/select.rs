@@ -9,52 +9,81 @@ use std::sync::{
 };
 
 use raccolta_syntax::{
+    common::Identifier,
     expression::{
+        CaseExpression,
+        cast_specification::CastOperand,
         ColumnReference,
+        NumericValueExpression,
+        numeric_value_function::NumericValueFunction,
         query_specification::{
             QuerySpecification,
             SelectList,
             SelectSublist,
         },
-        ValueExpression, SimpleValueSpecification,
+        SimpleValueSpecification,
+        string_value_expression::StringValueExpression,
+        string_value_function::StringValueFunction,
+        table_reference::{
+            TablePrimaryKind,
+            TableReference,
+        },
+        ValueExpression,
+        window_function::WindowFunctionType,
     },
     clause::{
-        fetch_first_clause::FetchFirstClause,
+        fetch_first_clause::{
+            FetchFirstClause,
+            FetchFirstQuantity,
+        },
         order_by_clause::{
             OrderByClause,
             OrderingSpecification,
         },
+        result_offset_clause::ResultOffsetClause,
+        WindowClause,
     },
+    set_function::SetFunctionSpecification,
+    Spanned,
 };
 
 use crate::{
     EngineMessage,
     EngineResult,
+    EngineRow,
+    expression::{
+        CompiledExpression,
+        CompileScope,
+    },
     sorting::{
+        compare_rows,
         EngineRowSortIteratorExtensionTrait,
         EngineSortingElement,
         EngineSortingMethod,
     },
     table::{
+        EngineColumn,
         EngineTable,
-        EngineTableColumnIterator,
     },
+    window::WindowOperator,
 };
 
 struct SelectionPhaseResult {
     row_count: usize,
-    column_names: Vec<String>,
-    row_iterator: EngineTableColumnIterator,
+    column_names: Vec<Identifier>,
+    row_iterator: Box<dyn Iterator<Item = EngineRow>>,
 }
 
-/// Execute a `SELECT` statement.
+/// Execute a `SELECT` statement. Without a `table`, i.e. without a `FROM`
+/// clause, the select list is evaluated against a single implicit row.
 pub fn execute(
     statement: QuerySpecification,
-    table: Arc<RwLock<EngineTable>>,
+    table: Option<Arc<RwLock<EngineTable>>>,
     order_by_clause: Option<OrderByClause>,
+    result_offset_clause: Option<ResultOffsetClause>,
     fetch_first_clause: Option<FetchFirstClause>,
 ) -> EngineResult {
-    match execute_inner(statement, table, order_by_clause, fetch_first_clause) {
+    match execute_inner(statement, table, order_by_clause, result_offset_clause, fetch_first_clause) {
         Ok(result) => result,
         Err(result) => result,
     }
@@ -64,37 +93,70 @@ pub fn execute(
 /// error propagation.
 fn execute_inner(
     statement: QuerySpecification,
-    table: Arc<RwLock<EngineTable>>,
+    table: Option<Arc<RwLock<EngineTable>>>,
     order_by_clause: Option<OrderByClause>,
+    result_offset_clause: Option<ResultOffsetClause>,
     fetch_first_clause: Option<FetchFirstClause>,
 ) -> Result<EngineResult, EngineResult> {
-    let selection_phase = match &statement.select_list {
-        SelectList::Asterisk => execute_select_return_all(table)?,
-        SelectList::Sublist(sublist) => execute_select_sublist(table, sublist)?,
+    let search_condition = statement.table_expression.as_ref()
+        .and_then(|table_expression| table_expression.where_clause.as_ref())
+        .map(|where_clause| &where_clause.search_condition);
+
+    let window_clause = statement.table_expression.as_ref()
+        .and_then(|table_expression| table_expression.window_clause.as_ref());
+
+    let table_name = statement.table_expression.as_ref()
+        .and_then(|table_expression| table_expression.from_clause.table_references.first())
+        .map(exposed_table_name);
+
+    let selection_phase = match (&statement.select_list, table) {
+        (SelectList::Asterisk, Some(table)) => execute_select_return_all(table, table_name.as_ref(), search_condition)?,
+        (SelectList::Asterisk, None) => return Err(EngineResult::with_messages(vec![
+            EngineMessage::Error("`SELECT *` requires a FROM clause".into()),
+            EngineMessage::Hint("Without a FROM clause, there are no columns to select.".into()),
+        ])),
+        (SelectList::Sublist(sublist), table) => {
+            execute_select_sublist(table, table_name.as_ref(), sublist, search_condition, window_clause)?
+        }
     };
 
-    let limited_row_count = fetch_first_clause.map(|clause| match clause.quantity.value {
-        SimpleValueSpecification::LiteralUnsigned(value) => value as usize,
+    let offset = result_offset_clause.map_or(0, |clause| match clause.offset_row_count {
+        SimpleValueSpecification::LiteralUnsigned(value) => usize::try_from(value).unwrap_or(usize::MAX),
     });
 
-    if limited_row_count == Some(0) {
+    let limited_row_count = fetch_first_clause.as_ref()
+        .map(|clause| resolve_fetch_first_quantity(&clause.quantity, selection_phase.row_count));
+
+    let column_names = selection_phase.column_names.iter()
+        .map(|name| name.value.clone())
+        .collect();
+
+    let mut row_count = selection_phase.row_count.saturating_sub(offset);
+
+    if limited_row_count == Some(0) || row_count == 0 {
         return Ok(EngineResult {
             messages: Vec::new(),
-            column_names: selection_phase.column_names,
+            column_names,
             row_count: 0,
             row_iterator: Box::new(std::iter::empty()),
         });
     }
 
-    let mut row_count = selection_phase.row_count;
-
     let sorting_method = resolve_sorting_method(&selection_phase.column_names, order_by_clause)?;
 
     let mut row_iterator = selection_phase.row_iterator
-        .apply_order_by(sorting_method);
+        .apply_order_by(sorting_method.clone());
+
+    if offset != 0 {
+        row_iterator = Box::new(row_iterator.skip(offset));
+    }
 
     if let Some(max) = limited_row_count {
-        if max < selection_phase.row_count {
+        if fetch_first_clause.is_some_and(|clause| clause.with_ties) {
+            let rows = take_with_ties(row_iterator, max, &sorting_method);
+            row_count = rows.len();
+            row_iterator = Box::new(rows.into_iter());
+        } else if max < row_count {
             row_iterator = Box::new(row_iterator.take(max));
             row_count = max;
         }
@@ -102,83 +164,236 @@ fn execute_inner(
 
     Ok(EngineResult {
         messages: Vec::new(),
-        column_names: selection_phase.column_names,
+        column_names,
         row_count,
         row_iterator,
     })
 }
 
+/// Returns the name by which the columns of the `table_reference` can be
+/// qualified. A correlation name hides the name of the table, as in
+/// `SELECT u.a FROM t AS u`.
+fn exposed_table_name(table_reference: &TableReference) -> Identifier {
+    let TableReference::Primary(primary) = table_reference;
+    match (&primary.correlation_name, &primary.kind) {
+        (Some(correlation_name), _) => Identifier::regular(correlation_name.clone()),
+        (None, TablePrimaryKind::TableOrQueryName(table_name)) => table_name.clone(),
+    }
+}
+
+/// Resolves the maximum number of rows to fetch from the `quantity` of a
+/// `FETCH FIRST` clause. A percentage is taken of the `row_count` of the
+/// query before the offset is applied, and rounded up as the standard
+/// prescribes.
+fn resolve_fetch_first_quantity(quantity: &FetchFirstQuantity, row_count: usize) -> usize {
+    let SimpleValueSpecification::LiteralUnsigned(value) = quantity.value;
+
+    if !quantity.is_percent {
+        return usize::try_from(value).unwrap_or(usize::MAX);
+    }
+
+    let rows = (u128::from(value) * row_count as u128).div_ceil(100);
+    usize::try_from(rows).unwrap_or(usize::MAX)
+}
+
+/// Takes the first `count` rows, followed by the rows that tie with the last
+/// of them, i.e. that are peers under the `sorting_method`. This implements
+/// `FETCH FIRST ... WITH TIES`.
+fn take_with_ties(
+    rows: impl Iterator<Item = EngineRow>,
+    count: usize,
+    sorting_method: &[EngineSortingElement],
+) -> Vec<EngineRow> {
+    let mut rows = rows.peekable();
+    let mut taken: Vec<_> = rows.by_ref().take(count).collect();
+
+    while let Some(row) = rows.next_if(|row| {
+        taken.last().is_some_and(|last| compare_rows(sorting_method, last, row).is_eq())
+    }) {
+        taken.push(row);
+    }
+
+    taken
+}
+
 /// Get a selected list of columns from the table. This is different from
 /// [`execute_select_return_all`], which returns an iterator with all columns.
 ///
+/// The derived columns are compiled to [`CompiledExpression`]s first. When all
+/// of them are plain column references and there is no `WHERE` clause, the rows
+/// are produced lazily from the table. Otherwise, the expressions are evaluated
+/// for every qualifying row up front, such that evaluation errors (e.g.
+/// division by zero) can be reported. The window functions are computed over
+/// the qualifying rows before the expressions are evaluated.
+///
 /// # Example
 /// ```sql
-/// SELECT column_name, other_column_name
+/// SELECT column_name, price * quantity AS total
 /// FROM table_name
+/// WHERE quantity > 1
 /// ```
 fn execute_select_sublist(
-    table_ptr: Arc<RwLock<EngineTable>>,
+    table_ptr: Option<Arc<RwLock<EngineTable>>>,
+    table_name: Option<&Identifier>,
     sublist: &[SelectSublist],
+    search_condition: Option<&ValueExpression>,
+    window_clause: Option<&WindowClause>,
 ) -> Result<SelectionPhaseResult, EngineResult> {
-    let table = table_ptr.as_ref().read().unwrap();
+    let table = table_ptr.as_ref().map(|table| table.read().unwrap());
+    let columns = table.as_ref().map_or(&[][..], |table| &table.columns[..]);
+
+    let condition = search_condition
+        .map(|search_condition| CompiledExpression::compile_search_condition("WHERE", search_condition, CompileScope::new(table_name, columns)))
+        .transpose()?;
+
+    let window_operator = WindowOperator::new(CompileScope::new(table_name, columns), window_clause)?;
+    let scope = CompileScope {
+        table_name,
+        columns,
+        window_operator: Some(&window_operator),
+    };
 
-    let mut column_indices = Vec::with_capacity(sublist.len());
+    let mut expressions = Vec::with_capacity(sublist.len());
+    let mut column_names = Vec::with_capacity(sublist.len());
     for select_element in sublist {
         match select_element {
             SelectSublist::DerivedColumn(derived_column) => {
-                match &derived_column.value_expression {
-                    ValueExpression::ColumnReference(column) => {
-                        if let ColumnReference::BasicIdentifierChain(chain) = column {
-                            let column = table.columns.iter()
-                                .enumerate()
-                                .find(|column| {
-                                    chain.last().unwrap().eq_ignore_ascii_case(&column.1.descriptor.name)
-                                });
-                            match column {
-                                Some(column) => column_indices.push(column.0),
-                                None => return Err(EngineResult::with_messages(vec![
-                                    EngineMessage::Error(format!("failed to find column: \"{}\"", chain.last().unwrap()).into())
-                                ]))
-                            }
-
-                            continue;
-                        }
-                    }
-                    _ => ()
-                }
+                let expression = CompiledExpression::compile(&derived_column.value_expression, scope)?;
+
+                column_names.push(match (&derived_column.alias, expression.column_index().and_then(|index| columns.get(index))) {
+                    (Some(alias), _) => alias.clone(),
+                    (None, Some(column)) => column.descriptor.name.clone(),
+                    (None, None) => derive_column_name(&derived_column.value_expression, columns)
+                        .unwrap_or_else(|| Identifier::delimited("?column?")),
+                });
+
+                expressions.push(expression);
             }
         }
-
-        return Err(EngineResult::with_messages(vec![
-            EngineMessage::Error(format!("column not resolvable: {select_element:?}").into())
-        ]));
     }
 
-    debug_assert_eq!(column_indices.len(), sublist.len());
+    let column_indices: Option<Vec<usize>> = expressions.iter()
+        .map(CompiledExpression::column_index)
+        .collect();
 
-    let column_names = column_indices.iter()
-        .enumerate()
-        .map(|(sublist_index, column_index)| {
-            match &sublist[sublist_index] {
-                SelectSublist::DerivedColumn(derived) => {
-                    if let Some(alias) = &derived.alias {
-                        return alias.clone();
-                    }
-                }
-            }
+    let Some(table) = table.as_deref() else {
+        let mut rows = [EngineRow { values: Vec::new() }];
+        window_operator.apply(&mut rows)?;
 
-            table.columns[*column_index].descriptor.name.clone()
-        })
-        .collect();
+        let row = evaluate_row(&expressions, &rows[0])?;
+        return Ok(SelectionPhaseResult {
+            column_names,
+            row_count: 1,
+            row_iterator: Box::new(std::iter::once(row)),
+        });
+    };
 
-    let row_count = table.columns[0].values.len();
+    if let (Some(column_indices), None, true) = (column_indices, &condition, window_operator.is_empty()) {
+        let row_count = table.row_count();
 
-    drop(table);
+        return Ok(SelectionPhaseResult {
+            column_names,
+            row_count,
+            row_iterator: Box::new(EngineTable::iter_with(Arc::clone(table_ptr.as_ref().unwrap()), column_indices)),
+        });
+    }
+
+    let mut rows = Vec::new();
+    for row_index in 0..table.row_count() {
+        let row = table.row_at(row_index);
+        if is_row_qualified(condition.as_ref(), &row)? {
+            rows.push(row);
+        }
+    }
+
+    window_operator.apply(&mut rows)?;
+
+    let rows = rows.iter()
+        .map(|row| evaluate_row(&expressions, row))
+        .collect::<Result<Vec<_>, _>>()?;
 
     Ok(SelectionPhaseResult {
         column_names,
-        row_count,
-        row_iterator: EngineTable::iter_with(table_ptr, column_indices)
+        row_count: rows.len(),
+        row_iterator: Box::new(rows.into_iter()),
+    })
+}
+
+/// Derives the name of a derived column without an alias. Function calls are
+/// named after their function, e.g. `UPPER(x)` is named `UPPER`, and a `CAST`
+/// after its operand, e.g. `CAST(price AS VARCHAR(10))` is named `price`.
+/// Other expressions, such as `1 + 1`, don't have a name.
+fn derive_column_name(expression: &ValueExpression, columns: &[EngineColumn]) -> Option<Identifier> {
+    let name = match expression {
+        ValueExpression::Case(CaseExpression::Coalesce(..)) => "COALESCE",
+        ValueExpression::Case(CaseExpression::NullIf { .. }) => "NULLIF",
+        ValueExpression::Case(..) => "CASE",
+
+        ValueExpression::Cast(specification) => {
+            return match &specification.operand {
+                CastOperand::ValueExpression(operand) => derive_column_name(operand, columns),
+                CastOperand::Null => None,
+            }.or_else(|| Some(Identifier::regular("CAST")));
+        }
+
+        ValueExpression::ColumnReference(ColumnReference::BasicIdentifierChain(chain)) => {
+            let name = chain.last()?;
+            return Some(columns.iter()
+                .find(|column| name.matches(&column.descriptor.name))
+                .map_or_else(|| name.clone(), |column| column.descriptor.name.clone()));
+        }
+
+        ValueExpression::Numeric(NumericValueExpression::Function(NumericValueFunction::Position { .. })) => "POSITION",
+
+        ValueExpression::RoutineInvocation(invocation) if invocation.name.is_case_sensitive() => {
+            return Some(invocation.name.clone());
+        }
+        ValueExpression::RoutineInvocation(invocation) => return Some(Identifier::regular(invocation.name.normalized())),
+
+        ValueExpression::SetFunctionSpecification(specification) => set_function_name(specification),
+
+        ValueExpression::StringValueExpression(StringValueExpression::Function(function)) => match function {
+            StringValueFunction::Overlay { .. } => "OVERLAY",
+            StringValueFunction::Substring { .. } => "SUBSTRING",
+            StringValueFunction::Trim { .. } => "TRIM",
+        },
+
+        ValueExpression::WindowFunction(function) => match &function.function_type {
+            WindowFunctionType::Aggregate(specification) => set_function_name(specification),
+            WindowFunctionType::Rank(rank_function_type) => rank_function_type.as_str(),
+            WindowFunctionType::RowNumber => "ROW_NUMBER",
+        },
+
+        _ => return None,
+    };
+
+    Some(Identifier::regular(name))
+}
+
+/// Get the name of the aggregate function, e.g. `COUNT` or `SUM`.
+fn set_function_name(specification: &SetFunctionSpecification) -> &'static str {
+    match specification {
+        SetFunctionSpecification::Count(..) => "COUNT",
+        SetFunctionSpecification::General(function) => function.function_type.as_str(),
+    }
+}
+
+/// Checks whether the `row` satisfies the search `condition` of the `WHERE`
+/// clause, if there is one.
+fn is_row_qualified(condition: Option<&CompiledExpression>, row: &EngineRow) -> Result<bool, EngineResult> {
+    match condition {
+        Some(condition) => condition.evaluate_search_condition(row),
+        None => Ok(true),
+    }
+}
+
+/// Evaluates the `expressions` of the select list for a single `row` of the
+/// table.
+fn evaluate_row(expressions: &[CompiledExpression], row: &EngineRow) -> Result<EngineRow, EngineResult> {
+    Ok(EngineRow {
+        values: expressions.iter()
+            .map(|expression| expression.evaluate(row))
+            .collect::<Result<_, _>>()?
     })
 }
 
@@ -193,6 +408,8 @@ fn execute_select_sublist(
 /// ```
 fn execute_select_return_all(
     table_ptr: Arc<RwLock<EngineTable>>,
+    table_name: Option<&Identifier>,
+    search_condition: Option<&ValueExpression>,
 ) -> Result<SelectionPhaseResult, EngineResult> {
     let table = table_ptr.as_ref().read().unwrap();
 
@@ -200,6 +417,24 @@ fn execute_select_return_all(
         .map(|column| column.descriptor.name.clone())
         .collect();
 
+    if let Some(search_condition) = search_condition {
+        let condition = CompiledExpression::compile_search_condition("WHERE", search_condition, CompileScope::new(table_name, &table.columns))?;
+
+        let mut rows = Vec::new();
+        for row_index in 0..table.row_count() {
+            let row = table.row_at(row_index);
+            if condition.evaluate_search_condition(&row)? {
+                rows.push(row);
+            }
+        }
+
+        return Ok(SelectionPhaseResult {
+            column_names,
+            row_count: rows.len(),
+            row_iterator: Box::new(rows.into_iter()),
+        });
+    }
+
     let row_count = table.columns[0].values.len();
 
     // Ha ha, this isn't what it seams like :^)
@@ -208,7 +443,7 @@ fn execute_select_return_all(
     Ok(SelectionPhaseResult {
         column_names,
         row_count,
-        row_iterator: EngineTable::iter(table_ptr),
+        row_iterator: Box::new(EngineTable::iter(table_ptr)),
     })
 }
 
@@ -216,7 +451,7 @@ fn execute_select_return_all(
 /// translated to steps that the engine can use. These steps tell the engine in
 /// which way and which order to sort the table in.
 fn resolve_sorting_method(
-    column_names: &Vec<String>,
+    column_names: &[Identifier],
     order_by_clause: Option<OrderByClause>
 ) -> Result<EngineSortingMethod, EngineResult> {
     let Some(order_by_clause) = order_by_clause else {
@@ -231,7 +466,7 @@ fn resolve_sorting_method(
                 match column_names.iter()
                     .enumerate()
                     .find(|(_, name)| {
-                        chain.last().unwrap().eq_ignore_ascii_case(name)
+                        chain.last().unwrap().matches(name)
                     }) {
                     Some((column_index, _)) => sorting_method.push(EngineSortingElement{
                         column_index,
@@ -248,9 +483,9 @@ fn resolve_sorting_method(
             }
             _ => return Err(EngineResult::with_messages(vec![
                 EngineMessage::Error(format!(
-                    "failed to resolve column reference: {:#?}",
-                    specification
-                ).into())
+                    "failed to resolve column reference: {}",
+                    specification.sort_key
+                ).into()).at(specification.sort_key.span())
             ])),
         }
     }
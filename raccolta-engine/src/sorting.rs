@@ -3,6 +3,8 @@
 
 //! This module contains iterator extensions for sorting engine rows.
 
+use std::cmp::Ordering;
+
 use raccolta_syntax::clause::order_by_clause::OrderingSpecification;
 
 use crate::EngineRow;
@@ -10,6 +12,7 @@ use crate::EngineRow;
 /// A sorting element is a translated step from `ORDER BY` clauses that specify
 /// in which way and which order to sort the table. This is part of
 /// [`EngineSortingElement`], which contain all steps.
+#[derive(Clone)]
 pub struct EngineSortingElement {
     pub column_index: usize,
     pub ordering_specification: OrderingSpecification,
@@ -20,6 +23,17 @@ pub struct EngineSortingElement {
 ///  which means that no sorting is requested.
 pub type EngineSortingMethod = Vec<EngineSortingElement>;
 
+/// Compares the rows `a` and `b` by each element of the `sorting_method` in
+/// turn, such that the first element is the most significant.
+pub fn compare_rows(sorting_method: &[EngineSortingElement], a: &EngineRow, b: &EngineRow) -> Ordering {
+    sorting_method.iter()
+        .map(|element| {
+            a.values[element.column_index].compare_ordering(&b.values[element.column_index], element.ordering_specification)
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
 /// This trait adds access to the [`EngineRowSortIterator`] to iterators
 /// which have an item of type [`EngineRow`].
 pub trait EngineRowSortIteratorExtensionTrait
@@ -42,16 +56,9 @@ pub trait EngineRowSortIteratorExtensionTrait
             ));
         }
 
-        let mut iter: Box<dyn Iterator<Item = EngineRow>> = Box::new(self);
-
-        for element in sorting_method {
-            iter = Box::new(iter.engine_sort_rows(
-                element.column_index,
-                element.ordering_specification,
-            ));
-        }
-
-        iter
+        let mut rows: Vec<_> = self.collect();
+        rows.sort_by(|a, b| compare_rows(&sorting_method, a, b));
+        Box::new(rows.into_iter())
     }
 
     /// Sort the rows based on a column index and an ordering specification.
@@ -9,15 +9,27 @@ use std::sync::{
 };
 
 use bitvec::vec::BitVec;
-use raccolta_syntax::expression::{
-    data_type::DataType,
-    NumericValueExpression,
-    row_value_constructor::ContextuallyTypedRowValueConstructorElement,
-    string_value_expression::StringValueExpression,
-    ValueExpression, BooleanExpression,
+use raccolta_syntax::{
+    common::Identifier,
+    expression::{
+        data_type::{
+            BinaryStringType,
+            BitStringType,
+            CharacterStringType,
+            DataType,
+            NationalCharacterStringType,
+            NumericType,
+            PredefinedType,
+        },
+        NumericValueExpression,
+        row_value_constructor::ContextuallyTypedRowValueConstructorElement,
+        string_value_expression::StringValueExpression,
+        ValueExpression, BooleanExpression,
+    },
 };
 
 use crate::{
+    LargeObject,
     EngineMessage,
     EngineResult,
     EngineRow,
@@ -42,7 +54,7 @@ impl EngineColumn {
 
         match value {
             ContextuallyTypedRowValueConstructorElement::ValueExpression(expression) => match expression {
-                ValueExpression::Boolean(BooleanExpression::Literal(value)) => match &mut self.values {
+                ValueExpression::Boolean(BooleanExpression::Literal(value, span)) => match &mut self.values {
                     EngineColumnContainer::Booleans(vec) => {
                         vec.push(value);
                         Ok(())
@@ -52,37 +64,110 @@ impl EngineColumn {
                         Ok(())
                     }
                     _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
-                        ValueExpression::Boolean(BooleanExpression::Literal(value))
+                        ValueExpression::Boolean(BooleanExpression::Literal(value, span))
                     ))
                 }
 
-                ValueExpression::Numeric(numeric_expression) => match numeric_expression {
-                    NumericValueExpression::SimpleU64(number) => {
+                ValueExpression::Numeric(numeric_expression) => {
+                    let number = match &numeric_expression {
+                        NumericValueExpression::SimpleI64(number, _) => i32::try_from(*number).map_err(|_| number.to_string()),
+                        NumericValueExpression::SimpleU64(number, _) => i32::try_from(*number).map_err(|_| number.to_string()),
+                        _ => return internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
+                            ValueExpression::Numeric(numeric_expression))
+                        ),
+                    };
+
+                    match (&mut self.values, number) {
+                        (EngineColumnContainer::Integers(vec), Ok(number)) => {
+                            vec.push(number);
+                            Ok(())
+                        }
+                        (EngineColumnContainer::Integers(..), Err(number)) => Err(EngineResult::with_messages(vec![
+                            EngineMessage::Error(format!(
+                                "Value {number} is out of range for column \"{}\" of type INTEGER",
+                                self.descriptor.name,
+                            ).into())
+                        ])),
+                        _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
+                            ValueExpression::Numeric(numeric_expression))
+                        )
+                    }
+                }
+
+                ValueExpression::StringValueExpression(string_expression) => match string_expression {
+                    StringValueExpression::BinaryLiteral(mut literal, span) => {
                         match &mut self.values {
-                            EngineColumnContainer::Integers(vec) => {
-                                vec.push(number as i32);
+                            EngineColumnContainer::BinariesFixed { length, .. } if literal.len() > *length => {
+                                Err(right_truncation(&self.descriptor.name, "BINARY", *length, literal.len(), "octets"))
+                            }
+                            EngineColumnContainer::BinariesFixed { length, values } => {
+                                literal.resize(*length, 0);
+                                values.push(literal);
+                                Ok(())
+                            }
+                            EngineColumnContainer::BinariesVarying { maximum_length, .. } if literal.len() > *maximum_length => {
+                                Err(right_truncation(&self.descriptor.name, "VARBINARY", *maximum_length, literal.len(), "octets"))
+                            }
+                            EngineColumnContainer::BinariesVarying { values, .. } => {
+                                values.push(literal);
+                                Ok(())
+                            }
+                            EngineColumnContainer::BinaryLargeObjects { maximum_length, values } => {
+                                literal.truncate(*maximum_length);
+                                values.push(LargeObject::from(literal));
                                 Ok(())
                             }
                             _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
-                                ValueExpression::Numeric(NumericValueExpression::SimpleU64(number)))
+                                ValueExpression::StringValueExpression(StringValueExpression::BinaryLiteral(literal, span)))
                             )
                         }
                     }
-                }
 
-                ValueExpression::StringValueExpression(string_expression) => match string_expression {
-                    StringValueExpression::Literal(mut literal) => {
+                    StringValueExpression::BitLiteral(literal, span) => {
+                        let mut bits: BitVec = literal.iter().collect();
+                        match &mut self.values {
+                            EngineColumnContainer::BitsFixed { length, .. } if bits.len() > *length => {
+                                Err(right_truncation(&self.descriptor.name, "BIT", *length, bits.len(), "bits"))
+                            }
+                            EngineColumnContainer::BitsFixed { length, values } => {
+                                bits.resize(*length, false);
+                                values.push(bits);
+                                Ok(())
+                            }
+                            EngineColumnContainer::BitsVarying { maximum_length, .. } if bits.len() > *maximum_length => {
+                                Err(right_truncation(&self.descriptor.name, "BIT VARYING", *maximum_length, bits.len(), "bits"))
+                            }
+                            EngineColumnContainer::BitsVarying { values, .. } => {
+                                values.push(bits);
+                                Ok(())
+                            }
+                            _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
+                                ValueExpression::StringValueExpression(StringValueExpression::BitLiteral(literal, span)))
+                            )
+                        }
+                    }
+
+                    StringValueExpression::Literal(mut literal, kind, span) => {
                         match &mut self.values {
+                            EngineColumnContainer::CharacterLargeObjects { maximum_length, values } => {
+                                truncate_characters(&mut literal, *maximum_length);
+                                values.push(LargeObject::from(literal));
+                                Ok(())
+                            }
                             EngineColumnContainer::StringsVarying { maximum_length, values } => {
-                                literal.truncate(*maximum_length);
+                                truncate_characters(&mut literal, *maximum_length);
                                 values.push(literal);
                                 Ok(())
                             }
                             _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
-                                ValueExpression::StringValueExpression(StringValueExpression::Literal(literal)))
+                                ValueExpression::StringValueExpression(StringValueExpression::Literal(literal, kind, span)))
                             )
                         }
                     }
+
+                    string_expression => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(
+                        ValueExpression::StringValueExpression(string_expression))
+                    ),
                 }
 
                 _ => internal_coercion_error(&self, ContextuallyTypedRowValueConstructorElement::ValueExpression(expression))
@@ -91,10 +176,64 @@ impl EngineColumn {
     }
 }
 
+/// The error of storing a value of `length` octets or bits in the column
+/// `column_name` of the `type_name`, which holds at most `maximum_length` of
+/// them.
+fn right_truncation(column_name: &Identifier, type_name: &str, maximum_length: usize, length: usize, unit: &str) -> EngineResult {
+    EngineResult::with_messages(vec![
+        EngineMessage::Error(format!(
+            "string data, right truncation: value of {length} {unit} is too long for column \"{column_name}\" of type {type_name}({maximum_length})",
+        ).into())
+    ])
+}
+
+/// Truncates the `string` to at most `maximum_length` characters.
+pub fn truncate_characters(string: &mut String, maximum_length: usize) {
+    if let Some((byte_idx, _)) = string.char_indices().nth(maximum_length) {
+        string.truncate(byte_idx);
+    }
+}
+
 #[derive(Debug)]
 pub enum EngineColumnContainer {
+    /// `BINARY(n)`, where shorter values are padded with zero octets.
+    BinariesFixed {
+        values: Vec<Vec<u8>>,
+        length: usize,
+    },
+
+    /// `BINARY VARYING(n)` and `VARBINARY(n)`
+    BinariesVarying {
+        values: Vec<Vec<u8>>,
+        maximum_length: usize,
+    },
+
+    /// `BLOB`, where the values are stored out of line.
+    BinaryLargeObjects {
+        values: Vec<LargeObject<[u8]>>,
+        maximum_length: usize,
+    },
+
+    /// `BIT(n)`, where shorter values are padded with zero bits.
+    BitsFixed {
+        values: Vec<BitVec>,
+        length: usize,
+    },
+
+    /// `BIT VARYING(n)`
+    BitsVarying {
+        values: Vec<BitVec>,
+        maximum_length: usize,
+    },
+
     Booleans(BitVec),
 
+    /// `CLOB` and `NCLOB`, where the values are stored out of line.
+    CharacterLargeObjects {
+        values: Vec<LargeObject<str>>,
+        maximum_length: usize,
+    },
+
     Integers(Vec<i32>),
 
     StringsVarying {
@@ -104,18 +243,94 @@ pub enum EngineColumnContainer {
 }
 
 impl EngineColumnContainer {
+    /// Creates an empty container for the values of a column of the given
+    /// `data_type`, or [`None`] if the data type isn't supported yet.
+    pub fn for_data_type(data_type: &DataType) -> Option<Self> {
+        match data_type {
+            DataType::Predefined(PredefinedType::BinaryString(binary_string_type)) => {
+                Some(match binary_string_type {
+                    BinaryStringType::Fixed { length } => Self::BinariesFixed {
+                        values: Vec::new(),
+                        length: *length,
+                    },
+                    BinaryStringType::Varying { length } => Self::BinariesVarying {
+                        values: Vec::new(),
+                        maximum_length: *length,
+                    },
+                    BinaryStringType::LargeObject { length } => Self::BinaryLargeObjects {
+                        values: Vec::new(),
+                        maximum_length: length.map_or(usize::MAX, |length| length.length()),
+                    },
+                })
+            }
+            DataType::Predefined(PredefinedType::BitString(bit_string_type)) => {
+                Some(match bit_string_type {
+                    BitStringType::Fixed { length } => Self::BitsFixed {
+                        values: Vec::new(),
+                        length: *length,
+                    },
+                    BitStringType::Varying { length } => Self::BitsVarying {
+                        values: Vec::new(),
+                        maximum_length: *length,
+                    },
+                })
+            }
+            DataType::Predefined(PredefinedType::Boolean) => {
+                Some(Self::Booleans(BitVec::new()))
+            }
+            DataType::Predefined(PredefinedType::Numeric(NumericType::Integer)) => {
+                Some(Self::Integers(Vec::new()))
+            }
+            DataType::Predefined(PredefinedType::CharacterString { definition: CharacterStringType::Varying { length }, .. }) => {
+                Some(Self::StringsVarying {
+                    values: Vec::new(),
+                    maximum_length: *length
+                })
+            }
+            DataType::Predefined(PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length }, .. })
+                    | DataType::Predefined(PredefinedType::NationalCharacterString(NationalCharacterStringType::LargeObject { length })) => {
+                Some(Self::CharacterLargeObjects {
+                    values: Vec::new(),
+                    maximum_length: length.map_or(usize::MAX, |length| length.length()),
+                })
+            }
+            _ => None
+        }
+    }
+
     pub fn len(&self) -> usize {
         match self {
+            Self::BinariesFixed { values, .. } => values.len(),
+            Self::BinariesVarying { values, .. } => values.len(),
+            Self::BinaryLargeObjects { values, .. } => values.len(),
+            Self::BitsFixed { values, .. } => values.len(),
+            Self::BitsVarying { values, .. } => values.len(),
             Self::Booleans(vec) => vec.len(),
+            Self::CharacterLargeObjects { values, .. } => values.len(),
             Self::Integers(vec) => vec.len(),
             Self::StringsVarying{ values, .. } => values.len(),
         }
     }
+
+    /// Get the value of the row at `row_index` as a result value.
+    fn value_at(&self, row_index: usize) -> EngineRowColumnValue {
+        match self {
+            Self::BinariesFixed { values, .. } => EngineRowColumnValue::Binary(values[row_index].clone()),
+            Self::BinariesVarying { values, .. } => EngineRowColumnValue::Binary(values[row_index].clone()),
+            Self::BinaryLargeObjects { values, .. } => EngineRowColumnValue::BinaryLargeObject(values[row_index].clone()),
+            Self::BitsFixed { values, .. } => EngineRowColumnValue::Bits(values[row_index].clone()),
+            Self::BitsVarying { values, .. } => EngineRowColumnValue::Bits(values[row_index].clone()),
+            Self::Booleans(vec) => EngineRowColumnValue::Bool(vec[row_index]),
+            Self::CharacterLargeObjects { values, .. } => EngineRowColumnValue::CharacterLargeObject(values[row_index].clone()),
+            Self::Integers(vec) => EngineRowColumnValue::I32(vec[row_index]),
+            Self::StringsVarying { values, .. } => EngineRowColumnValue::String(values[row_index].clone()),
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct EngineColumnDescriptor {
-    pub name: String,
+    pub name: Identifier,
     pub data_type: DataType,
 }
 
@@ -127,6 +342,21 @@ pub struct EngineTable {
 }
 
 impl EngineTable {
+    /// Returns the number of rows in this table.
+    pub fn row_count(&self) -> usize {
+        self.columns.first().map_or(0, |column| column.values.len())
+    }
+
+    /// Get the values of all the columns of the row at `row_index`.
+    pub fn row_at(&self, row_index: usize) -> EngineRow {
+        EngineRow {
+            values: self.columns
+                .iter()
+                .map(|column| column.values.value_at(row_index))
+                .collect()
+        }
+    }
+
     pub fn iter(instance: Arc<RwLock<Self>>) -> EngineTableColumnIterator {
         EngineTableColumnIterator {
             instance,
@@ -158,19 +388,8 @@ impl EngineTableColumnIterator {
         row_index: usize,
     ) -> EngineRow {
         EngineRow {
-            values: selected_column_indices.into_iter()
-                .map(|column_index| {
-                    let column = &table.columns[*column_index];
-
-                    match &column.values {
-                        EngineColumnContainer::Booleans(vec) =>
-                            EngineRowColumnValue::Bool(vec[row_index]),
-                        EngineColumnContainer::Integers(vec) =>
-                            EngineRowColumnValue::I32(vec[row_index]),
-                        EngineColumnContainer::StringsVarying { values, .. } =>
-                            EngineRowColumnValue::String(values[row_index].clone())
-                    }
-                })
+            values: selected_column_indices.iter()
+                .map(|column_index| table.columns[*column_index].values.value_at(row_index))
                 .collect()
         }
     }
@@ -199,13 +418,7 @@ impl Iterator for EngineTableColumnIterator {
         Some(EngineRow {
             values: table.columns
                 .iter()
-                .map(|column| {
-                    match &column.values {
-                        EngineColumnContainer::Booleans(vec) => EngineRowColumnValue::Bool(vec[idx]),
-                        EngineColumnContainer::Integers(vec) => EngineRowColumnValue::I32(vec[idx]),
-                        EngineColumnContainer::StringsVarying { values, .. } => EngineRowColumnValue::String(values[idx].clone()),
-                    }
-                })
+                .map(|column| column.values.value_at(idx))
                 .collect()
         })
     }
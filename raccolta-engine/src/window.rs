@@ -0,0 +1,727 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the window operator, which computes the values of the
+//! `<window function>`s of the select list. The qualifying rows are divided
+//! into partitions, which are sorted by the ordering of the window, after
+//! which the function is computed for every row of the partition.
+//!
+//! The frames of aggregates only move forward through a sorted partition, so
+//! the aggregate is updated incrementally: the rows entering the frame are
+//! added, and the rows leaving it are removed. Aggregates that can't remove
+//! values, such as `MAX`, are recomputed over the frame when a row leaves it.
+
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    ops::Range,
+};
+
+use raccolta_syntax::{
+    clause::{
+        order_by_clause::OrderingSpecification,
+        window_clause::{
+            WindowFrameBound,
+            WindowFrameUnits,
+            WindowSpecification,
+        },
+        WindowClause,
+    },
+    common::Identifier,
+    expression::{
+        SimpleValueSpecification,
+        ValueExpression,
+        WindowFunction,
+        window_function::{
+            RankFunctionType,
+            WindowFunctionType,
+            WindowNameOrSpecification,
+        },
+    },
+    set_function::{
+        ComputationalOperation,
+        SetFunctionSpecification,
+        SetQuantifier,
+    },
+    WithoutSpans,
+};
+
+use crate::{
+    EngineMessage,
+    EngineResult,
+    EngineRow,
+    EngineRowColumnValue,
+    expression::{
+        CompiledExpression,
+        CompileScope,
+        EngineValueType,
+        type_error,
+    },
+    sorting::{
+        compare_rows,
+        EngineSortingElement,
+        EngineSortingMethod,
+    },
+};
+
+/// Computes the window functions of a select list. The window functions are
+/// bound while the select list is compiled, and each gets a slot: the index of
+/// its value after the values of the columns of the row.
+pub struct WindowOperator<'a> {
+    scope: CompileScope<'a>,
+    window_clause: Option<&'a WindowClause>,
+    functions: RefCell<Vec<(WindowFunction, CompiledWindowFunction)>>,
+}
+
+struct CompiledWindowFunction {
+    kind: WindowFunctionKind,
+    partition: Vec<CompiledExpression>,
+    order: Vec<(CompiledExpression, OrderingSpecification)>,
+    frame: WindowFrame,
+    value_type: EngineValueType,
+}
+
+enum WindowFunctionKind {
+    /// An aggregate over the frame, of which the `argument` is [`None`] for
+    /// `COUNT(*)`.
+    Aggregate {
+        operation: ComputationalOperation,
+        argument: Option<CompiledExpression>,
+    },
+    Rank(RankFunctionType),
+    RowNumber,
+}
+
+/// The frame of an aggregate, with the offsets of the bounds resolved.
+#[derive(Copy, Clone)]
+struct WindowFrame {
+    units: WindowFrameUnits,
+    start: FrameBound,
+    end: FrameBound,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+impl<'a> WindowOperator<'a> {
+    /// Creates an operator without window functions, which resolves the names
+    /// of windows against the `window_clause`.
+    pub fn new(scope: CompileScope<'a>, window_clause: Option<&'a WindowClause>) -> Result<Self, EngineResult> {
+        if let Some(window_clause) = window_clause {
+            for (index, definition) in window_clause.window_definitions.iter().enumerate() {
+                if window_clause.window_definitions[..index].iter().any(|other| other.name.matches(&definition.name)) {
+                    return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!("window \"{}\" is already defined", definition.name).into()).at(definition.span)
+                    ]));
+                }
+            }
+        }
+
+        Ok(Self {
+            scope,
+            window_clause,
+            functions: RefCell::new(Vec::new()),
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.borrow().is_empty()
+    }
+
+    /// Binds the `window_function` to a slot, and returns the slot with the
+    /// type of the values of the function. Window functions that are written
+    /// the same share a slot.
+    pub fn bind(&self, window_function: &WindowFunction) -> Result<(usize, EngineValueType), EngineResult> {
+        if let Some((slot, (_, function))) = self.functions.borrow().iter()
+                .enumerate()
+                .find(|(_, (candidate, _))| candidate.eq_ignoring_spans(window_function)) {
+            return Ok((slot, function.value_type));
+        }
+
+        let function = self.compile(window_function)?;
+        let value_type = function.value_type;
+
+        let mut functions = self.functions.borrow_mut();
+        functions.push((window_function.clone(), function));
+        Ok((functions.len() - 1, value_type))
+    }
+
+    /// Computes the window functions over the `rows`, and appends the value
+    /// of each function to the row in the order of their slots.
+    pub fn apply(&self, rows: &mut [EngineRow]) -> Result<(), EngineResult> {
+        let mut results = self.functions.borrow().iter()
+            .map(|(_, function)| function.compute(rows).map(Vec::into_iter))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for row in rows {
+            row.values.extend(results.iter_mut().map(|values| values.next().unwrap()));
+        }
+
+        Ok(())
+    }
+
+    fn compile(&self, window_function: &WindowFunction) -> Result<CompiledWindowFunction, EngineResult> {
+        let scope = self.scope;
+
+        let specification = match &window_function.window {
+            WindowNameOrSpecification::Name(name) => self.resolve_window_name(name, self.window_definition_count())?,
+            WindowNameOrSpecification::Specification(specification) => {
+                self.resolve_specification(specification, self.window_definition_count())?
+            }
+        };
+
+        let partition = specification.partition_clause.iter()
+            .map(|column| CompiledExpression::compile(&ValueExpression::ColumnReference(column.clone()), scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let order = specification.order_clause.iter()
+            .flat_map(|order_clause| &order_clause.sort_specification_list)
+            .map(|specification| Ok((
+                CompiledExpression::compile(&ValueExpression::ColumnReference(specification.sort_key.clone()), scope)?,
+                specification.ordering_specification.unwrap_or(OrderingSpecification::Ascending),
+            )))
+            .collect::<Result<Vec<_>, EngineResult>>()?;
+
+        let (kind, value_type) = match &window_function.function_type {
+            WindowFunctionType::Aggregate(set_function) => compile_aggregate(set_function, scope)?,
+            WindowFunctionType::Rank(rank_function_type) => {
+                let value_type = match rank_function_type {
+                    RankFunctionType::Rank | RankFunctionType::DenseRank => EngineValueType::Integer,
+                    RankFunctionType::PercentRank | RankFunctionType::CumeDist => EngineValueType::Double,
+                };
+
+                (WindowFunctionKind::Rank(*rank_function_type), value_type)
+            }
+            WindowFunctionType::RowNumber => (WindowFunctionKind::RowNumber, EngineValueType::Integer),
+        };
+
+        let frame = compile_frame(&specification, &order)?;
+
+        Ok(CompiledWindowFunction {
+            kind,
+            partition,
+            order,
+            frame,
+            value_type,
+        })
+    }
+
+    fn window_definition_count(&self) -> usize {
+        self.window_clause.map_or(0, |window_clause| window_clause.window_definitions.len())
+    }
+
+    /// Resolves the window with the `name`, which must be one of the first
+    /// `visible_definitions` of the `WINDOW` clause, since windows can only
+    /// refer to the windows defined before them.
+    fn resolve_window_name(&self, name: &Identifier, visible_definitions: usize) -> Result<WindowSpecification, EngineResult> {
+        let definitions = self.window_clause.map_or(&[][..], |window_clause| &window_clause.window_definitions[..visible_definitions]);
+
+        let Some(index) = definitions.iter().position(|definition| definition.name.matches(name)) else {
+            return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error(format!("window \"{name}\" does not exist").into()).at(name.span),
+                EngineMessage::Hint("Windows are defined in the WINDOW clause, after the WHERE clause.".into()),
+            ]));
+        };
+
+        self.resolve_specification(&definitions[index].specification, index)
+    }
+
+    /// Resolves the `specification` by copying the window it refines, if any.
+    fn resolve_specification(&self, specification: &WindowSpecification, visible_definitions: usize) -> Result<WindowSpecification, EngineResult> {
+        let Some(existing_window_name) = &specification.existing_window_name else {
+            return Ok(specification.clone());
+        };
+
+        let existing = self.resolve_window_name(existing_window_name, visible_definitions)?;
+
+        let error = |message: String| Err(EngineResult::with_messages(vec![
+            EngineMessage::Error(message.into()).at(specification.span),
+        ]));
+
+        if !specification.partition_clause.is_empty() {
+            return error(format!("can't override the PARTITION BY clause of window \"{existing_window_name}\""));
+        }
+
+        if specification.order_clause.is_some() && existing.order_clause.is_some() {
+            return error(format!("can't override the ORDER BY clause of window \"{existing_window_name}\""));
+        }
+
+        if existing.frame_clause.is_some() {
+            return error(format!("can't copy window \"{existing_window_name}\" because it has a frame clause"));
+        }
+
+        Ok(WindowSpecification {
+            existing_window_name: None,
+            partition_clause: existing.partition_clause,
+            order_clause: specification.order_clause.clone().or(existing.order_clause),
+            frame_clause: specification.frame_clause.clone(),
+            span: specification.span,
+        })
+    }
+}
+
+/// Compiles the `<aggregate function>` of a window function, and returns it
+/// with the type of its values.
+fn compile_aggregate(
+    set_function: &SetFunctionSpecification,
+    scope: CompileScope<'_>,
+) -> Result<(WindowFunctionKind, EngineValueType), EngineResult> {
+    let general_set_function = match set_function {
+        SetFunctionSpecification::Count(_) => return Ok((
+            WindowFunctionKind::Aggregate { operation: ComputationalOperation::Count, argument: None },
+            EngineValueType::Integer,
+        )),
+        SetFunctionSpecification::General(general_set_function) => general_set_function,
+    };
+
+    let operation = general_set_function.function_type;
+    if general_set_function.set_quantifier == SetQuantifier::Distinct {
+        return Err(EngineResult::with_messages(vec![
+            EngineMessage::Error(format!("`{}(DISTINCT ...)` isn't supported in window functions", operation.as_str()).into())
+                .at(general_set_function.span),
+        ]));
+    }
+
+    let argument = CompiledExpression::compile(&general_set_function.value_expression, scope)?;
+    let argument_type = argument.value_type();
+
+    let value_type = match (operation, argument_type) {
+        (ComputationalOperation::Count, _) => EngineValueType::Integer,
+        (ComputationalOperation::Avg, value_type) if value_type.is_numeric() => EngineValueType::Double,
+        (ComputationalOperation::Sum, value_type) if value_type.is_numeric() => value_type,
+        (ComputationalOperation::Every, EngineValueType::Boolean) => EngineValueType::Boolean,
+        (ComputationalOperation::Max | ComputationalOperation::Min, value_type) => value_type,
+        (_, value_type) => return Err(type_error(
+            &general_set_function.value_expression,
+            format!("`{}` doesn't accept arguments of type ({value_type})", operation.as_str()),
+        )),
+    };
+
+    Ok((WindowFunctionKind::Aggregate { operation, argument: Some(argument) }, value_type))
+}
+
+/// Checks the `<window frame clause>` of the `specification`. Without a frame
+/// clause, the frame is the start of the partition up to the peers of the
+/// current row, i.e. `RANGE UNBOUNDED PRECEDING`.
+fn compile_frame(
+    specification: &WindowSpecification,
+    order: &[(CompiledExpression, OrderingSpecification)],
+) -> Result<WindowFrame, EngineResult> {
+    let Some(frame_clause) = &specification.frame_clause else {
+        return Ok(WindowFrame {
+            units: WindowFrameUnits::Range,
+            start: FrameBound::UnboundedPreceding,
+            end: FrameBound::CurrentRow,
+        });
+    };
+
+    let frame = WindowFrame {
+        units: frame_clause.units,
+        start: FrameBound::from(&frame_clause.start),
+        end: frame_clause.end.as_ref().map_or(FrameBound::CurrentRow, FrameBound::from),
+    };
+
+    let error = |message: &str| Err(EngineResult::with_messages(vec![
+        EngineMessage::Error(message.to_owned().into()).at(frame_clause.span),
+    ]));
+
+    match (frame.start, frame.end) {
+        (FrameBound::UnboundedFollowing, _) => return error("frame start can't be UNBOUNDED FOLLOWING"),
+        (_, FrameBound::UnboundedPreceding) => return error("frame end can't be UNBOUNDED PRECEDING"),
+        (FrameBound::CurrentRow, FrameBound::Preceding(..)) => {
+            return error("frame starting from the current row can't have preceding rows");
+        }
+        (FrameBound::Following(..), FrameBound::Preceding(..) | FrameBound::CurrentRow) => {
+            return error("frame starting from following rows can't have preceding rows");
+        }
+        _ => (),
+    }
+
+    if frame.units == WindowFrameUnits::Range && frame.has_offset() {
+        let [(key, _)] = order else {
+            return error("RANGE with offset PRECEDING or FOLLOWING requires exactly one ORDER BY column");
+        };
+
+        if !key.value_type().is_numeric() {
+            return Err(EngineResult::with_messages(vec![
+                EngineMessage::Error(format!(
+                    "type error: RANGE with offset PRECEDING or FOLLOWING requires a numeric ORDER BY column, but got {}",
+                    key.value_type(),
+                ).into()).at(frame_clause.span),
+            ]));
+        }
+    }
+
+    Ok(frame)
+}
+
+impl WindowFrame {
+    /// Checks whether a bound of this frame is an offset `PRECEDING` or
+    /// `FOLLOWING` the current row.
+    fn has_offset(&self) -> bool {
+        [self.start, self.end].iter()
+            .any(|bound| matches!(bound, FrameBound::Preceding(..) | FrameBound::Following(..)))
+    }
+}
+
+impl From<&WindowFrameBound> for FrameBound {
+    fn from(value: &WindowFrameBound) -> Self {
+        let offset = |value: &SimpleValueSpecification| match value {
+            SimpleValueSpecification::LiteralUnsigned(value) => *value,
+        };
+
+        match value {
+            WindowFrameBound::UnboundedPreceding => Self::UnboundedPreceding,
+            WindowFrameBound::Preceding(value) => Self::Preceding(offset(value)),
+            WindowFrameBound::CurrentRow => Self::CurrentRow,
+            WindowFrameBound::Following(value) => Self::Following(offset(value)),
+            WindowFrameBound::UnboundedFollowing => Self::UnboundedFollowing,
+        }
+    }
+}
+
+impl CompiledWindowFunction {
+    /// Computes the value of this function for each of the `rows`.
+    fn compute(&self, rows: &[EngineRow]) -> Result<Vec<EngineRowColumnValue>, EngineResult> {
+        // The sort keys of the rows, which are the values of the partition
+        // columns followed by those of the order columns.
+        let keys = rows.iter()
+            .map(|row| Ok(EngineRow {
+                values: self.partition.iter()
+                    .chain(self.order.iter().map(|(key, _)| key))
+                    .map(|key| key.evaluate(row))
+                    .collect::<Result<_, _>>()?,
+            }))
+            .collect::<Result<Vec<_>, EngineResult>>()?;
+
+        let sorting_method: EngineSortingMethod = self.partition.iter()
+            .map(|_| OrderingSpecification::Ascending)
+            .chain(self.order.iter().map(|(_, ordering_specification)| *ordering_specification))
+            .enumerate()
+            .map(|(column_index, ordering_specification)| EngineSortingElement { column_index, ordering_specification })
+            .collect();
+
+        let mut sorted: Vec<usize> = (0..rows.len()).collect();
+        sorted.sort_by(|a, b| compare_rows(&sorting_method, &keys[*a], &keys[*b]));
+
+        let partition_method = &sorting_method[..self.partition.len()];
+        let mut values = vec![EngineRowColumnValue::Null; rows.len()];
+
+        for partition in sorted.chunk_by(|a, b| compare_rows(partition_method, &keys[*a], &keys[*b]) == Ordering::Equal) {
+            let peers = peer_groups(partition, |a, b| compare_rows(&sorting_method, &keys[a], &keys[b]) == Ordering::Equal);
+
+            let partition_values = match &self.kind {
+                WindowFunctionKind::Aggregate { operation, argument } => {
+                    self.compute_aggregate(partition, rows, &keys, &peers, *operation, argument.as_ref())?
+                }
+                WindowFunctionKind::Rank(rank_function_type) => compute_rank(&peers, *rank_function_type),
+                WindowFunctionKind::RowNumber => (1..=partition.len())
+                    .map(|row_number| EngineRowColumnValue::I32(row_number as i32))
+                    .collect(),
+            };
+
+            for (row_index, value) in partition.iter().zip(partition_values) {
+                values[*row_index] = value;
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Computes the aggregate over the frame of each row of the `partition`,
+    /// which is sorted by the window ordering.
+    fn compute_aggregate(
+        &self,
+        partition: &[usize],
+        rows: &[EngineRow],
+        keys: &[EngineRow],
+        peers: &[(usize, usize)],
+        operation: ComputationalOperation,
+        argument: Option<&CompiledExpression>,
+    ) -> Result<Vec<EngineRowColumnValue>, EngineResult> {
+        let arguments = partition.iter()
+            .map(|row_index| match argument {
+                Some(argument) => argument.evaluate(&rows[*row_index]),
+                None => Ok(EngineRowColumnValue::Bool(true)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let range_keys = match (self.frame.units, self.order.first()) {
+            (WindowFrameUnits::Range, Some((_, ordering_specification))) if self.frame.has_offset() => {
+                RangeKeys::new(partition.iter().map(|row_index| &keys[*row_index].values[self.partition.len()]), *ordering_specification)
+            }
+            _ => RangeKeys::default(),
+        };
+
+        let mut accumulator = Accumulator::new(operation, self.value_type);
+        let (mut frame_start, mut frame_end) = (0, 0);
+        let mut values = Vec::with_capacity(partition.len());
+
+        for (position, peer_group) in peers.iter().enumerate() {
+            let (start, end) = self.frame_of(position, partition.len(), *peer_group, &range_keys);
+
+            if start < frame_start || end < frame_end {
+                accumulator = Accumulator::new(operation, self.value_type);
+                (frame_start, frame_end) = (start, start);
+            }
+
+            while frame_end < end {
+                accumulator.add(&arguments[frame_end]);
+                frame_end += 1;
+            }
+
+            if frame_start < start {
+                if (frame_start..start).all(|index| accumulator.remove(&arguments[index])) {
+                    frame_start = start;
+                } else {
+                    accumulator = Accumulator::new(operation, self.value_type);
+                    (frame_start, frame_end) = (start, end);
+                    arguments[start..end].iter().for_each(|argument| accumulator.add(argument));
+                }
+            }
+
+            values.push(accumulator.result()?);
+        }
+
+        Ok(values)
+    }
+
+    /// Returns the positions in the partition of the first row of the frame,
+    /// and of the row after the frame, of the row at `position`.
+    fn frame_of(
+        &self,
+        position: usize,
+        partition_len: usize,
+        (peer_start, peer_end): (usize, usize),
+        range_keys: &RangeKeys,
+    ) -> (usize, usize) {
+        let row_count = |offset: u64| usize::try_from(offset).unwrap_or(usize::MAX);
+
+        let bound = |bound: FrameBound, is_end: bool| -> usize {
+            match (self.frame.units, bound) {
+                (_, FrameBound::UnboundedPreceding) => 0,
+                (_, FrameBound::UnboundedFollowing) => usize::MAX,
+                (WindowFrameUnits::Range, FrameBound::CurrentRow) => if is_end { peer_end } else { peer_start },
+                (WindowFrameUnits::Rows, FrameBound::CurrentRow) => position + usize::from(is_end),
+                (WindowFrameUnits::Rows, FrameBound::Preceding(offset)) => {
+                    (position + usize::from(is_end)).saturating_sub(row_count(offset))
+                }
+                (WindowFrameUnits::Rows, FrameBound::Following(offset)) => {
+                    (position + usize::from(is_end)).saturating_add(row_count(offset))
+                }
+                (WindowFrameUnits::Range, FrameBound::Preceding(offset) | FrameBound::Following(offset)) => {
+                    // The peers of a null key are the only rows in its range.
+                    let Some(key) = range_keys.keys[position] else {
+                        return if is_end { peer_end } else { peer_start };
+                    };
+
+                    let threshold = match bound {
+                        FrameBound::Preceding(..) => key - offset as f64,
+                        _ => key + offset as f64,
+                    };
+
+                    let non_null = &range_keys.keys[range_keys.non_null.clone()];
+                    range_keys.non_null.start + non_null.partition_point(|other| {
+                        let other = other.expect("keys in the non-null range shouldn't be null");
+                        if is_end { other <= threshold } else { other < threshold }
+                    })
+                }
+            }
+        };
+
+        let start = bound(self.frame.start, false).min(partition_len);
+        let end = bound(self.frame.end, true).min(partition_len);
+        (start, end.max(start))
+    }
+}
+
+/// The single `ORDER BY` key of a `RANGE` frame with offsets, for each row of
+/// a partition. The keys are negated for a descending ordering, such that
+/// they are always ascending.
+#[derive(Default)]
+struct RangeKeys {
+    keys: Vec<Option<f64>>,
+
+    /// The positions of the keys that aren't null, which are adjacent, since
+    /// null keys are sorted after or before all other keys.
+    non_null: Range<usize>,
+}
+
+impl RangeKeys {
+    fn new<'a>(keys: impl Iterator<Item = &'a EngineRowColumnValue>, ordering_specification: OrderingSpecification) -> Self {
+        let sign = match ordering_specification {
+            OrderingSpecification::Ascending => 1.0,
+            OrderingSpecification::Descending => -1.0,
+        };
+
+        let keys: Vec<_> = keys
+            .map(|key| match key {
+                EngineRowColumnValue::I32(value) => Some(sign * f64::from(*value)),
+                EngineRowColumnValue::F64(value) => Some(sign * value),
+                _ => None,
+            })
+            .collect();
+
+        let start = keys.iter().position(Option::is_some).unwrap_or(keys.len());
+        let end = keys[start..].iter().position(Option::is_none).map_or(keys.len(), |length| start + length);
+
+        Self {
+            keys,
+            non_null: start..end,
+        }
+    }
+}
+
+/// Returns, for each row of the sorted `partition`, the positions of its first
+/// peer and of the row after its last peer.
+fn peer_groups(partition: &[usize], is_peer: impl Fn(usize, usize) -> bool) -> Vec<(usize, usize)> {
+    let mut peers = Vec::with_capacity(partition.len());
+
+    for group in partition.chunk_by(|a, b| is_peer(*a, *b)) {
+        let start = peers.len();
+        peers.extend(std::iter::repeat_n((start, start + group.len()), group.len()));
+    }
+
+    peers
+}
+
+/// Computes the `<rank function type>` for each row of a partition with the
+/// given `peers`.
+fn compute_rank(peers: &[(usize, usize)], rank_function_type: RankFunctionType) -> Vec<EngineRowColumnValue> {
+    let row_count = peers.len();
+    let mut dense_rank = 0;
+
+    peers.iter()
+        .enumerate()
+        .map(|(position, (peer_start, peer_end))| {
+            if position == *peer_start {
+                dense_rank += 1;
+            }
+
+            match rank_function_type {
+                RankFunctionType::Rank => EngineRowColumnValue::I32(*peer_start as i32 + 1),
+                RankFunctionType::DenseRank => EngineRowColumnValue::I32(dense_rank),
+                RankFunctionType::PercentRank if row_count == 1 => EngineRowColumnValue::F64(0.0),
+                RankFunctionType::PercentRank => EngineRowColumnValue::F64(*peer_start as f64 / (row_count - 1) as f64),
+                RankFunctionType::CumeDist => EngineRowColumnValue::F64(*peer_end as f64 / row_count as f64),
+            }
+        })
+        .collect()
+}
+
+/// The state of an aggregate over the rows of a frame. The values of
+/// `COUNT(*)` are all `TRUE`, such that every row is counted.
+struct Accumulator {
+    operation: ComputationalOperation,
+    value_type: EngineValueType,
+
+    /// The number of non-null values.
+    count: usize,
+    integer_sum: i64,
+    double_sum: f64,
+    false_count: usize,
+
+    /// The greatest value for `MAX`, or the least for `MIN`.
+    extreme: Option<EngineRowColumnValue>,
+}
+
+impl Accumulator {
+    fn new(operation: ComputationalOperation, value_type: EngineValueType) -> Self {
+        Self {
+            operation,
+            value_type,
+            count: 0,
+            integer_sum: 0,
+            double_sum: 0.0,
+            false_count: 0,
+            extreme: None,
+        }
+    }
+
+    fn add(&mut self, value: &EngineRowColumnValue) {
+        match value {
+            EngineRowColumnValue::Null => return,
+            EngineRowColumnValue::I32(value) => self.integer_sum += i64::from(*value),
+            EngineRowColumnValue::F64(value) => self.double_sum += value,
+            EngineRowColumnValue::Bool(false) => self.false_count += 1,
+            _ => (),
+        }
+
+        self.count += 1;
+
+        let replaces_extreme = |extreme: &EngineRowColumnValue| {
+            let ordering = value.compare_ordering(extreme, OrderingSpecification::Ascending);
+            match self.operation {
+                ComputationalOperation::Max => ordering == Ordering::Greater,
+                ComputationalOperation::Min => ordering == Ordering::Less,
+                _ => false,
+            }
+        };
+
+        if matches!(self.operation, ComputationalOperation::Max | ComputationalOperation::Min)
+                && self.extreme.as_ref().is_none_or(replaces_extreme) {
+            self.extreme = Some(value.clone());
+        }
+    }
+
+    /// Removes the `value` that was added before, and returns whether this
+    /// aggregate supports removing values. Sums of doubles aren't updated by
+    /// subtracting, to avoid the accumulation of rounding errors.
+    fn remove(&mut self, value: &EngineRowColumnValue) -> bool {
+        if matches!(self.operation, ComputationalOperation::Max | ComputationalOperation::Min) {
+            return false;
+        }
+
+        match value {
+            EngineRowColumnValue::Null => return true,
+            EngineRowColumnValue::I32(value) => self.integer_sum -= i64::from(*value),
+            EngineRowColumnValue::F64(..) => return false,
+            EngineRowColumnValue::Bool(false) => self.false_count -= 1,
+            _ => (),
+        }
+
+        self.count -= 1;
+        true
+    }
+
+    /// Returns the value of the aggregate, which is null for an empty frame,
+    /// except for `COUNT`.
+    fn result(&self) -> Result<EngineRowColumnValue, EngineResult> {
+        if self.count == 0 && self.operation != ComputationalOperation::Count {
+            return Ok(EngineRowColumnValue::Null);
+        }
+
+        Ok(match self.operation {
+            // Only one of the sums is used, depending on the type of the
+            // argument.
+            ComputationalOperation::Avg => {
+                EngineRowColumnValue::F64((self.integer_sum as f64 + self.double_sum) / self.count as f64)
+            }
+            ComputationalOperation::Count => EngineRowColumnValue::I32(self.count as i32),
+            ComputationalOperation::Every => EngineRowColumnValue::Bool(self.false_count == 0),
+            ComputationalOperation::Sum if self.value_type == EngineValueType::Integer => {
+                match i32::try_from(self.integer_sum) {
+                    Ok(sum) => EngineRowColumnValue::I32(sum),
+                    Err(..) => return Err(EngineResult::with_messages(vec![
+                        EngineMessage::Error(format!("integer out of range: SUM = {}", self.integer_sum).into()),
+                    ])),
+                }
+            }
+            ComputationalOperation::Sum if !self.double_sum.is_finite() => {
+                return Err(EngineResult::with_messages(vec![
+                    EngineMessage::Error("value out of range for type DOUBLE PRECISION".into()),
+                ]));
+            }
+            ComputationalOperation::Sum => EngineRowColumnValue::F64(self.double_sum),
+            ComputationalOperation::Max | ComputationalOperation::Min => {
+                self.extreme.clone().unwrap_or(EngineRowColumnValue::Null)
+            }
+        })
+    }
+}
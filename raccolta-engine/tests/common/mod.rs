@@ -0,0 +1,54 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+// Every test binary compiles this module, but not every one uses all of it.
+#![allow(dead_code)]
+
+use raccolta_engine::{
+    Engine,
+    EngineMessage,
+    EngineResult,
+    EngineRowColumnValue,
+};
+use raccolta_syntax::Parser;
+
+use pretty_assertions::assert_eq;
+
+/// Creates an engine with the table of `create_statement`, filled by
+/// `insert_statement`. Panics if the engine rejects either of them, so that
+/// tests expecting no rows can't pass because the table is missing.
+pub fn engine_with_table(create_statement: &str, insert_statement: &str) -> Engine {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    for statement in [create_statement, insert_statement] {
+        let result = engine.execute_statement(parser.parse_statement(statement).expect("failed to parse statement"));
+        let errors = result.messages.iter()
+            .filter(|message| matches!(message.message(), EngineMessage::Error(..)))
+            .collect::<Vec<_>>();
+        assert!(errors.is_empty(), "failed to set up the table with {statement}: {errors:?}");
+    }
+
+    engine
+}
+
+/// Checks that the `result` has no messages, and that the first column of its
+/// rows holds the `values`.
+pub fn assert_first_column(result: EngineResult, values: Vec<EngineRowColumnValue>) {
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.row_count, values.len());
+    assert_eq!(
+        result.row_iterator
+            .map(|row| row.values[0].clone())
+            .collect::<Vec<_>>(),
+        values
+    );
+}
+
+/// Checks that the `result` has no messages, and that the first column of its
+/// rows holds the `names`.
+pub fn assert_names(result: EngineResult, names: &[&str]) {
+    assert_first_column(result, names.iter()
+        .map(|name| EngineRowColumnValue::String((*name).into()))
+        .collect());
+}
@@ -0,0 +1,111 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{Engine, EngineMessage, EngineRow, EngineRowColumnValue};
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const STOCK_TABLE: &str = "CREATE TABLE stock (name VARCHAR(20), quantity INT, note VARCHAR(10));";
+const STOCK_ROWS: &str = "INSERT INTO stock VALUES ('apple', 0, ''), ('pear', 3, '12'), ('melon', 25, 'ripe')";
+
+#[rstest]
+#[case(
+    "SELECT CASE WHEN quantity = 0 THEN 'out' WHEN quantity < 10 THEN 'low' ELSE 'plenty' END FROM stock",
+    vec![
+        EngineRowColumnValue::String("out".into()),
+        EngineRowColumnValue::String("low".into()),
+        EngineRowColumnValue::String("plenty".into()),
+    ]
+)]
+#[case(
+    "SELECT CASE quantity WHEN 0 THEN NULL WHEN 3 THEN quantity * 2 END FROM stock",
+    vec![EngineRowColumnValue::Null, EngineRowColumnValue::I32(6), EngineRowColumnValue::Null]
+)]
+#[case(
+    "SELECT COALESCE(NULLIF(note, ''), name) FROM stock",
+    vec![
+        EngineRowColumnValue::String("apple".into()),
+        EngineRowColumnValue::String("12".into()),
+        EngineRowColumnValue::String("ripe".into()),
+    ]
+)]
+#[case(
+    "SELECT 100 / NULLIF(quantity, 0) FROM stock",
+    vec![EngineRowColumnValue::Null, EngineRowColumnValue::I32(33), EngineRowColumnValue::I32(4)]
+)]
+#[case(
+    "SELECT CAST(quantity AS VARCHAR(5)) || 'x' FROM stock",
+    vec![
+        EngineRowColumnValue::String("0x".into()),
+        EngineRowColumnValue::String("3x".into()),
+        EngineRowColumnValue::String("25x".into()),
+    ]
+)]
+#[case(
+    "SELECT CAST(name AS VARCHAR(3)) FROM stock",
+    vec![
+        EngineRowColumnValue::String("app".into()),
+        EngineRowColumnValue::String("pea".into()),
+        EngineRowColumnValue::String("mel".into()),
+    ]
+)]
+fn select_conditional_expressions(#[case] query: &str, #[case] values: Vec<EngineRowColumnValue>) {
+    let mut engine = common::engine_with_table(STOCK_TABLE, STOCK_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_first_column(result, values);
+}
+
+#[rstest]
+#[case("SELECT CASE WHEN FALSE THEN 1 WHEN UNKNOWN THEN 2 ELSE 3 END", EngineRowColumnValue::I32(3))]
+#[case("SELECT CASE WHEN FALSE THEN 1 END", EngineRowColumnValue::Null)]
+#[case("SELECT CASE UNKNOWN WHEN UNKNOWN THEN 1 ELSE 2 END", EngineRowColumnValue::I32(2))]
+#[case("SELECT CASE WHEN TRUE THEN 1 ELSE 1 / 0 END", EngineRowColumnValue::I32(1))]
+#[case("SELECT COALESCE(UNKNOWN, FALSE, TRUE)", EngineRowColumnValue::Bool(false))]
+#[case("SELECT COALESCE(UNKNOWN)", EngineRowColumnValue::Null)]
+#[case("SELECT NULLIF(1, 2)", EngineRowColumnValue::I32(1))]
+#[case("SELECT NULLIF('a', 'a')", EngineRowColumnValue::Null)]
+#[case("SELECT CAST(' -42 ' AS INTEGER)", EngineRowColumnValue::I32(-42))]
+#[case("SELECT CAST(TRUE AS INT) + 1", EngineRowColumnValue::I32(2))]
+#[case("SELECT CAST(0 AS BOOLEAN)", EngineRowColumnValue::Bool(false))]
+#[case("SELECT CAST('true' AS BOOLEAN)", EngineRowColumnValue::Bool(true))]
+#[case("SELECT CAST('Unknown' AS BOOLEAN)", EngineRowColumnValue::Null)]
+#[case("SELECT CAST(FALSE AS VARCHAR(5))", EngineRowColumnValue::String("FALSE".into()))]
+#[case("SELECT CAST(NULL AS INTEGER)", EngineRowColumnValue::Null)]
+#[case("SELECT CAST(UNKNOWN AS INTEGER)", EngineRowColumnValue::Null)]
+#[case("SELECT CAST(X'AB' AS BINARY(3))", EngineRowColumnValue::Binary(vec![0xAB, 0x00, 0x00]))]
+#[case("SELECT CAST(X'ABCD' AS VARBINARY(1))", EngineRowColumnValue::Binary(vec![0xAB]))]
+#[case("SELECT CAST(B'1' AS BIT(3)) = B'100'", EngineRowColumnValue::Bool(true))]
+#[case("SELECT CAST(B'101' AS BIT VARYING(2)) = B'10'", EngineRowColumnValue::Bool(true))]
+fn select_conditional_expressions_without_from(#[case] query: &str, #[case] value: EngineRowColumnValue) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![EngineRow { values: vec![value] }]);
+}
+
+#[rstest]
+#[case("SELECT CAST('abc' AS INTEGER)", "invalid input syntax for type INTEGER: 'abc'")]
+#[case("SELECT CAST('2147483648' AS INTEGER)", "value 2147483648 is out of range for type INTEGER")]
+#[case("SELECT CAST('yes' AS BOOLEAN)", "invalid input syntax for type BOOLEAN: 'yes'")]
+#[case("SELECT CAST(12345 AS VARCHAR(4))", "value 12345 is too long for type CHARACTER VARYING(4)")]
+#[case("SELECT CAST(note AS INT) FROM stock", "invalid input syntax for type INTEGER: ''")]
+#[case("SELECT CAST(TRUE AS BINARY(1))", "type error: can't cast BOOLEAN to BINARY VARYING")]
+#[case("SELECT CAST(X'00' AS INTEGER)", "type error: can't cast BINARY VARYING to INTEGER")]
+#[case("SELECT CASE WHEN quantity THEN 1 END FROM stock", "type error: WHEN clause expects a condition of type BOOLEAN, but got INTEGER")]
+#[case("SELECT CASE quantity WHEN 'a' THEN 1 END FROM stock", "type error: can't compare INTEGER with CHARACTER VARYING using `=`")]
+#[case("SELECT CASE WHEN TRUE THEN 1 ELSE 'a' END", "type error: `CASE` expects values of the same type, but got INTEGER and CHARACTER VARYING")]
+#[case("SELECT CASE WHEN TRUE THEN NULL END", "type error: `CASE` needs at least one result that isn't NULL")]
+#[case("SELECT COALESCE(1, name) FROM stock", "type error: `COALESCE` expects values of the same type, but got INTEGER and CHARACTER VARYING")]
+#[case("SELECT NULLIF(name, 1) FROM stock", "type error: can't compare CHARACTER VARYING with INTEGER using `NULLIF`")]
+fn select_conditional_expressions_erroneous(#[case] query: &str, #[case] error: &str) {
+    let mut engine = common::engine_with_table(STOCK_TABLE, STOCK_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.first().map(EngineMessage::message), Some(&EngineMessage::Error(error.to_owned().into())));
+}
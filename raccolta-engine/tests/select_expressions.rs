@@ -0,0 +1,200 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{Engine, EngineMessage, EngineRow, EngineRowColumnValue};
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const ORDERS_TABLE: &str = "CREATE TABLE orders (name VARCHAR(20), price INT, qty INT, paid BOOLEAN);";
+const ORDERS_ROWS: &str = "INSERT INTO orders VALUES ('apple', 3, 10, TRUE), ('pear', 5, 2, FALSE), ('melon', 12, 1, TRUE)";
+
+#[rstest]
+#[case("SELECT 1 + 1", "?column?", EngineRowColumnValue::I32(2))]
+#[case("SELECT 2 + 3 * 4 AS n", "n", EngineRowColumnValue::I32(14))]
+#[case("SELECT (2 + 3) * 4", "?column?", EngineRowColumnValue::I32(20))]
+#[case("SELECT 7 / 2", "?column?", EngineRowColumnValue::I32(3))]
+#[case("SELECT -7 / 2", "?column?", EngineRowColumnValue::I32(-3))]
+#[case("SELECT -(2 - 5)", "?column?", EngineRowColumnValue::I32(3))]
+#[case("SELECT 1 - -1", "?column?", EngineRowColumnValue::I32(2))]
+#[case("SELECT 'Hello' || ', ' || 'world'", "?column?", EngineRowColumnValue::String("Hello, world".into()))]
+#[case("SELECT X'DEAD' || X'BEEF'", "?column?", EngineRowColumnValue::Binary(vec![0xDE, 0xAD, 0xBE, 0xEF]))]
+#[case("SELECT 1 < 2", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT 'b' <= 'a'", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT 1 + 1 = 2 AND 3 <> 4", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT NOT TRUE OR FALSE", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT NOT (TRUE OR FALSE) = FALSE", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN", "?column?", EngineRowColumnValue::Null)]
+#[case("SELECT NOT UNKNOWN", "?column?", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE AND UNKNOWN", "?column?", EngineRowColumnValue::Null)]
+#[case("SELECT FALSE AND UNKNOWN", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT UNKNOWN AND FALSE", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT TRUE OR UNKNOWN", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN OR TRUE", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT FALSE OR UNKNOWN", "?column?", EngineRowColumnValue::Null)]
+#[case("SELECT UNKNOWN = TRUE", "?column?", EngineRowColumnValue::Null)]
+#[case("SELECT UNKNOWN IS UNKNOWN", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN IS NOT FALSE", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN IS TRUE", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT 1 < 2 IS TRUE", "?column?", EngineRowColumnValue::Bool(true))]
+#[case("SELECT NOT FALSE IS FALSE", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT FALSE AND 1 / 0 = 1", "?column?", EngineRowColumnValue::Bool(false))]
+#[case("SELECT TRUE OR 1 / 0 = 1", "?column?", EngineRowColumnValue::Bool(true))]
+fn select_without_from(#[case] query: &str, #[case] column_name: &str, #[case] value: EngineRowColumnValue) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.column_names, vec![column_name]);
+    assert_eq!(result.row_count, 1);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![EngineRow { values: vec![value] }]);
+}
+
+#[test]
+fn select_computed_columns() {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let query = "SELECT name, price * qty AS total, price > 4 OR NOT paid, name || '!' FROM orders ORDER BY total";
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.column_names, vec!["name", "total", "?column?", "?column?"]);
+    assert_eq!(result.row_count, 3);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![
+        EngineRow { values: vec![
+            EngineRowColumnValue::String("pear".into()),
+            EngineRowColumnValue::I32(10),
+            EngineRowColumnValue::Bool(true),
+            EngineRowColumnValue::String("pear!".into()),
+        ] },
+        EngineRow { values: vec![
+            EngineRowColumnValue::String("melon".into()),
+            EngineRowColumnValue::I32(12),
+            EngineRowColumnValue::Bool(true),
+            EngineRowColumnValue::String("melon!".into()),
+        ] },
+        EngineRow { values: vec![
+            EngineRowColumnValue::String("apple".into()),
+            EngineRowColumnValue::I32(30),
+            EngineRowColumnValue::Bool(false),
+            EngineRowColumnValue::String("apple!".into()),
+        ] },
+    ]);
+}
+
+#[rstest]
+#[case("SELECT upper(name) FROM orders", "UPPER")]
+#[case("SELECT CHAR_LENGTH(name) FROM orders", "CHAR_LENGTH")]
+#[case("SELECT TRIM(name), SUBSTRING(name FROM 2) FROM orders", "TRIM, SUBSTRING")]
+#[case("SELECT POSITION('e' IN name) FROM orders", "POSITION")]
+#[case("SELECT CAST(price AS VARCHAR(10)) FROM orders", "price")]
+#[case("SELECT CAST(PRICE AS VARCHAR(10)) FROM orders", "price")]
+#[case("SELECT CAST(upper(name) AS VARCHAR(10)) FROM orders", "UPPER")]
+#[case("SELECT CAST(1 + 1 AS VARCHAR(10))", "CAST")]
+#[case("SELECT COALESCE(price, qty), NULLIF(price, qty) FROM orders", "COALESCE, NULLIF")]
+#[case("SELECT CASE WHEN paid THEN 1 ELSE 0 END FROM orders", "CASE")]
+#[case("SELECT ROW_NUMBER() OVER (), RANK() OVER (ORDER BY price), SUM(qty) OVER (), COUNT(*) OVER () FROM orders", "ROW_NUMBER, RANK, SUM, COUNT")]
+#[case("SELECT price + 1, upper(name) AS n FROM orders", "?column?, n")]
+fn select_derived_column_names(#[case] query: &str, #[case] column_names: &str) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.column_names.join(", "), column_names);
+}
+
+#[rstest]
+#[case("SELECT 1 + 'a'", "type error: operator `+` expects an operand of type INTEGER, but got CHARACTER VARYING")]
+#[case("SELECT 1 = TRUE", "type error: can't compare INTEGER with BOOLEAN using `=`")]
+#[case("SELECT 'a' || 1", "type error: can't concatenate CHARACTER VARYING with INTEGER using `||`")]
+#[case("SELECT NOT 1", "type error: operator `NOT` expects an operand of type BOOLEAN, but got INTEGER")]
+#[case("SELECT 1 / 0", "division by zero")]
+#[case("SELECT 2147483647 + 1", "integer out of range: 2147483647 + 1")]
+#[case("SELECT -(-2147483648)", "integer out of range: -(-2147483648)")]
+#[case("SELECT 2147483648", "value 2147483648 is out of range for type INTEGER")]
+#[case("SELECT price", "failed to find column: \"price\"")]
+#[case("SELECT *", "`SELECT *` requires a FROM clause")]
+#[case("SELECT name * 2 FROM orders", "type error: operator `*` expects an operand of type INTEGER, but got CHARACTER VARYING")]
+#[case("SELECT price / (qty - qty) FROM orders", "division by zero")]
+#[case("SELECT NOT 1 IS TRUE", "type error: operator `IS` expects an operand of type BOOLEAN, but got INTEGER")]
+#[case("SELECT UNKNOWN AND 1 / 0 = 1", "division by zero")]
+#[case("SELECT name FROM orders WHERE price", "type error: WHERE clause expects a condition of type BOOLEAN, but got INTEGER")]
+#[case("SELECT * FROM orders WHERE name = 1", "type error: can't compare CHARACTER VARYING with INTEGER using `=`")]
+#[case("SELECT name FROM orders WHERE missing", "failed to find column: \"missing\"")]
+#[case("SELECT name FROM orders WHERE price / (qty - 1) > 0", "division by zero")]
+#[case("SELECT nope.name FROM orders", "Unknown table named \"nope\"")]
+#[case("SELECT orders.name FROM orders AS o", "Unknown table named \"orders\"")]
+#[case("SELECT name FROM orders WHERE nope.paid", "Unknown table named \"nope\"")]
+#[case("SELECT s.orders.name FROM orders", "Unknown table named \"s.orders\"")]
+#[case("SELECT nope.price", "Unknown table named \"nope\"")]
+fn select_expression_errors(#[case] query: &str, #[case] error: &str) {
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.first().map(EngineMessage::message), Some(&EngineMessage::Error(error.to_owned().into())));
+    assert_eq!(result.row_count, 0);
+}
+
+#[rstest]
+#[case("SELECT orders.price FROM orders")]
+#[case("SELECT ORDERS.price FROM orders")]
+#[case("SELECT o.price FROM orders AS o")]
+#[case("SELECT o.price FROM orders AS o WHERE o.price > 0")]
+#[case("SELECT o.price, RANK() OVER (ORDER BY o.price) FROM orders AS o")]
+fn select_qualified_columns(#[case] query: &str) {
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_first_column(result, vec![
+        EngineRowColumnValue::I32(3),
+        EngineRowColumnValue::I32(5),
+        EngineRowColumnValue::I32(12),
+    ]);
+}
+
+#[rstest]
+#[case("SELECT 1 + 'a'", "1 + 'a'")]
+#[case("SELECT NOT 1", "NOT 1")]
+#[case("SELECT 2147483648", "2147483648")]
+#[case("SELECT price", "price")]
+#[case("SELECT name * 2 FROM orders", "name * 2")]
+#[case("SELECT name FROM orders WHERE missing", "missing")]
+#[case("SELECT name FROM missing", "missing")]
+#[case("SELECT qty, frobnicate(qty) FROM orders", "frobnicate")]
+#[case("SELECT 1 + 10 / 0", "10 / 0")]
+#[case("SELECT price / 0 FROM orders", "price / 0")]
+#[case("SELECT 2147483647 + 1", "2147483647 + 1")]
+#[case("SELECT - -2147483648", "- -2147483648")]
+#[case("SELECT 1 + 1E308 * 10", "1E308 * 10")]
+#[case("SELECT 1.5 / 0", "1.5 / 0")]
+#[case("SELECT qty + MOD(5, 0) FROM orders", "MOD(5, 0)")]
+#[case("SELECT SQRT(-1)", "SQRT(-1)")]
+#[case("SELECT CAST('abc' AS INTEGER)", "CAST('abc' AS INTEGER)")]
+#[case("SELECT name LIKE 'a!' ESCAPE '!' FROM orders", "name LIKE 'a!' ESCAPE '!'")]
+#[case("SELECT name LIKE name || '!' ESCAPE '!' FROM orders", "name LIKE name || '!' ESCAPE '!'")]
+#[case("SELECT nope.name FROM orders", "nope")]
+#[case("SELECT s.orders.name FROM orders", "s.orders")]
+fn select_expression_error_spans(#[case] query: &str, #[case] text: &str) {
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    let span = result.messages.first().and_then(EngineMessage::span);
+    assert_eq!(span.map(|span| &query[span.range()]), Some(text));
+}
+
+#[rstest]
+#[case("SELECT 1 + 'a'", "in expression: 1 + 'a'")]
+#[case("SELECT (1 = TRUE) AND x", "in expression: 1 = TRUE")]
+#[case("SELECT 2147483648", "in expression: 2147483648")]
+fn select_expression_error_context(#[case] query: &str, #[case] context: &str) {
+    let mut engine = common::engine_with_table(ORDERS_TABLE, ORDERS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.get(1).map(EngineMessage::message), Some(&EngineMessage::Informational(context.to_owned().into())));
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::Engine;
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const SCORE_TABLE: &str = "CREATE TABLE score (name VARCHAR(10), points INT);";
+const SCORE_ROWS: &str = "INSERT INTO score VALUES ('a', 10), ('b', 30), ('c', 20), ('d', 30), ('e', 20), ('f', 5), ('g', 20)";
+
+#[rstest]
+#[case("SELECT name, points FROM score ORDER BY points DESC, name FETCH FIRST 2 ROWS ONLY", &["b", "d"])]
+#[case("SELECT name, points FROM score ORDER BY points FETCH FIRST ROW ONLY", &["f"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC, name OFFSET 2 ROWS", &["c", "e", "g", "a", "f"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC, name OFFSET 2 ROWS FETCH NEXT 2 ROWS ONLY", &["c", "e"])]
+#[case("SELECT name, points FROM score ORDER BY name OFFSET 10 ROWS", &[])]
+#[case("SELECT name, points FROM score ORDER BY points DESC FETCH FIRST 3 ROWS WITH TIES", &["b", "d", "c", "e", "g"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC FETCH FIRST 2 ROWS WITH TIES", &["b", "d"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC OFFSET 1 ROW FETCH FIRST 1 ROW WITH TIES", &["d"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC, name FETCH FIRST 30 PERCENT ROWS ONLY", &["b", "d", "c"])]
+#[case("SELECT name, points FROM score ORDER BY points DESC FETCH FIRST 10 PERCENT ROWS WITH TIES", &["b", "d"])]
+#[case("SELECT name, points FROM score ORDER BY name FETCH FIRST 200 PERCENT ROWS ONLY", &["a", "b", "c", "d", "e", "f", "g"])]
+#[case("SELECT name, points FROM score ORDER BY name FETCH FIRST 0 PERCENT ROWS ONLY", &[])]
+#[case("SELECT name, points FROM score ORDER BY name LIMIT 2", &["a", "b"])]
+#[case("SELECT name, points FROM score ORDER BY name LIMIT 2 OFFSET 3", &["d", "e"])]
+#[case("SELECT name, points FROM score ORDER BY name LIMIT 3, 2", &["d", "e"])]
+#[case("SELECT name, points FROM score WHERE points >= 20 ORDER BY name DESC OFFSET 1 ROWS FETCH FIRST 2 ROWS ONLY", &["e", "d"])]
+#[case("SELECT name, points FROM score WHERE points >= 20 ORDER BY points FETCH FIRST 50 PERCENT ROWS ONLY", &["c", "e", "g"])]
+#[case("SELECT TOP 2 name, points FROM score ORDER BY name", &["a", "b"])]
+#[case("SELECT TOP (30) PERCENT name, points FROM score ORDER BY points DESC, name", &["b", "d", "c"])]
+#[case("SELECT TOP 3 WITH TIES name, points FROM score ORDER BY points DESC", &["b", "d", "c", "e", "g"])]
+fn select_offset_fetch(#[case] query: &str, #[case] names: &[&str]) {
+    let mut engine = common::engine_with_table(SCORE_TABLE, SCORE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_names(result, names);
+}
+
+#[rstest]
+#[case("SELECT 1 FETCH FIRST ROW ONLY", 1)]
+#[case("SELECT 1 OFFSET 1 ROW", 0)]
+#[case("SELECT 1 LIMIT 0", 0)]
+fn select_offset_fetch_without_from(#[case] query: &str, #[case] row_count: usize) {
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.row_count, row_count);
+    assert_eq!(result.row_iterator.count(), row_count);
+}
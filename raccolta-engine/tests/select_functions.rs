@@ -0,0 +1,150 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{Engine, EngineMessage, EngineRow, EngineRowColumnValue};
+use raccolta_syntax::{Parser, Span};
+use rstest::rstest;
+
+mod common;
+
+const PERSON_TABLE: &str = "CREATE TABLE person (name VARCHAR(20), age INT, code VARCHAR(10));";
+const PERSON_ROWS: &str = "INSERT INTO person VALUES ('Ada Lovelace', 36, '007'), ('Alan Turing', 41, '42x')";
+
+#[rstest]
+#[case(
+    "SELECT UPPER(name) FROM person",
+    vec![EngineRowColumnValue::String("ADA LOVELACE".into()), EngineRowColumnValue::String("ALAN TURING".into())]
+)]
+#[case(
+    "SELECT lower(name) FROM person",
+    vec![EngineRowColumnValue::String("ada lovelace".into()), EngineRowColumnValue::String("alan turing".into())]
+)]
+#[case(
+    "SELECT SUBSTRING(name FROM POSITION(' ' IN name) + 1) FROM person",
+    vec![EngineRowColumnValue::String("Lovelace".into()), EngineRowColumnValue::String("Turing".into())]
+)]
+#[case(
+    "SELECT TRIM(LEADING '0' FROM code) FROM person",
+    vec![EngineRowColumnValue::String("7".into()), EngineRowColumnValue::String("42x".into())]
+)]
+#[case(
+    "SELECT CHAR_LENGTH(name) FROM person WHERE MOD(age, 2) = 0",
+    vec![EngineRowColumnValue::I32(12)]
+)]
+#[case(
+    "SELECT name FROM person WHERE SQRT(age) > 6",
+    vec![EngineRowColumnValue::String("Alan Turing".into())]
+)]
+fn select_functions(#[case] query: &str, #[case] values: Vec<EngineRowColumnValue>) {
+    let mut engine = common::engine_with_table(PERSON_TABLE, PERSON_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_first_column(result, values);
+}
+
+#[rstest]
+#[case("SELECT UPPER('straße')", EngineRowColumnValue::String("STRASSE".into()))]
+#[case("SELECT TRIM('  a b  ')", EngineRowColumnValue::String("a b".into()))]
+#[case("SELECT TRIM(BOTH 'x' FROM 'xxaxx')", EngineRowColumnValue::String("a".into()))]
+#[case("SELECT TRIM(TRAILING FROM ' a ')", EngineRowColumnValue::String(" a".into()))]
+#[case("SELECT SUBSTRING('hello' FROM 2 FOR 3)", EngineRowColumnValue::String("ell".into()))]
+#[case("SELECT SUBSTRING('hello' FROM 0 FOR 2)", EngineRowColumnValue::String("h".into()))]
+#[case("SELECT SUBSTRING('hello' FROM 4 FOR 10)", EngineRowColumnValue::String("lo".into()))]
+#[case("SELECT SUBSTRING('héllo' FROM 2)", EngineRowColumnValue::String("éllo".into()))]
+#[case("SELECT POSITION('l' IN 'héllo')", EngineRowColumnValue::I32(3))]
+#[case("SELECT POSITION('z' IN 'hello')", EngineRowColumnValue::I32(0))]
+#[case("SELECT POSITION('' IN 'hello')", EngineRowColumnValue::I32(1))]
+#[case("SELECT CHARACTER_LENGTH('héllo')", EngineRowColumnValue::I32(5))]
+#[case("SELECT OCTET_LENGTH('héllo')", EngineRowColumnValue::I32(6))]
+#[case("SELECT OCTET_LENGTH(X'ABCD')", EngineRowColumnValue::I32(2))]
+#[case("SELECT OVERLAY('Txxxxas' PLACING 'hom' FROM 2 FOR 4)", EngineRowColumnValue::String("Thomas".into()))]
+#[case("SELECT OVERLAY('abc' PLACING 'XY' FROM 2)", EngineRowColumnValue::String("aXY".into()))]
+#[case("SELECT ABS(-5)", EngineRowColumnValue::I32(5))]
+#[case("SELECT ABS(-2.5)", EngineRowColumnValue::F64(2.5))]
+#[case("SELECT MOD(-7, 3)", EngineRowColumnValue::I32(-1))]
+#[case("SELECT FLOOR(-2.5)", EngineRowColumnValue::F64(-3.0))]
+#[case("SELECT CEIL(2.1)", EngineRowColumnValue::F64(3.0))]
+#[case("SELECT CEILING(7)", EngineRowColumnValue::I32(7))]
+#[case("SELECT POWER(2, 10)", EngineRowColumnValue::F64(1024.0))]
+#[case("SELECT SQRT(16)", EngineRowColumnValue::F64(4.0))]
+#[case("SELECT SQRT(2) * SQRT(2) > 1.99", EngineRowColumnValue::Bool(true))]
+#[case("SELECT 1 + 0.5", EngineRowColumnValue::F64(1.5))]
+#[case("SELECT 1E3 / 4", EngineRowColumnValue::F64(250.0))]
+#[case("SELECT 2.0 = 2", EngineRowColumnValue::Bool(true))]
+#[case("SELECT CAST(2.5 AS INTEGER)", EngineRowColumnValue::I32(3))]
+#[case("SELECT CAST(1.5 AS VARCHAR(5))", EngineRowColumnValue::String("1.5".into()))]
+#[case("SELECT UPPER(NULLIF('a', 'a'))", EngineRowColumnValue::Null)]
+#[case("SELECT MOD(1, CAST(NULL AS INT))", EngineRowColumnValue::Null)]
+fn select_functions_without_from(#[case] query: &str, #[case] value: EngineRowColumnValue) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![EngineRow { values: vec![value] }]);
+}
+
+#[rstest]
+#[case("SELECT UPPR(name) FROM person", vec![
+    EngineMessage::Error("unknown function: UPPR".into()).at(Span::new(7, 11)),
+    EngineMessage::Hint("did you mean `UPPER`?".into()),
+])]
+#[case("SELECT char_lenght(name) FROM person", vec![
+    EngineMessage::Error("unknown function: char_lenght".into()).at(Span::new(7, 18)),
+    EngineMessage::Hint("did you mean `CHAR_LENGTH`?".into()),
+])]
+#[case("SELECT frobnicate(name) FROM person", vec![
+    EngineMessage::Error("unknown function: frobnicate".into()).at(Span::new(7, 17)),
+])]
+fn select_functions_unknown(#[case] query: &str, #[case] messages: Vec<EngineMessage>) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = common::engine_with_table(PERSON_TABLE, PERSON_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, messages);
+}
+
+#[rstest]
+#[case("SELECT UPPER(age) FROM person", "type error: `UPPER` doesn't accept arguments of type (INTEGER)")]
+#[case("SELECT MOD(age) FROM person", "type error: `MOD` doesn't accept arguments of type (INTEGER)")]
+#[case("SELECT MOD(2.5, 2)", "type error: `MOD` doesn't accept arguments of type (DOUBLE PRECISION, INTEGER)")]
+#[case("SELECT SUBSTRING(name FROM 'a') FROM person", "type error: `SUBSTRING` doesn't accept arguments of type (CHARACTER VARYING, CHARACTER VARYING)")]
+#[case("SELECT SUBSTRING('abc' FROM 1 FOR -1)", "negative substring length not allowed")]
+#[case("SELECT MOD(1, 0)", "division by zero")]
+#[case("SELECT ABS(-2147483647 - 1)", "integer out of range: ABS(-2147483648)")]
+#[case("SELECT SQRT(-1)", "can't take the square root of a negative number")]
+#[case("SELECT LN(0)", "can't take the logarithm of zero")]
+#[case("SELECT POWER(0, -1)", "zero raised to a negative power is undefined")]
+#[case("SELECT POWER(10, 400)", "value out of range for type DOUBLE PRECISION")]
+#[case("SELECT 1.0 / 0", "division by zero")]
+#[case("SELECT 1E308 * 10", "double precision out of range: 1E308 * 1E1")]
+#[case("SELECT -1.5E308 - 1.5E308", "double precision out of range: -1.5E308 - 1.5E308")]
+#[case("SELECT 1E400", "value 1E400 is out of range for type DOUBLE PRECISION")]
+#[case("SELECT 1.5 || 'a'", "type error: can't concatenate DOUBLE PRECISION with CHARACTER VARYING using `||`")]
+#[case("SELECT CAST(3E9 AS INTEGER)", "value 3000000000 is out of range for type INTEGER")]
+fn select_functions_erroneous(#[case] query: &str, #[case] error: &str) {
+    let mut engine = common::engine_with_table(PERSON_TABLE, PERSON_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.first().map(EngineMessage::message), Some(&EngineMessage::Error(error.to_owned().into())));
+}
+
+#[test]
+fn select_functions_overload_hints() {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement("SELECT OCTET_LENGTH(TRUE)").unwrap());
+
+    assert_eq!(
+        result.messages.iter()
+            .filter(|message| matches!(message, EngineMessage::Hint(..)))
+            .collect::<Vec<_>>(),
+        vec![
+            &EngineMessage::Hint("`OCTET_LENGTH` accepts (CHARACTER VARYING)".into()),
+            &EngineMessage::Hint("`OCTET_LENGTH` accepts (BINARY VARYING)".into()),
+        ]
+    );
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{Engine, EngineMessage, EngineRow, EngineRowColumnValue};
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const PRODUCTS_TABLE: &str = "CREATE TABLE products (name VARCHAR(30), price INT, code VARCHAR(10));";
+const PRODUCTS_ROWS: &str = "INSERT INTO products VALUES ('apple pie', 12, 'AP-100'), ('banana bread', 8, 'BB_200'), ('cherry tart', 15, 'CT-300'), ('apple juice', 4, 'AJ%400')";
+
+#[rstest]
+#[case("SELECT name FROM products WHERE price BETWEEN 8 AND 12", &["apple pie", "banana bread"])]
+#[case("SELECT name FROM products WHERE price NOT BETWEEN 8 AND 12", &["cherry tart", "apple juice"])]
+#[case("SELECT name FROM products WHERE price BETWEEN 12 AND 8", &[])]
+#[case("SELECT name FROM products WHERE price BETWEEN SYMMETRIC 12 AND 8", &["apple pie", "banana bread"])]
+#[case("SELECT name FROM products WHERE price BETWEEN price - 1 AND price + 1 AND price > 10", &["apple pie", "cherry tart"])]
+#[case("SELECT name FROM products WHERE price IN (4, 15, 99)", &["cherry tart", "apple juice"])]
+#[case("SELECT name FROM products WHERE price NOT IN (4, 15)", &["apple pie", "banana bread"])]
+#[case("SELECT name FROM products WHERE code IN ('BB_200', name)", &["banana bread"])]
+#[case("SELECT name FROM products WHERE name LIKE 'apple%'", &["apple pie", "apple juice"])]
+#[case("SELECT name FROM products WHERE name NOT LIKE 'apple%'", &["banana bread", "cherry tart"])]
+#[case("SELECT name FROM products WHERE name LIKE '%e%t'", &["cherry tart"])]
+#[case("SELECT name FROM products WHERE code LIKE '__-_00'", &["apple pie", "cherry tart"])]
+#[case("SELECT name FROM products WHERE code LIKE '%!_%' ESCAPE '!'", &["banana bread"])]
+#[case("SELECT name FROM products WHERE code LIKE '%\\%%' ESCAPE '\\'", &["apple juice"])]
+#[case("SELECT name FROM products WHERE name LIKE code || '%'", &[])]
+#[case("SELECT name FROM products WHERE name || code LIKE name || '%'", &["apple pie", "banana bread", "cherry tart", "apple juice"])]
+#[case("SELECT name FROM products WHERE name SIMILAR TO '(apple|cherry)%'", &["apple pie", "cherry tart", "apple juice"])]
+#[case("SELECT name FROM products WHERE code SIMILAR TO '[A-Z]{2}-[0-9]+'", &["apple pie", "cherry tart"])]
+#[case("SELECT name FROM products WHERE code NOT SIMILAR TO '%#%%' ESCAPE '#'", &["apple pie", "banana bread", "cherry tart"])]
+#[case("SELECT name FROM products WHERE price > ALL (4, 8, 12)", &["cherry tart"])]
+#[case("SELECT name FROM products WHERE price = ANY (4, 8)", &["banana bread", "apple juice"])]
+#[case("SELECT name FROM products WHERE price < SOME (5, 9)", &["banana bread", "apple juice"])]
+#[case("SELECT name FROM products WHERE price <> ALL (4, 8)", &["apple pie", "cherry tart"])]
+#[case("SELECT name FROM products WHERE name IS NOT NULL AND price IS DISTINCT FROM 12", &["banana bread", "cherry tart", "apple juice"])]
+#[case("SELECT name FROM products WHERE price IS NOT DISTINCT FROM 12", &["apple pie"])]
+#[case("SELECT name FROM products WHERE name IS NULL", &[])]
+fn select_predicates(#[case] query: &str, #[case] names: &[&str]) {
+    let mut engine = common::engine_with_table(PRODUCTS_TABLE, PRODUCTS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_names(result, names);
+}
+
+#[rstest]
+#[case("SELECT UNKNOWN IS NULL", EngineRowColumnValue::Bool(true))]
+#[case("SELECT TRUE IS NOT NULL", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN IS DISTINCT FROM UNKNOWN", EngineRowColumnValue::Bool(false))]
+#[case("SELECT UNKNOWN IS DISTINCT FROM TRUE", EngineRowColumnValue::Bool(true))]
+#[case("SELECT UNKNOWN = UNKNOWN", EngineRowColumnValue::Null)]
+#[case("SELECT UNKNOWN IN (TRUE, FALSE)", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE IN (FALSE, UNKNOWN)", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE IN (UNKNOWN, TRUE)", EngineRowColumnValue::Bool(true))]
+#[case("SELECT TRUE NOT IN (FALSE, UNKNOWN)", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE = ALL (TRUE, UNKNOWN)", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE = ALL (FALSE, UNKNOWN)", EngineRowColumnValue::Bool(false))]
+#[case("SELECT TRUE = ANY (FALSE, UNKNOWN)", EngineRowColumnValue::Null)]
+#[case("SELECT TRUE BETWEEN FALSE AND UNKNOWN", EngineRowColumnValue::Null)]
+#[case("SELECT FALSE BETWEEN TRUE AND UNKNOWN", EngineRowColumnValue::Bool(false))]
+#[case("SELECT 2 BETWEEN SYMMETRIC 3 AND 1", EngineRowColumnValue::Bool(true))]
+#[case("SELECT 'abc' LIKE 'a_c'", EngineRowColumnValue::Bool(true))]
+#[case("SELECT 'abc' SIMILAR TO 'a|b'", EngineRowColumnValue::Bool(false))]
+fn select_predicates_without_from(#[case] query: &str, #[case] value: EngineRowColumnValue) {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = Engine::new();
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![EngineRow { values: vec![value] }]);
+}
+
+#[rstest]
+#[case("SELECT name FROM products WHERE price BETWEEN 'a' AND 10", "type error: can't compare INTEGER with CHARACTER VARYING using `BETWEEN`")]
+#[case("SELECT name FROM products WHERE price IN (1, 'a')", "type error: can't compare INTEGER with CHARACTER VARYING using `IN`")]
+#[case("SELECT name FROM products WHERE price > ANY (1, name)", "type error: can't compare INTEGER with CHARACTER VARYING using `>`")]
+#[case("SELECT name FROM products WHERE price IS DISTINCT FROM name", "type error: can't compare INTEGER with CHARACTER VARYING using `IS DISTINCT FROM`")]
+#[case("SELECT name FROM products WHERE price LIKE '1%'", "type error: operator `LIKE` expects an operand of type CHARACTER VARYING, but got INTEGER")]
+#[case("SELECT name FROM products WHERE name SIMILAR TO 1", "type error: operator `SIMILAR TO` expects an operand of type CHARACTER VARYING, but got INTEGER")]
+#[case("SELECT name FROM products WHERE name LIKE 'a' ESCAPE 1", "type error: operator `ESCAPE` expects an operand of type CHARACTER VARYING, but got INTEGER")]
+#[case("SELECT name FROM products WHERE name LIKE 'a!' ESCAPE '!'", "invalid escape sequence in LIKE pattern: 'a!'")]
+#[case("SELECT name FROM products WHERE name LIKE 'a' ESCAPE '!!'", "invalid escape string: '!!'")]
+#[case("SELECT name FROM products WHERE name SIMILAR TO '(a'", "invalid SIMILAR TO pattern: '(a'")]
+#[case("SELECT name FROM products WHERE name LIKE name || '!' ESCAPE '!'", "invalid escape sequence in LIKE pattern: 'apple pie!'")]
+fn select_predicates_erroneous(#[case] query: &str, #[case] error: &str) {
+    let mut engine = common::engine_with_table(PRODUCTS_TABLE, PRODUCTS_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.first().map(EngineMessage::message), Some(&EngineMessage::Error(error.to_owned().into())));
+}
@@ -1,7 +1,7 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use raccolta_engine::{Engine, EngineRowColumnValue, EngineRow};
+use raccolta_engine::{Engine, EngineMessage, EngineRowColumnValue, EngineRow};
 use raccolta_syntax::Parser;
 use rstest::rstest;
 
@@ -76,3 +76,241 @@ fn select_some(#[case] create_statement: &str, #[case] insert_statement: &str, #
 
     assert_eq!(result.row_iterator.collect::<Vec<EngineRow>>(), rows);
 }
+
+#[rstest]
+#[case(
+    "CREATE TABLE hashes (hash VARBINARY(4));",
+    "INSERT INTO hashes VALUES (X'DEADBEEF'), (X'cafe'), (X'')",
+    vec!["X'DEADBEEF'", "X'CAFE'", "X''"]
+)]
+#[case(
+    "CREATE TABLE hashes (hash BINARY(3));",
+    "INSERT INTO hashes VALUES (X'01'), (X'AB CD EF')",
+    vec!["X'010000'", "X'ABCDEF'"]
+)]
+#[case(
+    "CREATE TABLE payloads (payload BLOB);",
+    "INSERT INTO payloads VALUES (X'0123456789ABCDEF')",
+    vec!["X'0123456789ABCDEF'"]
+)]
+#[case(
+    "CREATE TABLE flags (flags BIT(4));",
+    "INSERT INTO flags VALUES (B'1010'), (B'1'), (B'1111')",
+    vec!["B'1010'", "B'1000'", "B'1111'"]
+)]
+#[case(
+    "CREATE TABLE flags (flags BIT VARYING(8));",
+    "INSERT INTO flags VALUES (B'1010'), (B'')",
+    vec!["B'1010'", "B''"]
+)]
+fn select_binary_and_bit_strings(#[case] create_statement: &str, #[case] insert_statement: &str, #[case] expected: Vec<&str>) {
+    use pretty_assertions::assert_eq;
+
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement(create_statement).expect("failed to parse create_statement"));
+    _ = engine.execute_statement(parser.parse_statement(insert_statement).expect("failed to parse insert_statement"));
+
+    let table_name = create_statement.split_whitespace().nth(2).unwrap();
+    let query_statement = parser.parse_statement(&format!("SELECT * FROM {table_name}"))
+        .expect("failed to parse statement");
+
+    let result = engine.execute_statement(query_statement);
+    assert_eq!(result.row_count, expected.len());
+
+    let values: Vec<String> = result.row_iterator
+        .map(|row| row.values[0].to_string())
+        .collect();
+    assert_eq!(values, expected);
+}
+
+#[rstest]
+#[case(
+    "CREATE TABLE hashes (hash BINARY(2));",
+    "INSERT INTO hashes VALUES (X'010203')",
+    "string data, right truncation: value of 3 octets is too long for column \"hash\" of type BINARY(2)"
+)]
+#[case(
+    "CREATE TABLE hashes (hash VARBINARY(2));",
+    "INSERT INTO hashes VALUES (X'010203')",
+    "string data, right truncation: value of 3 octets is too long for column \"hash\" of type VARBINARY(2)"
+)]
+#[case(
+    "CREATE TABLE flags (flags BIT(3));",
+    "INSERT INTO flags VALUES (B'10101')",
+    "string data, right truncation: value of 5 bits is too long for column \"flags\" of type BIT(3)"
+)]
+#[case(
+    "CREATE TABLE flags (flags BIT VARYING(3));",
+    "INSERT INTO flags VALUES (B'1010')",
+    "string data, right truncation: value of 4 bits is too long for column \"flags\" of type BIT VARYING(3)"
+)]
+fn insert_binary_and_bit_strings_too_long(#[case] create_statement: &str, #[case] insert_statement: &str, #[case] error: &str) {
+    use pretty_assertions::assert_eq;
+
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement(create_statement).expect("failed to parse create_statement"));
+    let result = engine.execute_statement(parser.parse_statement(insert_statement).expect("failed to parse insert_statement"));
+
+    assert_eq!(result.messages, vec![EngineMessage::Error(error.to_owned().into())]);
+}
+
+#[rstest]
+#[case("CREATE TABLE documents (id INT, body CLOB);")]
+#[case("CREATE TABLE documents (id INT, body CLOB(1M));")]
+#[case("CREATE TABLE documents (id INT, body NCLOB(2K));")]
+#[case("CREATE TABLE documents (id INT, body CLOB(1T));")]
+#[case("CREATE TABLE documents (id INT, body CHARACTER LARGE OBJECT);")]
+fn select_character_large_objects(#[case] create_statement: &str) {
+    use pretty_assertions::assert_eq;
+
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement(create_statement).expect("failed to parse create_statement"));
+    _ = engine.execute_statement(parser.parse_statement("INSERT INTO documents VALUES (1, 'Lorem ipsum'), (2, 'dolor sit amet')").expect("failed to parse insert_statement"));
+
+    let query = || parser.parse_statement("SELECT * FROM documents").expect("failed to parse statement");
+
+    let first: Vec<EngineRow> = engine.execute_statement(query()).row_iterator.collect();
+    let second: Vec<EngineRow> = engine.execute_statement(query()).row_iterator.collect();
+
+    for (first, second) in first.iter().zip(&second) {
+        // The values should be shared with the table, not copied per row.
+        let (EngineRowColumnValue::CharacterLargeObject(first), EngineRowColumnValue::CharacterLargeObject(second)) = (&first.values[1], &second.values[1]) else {
+            panic!("expected large object values, but got: {first:?} and {second:?}");
+        };
+        assert!(first.ptr_eq(second));
+    }
+
+    let materialized: Vec<EngineRowColumnValue> = first.into_iter()
+        .map(|row| row.values[1].clone().materialize())
+        .collect();
+    assert_eq!(materialized, vec![
+        EngineRowColumnValue::String("Lorem ipsum".into()),
+        EngineRowColumnValue::String("dolor sit amet".into()),
+    ]);
+}
+
+#[test]
+fn select_character_large_object_truncated_to_length() {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement("CREATE TABLE notes (body CLOB(4))").unwrap());
+    _ = engine.execute_statement(parser.parse_statement("INSERT INTO notes VALUES ('Hello, world!')").unwrap());
+
+    let result = engine.execute_statement(parser.parse_statement("SELECT body FROM notes").unwrap());
+    let values: Vec<String> = result.row_iterator
+        .map(|row| row.values[0].to_string())
+        .collect();
+    assert_eq!(values, vec!["Hell"]);
+}
+
+#[rstest]
+#[case("SELECT \"Order\" FROM \"Select\"", Some(vec!["Order"]))]
+#[case("SELECT \"Order\" FROM [Select]", None)]
+#[case("SELECT `Order` FROM `Select`", None)]
+#[case("SELECT \"Order\" FROM select_", None)]
+#[case("SELECT \"order\" FROM \"Select\"", None)]
+#[case("SELECT \"Order\" FROM \"SELECT\"", None)]
+#[case("SELECT \"Order\", \"Item Name\" FROM \"Select\"", Some(vec!["Order", "Item Name"]))]
+#[case("SELECT \"Quote\"\"d\" FROM \"Select\"", Some(vec!["Quote\"d"]))]
+fn select_delimited_identifiers(#[case] query: &str, #[case] column_names: Option<Vec<&str>>) {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    let create_statement = parser.parse_statement("CREATE TABLE \"Select\" (\"Order\" INT, \"Item Name\" INT, \"Quote\"\"d\" INT);")
+        .expect("failed to parse create statement");
+    _ = engine.execute_statement(create_statement);
+
+    let query_statement = parser.parse_statement(query)
+        .expect("failed to parse statement");
+
+    let result = engine.execute_statement(query_statement);
+    match column_names {
+        Some(column_names) => {
+            assert_eq!(result.messages, vec![]);
+            assert_eq!(result.column_names, column_names);
+        }
+        None => assert!(!result.messages.is_empty()),
+    }
+}
+
+/// Regular identifiers are equivalent to their upper-case form, whereas
+/// delimited identifiers are taken verbatim. Backticks and square brackets
+/// don't make an identifier case-sensitive.
+#[rstest]
+#[case("CREATE TABLE café (a INT)", "SELECT a FROM CAFÉ", true)]
+#[case("CREATE TABLE foo (a INT)", "SELECT a FROM \"FOO\"", true)]
+#[case("CREATE TABLE foo (a INT)", "SELECT a FROM \"foo\"", false)]
+#[case("CREATE TABLE \"FOO\" (a INT)", "SELECT a FROM foo", true)]
+#[case("CREATE TABLE \"Foo\" (a INT)", "SELECT a FROM foo", false)]
+#[case("CREATE TABLE t (\"A\" INT)", "SELECT a FROM t", true)]
+#[case("CREATE TABLE t (\"a\" INT)", "SELECT a FROM t", false)]
+#[case("CREATE TABLE t (straße INT)", "SELECT STRASSE FROM t", true)]
+#[case("CREATE TABLE t (a INT)", "SELECT \"a\" FROM t", false)]
+#[case("CREATE TABLE t (a INT)", "SELECT \"A\" FROM t", true)]
+#[case("CREATE TABLE t (a INT)", "SELECT `a` FROM t", true)]
+#[case("CREATE TABLE t (a INT)", "SELECT [a] FROM t", true)]
+#[case("CREATE TABLE [Foo] (a INT)", "SELECT a FROM foo", true)]
+#[case("CREATE TABLE `Foo` (a INT)", "SELECT a FROM FOO", true)]
+fn select_normalized_identifiers(#[case] create_statement: &str, #[case] query: &str, #[case] is_found: bool) {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    let create_result = engine.execute_statement(parser.parse_statement(create_statement).unwrap());
+    assert_eq!(create_result.messages.iter().filter(|message| matches!(message.message(), EngineMessage::Error(..))).count(), 0);
+
+    let result = engine.execute_statement(parser.parse_statement(query).unwrap());
+    let has_errors = result.messages.iter().any(|message| matches!(message.message(), EngineMessage::Error(..)));
+    assert_eq!(has_errors, !is_found, "{:?}", result.messages);
+}
+
+#[rstest]
+#[case("CREATE TABLE \"Foo\" (a INT)", "CREATE TABLE FOO (a INT)", false)]
+#[case("CREATE TABLE \"Foo\" (a INT)", "CREATE TABLE \"foo\" (a INT)", false)]
+#[case("CREATE TABLE foo (a INT)", "CREATE TABLE \"FOO\" (a INT)", true)]
+#[case("CREATE TABLE foo (a INT)", "CREATE TABLE Foo (a INT)", true)]
+fn create_table_duplicate_name(#[case] first_statement: &str, #[case] second_statement: &str, #[case] is_duplicate: bool) {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement(first_statement).unwrap());
+    let result = engine.execute_statement(parser.parse_statement(second_statement).unwrap());
+
+    let has_errors = result.messages.iter().any(|message| matches!(message.message(), EngineMessage::Error(..)));
+    assert_eq!(has_errors, is_duplicate, "{:?}", result.messages);
+}
+
+#[rstest]
+#[case("INSERT INTO numbers VALUES (-5), (+7), (-2147483648), (2147483647)", Some(vec![-5, 7, i32::MIN, i32::MAX]))]
+#[case("INSERT INTO numbers VALUES (2147483648)", None)]
+#[case("INSERT INTO numbers VALUES (-2147483649)", None)]
+#[case("INSERT INTO numbers VALUES (3.14)", None)]
+fn select_signed_integers(#[case] insert_statement: &str, #[case] expected: Option<Vec<i32>>) {
+    let parser = Parser::new();
+    let mut engine = Engine::new();
+
+    _ = engine.execute_statement(parser.parse_statement("CREATE TABLE numbers (num INT);").unwrap());
+    let insert_result = engine.execute_statement(parser.parse_statement(insert_statement).unwrap());
+
+    let result = engine.execute_statement(parser.parse_statement("SELECT * FROM numbers").unwrap());
+    let values: Vec<_> = result.row_iterator
+        .map(|row| row.values[0].clone())
+        .collect();
+
+    match expected {
+        Some(expected) => {
+            let expected: Vec<_> = expected.into_iter().map(EngineRowColumnValue::I32).collect();
+            assert_eq!(values, expected);
+        }
+        None => {
+            assert!(insert_result.messages.iter().any(|message| matches!(message.message(), raccolta_engine::EngineMessage::Error(..))));
+            assert_eq!(values, vec![]);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{EngineRow, EngineRowColumnValue};
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const PEOPLE_TABLE: &str = "CREATE TABLE people (name VARCHAR(20), age INT, admin BOOLEAN);";
+const PEOPLE_ROWS: &str = "INSERT INTO people VALUES ('Ada', 36, TRUE), ('Bob', 17, FALSE), ('Cleo', 52, FALSE), ('Dan', 8, TRUE)";
+
+#[rstest]
+#[case("SELECT name FROM people WHERE age > 18", &["Ada", "Cleo"])]
+#[case("SELECT name FROM people WHERE admin", &["Ada", "Dan"])]
+#[case("SELECT name FROM people WHERE NOT admin", &["Bob", "Cleo"])]
+#[case("SELECT name FROM people WHERE age > 18 AND admin", &["Ada"])]
+#[case("SELECT name FROM people WHERE age < 10 OR age > 50", &["Cleo", "Dan"])]
+#[case("SELECT name FROM people WHERE NOT (age < 10 OR age > 50)", &["Ada", "Bob"])]
+#[case("SELECT name FROM people WHERE admin IS FALSE", &["Bob", "Cleo"])]
+#[case("SELECT name FROM people WHERE age > 18 IS NOT TRUE", &["Bob", "Dan"])]
+#[case("SELECT name FROM people WHERE name || '!' = 'Bob!'", &["Bob"])]
+#[case("SELECT name FROM people WHERE age * 2 >= 72 ORDER BY name DESC", &["Cleo", "Ada"])]
+#[case("SELECT name FROM people WHERE TRUE", &["Ada", "Bob", "Cleo", "Dan"])]
+#[case("SELECT name FROM people WHERE FALSE", &[])]
+#[case("SELECT name FROM people WHERE UNKNOWN", &[])]
+#[case("SELECT name FROM people WHERE NOT UNKNOWN", &[])]
+#[case("SELECT name FROM people WHERE admin OR UNKNOWN", &["Ada", "Dan"])]
+#[case("SELECT name FROM people WHERE (admin AND UNKNOWN) IS UNKNOWN", &["Ada", "Dan"])]
+#[case("SELECT name FROM people WHERE age < 18 AND 100 / age > 10", &["Dan"])]
+fn select_where(#[case] query: &str, #[case] names: &[&str]) {
+    let mut engine = common::engine_with_table(PEOPLE_TABLE, PEOPLE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_names(result, names);
+}
+
+#[test]
+fn select_all_where() {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = common::engine_with_table(PEOPLE_TABLE, PEOPLE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement("SELECT * FROM people WHERE age > 30 AND NOT admin").unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.column_names, vec!["name", "age", "admin"]);
+    assert_eq!(result.row_count, 1);
+    assert_eq!(result.row_iterator.collect::<Vec<_>>(), vec![
+        EngineRow { values: vec![
+            EngineRowColumnValue::String("Cleo".into()),
+            EngineRowColumnValue::I32(52),
+            EngineRowColumnValue::Bool(false),
+        ] },
+    ]);
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use raccolta_engine::{EngineMessage, EngineRow, EngineRowColumnValue};
+use raccolta_syntax::Parser;
+use rstest::rstest;
+
+mod common;
+
+const SALE_TABLE: &str = "CREATE TABLE sale (region VARCHAR(10), week INT, amount INT);";
+const SALE_ROWS: &str = "INSERT INTO sale VALUES ('north', 1, 10), ('south', 1, 5), ('north', 2, 20), ('north', 3, 20), ('south', 2, 7), ('north', 4, 30)";
+
+fn integers(values: &[Option<i32>]) -> Vec<EngineRowColumnValue> {
+    values.iter()
+        .map(|value| value.map_or(EngineRowColumnValue::Null, EngineRowColumnValue::I32))
+        .collect()
+}
+
+#[rstest]
+#[case(
+    "SELECT ROW_NUMBER() OVER (ORDER BY week, region) FROM sale",
+    integers(&[Some(1), Some(2), Some(3), Some(5), Some(4), Some(6)])
+)]
+#[case(
+    "SELECT ROW_NUMBER() OVER (PARTITION BY region ORDER BY week DESC) FROM sale",
+    integers(&[Some(4), Some(2), Some(3), Some(2), Some(1), Some(1)])
+)]
+#[case(
+    "SELECT RANK() OVER (ORDER BY amount DESC) FROM sale",
+    integers(&[Some(4), Some(6), Some(2), Some(2), Some(5), Some(1)])
+)]
+#[case(
+    "SELECT DENSE_RANK() OVER (PARTITION BY region ORDER BY amount) FROM sale",
+    integers(&[Some(1), Some(1), Some(2), Some(2), Some(2), Some(3)])
+)]
+#[case(
+    "SELECT SUM(amount) OVER (PARTITION BY region ORDER BY week) FROM sale",
+    integers(&[Some(10), Some(5), Some(30), Some(50), Some(12), Some(80)])
+)]
+#[case(
+    "SELECT SUM(amount) OVER (PARTITION BY region) FROM sale",
+    integers(&[Some(80), Some(12), Some(80), Some(80), Some(12), Some(80)])
+)]
+#[case(
+    "SELECT SUM(amount) OVER (ORDER BY amount) FROM sale",
+    integers(&[Some(22), Some(5), Some(62), Some(62), Some(12), Some(92)])
+)]
+#[case(
+    "SELECT SUM(amount) OVER (PARTITION BY region ORDER BY week ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM sale",
+    integers(&[Some(10), Some(5), Some(30), Some(40), Some(12), Some(50)])
+)]
+#[case(
+    "SELECT COUNT(*) OVER (PARTITION BY region ORDER BY week ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) FROM sale",
+    integers(&[Some(4), Some(2), Some(3), Some(2), Some(1), Some(1)])
+)]
+#[case(
+    "SELECT COUNT(NULLIF(amount, 20)) OVER (PARTITION BY region ORDER BY week) FROM sale",
+    integers(&[Some(1), Some(1), Some(1), Some(1), Some(2), Some(2)])
+)]
+#[case(
+    "SELECT MAX(amount) OVER (ORDER BY week, region ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) FROM sale",
+    integers(&[Some(10), Some(20), Some(20), Some(30), Some(20), Some(30)])
+)]
+#[case(
+    "SELECT MIN(week) OVER (PARTITION BY region ORDER BY week ROWS BETWEEN 1 FOLLOWING AND 2 FOLLOWING) FROM sale",
+    integers(&[Some(2), Some(2), Some(3), Some(4), None, None])
+)]
+#[case(
+    "SELECT SUM(week) OVER (ORDER BY week RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM sale",
+    integers(&[Some(2), Some(2), Some(6), Some(7), Some(6), Some(7)])
+)]
+#[case(
+    "SELECT SUM(week) OVER (ORDER BY week DESC RANGE BETWEEN CURRENT ROW AND 1 FOLLOWING) FROM sale",
+    integers(&[Some(2), Some(2), Some(6), Some(7), Some(6), Some(7)])
+)]
+#[case(
+    "SELECT SUM(week) OVER (ORDER BY amount RANGE BETWEEN 5 PRECEDING AND 5 FOLLOWING) FROM sale",
+    integers(&[Some(4), Some(4), Some(5), Some(5), Some(4), Some(4)])
+)]
+#[case(
+    "SELECT RANK() OVER w + SUM(amount) OVER (w ORDER BY week) FROM sale WINDOW w AS (PARTITION BY region)",
+    integers(&[Some(11), Some(6), Some(31), Some(51), Some(13), Some(81)])
+)]
+#[case(
+    "SELECT ROW_NUMBER() OVER v FROM sale WHERE week > 1 WINDOW w AS (PARTITION BY region), v AS (w ORDER BY week)",
+    integers(&[Some(1), Some(2), Some(1), Some(3)])
+)]
+fn select_window_functions(#[case] query: &str, #[case] values: Vec<EngineRowColumnValue>) {
+    let mut engine = common::engine_with_table(SALE_TABLE, SALE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_first_column(result, values);
+}
+
+#[rstest]
+#[case(
+    "SELECT PERCENT_RANK() OVER (PARTITION BY region ORDER BY week) FROM sale",
+    vec![0.0, 0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0, 1.0]
+)]
+#[case(
+    "SELECT CUME_DIST() OVER (ORDER BY amount) FROM sale",
+    vec![0.5, 1.0 / 6.0, 5.0 / 6.0, 5.0 / 6.0, 1.0 / 3.0, 1.0]
+)]
+#[case(
+    "SELECT AVG(amount) OVER (PARTITION BY region ORDER BY week) FROM sale",
+    vec![10.0, 5.0, 15.0, 50.0 / 3.0, 6.0, 20.0]
+)]
+fn select_window_functions_double(#[case] query: &str, #[case] values: Vec<f64>) {
+    let mut engine = common::engine_with_table(SALE_TABLE, SALE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    common::assert_first_column(result, values.into_iter().map(EngineRowColumnValue::F64).collect());
+}
+
+#[test]
+fn select_window_functions_sorted_by_query() {
+    use pretty_assertions::assert_eq;
+
+    let mut engine = common::engine_with_table(SALE_TABLE, SALE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(
+        "SELECT week, RANK() OVER (ORDER BY week) AS place FROM sale WHERE region = 'north' ORDER BY place DESC"
+    ).unwrap());
+
+    assert_eq!(result.messages, vec![]);
+    assert_eq!(result.column_names, vec!["week", "place"]);
+    assert_eq!(
+        result.row_iterator.collect::<Vec<_>>(),
+        [4, 3, 2, 1].into_iter()
+            .map(|value| EngineRow { values: vec![EngineRowColumnValue::I32(value), EngineRowColumnValue::I32(value)] })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[rstest]
+#[case("SELECT week FROM sale WHERE RANK() OVER () = 1", "window functions are not allowed here")]
+#[case("SELECT SUM(RANK() OVER ()) OVER () FROM sale", "window functions are not allowed here")]
+#[case("SELECT RANK() OVER w FROM sale", "window \"w\" does not exist")]
+#[case("SELECT RANK() OVER w FROM sale WINDOW w AS (), w AS ()", "window \"w\" is already defined")]
+#[case("SELECT RANK() OVER v FROM sale WINDOW v AS (w), w AS ()", "window \"w\" does not exist")]
+#[case(
+    "SELECT RANK() OVER (w PARTITION BY week) FROM sale WINDOW w AS (PARTITION BY region)",
+    "can't override the PARTITION BY clause of window \"w\""
+)]
+#[case(
+    "SELECT RANK() OVER (w ORDER BY week) FROM sale WINDOW w AS (ORDER BY region)",
+    "can't override the ORDER BY clause of window \"w\""
+)]
+#[case(
+    "SELECT RANK() OVER (w) FROM sale WINDOW w AS (ROWS UNBOUNDED PRECEDING)",
+    "can't copy window \"w\" because it has a frame clause"
+)]
+#[case("SELECT RANK() OVER (ORDER BY nothing) FROM sale", "failed to find column: \"nothing\"")]
+#[case("SELECT SUM(region) OVER () FROM sale", "type error: `SUM` doesn't accept arguments of type (CHARACTER VARYING)")]
+#[case("SELECT EVERY(week) OVER () FROM sale", "type error: `EVERY` doesn't accept arguments of type (INTEGER)")]
+#[case("SELECT COUNT(DISTINCT week) OVER () FROM sale", "`COUNT(DISTINCT ...)` isn't supported in window functions")]
+#[case("SELECT SUM(week) OVER (ROWS UNBOUNDED FOLLOWING) FROM sale", "frame start can't be UNBOUNDED FOLLOWING")]
+#[case(
+    "SELECT SUM(week) OVER (ROWS BETWEEN CURRENT ROW AND UNBOUNDED PRECEDING) FROM sale",
+    "frame end can't be UNBOUNDED PRECEDING"
+)]
+#[case(
+    "SELECT SUM(week) OVER (ROWS BETWEEN CURRENT ROW AND 1 PRECEDING) FROM sale",
+    "frame starting from the current row can't have preceding rows"
+)]
+#[case(
+    "SELECT SUM(week) OVER (ROWS BETWEEN 1 FOLLOWING AND CURRENT ROW) FROM sale",
+    "frame starting from following rows can't have preceding rows"
+)]
+#[case(
+    "SELECT SUM(week) OVER (ORDER BY week, amount RANGE 1 PRECEDING) FROM sale",
+    "RANGE with offset PRECEDING or FOLLOWING requires exactly one ORDER BY column"
+)]
+#[case(
+    "SELECT SUM(week) OVER (ORDER BY region RANGE 1 PRECEDING) FROM sale",
+    "type error: RANGE with offset PRECEDING or FOLLOWING requires a numeric ORDER BY column, but got CHARACTER VARYING"
+)]
+fn select_window_functions_erroneous(#[case] query: &str, #[case] error: &str) {
+    let mut engine = common::engine_with_table(SALE_TABLE, SALE_ROWS);
+    let result = engine.execute_statement(Parser::new().parse_statement(query).unwrap());
+
+    assert_eq!(result.messages.first().map(EngineMessage::message), Some(&EngineMessage::Error(error.to_owned().into())));
+}
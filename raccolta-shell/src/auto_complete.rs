@@ -10,26 +10,18 @@ use raccolta_engine::Engine;
 use raccolta_syntax::{
     expression::{
         ColumnReference,
-        query_expression::{
-            QueryExpressionBody,
-            SimpleTable,
-        },
-        query_specification::{
-            SelectList,
-            SelectSublist
-        },
+        query_specification::DerivedColumn,
+        ValueExpression,
     },
     keyword::{
         NonReservedWord,
         ReservedWord,
     },
     Lexer,
-    statement::{
-        SqlDataStatement,
-        SqlExecutableStatement,
-    },
+    statement::SqlExecutableStatement,
     Token,
     TokenKind,
+    visit::Visit,
 };
 use strum::IntoEnumIterator;
 
@@ -73,16 +65,13 @@ impl AutoCompleter {
 
             if let TokenKind::ReservedWord(reserved_word) = tokens[tokens.len() - 2].kind() {
                 match reserved_word {
-                    ReservedWord::By => {
-                        if tokens.len() > 3 && tokens[tokens.len() - 3].kind() == TokenKind::ReservedWord(ReservedWord::Order) {
-                            let order_token = tokens[tokens.len() - 3];
-
-                            let statement = raccolta_syntax::Parser::new()
-                                .parse_statement(&input[0..order_token.first_character_byte_idx]);
-                            match statement {
-                                Ok(statement) => return self.suggest_column_names(statement, identifier),
-                                Err(_) => (),
-                            }
+                    ReservedWord::By if tokens.len() > 3 && tokens[tokens.len() - 3].kind() == TokenKind::ReservedWord(ReservedWord::Order) => {
+                        let order_token = tokens[tokens.len() - 3];
+
+                        let statement = raccolta_syntax::Parser::new()
+                            .parse_statement(&input[0..order_token.first_character_byte_idx]);
+                        if let Ok(statement) = statement {
+                            return self.suggest_column_names(statement, identifier);
                         }
                     }
 
@@ -119,8 +108,8 @@ impl AutoCompleter {
         let last_token = tokens.last().to_owned();
         let second_last_token = tokens.iter().nth_back(1).to_owned();
 
-        let last_token_reserved_word = last_token.map(|token| token.as_reserved_word()).flatten();
-        let second_last_token_reserved_word = second_last_token.map(|token| token.as_reserved_word()).flatten();
+        let last_token_reserved_word = last_token.and_then(|token| token.as_reserved_word());
+        let second_last_token_reserved_word = second_last_token.and_then(|token| token.as_reserved_word());
 
         if second_last_token_reserved_word == Some(ReservedWord::Order)
                 && last_token_reserved_word == Some(ReservedWord::By)
@@ -128,9 +117,7 @@ impl AutoCompleter {
             let result = raccolta_syntax::Parser::new()
                 .parse_statement(&input[0..second_last_token.unwrap().first_character_byte_idx]);
             if let Ok(statement) = result {
-                if let Some(column_names) = self.get_column_names(statement) {
-                    return Some(column_names.collect());
-                }
+                return Some(self.get_column_names(&statement));
             }
         }
 
@@ -154,38 +141,28 @@ impl AutoCompleter {
         ]
     }
 
-    fn get_column_names(&self, statement: SqlExecutableStatement) -> Option<Box<dyn Iterator<Item = String>>> {
-        if let SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query_expression)) = statement {
-            if let QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(query_specification)) = query_expression.body {
-                if let SelectList::Sublist(sublist) = query_specification.select_list {
-                    let iterator = sublist
-                        .into_iter()
-                        .map(|sublist| {
-                            match sublist {
-                                SelectSublist::DerivedColumn(column) => match column.value_expression {
-                                    raccolta_syntax::expression::ValueExpression::ColumnReference(column) => {
-                                        match column {
-                                            ColumnReference::BasicIdentifierChain(chain) => Some(chain.join(".")),
-                                            _ => None,
-                                        }
-                                    }
-                                    _ => None,
-                                }
-                            }
-                        })
-                        .flatten();
-                    return Some(Box::new(iterator));
-                }
-            }
-        }
-
-        None
+    /// Get the names of the columns that are selected by the `SELECT`
+    /// statement.
+    fn get_column_names(&self, statement: &SqlExecutableStatement) -> Vec<String> {
+        let mut column_names = SelectedColumnNames::default();
+        column_names.visit_sql_executable_statement(statement);
+        column_names.0
     }
 
     fn suggest_column_names(&self, statement: SqlExecutableStatement, identifier: &str) -> Vec<String> {
-        match self.get_column_names(statement) {
-            Some(iterator) => filter_strings(iterator, identifier),
-            None => Vec::new()
+        filter_strings(self.get_column_names(&statement).into_iter(), identifier)
+    }
+}
+
+/// Collects the names of the columns of the select lists that are plain
+/// column references.
+#[derive(Debug, Default)]
+struct SelectedColumnNames(Vec<String>);
+
+impl Visit for SelectedColumnNames {
+    fn visit_derived_column(&mut self, derived_column: &DerivedColumn) {
+        if let ValueExpression::ColumnReference(ColumnReference::BasicIdentifierChain(chain)) = &derived_column.value_expression {
+            self.0.push(chain.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join("."));
         }
     }
 }
@@ -300,26 +277,24 @@ impl inquire::Autocomplete for AutoCompleter {
 
             TokenKind::ReservedWord(ReservedWord::Select) => return Ok(self.complete_select(input, &tokens)),
 
-            TokenKind::Identifier => {
-                if tokens.len() == 1 {
-                    let word = tokens[0].as_string(input);
-                    let mut keywords: Vec<_> = self.get_starting_tokens()
-                        .into_iter()
-                        .filter(|keyword| starts_with_ignore_case(keyword, word))
-                        .collect();
-
-                    let mut other_words: Vec<_> = filter_all_keywords(word)
-                        .into_iter()
-                        .filter(|word| {
-                            !keywords.contains(word)
-                        })
-                        .collect();
-                    other_words.sort();
-
-                    keywords.extend(other_words);
-
-                    return Ok(keywords);
-                }
+            TokenKind::Identifier if tokens.len() == 1 => {
+                let word = tokens[0].as_string(input);
+                let mut keywords: Vec<_> = self.get_starting_tokens()
+                    .into_iter()
+                    .filter(|keyword| starts_with_ignore_case(keyword, word))
+                    .collect();
+
+                let mut other_words: Vec<_> = filter_all_keywords(word)
+                    .into_iter()
+                    .filter(|word| {
+                        !keywords.contains(word)
+                    })
+                    .collect();
+                other_words.sort();
+
+                keywords.extend(other_words);
+
+                return Ok(keywords);
             }
 
             _ => ()
@@ -14,6 +14,7 @@ use raccolta_engine::EngineMessage;
 
 use raccolta_syntax::{
     parse::ErrorFindLocation,
+    Lexer,
     StatementParseError,
     Token,
     TokenKind,
@@ -51,7 +52,7 @@ impl MessageKind {
         });
     }
 
-    fn print_with(&self, f: impl FnOnce() -> ()) {
+    fn print_with(&self, f: impl FnOnce()) {
         let color = match self {
             Self::Debug => Color::Magenta,
             Self::Error => Color::Red,
@@ -134,19 +135,14 @@ fn main() {
 
         let mut engine = engine.as_ref().borrow_mut();
 
-        let (result, tokens) = parser.parse_statement_extended(&line);
+        let result = parser.parse_statement(&line);
 
         match result {
             Ok(res) => {
                 println!("{res:#?}");
                 let result = engine.execute_statement(res);
                 for message in &result.messages {
-                    match message {
-                        EngineMessage::Error(err) => println!("Error: {err}"),
-                        EngineMessage::Help(err) => println!("Info: {err}"),
-                        EngineMessage::Hint(err) => println!("Help: {err}"),
-                        EngineMessage::Informational(err) => println!("Info: {err}"),
-                    }
+                    print_engine_message(&line, message);
                 }
 
                 if !result.column_names.is_empty() {
@@ -175,8 +171,8 @@ fn main() {
 
                     println!("{s:-<total_length$}-+", s = "+-");
                     for row_index in 0..=result.row_count {
-                        for (column_idx, column_length) in lengths.iter().enumerate() {
-                            let column_value = &column_values[column_idx][row_index];
+                        for (values, column_length) in column_values.iter().zip(&lengths) {
+                            let column_value = &values[row_index];
                             print!("| {column_value: <column_length$}");
                         }
                         println!(" |");
@@ -190,7 +186,7 @@ fn main() {
 
                 println!("{} row(s)", result.row_count);
 
-                if result.messages.iter().all(|message| matches!(message, EngineMessage::Informational(..))) {
+                if result.messages.iter().all(|message| matches!(message.message(), EngineMessage::Informational(..))) {
                     previous_line = String::new();
                     continue;
                 }
@@ -199,6 +195,8 @@ fn main() {
                 MessageKind::Error.print("error");
                 println!(": {e}");
 
+                // Include the comments, so they are highlighted as well.
+                let tokens: Vec<_> = Lexer::new(&line).with_comments(true).collect();
                 print_error_findings(&line, &e, &tokens);
 
                 if let Some(hint) = e.get_str("Hint") {
@@ -219,6 +217,35 @@ fn main() {
     }
 }
 
+/// Prints a message of the engine. When the message is about a part of the
+/// statement, that part is underlined like the findings of parse errors.
+fn print_engine_message(input: &str, message: &EngineMessage) {
+    match message {
+        EngineMessage::Error(err) => println!("Error: {err}"),
+        EngineMessage::Help(err) => println!("Info: {err}"),
+        EngineMessage::Hint(err) => println!("Help: {err}"),
+        EngineMessage::Informational(err) => println!("Info: {err}"),
+
+        EngineMessage::Located { message, span } => {
+            print_engine_message(input, message);
+
+            let range = span.range();
+            if range.is_empty() || range.end > input.len() {
+                return;
+            }
+
+            let match_item = match message.message() {
+                EngineMessage::Error(..) => Match::error(range, "error occurred here"),
+                _ => Match::hint(range, "here"),
+            };
+
+            // Include the comments, so they are highlighted as well.
+            let tokens: Vec<_> = Lexer::new(input).with_comments(true).collect();
+            print_matches(input, &tokens, vec![match_item]);
+        }
+    }
+}
+
 fn print_debug_info(error: StatementParseError) {
     MessageKind::Debug.print("debug");
 
@@ -260,9 +287,9 @@ fn print_error_findings<'input>(
     error: &StatementParseError<'input>,
     tokens: &[Token],
 ) {
-    let mut matches = [
+    let matches = [
         error.found()
-            .map(|found| {
+            .and_then(|found| {
                 let message = "error occurred here";
                 match found {
                     ErrorFindLocation::EndOfFile { .. } => {
@@ -270,18 +297,13 @@ fn print_error_findings<'input>(
                         Some(Match::error(range, message))
                     }
                     ErrorFindLocation::Position(found) => {
-                        if let Some(range) = get_range_of_string_slice(input, found) {
-                            Some(Match::error(range, message))
-                        } else {
-                            None
-                        }
+                        get_range_of_string_slice(input, found).map(|range| Match::error(range, message))
                     }
                 }
-            })
-            .flatten(),
+            }),
 
         error.should_be_matching()
-            .map(|should_be_matching| {
+            .and_then(|should_be_matching| {
                 if let Some(range) = get_range_of_string_slice(input, should_be_matching.found) {
                     if let TokenKind::LeftParenthesis = should_be_matching.token_kind {
                         Some(Match::hint(range, "match this opening parenthesis here"))
@@ -291,26 +313,29 @@ fn print_error_findings<'input>(
                 } else {
                     None
                 }
-            })
-            .flatten()
+            }),
+
+        error.conflicting_clause()
+            .and_then(|conflicting_clause| match conflicting_clause {
+                ErrorFindLocation::EndOfFile { .. } => None,
+                ErrorFindLocation::Position(found) => {
+                    get_range_of_string_slice(input, found).map(|range| Match::hint(range, "conflicts with this clause"))
+                }
+            }),
     ];
 
-    matches.sort_by(|a, b| {
-        if let Some(a) = a {
-            if let Some(b) = b {
-                a.range.start.cmp(&b.range.start)
-            } else {
-                std::cmp::Ordering::Greater
-            }
-        } else {
-            std::cmp::Ordering::Equal
-        }
-    });
+    print_matches(input, tokens, matches.into_iter().flatten().collect());
+}
+
+/// Prints the `input` with the ranges of the `matches` underlined, and their
+/// messages below them.
+fn print_matches(input: &str, tokens: &[Token], mut matches: Vec<Match>) {
+    matches.sort_by_key(|item| item.range.start);
 
-    let match_count = matches.iter().filter(|item| item.is_some()).count();
+    let match_count = matches.len();
 
     // Nothing to show...
-    if !matches.iter().any(|item| item.is_some()) {
+    if matches.is_empty() {
         return;
     }
 
@@ -318,20 +343,18 @@ fn print_error_findings<'input>(
     println!();
 
     print!("  ");
-    syntax_highlighted::print_syntax_highlighted(input, &tokens, &mut std::io::stdout());
+    syntax_highlighted::print_syntax_highlighted(input, tokens, &mut std::io::stdout());
 
     print!("  ");
 
     let mut last_point = 0;
     for match_item in &matches {
-        let Some(match_item) = match_item else { continue };
-
         assert!(last_point <= match_item.range.start);
 
         match_item.message_kind.print(&format!(
             "{}^{}",
             " ".repeat(match_item.range.start - last_point),
-            "~".repeat(match_item.range.end - match_item.range.start - 1)
+            "~".repeat(match_item.range.len().saturating_sub(1))
         ));
 
         // If there is only one match, print the message on the same line.
@@ -350,13 +373,10 @@ fn print_error_findings<'input>(
     println!();
 
     for (match_item_index, match_item) in matches.iter().enumerate().rev() {
-        let Some(match_item) = match_item else { continue };
-
         print!("  ");
 
         let mut start_point = 0;
-        for match_item in matches[0..match_item_index].iter().rev() {
-            let Some(Match{ range, message_kind, .. }) = match_item else { continue };
+        for Match { range, message_kind, .. } in matches[0..match_item_index].iter().rev() {
             print!("{}", " ".repeat(range.start - start_point));
             message_kind.print("|");
             start_point = range.start + 1;
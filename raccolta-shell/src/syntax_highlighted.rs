@@ -24,7 +24,17 @@ pub fn print_syntax_highlighted(input: &str, tokens: &[Token], output: &mut impl
         index = token.first_character_byte_idx;
 
         match token.kind() {
-            TokenKind::Identifier => {
+            TokenKind::Comment => {
+                _ = execute!(
+                    output,
+                    SetForegroundColor(Color::DarkGrey),
+                    Print(token.as_string(input)),
+                    ResetColor
+                );
+                index = token.last_character_byte_idx;
+            }
+
+            TokenKind::Identifier | TokenKind::DelimitedIdentifier { .. } => {
                 _ = execute!(
                     output,
                     SetForegroundColor(Color::Green),
@@ -0,0 +1,41 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+    Throughput,
+};
+
+use raccolta_syntax::Lexer;
+
+/// Creates a bulk `INSERT` script, like the ones produced by dump tools.
+fn large_insert_script(rows: usize) -> String {
+    let mut script = String::from("CREATE TABLE persons (id INTEGER, name VARCHAR(50), city CHARACTER VARYING(80), birth_year INTEGER);\n");
+
+    for row in 0..rows {
+        script.push_str(&format!(
+            "INSERT INTO persons (id, name, city, birth_year) VALUES ({row}, 'Person number {row}', 'City {}', {});\n",
+            row % 97,
+            1900 + row % 120,
+        ));
+    }
+
+    script
+}
+
+fn lex_large_insert_script(c: &mut Criterion) {
+    let script = large_insert_script(10_000);
+    let token_count = Lexer::new(&script).count();
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Elements(token_count as u64));
+    group.bench_function("large_insert_script", |b| {
+        b.iter(|| Lexer::new(std::hint::black_box(&script)).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lex_large_insert_script);
+criterion_main!(benches);
@@ -0,0 +1,114 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Generates the perfect-hash keyword table used by the lexer, from the
+//! variants of the keyword enums in `src/keyword.rs`.
+
+use std::{
+    collections::HashSet,
+    env,
+    fs,
+    path::Path,
+};
+
+/// The keyword enums, in order of precedence when a word would occur in more
+/// than one of them.
+const KEYWORD_ENUMS: [(&str, &str); 3] = [
+    ("ReservedWord", "Reserved"),
+    ("NonReservedWord", "NonReserved"),
+    ("VendorReservedWord", "VendorReserved"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/keyword.rs");
+
+    let source = fs::read_to_string("src/keyword.rs").expect("failed to read src/keyword.rs");
+
+    let mut map = phf_codegen::Map::new();
+    let mut seen = HashSet::new();
+    let mut max_length = 0;
+
+    for (enum_name, keyword_variant) in KEYWORD_ENUMS {
+        for (variant, word) in enum_variants(&source, enum_name) {
+            if !seen.insert(word.clone()) {
+                continue;
+            }
+
+            max_length = max_length.max(word.len());
+            map.entry(word, format!("Keyword::{keyword_variant}({enum_name}::{variant})"));
+        }
+    }
+
+    let output = format!(
+        "/// The length of the longest keyword, in bytes.\n\
+         const MAX_KEYWORD_LENGTH: usize = {max_length};\n\n\
+         /// All keywords, keyed by their uppercase spelling.\n\
+         static KEYWORDS: phf::Map<&'static str, Keyword> = {};\n",
+        map.build()
+    );
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("keyword_table.rs");
+    fs::write(path, output).expect("failed to write keyword table");
+}
+
+/// Returns the variants of the enum `enum_name`, together with the uppercase
+/// spelling `strum` gives them.
+fn enum_variants(source: &str, enum_name: &str) -> Vec<(String, String)> {
+    let declaration = format!("pub enum {enum_name} {{");
+    let start = source.find(&declaration).unwrap_or_else(|| panic!("enum {enum_name} not found"));
+    let body = &source[start + declaration.len()..];
+    let body = &body[..body.find("\n}").expect("enum isn't closed")];
+
+    let mut variants = Vec::new();
+    let mut explicit_name = None;
+    let mut in_block_comment = false;
+
+    for line in body.lines().map(str::trim) {
+        // Retired keywords are commented out using block comments.
+        if line.starts_with("/*") {
+            in_block_comment = !line.ends_with("*/");
+            continue;
+        }
+
+        if in_block_comment {
+            in_block_comment = !line.ends_with("*/");
+            continue;
+        }
+
+        if let Some(attribute) = line.strip_prefix("#[strum(to_string=\"") {
+            explicit_name = Some(attribute.trim_end_matches("\")]").to_owned());
+            continue;
+        }
+
+        let Some(variant) = line.strip_suffix(',') else {
+            continue;
+        };
+
+        if line.starts_with("//") || line.starts_with('#') || !variant.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            continue;
+        }
+
+        let word = explicit_name.take().unwrap_or_else(|| screaming_snake_case(variant));
+        variants.push((variant.to_owned(), word));
+    }
+
+    variants
+}
+
+/// Converts a `CamelCase` variant name to `SCREAMING_SNAKE_CASE`, like
+/// `#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]` does.
+fn screaming_snake_case(variant: &str) -> String {
+    let mut word = String::with_capacity(variant.len() + 4);
+    let mut previous = None;
+
+    for c in variant.chars() {
+        if c.is_ascii_uppercase() && previous.is_some_and(|p: char| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+            word.push('_');
+        }
+
+        word.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+
+    word
+}
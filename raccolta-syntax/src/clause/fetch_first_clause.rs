@@ -1,7 +1,11 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::SimpleValueSpecification;
+use crate::{
+    expression::SimpleValueSpecification,
+    Span,
+    Spanned,
+};
 
 /// ```text
 /// fetch_first_clause ::=
@@ -11,16 +15,33 @@ use crate::expression::SimpleValueSpecification;
 ///     ( ROW | ROWS )
 ///     ( ONLY | WITH TIES )
 /// ```
+///
+/// When the `fetch_first_quantity` is omitted, a single row is fetched.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FetchFirstClause {
     pub quantity: FetchFirstQuantity,
     pub origin: FetchFirstClauseOrigin,
+
+    /// Whether `WITH TIES` was specified, which means that the rows that are
+    /// peers of the last fetched row in the `ORDER BY` clause are fetched as
+    /// well.
+    pub with_ties: bool,
+
+    pub span: Span,
+}
+
+impl Spanned for FetchFirstClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// Clarifies where this [`FetchFirstClause`] came from. Since many vendor
 /// extensions such as `LIMIT` and `TOP` predate `FETCH FIRST`, it is good
 /// practice to support those as well.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FetchFirstClauseOrigin {
     /// Originated from standard SQL `FETCH FIRST`
     FetchFirst,
@@ -43,7 +64,15 @@ pub enum FetchFirstClauseOrigin {
 /// fetch_first_percentage ::=
 ///     simple_value_specification PERCENT
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FetchFirstQuantity {
     pub value: SimpleValueSpecification,
     pub is_percent: bool,
+    pub span: Span,
+}
+
+impl Spanned for FetchFirstQuantity {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
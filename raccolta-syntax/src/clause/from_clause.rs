@@ -1,7 +1,11 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::TableReference;
+use crate::{
+    expression::TableReference,
+    Span,
+    Spanned,
+};
 
 /// ```text
 /// <from clause> ::=
@@ -11,6 +15,14 @@ use crate::expression::TableReference;
 ///     <table reference> [ { <comma> <table reference> }... ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FromClause {
     pub table_references: Vec<TableReference>,
+    pub span: Span,
+}
+
+impl Spanned for FromClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
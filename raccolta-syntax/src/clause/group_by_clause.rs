@@ -1,7 +1,16 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupByClause {
+    pub span: Span,
+}
 
+impl Spanned for GroupByClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -1,6 +1,8 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
 /// # References
 /// ## 4.18.9 Known functional dependencies in the result of a `<having clause>`
 /// Let *T1* be the table that is the operand of the `<having clause>`, let *SC*
@@ -17,6 +19,13 @@
 /// ```
 /// is a *known functional dependency* in *R*.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HavingClause {
+    pub span: Span,
+}
 
+impl Spanned for HavingClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -5,10 +5,13 @@ mod from_clause;
 mod group_by_clause;
 mod having_clause;
 pub mod order_by_clause;
+pub mod result_offset_clause;
 mod where_clause;
+pub mod window_clause;
 pub mod fetch_first_clause;
 
 pub use from_clause::FromClause;
 pub use group_by_clause::GroupByClause;
 pub use having_clause::HavingClause;
 pub use where_clause::WhereClause;
+pub use window_clause::WindowClause;
@@ -1,12 +1,17 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::ColumnReference;
+use crate::{
+    expression::ColumnReference,
+    Span,
+    Spanned,
+};
 
 /// This specifies in which order the rows should be sorted in, ASCending or
 /// DESCending. Ascending sorts from lowest to highest, descending from highest
 /// to lowest.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OrderingSpecification {
     /// Sort from lowest to highest.
     Ascending,
@@ -16,16 +21,33 @@ pub enum OrderingSpecification {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderByClause {
     pub sort_specification_list: Vec<SortSpecification>,
+    pub span: Span,
+}
+
+impl Spanned for OrderByClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SortSpecification {
     /// TODO: This isn't completely correct, as it should be a
     /// `<value expression>`, with a lot of restrictions.
     pub sort_key: ColumnReference,
 
     /// Specifies in which order the rows should be sorted in.
-    pub ordering_specification: Option<OrderingSpecification>
+    pub ordering_specification: Option<OrderingSpecification>,
+
+    pub span: Span,
+}
+
+impl Spanned for SortSpecification {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
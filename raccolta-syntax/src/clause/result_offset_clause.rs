@@ -0,0 +1,31 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{
+    expression::SimpleValueSpecification,
+    Span,
+    Spanned,
+};
+
+/// ```text
+/// <result offset clause> ::=
+///     OFFSET <offset row count> { ROW | ROWS }
+///
+/// <offset row count> ::=
+///     <simple value specification>
+/// ```
+///
+/// The vendor-specific `LIMIT` clause can also specify an offset, using
+/// either `LIMIT <count> OFFSET <offset>` or `LIMIT <offset>, <count>`.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResultOffsetClause {
+    pub offset_row_count: SimpleValueSpecification,
+    pub span: Span,
+}
+
+impl Spanned for ResultOffsetClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
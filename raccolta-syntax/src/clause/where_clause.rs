@@ -1,9 +1,21 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::ValueExpression;
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WhereClause {
     pub search_condition: ValueExpression,
+    pub span: Span,
+}
+
+impl Spanned for WhereClause {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -0,0 +1,154 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{
+    clause::order_by_clause::OrderByClause,
+    common::Identifier,
+    expression::{
+        ColumnReference,
+        SimpleValueSpecification,
+    },
+    Span,
+    Spanned,
+};
+
+/// Names windows, so they can be shared between window functions.
+///
+/// ```text
+/// <window clause> ::=
+///     WINDOW <window definition list>
+///
+/// <window definition list> ::=
+///     <window definition> [ { <comma> <window definition> }... ]
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowClause {
+    pub window_definitions: Vec<WindowDefinition>,
+    pub span: Span,
+}
+
+impl Spanned for WindowClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <window definition> ::=
+///     <new window name> AS <window specification>
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowDefinition {
+    pub name: Identifier,
+    pub specification: WindowSpecification,
+    pub span: Span,
+}
+
+impl Spanned for WindowDefinition {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <window specification> ::=
+///     <left paren> <window specification details> <right paren>
+///
+/// <window specification details> ::=
+///     [ <existing window name> ]
+///     [ <window partition clause> ]
+///     [ <window order clause> ]
+///     [ <window frame clause> ]
+///
+/// <window partition clause> ::=
+///     PARTITION BY <window partition column reference list>
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowSpecification {
+    /// The window this specification refines.
+    pub existing_window_name: Option<Identifier>,
+
+    /// The columns of which the values divide the rows into separate
+    /// windows. Empty if the specification has no `PARTITION BY`.
+    pub partition_clause: Vec<ColumnReference>,
+
+    pub order_clause: Option<OrderByClause>,
+    pub frame_clause: Option<WindowFrameClause>,
+
+    pub span: Span,
+}
+
+impl Spanned for WindowSpecification {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// Selects the rows of the window of the current row an aggregate is
+/// computed over.
+///
+/// ```text
+/// <window frame clause> ::=
+///     <window frame units> <window frame extent>
+///
+/// <window frame extent> ::=
+///       <window frame start>
+///     | <window frame between>
+///
+/// <window frame between> ::=
+///     BETWEEN <window frame bound 1> AND <window frame bound 2>
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowFrameClause {
+    pub units: WindowFrameUnits,
+    pub start: WindowFrameBound,
+
+    /// The end of the frame, which is `None` without `BETWEEN`, meaning the
+    /// frame ends at the `CURRENT ROW`.
+    pub end: Option<WindowFrameBound>,
+
+    pub span: Span,
+}
+
+impl Spanned for WindowFrameClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <window frame units> ::=
+///     ROWS | RANGE
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowFrameUnits {
+    /// The offsets count physical rows.
+    Rows,
+
+    /// The offsets are distances between the values of the sort key, and
+    /// the peers of the current row are part of the `CURRENT ROW`.
+    Range,
+}
+
+/// ```text
+/// <window frame bound> ::=
+///       UNBOUNDED PRECEDING
+///     | <unsigned value specification> PRECEDING
+///     | CURRENT ROW
+///     | <unsigned value specification> FOLLOWING
+///     | UNBOUNDED FOLLOWING
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowFrameBound {
+    UnboundedPreceding,
+    Preceding(SimpleValueSpecification),
+    CurrentRow,
+    Following(SimpleValueSpecification),
+    UnboundedFollowing,
+}
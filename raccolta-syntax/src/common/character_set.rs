@@ -8,6 +8,7 @@
 ///     | <user-defined character set name>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CharacterSetSpecification {
     // TODO schema name
 
@@ -21,6 +22,7 @@ pub struct CharacterSetSpecification {
 /// <user-defined character set name> ::= <character set name>
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharacterSetSpecificationKind {
     Standard,
     ImplementationDefined,
@@ -0,0 +1,167 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use std::{
+    borrow::Cow,
+    fmt::Display,
+};
+
+use crate::{Span, Spanned};
+
+/// ```text
+/// <identifier> ::=
+///     <actual identifier>
+///
+/// <actual identifier> ::=
+///       <regular identifier>
+///     | <delimited identifier>
+///     | <Unicode delimited identifier>
+///
+/// <delimited identifier> ::=
+///     <double quote> <delimited identifier body> <double quote>
+/// ```
+///
+/// Some dialects also delimit identifiers with backticks or square brackets,
+/// see [`IdentifierDelimiter`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Identifier {
+    /// The name, without delimiters and with escaped delimiters resolved.
+    pub value: String,
+
+    /// The delimiter the identifier was written with, if any, e.g. `"Name"`.
+    pub delimiter: Option<IdentifierDelimiter>,
+
+    /// Where the identifier was written, including its delimiters.
+    pub span: Span,
+}
+
+impl Identifier {
+    /// Creates a `<regular identifier>`, which is case-insensitive.
+    pub fn regular(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            delimiter: None,
+            span: Span::default(),
+        }
+    }
+
+    /// Creates a `<delimited identifier>`, which is case-sensitive.
+    pub fn delimited(value: impl Into<String>) -> Self {
+        Self::delimited_by(value, IdentifierDelimiter::DoubleQuote)
+    }
+
+    /// Creates an identifier written between the given `delimiter`.
+    pub fn delimited_by(value: impl Into<String>, delimiter: IdentifierDelimiter) -> Self {
+        Self {
+            value: value.into(),
+            delimiter: Some(delimiter),
+            span: Span::default(),
+        }
+    }
+
+    /// Sets where the identifier was written.
+    pub fn with_span(self, span: Span) -> Self {
+        Self {
+            span,
+            ..self
+        }
+    }
+
+    /// Whether the identifier must match names exactly, instead of
+    /// case-insensitively. See [`IdentifierDelimiter::is_case_sensitive`].
+    pub fn is_case_sensitive(&self) -> bool {
+        self.delimiter.is_some_and(|delimiter| delimiter.is_case_sensitive())
+    }
+
+    /// Returns the name this identifier refers to. A `<regular identifier>`
+    /// is equivalent to its upper-case form, whereas a `<delimited identifier>`
+    /// is taken verbatim. This means that `foo`, `FOO` and `"FOO"` all refer
+    /// to the same object, but `"Foo"` doesn't.
+    pub fn normalized(&self) -> Cow<'_, str> {
+        if self.is_case_sensitive() {
+            Cow::Borrowed(&self.value)
+        } else {
+            Cow::Owned(self.value.to_uppercase())
+        }
+    }
+
+    /// Checks whether this identifier and `other` refer to the same object,
+    /// i.e. whether their [normalized](Self::normalized) names are equal.
+    pub fn matches(&self, other: &Identifier) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+/// The characters an identifier is delimited by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentifierDelimiter {
+    /// The standard `"name"`.
+    DoubleQuote,
+
+    /// The MySQL and SQLite `` `name` ``.
+    Backtick,
+
+    /// The T-SQL and SQLite `[name]`.
+    SquareBrackets,
+}
+
+impl IdentifierDelimiter {
+    /// The character that opens the identifier.
+    pub const fn opening_character(&self) -> char {
+        match self {
+            Self::DoubleQuote => '"',
+            Self::Backtick => '`',
+            Self::SquareBrackets => '[',
+        }
+    }
+
+    /// The character that closes the identifier. It is escaped inside the
+    /// identifier by writing it twice.
+    pub const fn closing_character(&self) -> char {
+        match self {
+            Self::DoubleQuote => '"',
+            Self::Backtick => '`',
+            Self::SquareBrackets => ']',
+        }
+    }
+
+    /// Only standard delimited identifiers are case-sensitive. Backticks and
+    /// square brackets merely allow names that would otherwise be keywords
+    /// or contain special characters, and are matched case-insensitively like
+    /// their dialects do.
+    pub const fn is_case_sensitive(&self) -> bool {
+        matches!(self, Self::DoubleQuote)
+    }
+}
+
+impl AsRef<str> for Identifier {
+    fn as_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Spanned for Identifier {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.value, f)
+    }
+}
+
+impl From<&str> for Identifier {
+    fn from(value: &str) -> Self {
+        Self::regular(value)
+    }
+}
+
+impl From<String> for Identifier {
+    fn from(value: String) -> Self {
+        Self::regular(value)
+    }
+}
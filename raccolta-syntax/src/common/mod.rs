@@ -2,6 +2,8 @@
 // All Rights Reserved.
 
 pub mod character_set;
+pub mod identifier;
 pub mod table_name;
 
+pub use identifier::{Identifier, IdentifierDelimiter};
 pub use table_name::TableName;
@@ -1,6 +1,10 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
+use super::Identifier;
+
 /// ```text
 /// <table name> ::=
 ///     <local or schema qualified name>
@@ -9,13 +13,20 @@
 ///     [ <local or schema qualifier> <period> ] <qualified identifier>
 ///
 /// <local or schema qualifier> ::=
-////      <schema name>
-////    | MODULE
+///      <schema name>
+///    | MODULE
 ///
-//// <qualified identifier> ::= <identifier>
+/// <qualified identifier> ::= <identifier>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableName {
     /// `<qualified identifier>`
-    pub table_qualifier: String,
+    pub table_qualifier: Identifier,
+}
+
+impl Spanned for TableName {
+    fn span(&self) -> Span {
+        self.table_qualifier.span()
+    }
 }
@@ -0,0 +1,137 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Many database systems extend the SQL standard with constructs of their
+//! own, such as the `LIMIT` clause of **MySQL** and the `TOP` clause of
+//! **T-SQL**. A [`Dialect`] describes which of these extensions are accepted
+//! by the [`Lexer`](crate::Lexer) and the [`Parser`](crate::Parser).
+
+use strum::{AsRefStr, EnumIter, IntoEnumIterator};
+
+/// A dialect of SQL, which decides which vendor-specific extensions are
+/// accepted.
+///
+/// When no dialect is chosen, the [`Parser`](crate::Parser) accepts the
+/// extensions of all dialects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(AsRefStr, EnumIter, strum::Display)]
+pub enum Dialect {
+    /// The SQL standard, without any vendor-specific extensions.
+    #[strum(serialize = "standard SQL")]
+    Standard,
+
+    /// The dialect of **MySQL** and **MariaDB**.
+    #[strum(serialize = "MySQL")]
+    MySql,
+
+    /// The dialect of **PostgreSQL**.
+    #[strum(serialize = "PostgreSQL")]
+    PostgreSql,
+
+    /// Transact-SQL, the dialect of **Microsoft SQL Server**.
+    #[strum(serialize = "T-SQL")]
+    TSql,
+
+    /// The dialect of **SQLite**.
+    #[strum(serialize = "SQLite")]
+    Sqlite,
+}
+
+impl Dialect {
+    /// Checks whether the `feature` is accepted by this dialect.
+    pub const fn supports(self, feature: DialectFeature) -> bool {
+        match feature {
+            DialectFeature::BacktickDelimitedIdentifier => matches!(self, Self::MySql | Self::Sqlite),
+            DialectFeature::BracketDelimitedIdentifier => matches!(self, Self::TSql | Self::Sqlite),
+            DialectFeature::DoubleEqualsOperator => matches!(self, Self::Sqlite),
+            DialectFeature::ExclamationNotEqualsOperator => !matches!(self, Self::Standard),
+            DialectFeature::HexadecimalIntegerLiteral => !matches!(self, Self::Standard),
+            DialectFeature::LimitClause => matches!(self, Self::MySql | Self::PostgreSql | Self::Sqlite),
+            DialectFeature::LimitClauseWithComma => matches!(self, Self::MySql | Self::Sqlite),
+            DialectFeature::TopClause => matches!(self, Self::TSql),
+        }
+    }
+}
+
+/// A vendor-specific extension to the SQL standard, which only some
+/// [`Dialect`]s accept.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(AsRefStr, EnumIter)]
+pub enum DialectFeature {
+    /// Identifiers delimited by backticks, e.g. `` `name` ``.
+    BacktickDelimitedIdentifier,
+
+    /// Identifiers delimited by square brackets, e.g. `[name]`.
+    BracketDelimitedIdentifier,
+
+    /// The `==` alias of the `=` operator.
+    DoubleEqualsOperator,
+
+    /// The `!=` alias of the `<>` operator.
+    ExclamationNotEqualsOperator,
+
+    /// Hexadecimal integer literals, e.g. `0x1F`.
+    HexadecimalIntegerLiteral,
+
+    /// The `LIMIT` clause, which acts as the `FETCH FIRST` and `OFFSET`
+    /// clauses, e.g. `LIMIT 10 OFFSET 20`.
+    LimitClause,
+
+    /// The `LIMIT <offset>, <count>` form of the `LIMIT` clause.
+    LimitClauseWithComma,
+
+    /// The `TOP` clause, which acts as the `FETCH FIRST` clause, e.g.
+    /// `SELECT TOP 10 name FROM person`.
+    TopClause,
+}
+
+impl DialectFeature {
+    /// Returns a human-readable description of this feature, used in error
+    /// messages.
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::BacktickDelimitedIdentifier => "delimiting identifiers with backticks (`` ` ``)",
+            Self::BracketDelimitedIdentifier => "delimiting identifiers with square brackets (`[]`)",
+            Self::DoubleEqualsOperator => "the `==` operator",
+            Self::ExclamationNotEqualsOperator => "the `!=` operator",
+            Self::HexadecimalIntegerLiteral => "writing integers in hexadecimal (`0x1F`)",
+            Self::LimitClause => "the `LIMIT` clause",
+            Self::LimitClauseWithComma => "the `LIMIT <offset>, <count>` clause",
+            Self::TopClause => "the `TOP` clause",
+        }
+    }
+
+    /// Returns the dialects that accept this feature.
+    pub fn dialects(self) -> impl Iterator<Item = Dialect> {
+        Dialect::iter().filter(move |dialect| dialect.supports(self))
+    }
+
+    /// Returns the names of the dialects that accept this feature as a list
+    /// for use in a sentence, e.g. `"MySQL or SQLite"`.
+    pub fn dialect_names(self) -> String {
+        let names: Vec<_> = self.dialects().map(|dialect| dialect.as_ref().to_string()).collect();
+        match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {last}", rest.join(", ")),
+            _ => names.concat(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(DialectFeature::LimitClause, "MySQL, PostgreSQL or SQLite")]
+    #[case(DialectFeature::LimitClauseWithComma, "MySQL or SQLite")]
+    #[case(DialectFeature::TopClause, "T-SQL")]
+    fn dialect_feature_dialect_names(#[case] feature: DialectFeature, #[case] expected: &str) {
+        assert_eq!(feature.dialect_names(), expected);
+    }
+
+    #[test]
+    fn dialect_standard_supports_no_features() {
+        assert_eq!(DialectFeature::iter().filter(|feature| Dialect::Standard.supports(*feature)).count(), 0);
+    }
+}
@@ -1,10 +1,85 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::predicate::Predicate;
+use crate::{
+    predicate::Predicate,
+    Span,
+    Spanned,
+};
 
+use super::ValueExpression;
+
+/// ```text
+/// <boolean value expression> ::=
+///       <boolean term>
+///     | <boolean value expression> OR <boolean term>
+///
+/// <boolean term> ::=
+///       <boolean factor>
+///     | <boolean term> AND <boolean factor>
+///
+/// <boolean factor> ::=
+///     [ NOT ] <boolean test>
+///
+/// <boolean test> ::=
+///     <boolean primary> [ IS [ NOT ] <truth value> ]
+/// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BooleanExpression {
-    Literal(bool),
+    /// `<boolean term> AND <boolean factor>`
+    And {
+        left_hand_side: Box<ValueExpression>,
+        right_hand_side: Box<ValueExpression>,
+    },
+
+    /// `<boolean primary> IS [ NOT ] <truth value>`
+    Is {
+        operand: Box<ValueExpression>,
+        negated: bool,
+        truth_value: TruthValue,
+        span: Span,
+    },
+
+    Literal(bool, Span),
+
+    /// `NOT <boolean test>`
+    Not(Box<ValueExpression>, Span),
+
+    /// `<boolean value expression> OR <boolean term>`
+    Or {
+        left_hand_side: Box<ValueExpression>,
+        right_hand_side: Box<ValueExpression>,
+    },
+
     Predicate(Box<Predicate>),
+
+    /// The `UNKNOWN` literal, which is the null value of the boolean type.
+    Unknown(Span),
+}
+
+impl Spanned for BooleanExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::And { left_hand_side, right_hand_side } | Self::Or { left_hand_side, right_hand_side } => {
+                left_hand_side.span().to(right_hand_side.span())
+            }
+            Self::Is { span, .. } | Self::Literal(_, span) | Self::Not(_, span) | Self::Unknown(span) => *span,
+            Self::Predicate(predicate) => predicate.span(),
+        }
+    }
+}
+
+/// ```text
+/// <truth value> ::=
+///       TRUE
+///     | FALSE
+///     | UNKNOWN
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruthValue {
+    False,
+    True,
+    Unknown,
 }
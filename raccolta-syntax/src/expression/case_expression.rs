@@ -0,0 +1,128 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{Span, Spanned};
+
+use super::ValueExpression;
+
+/// ```text
+/// <case expression> ::=
+///       <case abbreviation>
+///     | <case specification>
+///
+/// <case abbreviation> ::=
+///       NULLIF <left paren> <value expression> <comma> <value expression> <right paren>
+///     | COALESCE <left paren> <value expression> { <comma> <value expression> }... <right paren>
+///
+/// <case specification> ::=
+///       <simple case>
+///     | <searched case>
+///
+/// <simple case> ::=
+///     CASE <case operand> <simple when clause>... [ <else clause> ] END
+///
+/// <searched case> ::=
+///     CASE <searched when clause>... [ <else clause> ] END
+///
+/// <else clause> ::=
+///     ELSE <result>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaseExpression {
+    /// `COALESCE <left paren> <value expression> { <comma> <value expression> }... <right paren>`
+    Coalesce(Vec<ValueExpression>, Span),
+
+    /// `NULLIF <left paren> <value expression> <comma> <value expression> <right paren>`
+    NullIf {
+        left_hand_side: Box<ValueExpression>,
+        right_hand_side: Box<ValueExpression>,
+        span: Span,
+    },
+
+    /// `CASE <searched when clause>... [ <else clause> ] END`
+    Searched {
+        when_clauses: Vec<SearchedWhenClause>,
+        else_clause: Option<Box<CaseResult>>,
+        span: Span,
+    },
+
+    /// `CASE <case operand> <simple when clause>... [ <else clause> ] END`
+    Simple {
+        operand: Box<ValueExpression>,
+        when_clauses: Vec<SimpleWhenClause>,
+        else_clause: Option<Box<CaseResult>>,
+        span: Span,
+    },
+}
+
+impl Spanned for CaseExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::Coalesce(_, span)
+                | Self::NullIf { span, .. }
+                | Self::Searched { span, .. }
+                | Self::Simple { span, .. } => *span,
+        }
+    }
+}
+
+/// ```text
+/// <result> ::=
+///       <result expression>
+///     | NULL
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaseResult {
+    /// `NULL`, which takes the type of the other results.
+    Null(Span),
+
+    /// `<result expression>`
+    ValueExpression(ValueExpression),
+}
+
+impl Spanned for CaseResult {
+    fn span(&self) -> Span {
+        match self {
+            Self::Null(span) => *span,
+            Self::ValueExpression(value_expression) => value_expression.span(),
+        }
+    }
+}
+
+/// ```text
+/// <searched when clause> ::=
+///     WHEN <search condition> THEN <result>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchedWhenClause {
+    pub search_condition: ValueExpression,
+    pub result: CaseResult,
+    pub span: Span,
+}
+
+impl Spanned for SearchedWhenClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <simple when clause> ::=
+///     WHEN <when operand> THEN <result>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimpleWhenClause {
+    pub when_operand: ValueExpression,
+    pub result: CaseResult,
+    pub span: Span,
+}
+
+impl Spanned for SimpleWhenClause {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
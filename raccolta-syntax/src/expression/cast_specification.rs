@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{Span, Spanned};
+
+use super::{
+    data_type::DataType,
+    ValueExpression,
+};
+
+/// ```text
+/// <cast specification> ::=
+///     CAST <left paren> <cast operand> AS <cast target> <right paren>
+///
+/// <cast target> ::=
+///       <domain name>
+///     | <data type>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CastSpecification {
+    pub operand: CastOperand,
+    pub target: DataType,
+    pub span: Span,
+}
+
+impl Spanned for CastSpecification {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <cast operand> ::=
+///       <value expression>
+///     | <implicit null value specification>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CastOperand {
+    /// `NULL`, which is the null value of the `<cast target>`.
+    Null,
+
+    ValueExpression(Box<ValueExpression>),
+}
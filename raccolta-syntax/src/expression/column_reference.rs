@@ -1,12 +1,19 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{
+    common::Identifier,
+    Span,
+    Spanned,
+};
+
 /// ```text
 /// <column reference> ::=
 ///       <basic identifier chain>
 ///     | MODULE <period> <qualified identifier> <period> <column name>
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum ColumnReference {
     /// ```text
@@ -16,5 +23,16 @@ pub enum ColumnReference {
     /// <basic identifier chain> ::=
     ///     <identifier chain>
     /// ```
-    BasicIdentifierChain(Vec<String>),
+    BasicIdentifierChain(Vec<Identifier>),
+}
+
+impl Spanned for ColumnReference {
+    fn span(&self) -> Span {
+        match self {
+            Self::BasicIdentifierChain(identifiers) => match (identifiers.first(), identifiers.last()) {
+                (Some(first), Some(last)) => first.span().to(last.span()),
+                _ => Span::default(),
+            },
+        }
+    }
 }
@@ -3,6 +3,57 @@
 
 use crate::common::character_set::CharacterSetSpecification;
 
+/// ```text
+/// <binary string type> ::=
+///       BINARY [ <left paren> <length> <right paren> ]
+///     | BINARY VARYING <left paren> <length> <right paren>
+///     | VARBINARY <left paren> <length> <right paren>
+///     | <binary large object string type>
+///
+/// <binary large object string type> ::=
+///       BINARY LARGE OBJECT [ <left paren> <large object length> <right paren> ]
+///     | BLOB [ <left paren> <large object length> <right paren> ]
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BinaryStringType {
+    /// `BINARY`, where a missing length implies a length of 1.
+    Fixed {
+        length: usize,
+    },
+
+    /// `BINARY VARYING` or `VARBINARY`
+    Varying {
+        length: usize,
+    },
+
+    /// `BINARY LARGE OBJECT` or `BLOB`
+    LargeObject {
+        length: Option<LargeObjectLength>,
+    },
+}
+
+/// ```text
+/// <bit string type> ::=
+///       BIT [ <left paren> <length> <right paren> ]
+///     | BIT VARYING <left paren> <length> <right paren>
+/// ```
+///
+/// This type was removed after SQL 1992, but is still commonly used.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BitStringType {
+    /// `BIT`, where a missing length implies a length of 1.
+    Fixed {
+        length: usize,
+    },
+
+    /// `BIT VARYING`
+    Varying {
+        length: usize,
+    },
+}
+
 /// ```text
 /// <character string type> ::=
 ///       CHARACTER [ <left paren> <length> <right paren> ]
@@ -15,6 +66,7 @@ use crate::common::character_set::CharacterSetSpecification;
 ///     | CLOB [ <left paren> <large object length> <right paren> ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CharacterStringType {
     /// Non-Unicode, fixed
     Fixed {
@@ -25,6 +77,11 @@ pub enum CharacterStringType {
     Varying {
         length: usize,
     },
+
+    /// `CHARACTER LARGE OBJECT` or `CLOB`
+    LargeObject {
+        length: Option<LargeObjectLength>,
+    },
 }
 
 /// ```text
@@ -36,10 +93,74 @@ pub enum CharacterStringType {
 ///     | <collection type>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DataType {
     Predefined(PredefinedType),
 }
 
+/// ```text
+/// <large object length> ::=
+///     <unsigned integer> [ <multiplier> ]
+///
+/// <multiplier> ::=
+///       K
+///     | M
+///     | G
+///     | T
+///     | P
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LargeObjectLength {
+    pub value: usize,
+    pub multiplier: Option<LargeObjectLengthMultiplier>,
+}
+
+impl LargeObjectLength {
+    /// Get the length with the multiplier applied. Lengths that don't fit in
+    /// an [`usize`] are saturated.
+    pub fn length(&self) -> usize {
+        match self.multiplier {
+            Some(multiplier) => usize::try_from((self.value as u64).saturating_mul(multiplier.factor()))
+                .unwrap_or(usize::MAX),
+            None => self.value,
+        }
+    }
+}
+
+/// The `<multiplier>` of a `<large object length>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LargeObjectLengthMultiplier {
+    /// `K`, i.e. 1 024
+    K,
+
+    /// `M`, i.e. 1 048 576
+    M,
+
+    /// `G`, i.e. 1 073 741 824
+    G,
+
+    /// `T`, i.e. 1 099 511 627 776
+    T,
+
+    /// `P`, i.e. 1 125 899 906 842 624
+    P,
+}
+
+impl LargeObjectLengthMultiplier {
+    /// The factor to multiply the `<unsigned integer>` with.
+    pub const fn factor(&self) -> u64 {
+        match self {
+            Self::K => 1 << 10,
+            Self::M => 1 << 20,
+            Self::G => 1 << 30,
+            Self::T => 1 << 40,
+            Self::P => 1 << 50,
+        }
+    }
+}
+
 /// ```text
 /// <national character string type> ::=
 ///       NATIONAL CHARACTER [ <left paren> <length> <right paren> ]
@@ -53,6 +174,7 @@ pub enum DataType {
 ///     | NCLOB [ <left paren> <large object length> <right paren> ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NationalCharacterStringType {
     /// Unicode, fixed
     Fixed {
@@ -63,6 +185,11 @@ pub enum NationalCharacterStringType {
     Varying {
         length: usize,
     },
+
+    /// `NATIONAL CHARACTER LARGE OBJECT` or `NCLOB`
+    LargeObject {
+        length: Option<LargeObjectLength>,
+    },
 }
 
 /// ```text
@@ -84,6 +211,7 @@ pub enum NationalCharacterStringType {
 ///     | DOUBLE PRECISION
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumericType {
     Integer,
 
@@ -98,7 +226,7 @@ pub enum NumericType {
 /// <predefined type> ::=
 ///       <character string type> [ CHARACTER SET <character set specification> ]
 ///     | <national character string type>
-///     | <binary large object string type>
+///     | <binary string type>
 ///     | <bit string type>
 ///     | <numeric type>
 ///     | <boolean type>
@@ -106,6 +234,7 @@ pub enum NumericType {
 ///     | <interval type>
 ///
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PredefinedType {
     /// `<character string type> [ CHARACTER SET <character set specification>`
     CharacterString {
@@ -116,9 +245,11 @@ pub enum PredefinedType {
     /// `<national character string type>`
     NationalCharacterString(NationalCharacterStringType),
 
-    // <--- `<binary large object>` ...
+    /// `<binary string type>`
+    BinaryString(BinaryStringType),
 
-    // <--- `<bit string type>` ...
+    /// `<bit string type>`
+    BitString(BitStringType),
 
     /// `<numeric type>`
     Numeric(NumericType),
@@ -2,26 +2,39 @@
 // All Rights Reserved.
 
 pub mod boolean_expression;
+pub mod case_expression;
+pub mod cast_specification;
 pub mod column_reference;
 pub mod data_type;
 pub mod numeric_value_expression;
+pub mod numeric_value_function;
 pub mod query_expression;
 pub mod query_specification;
+pub mod routine_invocation;
 pub mod row_value_constructor;
 pub mod row_value_expression;
 pub mod simple_value_specification;
 pub mod string_value_expression;
+pub mod string_value_function;
 pub mod table_expression;
 pub mod table_reference;
 pub mod table_value_constructor;
 pub mod value_expression;
+pub mod window_function;
 
 pub use boolean_expression::BooleanExpression;
+pub use case_expression::CaseExpression;
+pub use cast_specification::CastSpecification;
 pub use column_reference::ColumnReference;
 pub use query_specification::QuerySpecification;
 pub use query_expression::QueryExpression;
-pub use numeric_value_expression::NumericValueExpression;
+pub use numeric_value_expression::{
+    NumericOperator,
+    NumericValueExpression,
+};
+pub use routine_invocation::RoutineInvocation;
 pub use simple_value_specification::SimpleValueSpecification;
 pub use table_expression::TableExpression;
 pub use table_reference::TableReference;
 pub use value_expression::ValueExpression;
+pub use window_function::WindowFunction;
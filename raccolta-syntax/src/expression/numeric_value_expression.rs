@@ -1,6 +1,13 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
+use super::{
+    numeric_value_function::NumericValueFunction,
+    ValueExpression,
+};
+
 /// ```text
 /// <numeric value expression> ::=
 ///       <term>
@@ -20,6 +27,79 @@
 ///     | <numeric value function>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NumericValueExpression {
-    SimpleU64(u64),
+    /// An `<approximate numeric literal>` with an optional sign, e.g.
+    /// `-6.02E23`, kept as the source text to be converted losslessly.
+    ApproximateNumericLiteral(String, Span),
+
+    /// An arithmetic operation on two operands, e.g. `price * amount`. The
+    /// operands are value expressions, since column references aren't typed
+    /// until they're resolved.
+    BinaryOperation {
+        left_hand_side: Box<ValueExpression>,
+        operator: NumericOperator,
+        right_hand_side: Box<ValueExpression>,
+    },
+
+    /// An `<exact numeric literal>` with a period and an optional sign, e.g.
+    /// `-3.14`, kept as the source text to be converted losslessly.
+    ExactNumericLiteral(String, Span),
+
+    /// A `<numeric value function>` with keywords between its arguments,
+    /// e.g. `POSITION('a' IN name)`.
+    Function(NumericValueFunction),
+
+    /// A `<factor>` with a `<minus sign>` that isn't a literal, e.g. `-price`.
+    Negation(Box<ValueExpression>, Span),
+
+    /// A negative `<signed numeric literal>` without a period, e.g. `-5`.
+    SimpleI64(i64, Span),
+
+    SimpleU64(u64, Span),
+}
+
+impl Spanned for NumericValueExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::BinaryOperation { left_hand_side, right_hand_side, .. } => {
+                left_hand_side.span().to(right_hand_side.span())
+            }
+            Self::Function(function) => function.span(),
+            Self::ApproximateNumericLiteral(_, span)
+                | Self::ExactNumericLiteral(_, span)
+                | Self::Negation(_, span)
+                | Self::SimpleI64(_, span)
+                | Self::SimpleU64(_, span) => *span,
+        }
+    }
+}
+
+/// The operator of a [`NumericValueExpression::BinaryOperation`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericOperator {
+    /// The `+` operator of a `<numeric value expression>`.
+    Addition,
+
+    /// The `/` operator of a `<term>`.
+    Division,
+
+    /// The `*` operator of a `<term>`.
+    Multiplication,
+
+    /// The `-` operator of a `<numeric value expression>`.
+    Subtraction,
+}
+
+impl NumericOperator {
+    /// Returns the SQL spelling of this operator, e.g. `*`.
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Addition => "+",
+            Self::Division => "/",
+            Self::Multiplication => "*",
+            Self::Subtraction => "-",
+        }
+    }
 }
@@ -0,0 +1,34 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{Span, Spanned};
+
+use super::ValueExpression;
+
+/// The numeric functions that have keywords between their arguments. Other
+/// functions, e.g. `ABS`, are a
+/// [`RoutineInvocation`](super::routine_invocation::RoutineInvocation).
+///
+/// ```text
+/// <position expression> ::=
+///     POSITION <left paren> <string value expression>
+///     IN <string value expression> <right paren>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NumericValueFunction {
+    /// `POSITION(<needle> IN <haystack>)`
+    Position {
+        needle: Box<ValueExpression>,
+        haystack: Box<ValueExpression>,
+        span: Span,
+    },
+}
+
+impl Spanned for NumericValueFunction {
+    fn span(&self) -> Span {
+        match self {
+            Self::Position { span, .. } => *span,
+        }
+    }
+}
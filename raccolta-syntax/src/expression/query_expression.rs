@@ -1,7 +1,12 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::clause::{order_by_clause::OrderByClause, fetch_first_clause::FetchFirstClause};
+use crate::clause::{
+    order_by_clause::OrderByClause,
+    fetch_first_clause::FetchFirstClause,
+    result_offset_clause::ResultOffsetClause,
+};
+use crate::{Span, Spanned};
 
 use super::QuerySpecification;
 
@@ -14,20 +19,38 @@ use super::QuerySpecification;
 ///       [ <corresponding spec> ] <query term>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NonJoinQueryExpression {
     NonJoinQueryTerm(NonJoinQueryTerm),
 }
 
+impl Spanned for NonJoinQueryExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::NonJoinQueryTerm(term) => term.span(),
+        }
+    }
+}
+
 /// ```text
 /// <non-join query primary> ::=
 ///     <simple table>
 ///     | <left paren> <non-join query expression> <right paren>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NonJoinQueryPrimary {
     SimpleTable(SimpleTable),
 }
 
+impl Spanned for NonJoinQueryPrimary {
+    fn span(&self) -> Span {
+        match self {
+            Self::SimpleTable(simple_table) => simple_table.span(),
+        }
+    }
+}
+
 /// ```text
 /// <non-join query term> ::=
 ///     <non-join query primary>
@@ -35,10 +58,19 @@ pub enum NonJoinQueryPrimary {
 ///     [ <corresponding spec> ] <query primary>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NonJoinQueryTerm {
     NonJoinQueryPrimary(NonJoinQueryPrimary),
 }
 
+impl Spanned for NonJoinQueryTerm {
+    fn span(&self) -> Span {
+        match self {
+            Self::NonJoinQueryPrimary(primary) => primary.span(),
+        }
+    }
+}
+
 /// ```text
 /// <query expression> ::=
 ///     [ <with clause> ] <query expression body>
@@ -46,13 +78,25 @@ pub enum NonJoinQueryTerm {
 ///
 /// **TODO:** optional **`WITH`** clause
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QueryExpression {
     pub body: QueryExpressionBody,
 
     /// An optional **`ORDER BY`** clause.
     pub order_by: Option<OrderByClause>,
 
+    /// An optional **`OFFSET`** clause.
+    pub offset: Option<ResultOffsetClause>,
+
     pub fetch: Option<FetchFirstClause>,
+
+    pub span: Span,
+}
+
+impl Spanned for QueryExpression {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -61,6 +105,7 @@ pub struct QueryExpression {
 ///     | <joined table>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueryExpressionBody {
     NonJoinQueryExpression(NonJoinQueryExpression),
 
@@ -68,12 +113,30 @@ pub enum QueryExpressionBody {
     SimpleTable(SimpleTable),
 }
 
+impl Spanned for QueryExpressionBody {
+    fn span(&self) -> Span {
+        match self {
+            Self::NonJoinQueryExpression(body) => body.span(),
+            Self::SimpleTable(body) => body.span(),
+        }
+    }
+}
+
 /// `<query primary>`
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueryPrimary {
     NonJoinQueryPrimary(NonJoinQueryPrimary),
 }
 
+impl Spanned for QueryPrimary {
+    fn span(&self) -> Span {
+        match self {
+            Self::NonJoinQueryPrimary(primary) => primary.span(),
+        }
+    }
+}
+
 /// ```text
 /// <simple table> ::=
 ///       <query specification>
@@ -81,6 +144,15 @@ pub enum QueryPrimary {
 ///     | <explicit table>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimpleTable {
     QuerySpecification(QuerySpecification),
 }
+
+impl Spanned for SimpleTable {
+    fn span(&self) -> Span {
+        match self {
+            Self::QuerySpecification(specification) => specification.span(),
+        }
+    }
+}
@@ -1,7 +1,12 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::set_function::SetQuantifier;
+use crate::{
+    common::Identifier,
+    set_function::SetQuantifier,
+    Span,
+    Spanned,
+};
 
 use super::{TableExpression, ValueExpression};
 
@@ -10,11 +15,20 @@ use super::{TableExpression, ValueExpression};
 ///     <value expression> [ <as clause> ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DerivedColumn {
     pub value_expression: ValueExpression,
 
     /// The `<as clause> <column name>`
-    pub alias: Option<String>,
+    pub alias: Option<Identifier>,
+
+    pub span: Span,
+}
+
+impl Spanned for DerivedColumn {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -23,10 +37,18 @@ pub struct DerivedColumn {
 ///     <table expression>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct QuerySpecification {
     pub set_quantifier: SetQuantifier,
     pub select_list: SelectList,
     pub table_expression: Option<TableExpression>,
+    pub span: Span,
+}
+
+impl Spanned for QuerySpecification {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -35,6 +57,7 @@ pub struct QuerySpecification {
 ///     | <select sublist> [ { <comma> <select sublist> }... ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectList {
     /// '*' or wildcard: select all rows from all referenced tables
     /// (in FROM-clause, subclause and JOIN-clauses).
@@ -48,6 +71,15 @@ pub enum SelectList {
 ///     | <qualified asterisk>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectSublist {
     DerivedColumn(DerivedColumn),
 }
+
+impl Spanned for SelectSublist {
+    fn span(&self) -> Span {
+        match self {
+            Self::DerivedColumn(derived_column) => derived_column.span(),
+        }
+    }
+}
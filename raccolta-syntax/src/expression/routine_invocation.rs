@@ -0,0 +1,36 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{
+    common::Identifier,
+    Span,
+    Spanned,
+};
+
+use super::ValueExpression;
+
+/// A call of a function by its name, e.g. `UPPER(name)` or `MOD(a, b)`. The
+/// functions with keywords between their arguments have their own syntax, see
+/// [`StringValueFunction`](super::string_value_function::StringValueFunction)
+/// and [`NumericValueFunction`](super::numeric_value_function::NumericValueFunction).
+///
+/// ```text
+/// <routine invocation> ::=
+///     <routine name> <SQL argument list>
+///
+/// <SQL argument list> ::=
+///     <left paren> [ <SQL argument> [ { <comma> <SQL argument> }... ] ] <right paren>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoutineInvocation {
+    pub name: Identifier,
+    pub arguments: Vec<ValueExpression>,
+    pub span: Span,
+}
+
+impl Spanned for RoutineInvocation {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
@@ -33,6 +33,8 @@
 //!     | <contextually typed value specification>
 //! ```
 
+use crate::{Span, Spanned};
+
 use super::ValueExpression;
 
 /// ```text
@@ -52,8 +54,16 @@ use super::ValueExpression;
 ///     | <contextually typed value specification>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContextuallyTypedRowValueConstructor {
     pub elements: Vec<ContextuallyTypedRowValueConstructorElement>,
+    pub span: Span,
+}
+
+impl Spanned for ContextuallyTypedRowValueConstructor {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -62,6 +72,15 @@ pub struct ContextuallyTypedRowValueConstructor {
 ///     | <contextually typed value specification>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContextuallyTypedRowValueConstructorElement {
     ValueExpression(ValueExpression),
 }
+
+impl Spanned for ContextuallyTypedRowValueConstructorElement {
+    fn span(&self) -> Span {
+        match self {
+            Self::ValueExpression(value_expression) => value_expression.span(),
+        }
+    }
+}
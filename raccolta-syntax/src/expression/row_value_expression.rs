@@ -18,7 +18,11 @@
 //!     | <value expression>
 //! ```
 
-use crate::expression::row_value_constructor::ContextuallyTypedRowValueConstructor;
+use crate::{
+    expression::row_value_constructor::ContextuallyTypedRowValueConstructor,
+    Span,
+    Spanned,
+};
 
 /// ```text
 /// <contextually typed row value expression> ::=
@@ -30,6 +34,15 @@ use crate::expression::row_value_constructor::ContextuallyTypedRowValueConstruct
 ///     | <value expression>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContextuallyTypedRowValueExpression {
     ContextuallyTypedRowValueConstructor(ContextuallyTypedRowValueConstructor),
 }
+
+impl Spanned for ContextuallyTypedRowValueExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::ContextuallyTypedRowValueConstructor(constructor) => constructor.span(),
+        }
+    }
+}
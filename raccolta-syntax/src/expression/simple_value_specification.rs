@@ -9,6 +9,7 @@
 ///     | embedded_variable_name
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimpleValueSpecification {
     LiteralUnsigned(u64),
 }
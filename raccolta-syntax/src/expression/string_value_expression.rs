@@ -1,8 +1,58 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned, StringLiteralKind};
+
+use super::{
+    string_value_function::StringValueFunction,
+    ValueExpression,
+};
+
+/// ```text
+/// <character value expression> ::=
+///       <concatenation>
+///     | <character factor>
+///
+/// <concatenation> ::=
+///     <character value expression> <concatenation operator> <character factor>
+///
+/// <character primary> ::=
+///       <value expression primary>
+///     | <string value function>
+/// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StringValueExpression {
-    /// A string literal.
-    Literal(String),
+    /// A binary string literal, e.g. `X'DEADBEEF'`.
+    BinaryLiteral(Vec<u8>, Span),
+
+    /// A bit string literal, e.g. `B'1010'`.
+    BitLiteral(Vec<bool>, Span),
+
+    /// The concatenation of two strings using `||`, e.g.
+    /// `first_name || ' ' || last_name`.
+    Concatenation {
+        left_hand_side: Box<ValueExpression>,
+        right_hand_side: Box<ValueExpression>,
+    },
+
+    /// A `<string value function>` with keywords between its arguments, e.g.
+    /// `TRIM(LEADING '0' FROM code)`.
+    Function(StringValueFunction),
+
+    /// A string literal, e.g. `'Hello'` or `N'Hello'`, with the value its
+    /// quotes and escapes stand for.
+    Literal(String, StringLiteralKind, Span),
+}
+
+impl Spanned for StringValueExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::Concatenation { left_hand_side, right_hand_side } => {
+                left_hand_side.span().to(right_hand_side.span())
+            }
+            Self::Function(function) => function.span(),
+            Self::BinaryLiteral(_, span) | Self::BitLiteral(_, span) | Self::Literal(_, _, span) => *span,
+        }
+    }
 }
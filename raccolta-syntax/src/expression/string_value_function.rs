@@ -0,0 +1,81 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{Span, Spanned};
+
+use super::ValueExpression;
+
+/// The string functions that have keywords between their arguments. Other
+/// functions, e.g. `UPPER`, are a
+/// [`RoutineInvocation`](super::routine_invocation::RoutineInvocation).
+///
+/// ```text
+/// <character overlay function> ::=
+///     OVERLAY <left paren> <character value expression>
+///     PLACING <character value expression>
+///     FROM <start position> [ FOR <string length> ] <right paren>
+///
+/// <character substring function> ::=
+///     SUBSTRING <left paren> <character value expression>
+///     FROM <start position> [ FOR <string length> ] <right paren>
+///
+/// <trim function> ::=
+///     TRIM <left paren> <trim operands> <right paren>
+///
+/// <trim operands> ::=
+///     [ [ <trim specification> ] [ <trim character> ] FROM ] <trim source>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StringValueFunction {
+    Overlay {
+        operand: Box<ValueExpression>,
+        placing: Box<ValueExpression>,
+        start_position: Box<ValueExpression>,
+        string_length: Option<Box<ValueExpression>>,
+        span: Span,
+    },
+
+    Substring {
+        operand: Box<ValueExpression>,
+        start_position: Box<ValueExpression>,
+        string_length: Option<Box<ValueExpression>>,
+        span: Span,
+    },
+
+    Trim {
+        specification: TrimSpecification,
+
+        /// The character to remove, which is a space when omitted.
+        character: Option<Box<ValueExpression>>,
+
+        source: Box<ValueExpression>,
+
+        span: Span,
+    },
+}
+
+impl Spanned for StringValueFunction {
+    fn span(&self) -> Span {
+        match self {
+            Self::Overlay { span, .. } | Self::Substring { span, .. } | Self::Trim { span, .. } => *span,
+        }
+    }
+}
+
+/// ```text
+/// <trim specification> ::=
+///       LEADING
+///     | TRAILING
+///     | BOTH
+/// ```
+///
+/// **`BOTH`** is implied if no `<trim specification>` is provided.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrimSpecification {
+    #[default]
+    Both,
+    Leading,
+    Trailing,
+}
@@ -6,7 +6,9 @@ use crate::clause::{
     GroupByClause,
     HavingClause,
     WhereClause,
+    WindowClause,
 };
+use crate::{Span, Spanned};
 
 /// ```text
 /// <table expression> ::=
@@ -14,11 +16,21 @@ use crate::clause::{
 ///     [ <where clause> ]
 ///     [ <group by clause> ]
 ///     [ <having clause> ]
+///     [ <window clause> ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableExpression {
     pub from_clause: FromClause,
     pub where_clause: Option<WhereClause>,
     pub group_by_clause: Option<GroupByClause>,
     pub having_clause: Option<HavingClause>,
+    pub window_clause: Option<WindowClause>,
+    pub span: Span,
+}
+
+impl Spanned for TableExpression {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
@@ -1,16 +1,31 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{
+    common::Identifier,
+    Span,
+    Spanned,
+};
+
 /// ```text
 /// <table reference> ::=
 ///       <table primary>
 ///     | <joined table>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableReference {
     Primary(TablePrimary),
 }
 
+impl Spanned for TableReference {
+    fn span(&self) -> Span {
+        match self {
+            Self::Primary(primary) => primary.span(),
+        }
+    }
+}
+
 /// ```text
 /// <table primary> ::=
 ///       <table or query name> [ [ AS ] <correlation name>
@@ -52,10 +67,18 @@ pub enum TableReference {
 ///     <column name> [ { <comma> <column name> }... ]
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TablePrimary {
     pub kind: TablePrimaryKind,
     pub correlation_name: Option<String>,
     // pub derived_column_list: Vec<String>,
+    pub span: Span,
+}
+
+impl Spanned for TablePrimary {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -79,10 +102,19 @@ pub struct TablePrimary {
 ///     | <left paren> <joined table> <right paren>
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TablePrimaryKind {
     /// The name of a table or query.
     /// ```text
     /// <table or query name> ::= <identifier>
     /// ```
-    TableOrQueryName(String),
+    TableOrQueryName(Identifier),
+}
+
+impl Spanned for TablePrimaryKind {
+    fn span(&self) -> Span {
+        match self {
+            Self::TableOrQueryName(name) => name.span(),
+        }
+    }
 }
@@ -16,7 +16,11 @@
 //!     [ { <comma> <contextually typed row value expression> }... ]
 //! ```
 
-use crate::expression::row_value_expression::ContextuallyTypedRowValueExpression;
+use crate::{
+    expression::row_value_expression::ContextuallyTypedRowValueExpression,
+    Span,
+    Spanned,
+};
 
 /// ```text
 /// <contextually typed table value constructor> ::=
@@ -27,6 +31,14 @@ use crate::expression::row_value_expression::ContextuallyTypedRowValueExpression
 ///     [ { <comma> <contextually typed row value expression> }... ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContextuallyTypedTableValueConstructor {
     pub values: Vec<ContextuallyTypedRowValueExpression>,
+    pub span: Span,
+}
+
+impl Spanned for ContextuallyTypedTableValueConstructor {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
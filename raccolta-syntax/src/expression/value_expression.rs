@@ -1,13 +1,21 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::set_function::SetFunctionSpecification;
+use crate::{
+    set_function::SetFunctionSpecification,
+    Span,
+    Spanned,
+};
 
 use super::{
     BooleanExpression,
+    CaseExpression,
+    CastSpecification,
     ColumnReference,
     NumericValueExpression,
+    RoutineInvocation,
     string_value_expression::StringValueExpression,
+    WindowFunction,
 };
 
 /// ```text
@@ -23,15 +31,44 @@ use super::{
 ///     | <collection value expression>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValueExpression {
     Boolean(BooleanExpression),
 
+    /// `<case expression>`
+    Case(CaseExpression),
+
+    /// `<cast specification>`
+    Cast(CastSpecification),
+
     ColumnReference(ColumnReference),
 
     /// `<numeric value expression>`
     Numeric(NumericValueExpression),
 
+    /// `<routine invocation>`
+    RoutineInvocation(RoutineInvocation),
+
     SetFunctionSpecification(SetFunctionSpecification),
 
     StringValueExpression(StringValueExpression),
+
+    /// `<window function>`
+    WindowFunction(Box<WindowFunction>),
+}
+
+impl Spanned for ValueExpression {
+    fn span(&self) -> Span {
+        match self {
+            Self::Boolean(expression) => expression.span(),
+            Self::Case(expression) => expression.span(),
+            Self::Cast(specification) => specification.span(),
+            Self::ColumnReference(column_reference) => column_reference.span(),
+            Self::Numeric(expression) => expression.span(),
+            Self::RoutineInvocation(invocation) => invocation.span(),
+            Self::SetFunctionSpecification(specification) => specification.span(),
+            Self::StringValueExpression(expression) => expression.span(),
+            Self::WindowFunction(function) => function.span(),
+        }
+    }
 }
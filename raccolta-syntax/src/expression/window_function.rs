@@ -0,0 +1,110 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+use crate::{
+    clause::window_clause::WindowSpecification,
+    common::Identifier,
+    set_function::SetFunctionSpecification,
+    Span,
+    Spanned,
+};
+
+/// A function that is computed over the rows of the window of the current
+/// row, e.g. `RANK() OVER (ORDER BY score DESC)` or
+/// `SUM(amount) OVER (PARTITION BY account ORDER BY day)`.
+///
+/// ```text
+/// <window function> ::=
+///     <window function type> OVER <window name or specification>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowFunction {
+    pub function_type: WindowFunctionType,
+    pub window: WindowNameOrSpecification,
+    pub span: Span,
+}
+
+impl Spanned for WindowFunction {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// ```text
+/// <window function type> ::=
+///       <rank function type> <left paren> <right paren>
+///     | ROW_NUMBER <left paren> <right paren>
+///     | <aggregate function>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowFunctionType {
+    /// `<aggregate function>`, e.g. `SUM(amount)` or `COUNT(*)`
+    Aggregate(SetFunctionSpecification),
+
+    /// `<rank function type> <left paren> <right paren>`
+    Rank(RankFunctionType),
+
+    /// `ROW_NUMBER <left paren> <right paren>`
+    RowNumber,
+}
+
+/// ```text
+/// <rank function type> ::=
+///     RANK | DENSE_RANK | PERCENT_RANK | CUME_DIST
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RankFunctionType {
+    /// The relative position of the peers of the current row within the
+    /// window, with gaps after peers.
+    Rank,
+
+    /// The relative position of the peers of the current row within the
+    /// window, without gaps.
+    DenseRank,
+
+    /// `(RANK - 1) / (rows in window - 1)`
+    PercentRank,
+
+    /// The rows preceding or peer with the current row, divided by the rows
+    /// in the window.
+    CumeDist,
+}
+
+impl RankFunctionType {
+    /// Returns the keyword of this function, e.g. `DENSE_RANK`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rank => "RANK",
+            Self::DenseRank => "DENSE_RANK",
+            Self::PercentRank => "PERCENT_RANK",
+            Self::CumeDist => "CUME_DIST",
+        }
+    }
+}
+
+/// ```text
+/// <window name or specification> ::=
+///       <window name>
+///     | <in-line window specification>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowNameOrSpecification {
+    /// Refers to a window defined in the `WINDOW` clause.
+    Name(Identifier),
+
+    /// `<in-line window specification>`
+    Specification(WindowSpecification),
+}
+
+impl Spanned for WindowNameOrSpecification {
+    fn span(&self) -> Span {
+        match self {
+            Self::Name(window) => window.span(),
+            Self::Specification(window) => window.span(),
+        }
+    }
+}
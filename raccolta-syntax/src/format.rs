@@ -0,0 +1,393 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Converts syntax trees back to SQL, e.g. to canonicalize queries. The
+//! output parses to the same syntax tree, disregarding the [`Span`]s:
+//!
+//! ```
+//! use raccolta_syntax::{format::{Format, FormatOptions, KeywordCase}, Parser};
+//!
+//! let statement = Parser::new().parse_statement("select  NAME from PERSON where (age > 18)").unwrap();
+//! assert_eq!(statement.to_string(), "SELECT NAME FROM PERSON WHERE age > 18");
+//!
+//! let options = FormatOptions { keyword_case: KeywordCase::Lower, ..Default::default() };
+//! assert_eq!(statement.to_sql(&options), "select NAME from PERSON where age > 18");
+//! ```
+//!
+//! The [`Display`](std::fmt::Display) implementations of the syntax nodes
+//! write them on a single line, while the alternate form (`{:#}`) uses the
+//! default [`FormatOptions`].
+//!
+//! [`Span`]: crate::Span
+
+/// Implements [`Display`](std::fmt::Display) for syntax nodes using their
+/// [`Format`] implementation.
+macro_rules! impl_display_using_format {
+    ($($node:ty),+ $(,)?) => {
+        $(
+            impl std::fmt::Display for $node {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    let options = if f.alternate() {
+                        $crate::format::FormatOptions::default()
+                    } else {
+                        $crate::format::FormatOptions::single_line()
+                    };
+
+                    f.write_str(&$crate::format::Format::to_sql(self, &options))
+                }
+            }
+        )+
+    };
+}
+
+mod document;
+mod expression;
+mod query;
+mod statement;
+
+use crate::{
+    common::{Identifier, IdentifierDelimiter},
+    keyword::Keyword,
+};
+
+use self::document::Document;
+
+/// The options of [`Format::to_sql()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// The case in which keywords are written.
+    pub keyword_case: KeywordCase,
+
+    /// The number of spaces to indent nested lines with.
+    pub indentation: usize,
+
+    /// The width that lines shouldn't exceed. Clauses, lists and operands are
+    /// moved to lines of their own when they don't fit.
+    pub line_width: usize,
+}
+
+impl FormatOptions {
+    /// The options that write the whole statement on a single line.
+    pub fn single_line() -> Self {
+        Self {
+            line_width: usize::MAX,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            keyword_case: KeywordCase::Upper,
+            indentation: 4,
+            line_width: 80,
+        }
+    }
+}
+
+/// The case in which keywords are written, e.g. `SELECT` or `select`.
+/// Identifiers are always written as they were parsed.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+/// A syntax node that can be written as SQL.
+pub trait Format {
+    /// Writes this node to the `formatter`.
+    fn format(&self, formatter: &mut SqlFormatter<'_>);
+
+    /// Converts this node to SQL, laid out according to the `options`.
+    fn to_sql(&self, options: &FormatOptions) -> String {
+        let mut formatter = SqlFormatter::new(options);
+        self.format(&mut formatter);
+        formatter.finish()
+    }
+}
+
+/// Collects the SQL of the syntax nodes as a [`Document`], which is laid out
+/// when the formatter is [finished](SqlFormatter::finish).
+pub struct SqlFormatter<'options> {
+    options: &'options FormatOptions,
+
+    /// The contents of the groups and indentations that are being written,
+    /// the innermost last.
+    stack: Vec<Vec<Document>>,
+}
+
+impl<'options> SqlFormatter<'options> {
+    fn new(options: &'options FormatOptions) -> Self {
+        Self {
+            options,
+            stack: vec![Vec::new()],
+        }
+    }
+
+    fn finish(mut self) -> String {
+        debug_assert_eq!(self.stack.len(), 1, "unbalanced groups or indentations");
+        document::render(&self.stack.pop().unwrap_or_default(), self.options)
+    }
+
+    /// Writes the `node`.
+    pub fn format<T: Format + ?Sized>(&mut self, node: &T) {
+        node.format(self);
+    }
+
+    fn push(&mut self, document: Document) {
+        self.stack.last_mut()
+            .expect("the outermost document is never popped")
+            .push(document);
+    }
+
+    /// Writes the `text` as is.
+    pub(crate) fn text(&mut self, text: impl Into<String>) {
+        self.push(Document::Text(text.into()));
+    }
+
+    pub(crate) fn space(&mut self) {
+        self.text(" ");
+    }
+
+    /// Writes the `keyword` in the configured [`KeywordCase`].
+    pub(crate) fn keyword(&mut self, keyword: impl AsRef<str>) {
+        let keyword = keyword.as_ref();
+        self.text(match self.options.keyword_case {
+            KeywordCase::Upper => keyword.to_ascii_uppercase(),
+            KeywordCase::Lower => keyword.to_ascii_lowercase(),
+        });
+    }
+
+    /// Writes the `keywords`, separated by spaces.
+    pub(crate) fn keywords(&mut self, keywords: &[&dyn AsRef<str>]) {
+        for (index, keyword) in keywords.iter().enumerate() {
+            if index != 0 {
+                self.space();
+            }
+            self.keyword(keyword);
+        }
+    }
+
+    /// Writes a space, or breaks the line when the enclosing group doesn't
+    /// fit.
+    pub(crate) fn line(&mut self) {
+        self.push(Document::Line);
+    }
+
+    /// Writes nothing, or breaks the line when the enclosing group doesn't
+    /// fit.
+    pub(crate) fn soft_line(&mut self) {
+        self.push(Document::SoftLine);
+    }
+
+    /// Writes the contents as a group, of which the lines are only broken
+    /// when it doesn't fit on the current line.
+    pub(crate) fn group(&mut self, contents: impl FnOnce(&mut Self)) {
+        self.stack.push(Vec::new());
+        contents(self);
+        let contents = self.stack.pop().expect("pushed above");
+        self.push(Document::Group(contents));
+    }
+
+    /// Writes the contents such that the lines it breaks are indented one
+    /// level deeper.
+    pub(crate) fn indent(&mut self, contents: impl FnOnce(&mut Self)) {
+        self.stack.push(Vec::new());
+        contents(self);
+        let contents = self.stack.pop().expect("pushed above");
+        self.push(Document::Indent(contents));
+    }
+
+    /// Writes a clause, such as `WHERE <search condition>`, of which the body
+    /// is moved to the next lines when it doesn't fit.
+    pub(crate) fn clause(&mut self, keywords: &[&dyn AsRef<str>], body: impl FnOnce(&mut Self)) {
+        self.group(|f| {
+            f.keywords(keywords);
+            f.indent(|f| {
+                f.line();
+                body(f);
+            });
+        });
+    }
+
+    /// Writes the `items` separated by commas, one per line when they don't
+    /// fit.
+    pub(crate) fn comma_separated<T>(&mut self, items: impl IntoIterator<Item = T>, mut item: impl FnMut(&mut Self, T)) {
+        for (index, value) in items.into_iter().enumerate() {
+            if index != 0 {
+                self.text(",");
+                self.line();
+            }
+            item(self, value);
+        }
+    }
+
+    /// Writes the `contents` between parentheses, which are put on lines of
+    /// their own when the contents don't fit.
+    pub(crate) fn parenthesized(&mut self, contents: impl FnOnce(&mut Self)) {
+        self.group(|f| {
+            f.text("(");
+            f.indent(|f| {
+                f.soft_line();
+                contents(f);
+            });
+            f.soft_line();
+            f.text(")");
+        });
+    }
+
+    /// Writes the `name` of a routine, e.g. `UPPER` or `my_function`.
+    /// Routines with special syntax, e.g. `TRIM`, write their keyword instead.
+    pub(crate) fn routine_name(&mut self, name: &Identifier) {
+        if name.delimiter.is_none() && matches!(Keyword::lookup(&name.value), Some(Keyword::Reserved(..))) {
+            self.keyword(&name.value);
+        } else {
+            self.format(name);
+        }
+    }
+
+    /// Writes a `name` of which the parser doesn't keep whether it was
+    /// delimited, such as a column name or alias. It is delimited when it
+    /// couldn't be written as a regular identifier.
+    pub(crate) fn name(&mut self, name: &str) {
+        if is_regular_identifier(name) {
+            self.text(name);
+        } else {
+            self.delimited_identifier(name, IdentifierDelimiter::DoubleQuote);
+        }
+    }
+
+    pub(crate) fn delimited_identifier(&mut self, value: &str, delimiter: IdentifierDelimiter) {
+        let closing_character = delimiter.closing_character().to_string();
+        let escaped = value.replace(&closing_character, &closing_character.repeat(2));
+        self.text(format!("{}{escaped}{closing_character}", delimiter.opening_character()));
+    }
+}
+
+/// Checks whether the `name` is lexed as an identifier when it isn't
+/// delimited.
+fn is_regular_identifier(name: &str) -> bool {
+    let mut characters = name.chars();
+    let starts_with_letter = characters.next().is_some_and(char::is_alphabetic);
+
+    starts_with_letter
+        && characters.all(|character| character.is_alphanumeric() || character == '_')
+        && matches!(Keyword::lookup(name), None | Some(Keyword::NonReserved(..)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+    use rstest::rstest;
+
+    use pretty_assertions::assert_eq;
+
+    #[rstest]
+    #[case("select a,b from t", "SELECT a, b FROM t")]
+    #[case("SELECT (a + b) * c, a + (b * c), a - (b - c), (a - b) - c", "SELECT (a + b) * c, a + b * c, a - (b - c), a - b - c")]
+    #[case("SELECT NOT (a = 1), (NOT a) = 1, (a OR b) AND c", "SELECT NOT a = 1, (NOT a) = 1, (a OR b) AND c")]
+    #[case("SELECT -(5), - -5, -(-x), -(a + b)", "SELECT -(5), -(-5), -(-x), -(a + b)")]
+    #[case("SELECT (a IS TRUE) = b, a = (b IS NULL), (a = b) IS NOT DISTINCT FROM c", "SELECT (a IS TRUE) = b, a = (b IS NULL), a = b IS NOT DISTINCT FROM c")]
+    #[case("SELECT a BETWEEN (1 + 2) AND 3 || 'x', a NOT IN (1, 2), a LIKE 'x%' ESCAPE '!'", "SELECT a BETWEEN 1 + 2 AND 3 || 'x', a NOT IN (1, 2), a LIKE 'x%' ESCAPE '!'")]
+    #[case("SELECT 'it''s', X'0aFF', B'101', N'national', n'it''s'", "SELECT 'it''s', X'0AFF', B'101', N'national', N'it''s'")]
+    #[case(r"SELECT U&'\0041\\', U&'#0041' UESCAPE '#'", r"SELECT U&'A\\', U&'A'")]
+    #[case(r#"SELECT "Name", "a""b" AS "select", upper(x) FROM "order" AS o"#, r#"SELECT "Name", "a""b" AS "select", UPPER(x) FROM "order" AS o"#)]
+    #[case("SELECT TRIM(x), TRIM(LEADING FROM x), TRIM(BOTH '-' FROM x), SUBSTRING(x FROM 2 FOR 3)", "SELECT TRIM(x), TRIM(LEADING FROM x), TRIM(BOTH '-' FROM x), SUBSTRING(x FROM 2 FOR 3)")]
+    #[case("SELECT CASE WHEN a THEN NULL ELSE 1 END, CAST(NULL AS varchar(5)), COUNT(*), SUM(DISTINCT a)", "SELECT CASE WHEN a THEN NULL ELSE 1 END, CAST(NULL AS CHARACTER VARYING(5)), COUNT(*), SUM(DISTINCT a)")]
+    #[case("SELECT RANK() OVER (PARTITION BY a ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM t WINDOW w AS (ORDER BY a)", "SELECT RANK() OVER (PARTITION BY a ORDER BY b DESC ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM t WINDOW w AS (ORDER BY a)")]
+    #[case("SELECT a FROM t ORDER BY a OFFSET 1 ROW FETCH NEXT ROWS WITH TIES", "SELECT a FROM t ORDER BY a OFFSET 1 ROW FETCH FIRST 1 ROW WITH TIES")]
+    #[case("SELECT a FROM t LIMIT 5, 10", "SELECT a FROM t LIMIT 10 OFFSET 5")]
+    #[case("SELECT TOP (5) PERCENT a FROM t", "SELECT TOP 5 PERCENT a FROM t")]
+    #[case("insert into t values (1, 'a'), (2, 'b')", "INSERT INTO t VALUES (1, 'a'), (2, 'b')")]
+    #[case("create table t (id int, data BLOB(2M), bits bit varying(8))", "CREATE TABLE t (id INTEGER, data BINARY LARGE OBJECT(2M), bits BIT VARYING(8))")]
+    #[case(r#"create table "Order" ("Id" int, name int)"#, r#"CREATE TABLE "Order" ("Id" INTEGER, name INTEGER)"#)]
+    fn format_single_line(#[case] input: &str, #[case] expected: &str) {
+        let statement = Parser::new().parse_statement(input).unwrap();
+        assert_eq!(statement.to_string(), expected);
+    }
+
+    #[test]
+    fn format_keyword_case_lower() {
+        let statement = Parser::new().parse_statement("SELECT DISTINCT Upper(A) AS B FROM T WHERE C IS NOT NULL").unwrap();
+        let options = FormatOptions {
+            keyword_case: KeywordCase::Lower,
+            ..FormatOptions::single_line()
+        };
+
+        assert_eq!(statement.to_sql(&options), "select distinct upper(A) as B from T where C is not null");
+    }
+
+    #[rstest]
+    #[case(
+        "SELECT name, price FROM product WHERE price > 10",
+        80,
+        "SELECT name, price FROM product WHERE price > 10",
+    )]
+    #[case(
+        "SELECT name, price FROM product WHERE price > 10",
+        30,
+        "SELECT name, price\nFROM product\nWHERE price > 10",
+    )]
+    #[case(
+        "SELECT name, price, stock, category FROM product WHERE price > 10 AND stock > 0 OR category = 'sale'",
+        24,
+        "SELECT\n    name,\n    price,\n    stock,\n    category\nFROM product\nWHERE\n    price > 10\n    AND stock > 0\n    OR category = 'sale'",
+    )]
+    #[case(
+        "CREATE TABLE person (id INT, name VARCHAR(100), email VARCHAR(100))",
+        40,
+        "CREATE TABLE person (\n    id INTEGER,\n    name CHARACTER VARYING(100),\n    email CHARACTER VARYING(100)\n)",
+    )]
+    #[case(
+        "INSERT INTO person VALUES (1, 'Alice'), (2, 'Bob')",
+        32,
+        "INSERT INTO person\nVALUES (1, 'Alice'), (2, 'Bob')",
+    )]
+    fn format_line_width(#[case] input: &str, #[case] line_width: usize, #[case] expected: &str) {
+        let statement = Parser::new().parse_statement(input).unwrap();
+        let options = FormatOptions {
+            line_width,
+            ..Default::default()
+        };
+
+        assert_eq!(statement.to_sql(&options), expected);
+    }
+
+    #[test]
+    fn format_alternate_display_uses_default_options() {
+        let statement = Parser::new().parse_statement("SELECT a FROM t").unwrap();
+        assert_eq!(format!("{statement:#}"), statement.to_sql(&FormatOptions::default()));
+    }
+
+    #[test]
+    fn format_indentation() {
+        let statement = Parser::new().parse_statement("SELECT first_column, second_column FROM t").unwrap();
+        let options = FormatOptions {
+            indentation: 2,
+            line_width: 20,
+            ..Default::default()
+        };
+
+        assert_eq!(statement.to_sql(&options), "SELECT\n  first_column,\n  second_column\nFROM t");
+    }
+
+    #[rstest]
+    #[case("name", "name")]
+    #[case("first", "first")]
+    #[case("Mixed_Case1", "Mixed_Case1")]
+    #[case("select", "\"select\"")]
+    #[case("limit", "\"limit\"")]
+    #[case("two words", "\"two words\"")]
+    #[case("1st", "\"1st\"")]
+    #[case("", "\"\"")]
+    #[case("quote\"d", "\"quote\"\"d\"")]
+    fn format_name(#[case] name: &str, #[case] expected: &str) {
+        let options = FormatOptions::default();
+        let mut formatter = SqlFormatter::new(&options);
+        formatter.name(name);
+        assert_eq!(formatter.finish(), expected);
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the layout of the formatter. The SQL is first
+//! collected as a tree of [`Document`]s, after which the [groups] that fit on
+//! the current line are written flat, and the others are broken over
+//! multiple lines.
+//!
+//! [groups]: Document::Group
+
+use super::FormatOptions;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(super) enum Document {
+    Text(String),
+
+    /// A space, or a line break when the enclosing group is broken.
+    Line,
+
+    /// Nothing, or a line break when the enclosing group is broken.
+    SoftLine,
+
+    /// Documents that are written flat when they fit on the current line.
+    Group(Vec<Document>),
+
+    /// Documents of which the line breaks are indented one level deeper.
+    Indent(Vec<Document>),
+}
+
+impl Document {
+    /// Returns the width of this document when it is written flat.
+    fn flat_width(&self) -> usize {
+        match self {
+            Self::Text(text) => text.chars().count(),
+            Self::Line => 1,
+            Self::SoftLine => 0,
+            Self::Group(documents) | Self::Indent(documents) => flat_width(documents),
+        }
+    }
+}
+
+fn flat_width(documents: &[Document]) -> usize {
+    documents.iter().fold(0, |width, document| width.saturating_add(document.flat_width()))
+}
+
+/// Lays out the `documents` according to the `options`.
+pub(super) fn render(documents: &[Document], options: &FormatOptions) -> String {
+    let mut renderer = Renderer {
+        output: String::new(),
+        column: 0,
+        options,
+    };
+
+    renderer.render(documents, 0, false);
+    renderer.output
+}
+
+struct Renderer<'options> {
+    output: String,
+
+    /// The width of the current line.
+    column: usize,
+
+    options: &'options FormatOptions,
+}
+
+impl Renderer<'_> {
+    fn render(&mut self, documents: &[Document], indentation: usize, is_flat: bool) {
+        for document in documents {
+            match document {
+                Document::Text(text) => {
+                    self.output.push_str(text);
+                    self.column += text.chars().count();
+                }
+
+                Document::Line if is_flat => {
+                    self.output.push(' ');
+                    self.column += 1;
+                }
+
+                Document::SoftLine if is_flat => (),
+
+                Document::Line | Document::SoftLine => {
+                    self.output.push('\n');
+                    self.output.extend(std::iter::repeat_n(' ', indentation));
+                    self.column = indentation;
+                }
+
+                Document::Group(documents) => {
+                    let fits = self.column.saturating_add(flat_width(documents)) <= self.options.line_width;
+                    self.render(documents, indentation, is_flat || fits);
+                }
+
+                Document::Indent(documents) => {
+                    self.render(documents, indentation + self.options.indentation, is_flat);
+                }
+            }
+        }
+    }
+}
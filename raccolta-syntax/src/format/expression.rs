@@ -0,0 +1,991 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module formats value expressions, predicates and data types. Since
+//! the syntax tree doesn't contain parentheses, operands are parenthesized
+//! when the parser would otherwise group them differently.
+
+use crate::{
+    common::{
+        character_set::CharacterSetSpecification,
+        Identifier,
+    },
+    expression::{
+        boolean_expression::TruthValue,
+        case_expression::{
+            CaseResult,
+            SearchedWhenClause,
+            SimpleWhenClause,
+        },
+        cast_specification::CastOperand,
+        data_type::{
+            BinaryStringType,
+            BitStringType,
+            CharacterStringType,
+            DataType,
+            LargeObjectLength,
+            LargeObjectLengthMultiplier,
+            NationalCharacterStringType,
+            NumericType,
+            PredefinedType,
+        },
+        numeric_value_function::NumericValueFunction,
+        string_value_expression::StringValueExpression,
+        string_value_function::{
+            StringValueFunction,
+            TrimSpecification,
+        },
+        window_function::{
+            RankFunctionType,
+            WindowFunctionType,
+            WindowNameOrSpecification,
+        },
+        BooleanExpression,
+        CaseExpression,
+        CastSpecification,
+        ColumnReference,
+        NumericOperator,
+        NumericValueExpression,
+        RoutineInvocation,
+        SimpleValueSpecification,
+        ValueExpression,
+        WindowFunction,
+    },
+    keyword::{
+        NonReservedWord,
+        ReservedWord,
+    },
+    parse::operator::OperatorPrecedence,
+    predicate::{
+        between_predicate::BetweenPredicate,
+        comparison_predicate::{
+            ComparisonOperator,
+            ComparisonPredicate,
+        },
+        distinct_predicate::DistinctPredicate,
+        in_predicate::InPredicate,
+        like_predicate::LikePredicate,
+        null_predicate::NullPredicate,
+        quantified_comparison_predicate::{
+            QuantifiedComparisonPredicate,
+            Quantifier,
+        },
+        similar_predicate::SimilarPredicate,
+        Predicate,
+    },
+    set_function::{
+        ComputationalOperation,
+        GeneralSetFunction,
+        SetFunctionSpecification,
+        SetQuantifier,
+    },
+    StringLiteralKind,
+};
+
+use super::{Format, SqlFormatter};
+
+/// Returns the precedence of the operator that is applied last in the
+/// `expression`, or `None` if it is a primary that never needs parentheses.
+fn precedence(expression: &ValueExpression) -> Option<OperatorPrecedence> {
+    Some(match expression {
+        ValueExpression::Boolean(BooleanExpression::Or { .. }) => OperatorPrecedence::Or,
+        ValueExpression::Boolean(BooleanExpression::And { .. }) => OperatorPrecedence::And,
+        ValueExpression::Boolean(BooleanExpression::Not(..)) => OperatorPrecedence::Not,
+        ValueExpression::Boolean(BooleanExpression::Is { .. }) => OperatorPrecedence::Is,
+
+        ValueExpression::Boolean(BooleanExpression::Predicate(predicate)) => match predicate.as_ref() {
+            Predicate::Distinct(..) | Predicate::Null(..) => OperatorPrecedence::Is,
+            _ => OperatorPrecedence::Comparison,
+        },
+
+        ValueExpression::StringValueExpression(StringValueExpression::Concatenation { .. }) => OperatorPrecedence::Concatenation,
+
+        ValueExpression::Numeric(NumericValueExpression::BinaryOperation { operator, .. }) => numeric_operator_precedence(*operator),
+
+        ValueExpression::Numeric(NumericValueExpression::Negation(..)) => OperatorPrecedence::Sign,
+
+        _ => return None,
+    })
+}
+
+const fn numeric_operator_precedence(operator: NumericOperator) -> OperatorPrecedence {
+    match operator {
+        NumericOperator::Addition | NumericOperator::Subtraction => OperatorPrecedence::Additive,
+        NumericOperator::Division | NumericOperator::Multiplication => OperatorPrecedence::Multiplicative,
+    }
+}
+
+/// Writes the `operand` of an operator, parenthesized if it contains an
+/// operator that binds looser than `minimum_precedence`.
+fn format_operand(f: &mut SqlFormatter<'_>, operand: &ValueExpression, minimum_precedence: OperatorPrecedence) {
+    if precedence(operand).is_some_and(|precedence| precedence < minimum_precedence) {
+        format_parenthesized_operand(f, operand);
+    } else {
+        f.format(operand);
+    }
+}
+
+fn format_parenthesized_operand(f: &mut SqlFormatter<'_>, operand: &ValueExpression) {
+    f.text("(");
+    f.format(operand);
+    f.text(")");
+}
+
+/// Writes the `[ NOT ] <keyword>` of a predicate that can be negated.
+fn format_negatable_keyword(f: &mut SqlFormatter<'_>, negated: bool, keyword: ReservedWord) {
+    f.space();
+    if negated {
+        f.keyword(ReservedWord::Not);
+        f.space();
+    }
+    f.keyword(keyword);
+}
+
+/// Writes the `ESCAPE <escape character>` of a `LIKE` or `SIMILAR`
+/// predicate, if there is one.
+fn format_escape(f: &mut SqlFormatter<'_>, escape: Option<&ValueExpression>) {
+    if let Some(escape) = escape {
+        f.space();
+        f.keyword(ReservedWord::Escape);
+        f.space();
+        format_operand(f, escape, OperatorPrecedence::Comparison.tighter());
+    }
+}
+
+/// Writes the `name` followed by the `arguments` between parentheses.
+fn format_function_call<'a>(
+    f: &mut SqlFormatter<'_>,
+    name: impl AsRef<str>,
+    arguments: impl IntoIterator<Item = &'a ValueExpression>,
+) {
+    f.group(|f| {
+        f.keyword(name);
+        f.parenthesized(|f| f.comma_separated(arguments, SqlFormatter::format));
+    });
+}
+
+/// Returns the operands of a chain of `AND` or `OR`, e.g. `a`, `b` and `c`
+/// of `a AND b AND c`.
+fn logical_operands(expression: &BooleanExpression) -> Option<(ReservedWord, &ValueExpression, &ValueExpression)> {
+    match expression {
+        BooleanExpression::And { left_hand_side, right_hand_side } => Some((ReservedWord::And, left_hand_side, right_hand_side)),
+        BooleanExpression::Or { left_hand_side, right_hand_side } => Some((ReservedWord::Or, left_hand_side, right_hand_side)),
+        _ => None,
+    }
+}
+
+/// Writes a chain of the same logical operator, e.g. `a AND b AND c`, which
+/// is broken before each operator when it doesn't fit.
+fn format_logical_chain(f: &mut SqlFormatter<'_>, keyword: ReservedWord, mut left_hand_side: &ValueExpression, right_hand_side: &ValueExpression) {
+    let mut right_hand_sides = vec![right_hand_side];
+    while let ValueExpression::Boolean(expression) = left_hand_side {
+        match logical_operands(expression) {
+            Some((operator, left, right)) if operator == keyword => {
+                right_hand_sides.push(right);
+                left_hand_side = left;
+            }
+            _ => break,
+        }
+    }
+
+    let precedence = match keyword {
+        ReservedWord::And => OperatorPrecedence::And,
+        _ => OperatorPrecedence::Or,
+    };
+
+    f.group(|f| {
+        format_operand(f, left_hand_side, precedence);
+        for right_hand_side in right_hand_sides.into_iter().rev() {
+            f.line();
+            f.keyword(keyword);
+            f.space();
+            format_operand(f, right_hand_side, precedence.tighter());
+        }
+    });
+}
+
+impl Format for ValueExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Boolean(expression) => f.format(expression),
+            Self::Case(expression) => f.format(expression),
+            Self::Cast(specification) => f.format(specification),
+            Self::ColumnReference(column_reference) => f.format(column_reference),
+            Self::Numeric(expression) => f.format(expression),
+            Self::RoutineInvocation(invocation) => f.format(invocation),
+            Self::SetFunctionSpecification(specification) => f.format(specification),
+            Self::StringValueExpression(expression) => f.format(expression),
+            Self::WindowFunction(function) => f.format(function.as_ref()),
+        }
+    }
+}
+
+impl Format for BooleanExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::And { left_hand_side, right_hand_side } => format_logical_chain(f, ReservedWord::And, left_hand_side, right_hand_side),
+            Self::Or { left_hand_side, right_hand_side } => format_logical_chain(f, ReservedWord::Or, left_hand_side, right_hand_side),
+
+            Self::Is { operand, negated, truth_value, .. } => {
+                format_operand(f, operand, OperatorPrecedence::Is);
+                f.space();
+                f.keyword(ReservedWord::Is);
+                f.space();
+                if *negated {
+                    f.keyword(ReservedWord::Not);
+                    f.space();
+                }
+                f.format(truth_value);
+            }
+
+            Self::Literal(true, _) => f.keyword(ReservedWord::True),
+            Self::Literal(false, _) => f.keyword(ReservedWord::False),
+
+            Self::Not(operand, _) => {
+                f.keyword(ReservedWord::Not);
+                f.space();
+                format_operand(f, operand, OperatorPrecedence::Not.tighter());
+            }
+
+            Self::Predicate(predicate) => f.format(predicate.as_ref()),
+            Self::Unknown(_) => f.keyword(ReservedWord::Unknown),
+        }
+    }
+}
+
+impl Format for TruthValue {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::False => ReservedWord::False,
+            Self::True => ReservedWord::True,
+            Self::Unknown => ReservedWord::Unknown,
+        });
+    }
+}
+
+impl Format for Predicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Between(predicate) => f.format(predicate),
+            Self::Comparison(predicate) => f.format(predicate),
+            Self::Distinct(predicate) => f.format(predicate),
+            Self::In(predicate) => f.format(predicate),
+            Self::Like(predicate) => f.format(predicate),
+            Self::Null(predicate) => f.format(predicate),
+            Self::QuantifiedComparison(predicate) => f.format(predicate),
+            Self::Similar(predicate) => f.format(predicate),
+        }
+    }
+}
+
+impl Format for BetweenPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.operand, OperatorPrecedence::Comparison);
+        format_negatable_keyword(f, self.negated, ReservedWord::Between);
+        f.space();
+        if self.symmetric {
+            f.keyword(ReservedWord::Symmetric);
+            f.space();
+        }
+        format_operand(f, &self.lower_bound, OperatorPrecedence::Comparison.tighter());
+        f.space();
+        f.keyword(ReservedWord::And);
+        f.space();
+        format_operand(f, &self.upper_bound, OperatorPrecedence::Comparison.tighter());
+    }
+}
+
+impl Format for ComparisonPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.left_hand_side, OperatorPrecedence::Comparison);
+        f.space();
+        f.format(&self.operator);
+        f.space();
+        format_operand(f, &self.right_hand_side, OperatorPrecedence::Comparison.tighter());
+    }
+}
+
+impl Format for ComparisonOperator {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.text(self.symbol());
+    }
+}
+
+impl Format for DistinctPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.left_hand_side, OperatorPrecedence::Is);
+        f.space();
+        f.keyword(ReservedWord::Is);
+        format_negatable_keyword(f, self.negated, ReservedWord::Distinct);
+        f.space();
+        f.keyword(ReservedWord::From);
+        f.space();
+        format_operand(f, &self.right_hand_side, OperatorPrecedence::Comparison.tighter());
+    }
+}
+
+impl Format for InPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.operand, OperatorPrecedence::Comparison);
+        format_negatable_keyword(f, self.negated, ReservedWord::In);
+        f.space();
+        f.parenthesized(|f| f.comma_separated(&self.values, SqlFormatter::format));
+    }
+}
+
+impl Format for LikePredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.operand, OperatorPrecedence::Comparison);
+        format_negatable_keyword(f, self.negated, ReservedWord::Like);
+        f.space();
+        format_operand(f, &self.pattern, OperatorPrecedence::Comparison.tighter());
+        format_escape(f, self.escape.as_ref());
+    }
+}
+
+impl Format for NullPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.operand, OperatorPrecedence::Is);
+        f.space();
+        f.keyword(ReservedWord::Is);
+        format_negatable_keyword(f, self.negated, ReservedWord::Null);
+    }
+}
+
+impl Format for QuantifiedComparisonPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.left_hand_side, OperatorPrecedence::Comparison);
+        f.space();
+        f.format(&self.operator);
+        f.space();
+        f.format(&self.quantifier);
+        f.space();
+        f.parenthesized(|f| f.comma_separated(&self.values, SqlFormatter::format));
+    }
+}
+
+impl Format for Quantifier {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::All => ReservedWord::All,
+            Self::Any => ReservedWord::Any,
+        });
+    }
+}
+
+impl Format for SimilarPredicate {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        format_operand(f, &self.operand, OperatorPrecedence::Comparison);
+        format_negatable_keyword(f, self.negated, ReservedWord::Similar);
+        f.space();
+        f.keyword(ReservedWord::To);
+        f.space();
+        format_operand(f, &self.pattern, OperatorPrecedence::Comparison.tighter());
+        format_escape(f, self.escape.as_ref());
+    }
+}
+
+impl Format for CaseExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Coalesce(arguments, _) => format_function_call(f, ReservedWord::Coalesce, arguments),
+
+            Self::NullIf { left_hand_side, right_hand_side, .. } => {
+                format_function_call(f, ReservedWord::Nullif, [left_hand_side.as_ref(), right_hand_side.as_ref()]);
+            }
+
+            Self::Searched { when_clauses, else_clause, .. } => {
+                format_case(f, None, when_clauses, else_clause.as_deref());
+            }
+
+            Self::Simple { operand, when_clauses, else_clause, .. } => {
+                format_case(f, Some(operand), when_clauses, else_clause.as_deref());
+            }
+        }
+    }
+}
+
+/// Writes a `CASE` expression, which is broken before each `WHEN`, the
+/// `ELSE` and the `END` when it doesn't fit.
+fn format_case<WhenClause: Format>(
+    f: &mut SqlFormatter<'_>,
+    operand: Option<&ValueExpression>,
+    when_clauses: &[WhenClause],
+    else_clause: Option<&CaseResult>,
+) {
+    f.group(|f| {
+        f.keyword(ReservedWord::Case);
+        if let Some(operand) = operand {
+            f.space();
+            f.format(operand);
+        }
+
+        f.indent(|f| {
+            for when_clause in when_clauses {
+                f.line();
+                f.format(when_clause);
+            }
+
+            if let Some(else_clause) = else_clause {
+                f.line();
+                f.keyword(ReservedWord::Else);
+                f.space();
+                f.format(else_clause);
+            }
+        });
+
+        f.line();
+        f.keyword(ReservedWord::End);
+    });
+}
+
+impl Format for CaseResult {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Null(_) => f.keyword(ReservedWord::Null),
+            Self::ValueExpression(value_expression) => f.format(value_expression),
+        }
+    }
+}
+
+impl Format for SearchedWhenClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::When);
+        f.space();
+        f.format(&self.search_condition);
+        f.space();
+        f.keyword(ReservedWord::Then);
+        f.space();
+        f.format(&self.result);
+    }
+}
+
+impl Format for SimpleWhenClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::When);
+        f.space();
+        f.format(&self.when_operand);
+        f.space();
+        f.keyword(ReservedWord::Then);
+        f.space();
+        f.format(&self.result);
+    }
+}
+
+impl Format for CastSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Cast);
+        f.text("(");
+        f.format(&self.operand);
+        f.space();
+        f.keyword(ReservedWord::As);
+        f.space();
+        f.format(&self.target);
+        f.text(")");
+    }
+}
+
+impl Format for CastOperand {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Null => f.keyword(ReservedWord::Null),
+            Self::ValueExpression(value_expression) => f.format(value_expression.as_ref()),
+        }
+    }
+}
+
+impl Format for ColumnReference {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::BasicIdentifierChain(identifiers) => {
+                for (index, identifier) in identifiers.iter().enumerate() {
+                    if index != 0 {
+                        f.text(".");
+                    }
+                    f.format(identifier);
+                }
+            }
+        }
+    }
+}
+
+impl Format for Identifier {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self.delimiter {
+            Some(delimiter) => f.delimited_identifier(&self.value, delimiter),
+            None => f.text(self.value.as_str()),
+        }
+    }
+}
+
+impl Format for NumericValueExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::ApproximateNumericLiteral(literal, _) | Self::ExactNumericLiteral(literal, _) => f.text(literal.as_str()),
+
+            Self::BinaryOperation { left_hand_side, operator, right_hand_side } => {
+                let precedence = numeric_operator_precedence(*operator);
+                format_operand(f, left_hand_side, precedence);
+                f.space();
+                f.format(operator);
+                f.space();
+                format_operand(f, right_hand_side, precedence.tighter());
+            }
+
+            Self::Function(function) => f.format(function),
+
+            Self::Negation(operand, _) => {
+                f.text("-");
+
+                // A sign before a numeric literal would be part of the
+                // literal, and two signs would start a comment.
+                let is_signed_primary = matches!(operand.as_ref(), ValueExpression::Numeric(
+                    NumericValueExpression::ApproximateNumericLiteral(..) | NumericValueExpression::ExactNumericLiteral(..)
+                        | NumericValueExpression::Negation(..) | NumericValueExpression::SimpleI64(..)
+                        | NumericValueExpression::SimpleU64(..)
+                ));
+
+                if is_signed_primary {
+                    format_parenthesized_operand(f, operand);
+                } else {
+                    format_operand(f, operand, OperatorPrecedence::Sign);
+                }
+            }
+
+            // Only a negative zero is parsed as a signed integer that isn't
+            // negative.
+            Self::SimpleI64(0, _) => f.text("-0"),
+            Self::SimpleI64(integer, _) => f.text(integer.to_string()),
+            Self::SimpleU64(integer, _) => f.text(integer.to_string()),
+        }
+    }
+}
+
+impl Format for NumericOperator {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.text(self.symbol());
+    }
+}
+
+impl Format for NumericValueFunction {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Position { needle, haystack, .. } => {
+                f.keyword(ReservedWord::Position);
+                f.text("(");
+                format_operand(f, needle, OperatorPrecedence::Comparison.tighter());
+                f.space();
+                f.keyword(ReservedWord::In);
+                f.space();
+                f.format(haystack.as_ref());
+                f.text(")");
+            }
+        }
+    }
+}
+
+impl Format for RoutineInvocation {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.group(|f| {
+            f.routine_name(&self.name);
+            f.parenthesized(|f| f.comma_separated(&self.arguments, SqlFormatter::format));
+        });
+    }
+}
+
+impl Format for SetFunctionSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Count(_) => {
+                f.keyword(ReservedWord::Count);
+                f.text("(*)");
+            }
+            Self::General(function) => f.format(function),
+        }
+    }
+}
+
+impl Format for GeneralSetFunction {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.function_type);
+        f.text("(");
+        if self.set_quantifier == SetQuantifier::Distinct {
+            f.format(&self.set_quantifier);
+            f.space();
+        }
+        f.format(self.value_expression.as_ref());
+        f.text(")");
+    }
+}
+
+impl Format for ComputationalOperation {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(self.as_str());
+    }
+}
+
+impl Format for SetQuantifier {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::All => ReservedWord::All,
+            Self::Distinct => ReservedWord::Distinct,
+        });
+    }
+}
+
+impl Format for SimpleValueSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::LiteralUnsigned(integer) => f.text(integer.to_string()),
+        }
+    }
+}
+
+impl Format for StringValueExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::BinaryLiteral(bytes, _) => {
+                let hexits: String = bytes.iter().map(|byte| format!("{byte:02X}")).collect();
+                f.text(format!("X'{hexits}'"));
+            }
+
+            Self::BitLiteral(bits, _) => {
+                let bits: String = bits.iter().map(|bit| if *bit { '1' } else { '0' }).collect();
+                f.text(format!("B'{bits}'"));
+            }
+
+            Self::Concatenation { left_hand_side, right_hand_side } => {
+                format_operand(f, left_hand_side, OperatorPrecedence::Concatenation);
+                f.text(" || ");
+                format_operand(f, right_hand_side, OperatorPrecedence::Concatenation.tighter());
+            }
+
+            Self::Function(function) => f.format(function),
+            Self::Literal(literal, kind, _) => {
+                let literal = literal.replace('\'', "''");
+                f.text(match kind {
+                    StringLiteralKind::Character => format!("'{literal}'"),
+                    StringLiteralKind::National => format!("N'{literal}'"),
+                    // The value is written with the default escape character,
+                    // which then has to be escaped itself.
+                    StringLiteralKind::Unicode => format!("U&'{}'", literal.replace('\\', "\\\\")),
+                });
+            }
+        }
+    }
+}
+
+impl Format for StringValueFunction {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Overlay { operand, placing, start_position, string_length, .. } => {
+                f.keyword(ReservedWord::Overlay);
+                f.text("(");
+                f.format(operand.as_ref());
+                f.space();
+                f.keyword(NonReservedWord::Placing);
+                f.space();
+                f.format(placing.as_ref());
+                format_from_for(f, start_position, string_length.as_deref());
+                f.text(")");
+            }
+
+            Self::Substring { operand, start_position, string_length, .. } => {
+                f.keyword(ReservedWord::Substring);
+                f.text("(");
+                f.format(operand.as_ref());
+                format_from_for(f, start_position, string_length.as_deref());
+                f.text(")");
+            }
+
+            Self::Trim { specification, character, source, .. } => {
+                f.keyword(ReservedWord::Trim);
+                f.text("(");
+
+                // `TRIM(BOTH FROM x)` is abbreviated to `TRIM(x)`.
+                if *specification != TrimSpecification::Both || character.is_some() {
+                    f.format(specification);
+                    f.space();
+                    if let Some(character) = character {
+                        f.format(character.as_ref());
+                        f.space();
+                    }
+                    f.keyword(ReservedWord::From);
+                    f.space();
+                }
+
+                f.format(source.as_ref());
+                f.text(")");
+            }
+        }
+    }
+}
+
+/// Writes the `FROM <start position> [ FOR <string length> ]` of the
+/// `OVERLAY` and `SUBSTRING` functions.
+fn format_from_for(f: &mut SqlFormatter<'_>, start_position: &ValueExpression, string_length: Option<&ValueExpression>) {
+    f.space();
+    f.keyword(ReservedWord::From);
+    f.space();
+    f.format(start_position);
+
+    if let Some(string_length) = string_length {
+        f.space();
+        f.keyword(ReservedWord::For);
+        f.space();
+        f.format(string_length);
+    }
+}
+
+impl Format for TrimSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::Both => ReservedWord::Both,
+            Self::Leading => ReservedWord::Leading,
+            Self::Trailing => ReservedWord::Trailing,
+        });
+    }
+}
+
+impl Format for WindowFunction {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.function_type);
+        f.space();
+        f.keyword(ReservedWord::Over);
+        f.space();
+        f.format(&self.window);
+    }
+}
+
+impl Format for WindowFunctionType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Aggregate(function) => f.format(function),
+
+            Self::Rank(function) => {
+                f.format(function);
+                f.text("()");
+            }
+
+            Self::RowNumber => {
+                f.keyword(ReservedWord::RowNumber);
+                f.text("()");
+            }
+        }
+    }
+}
+
+impl Format for RankFunctionType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(self.as_str());
+    }
+}
+
+impl Format for WindowNameOrSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Name(name) => f.format(name),
+            Self::Specification(specification) => f.format(specification),
+        }
+    }
+}
+
+impl Format for DataType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Predefined(predefined_type) => f.format(predefined_type),
+        }
+    }
+}
+
+impl Format for PredefinedType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::CharacterString { definition, character_set } => {
+                f.format(definition);
+                if let Some(character_set) = character_set {
+                    f.space();
+                    f.keywords(&[&ReservedWord::Character, &ReservedWord::Set]);
+                    f.space();
+                    f.format(character_set);
+                }
+            }
+
+            Self::NationalCharacterString(definition) => f.format(definition),
+            Self::BinaryString(definition) => f.format(definition),
+            Self::BitString(definition) => f.format(definition),
+            Self::Numeric(definition) => f.format(definition),
+            Self::Boolean => f.keyword(ReservedWord::Boolean),
+        }
+    }
+}
+
+/// Writes the `(<length>)` of a data type.
+fn format_length(f: &mut SqlFormatter<'_>, length: impl ToString) {
+    f.text(format!("({})", length.to_string()));
+}
+
+/// Writes the `LARGE OBJECT [ (<length>) ]` of a data type.
+fn format_large_object(f: &mut SqlFormatter<'_>, length: Option<&LargeObjectLength>) {
+    f.space();
+    f.keywords(&[&ReservedWord::Large, &NonReservedWord::Object]);
+    if let Some(length) = length {
+        f.text("(");
+        f.format(length);
+        f.text(")");
+    }
+}
+
+impl Format for CharacterStringType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Character);
+        match self {
+            Self::Fixed { length } => format_length(f, length),
+            Self::Varying { length } => {
+                f.space();
+                f.keyword(ReservedWord::Varying);
+                format_length(f, length);
+            }
+            Self::LargeObject { length } => format_large_object(f, length.as_ref()),
+        }
+    }
+}
+
+impl Format for NationalCharacterStringType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keywords(&[&ReservedWord::National, &ReservedWord::Character]);
+        match self {
+            Self::Fixed { length } => format_length(f, length),
+            Self::Varying { length } => {
+                f.space();
+                f.keyword(ReservedWord::Varying);
+                format_length(f, length);
+            }
+            Self::LargeObject { length } => format_large_object(f, length.as_ref()),
+        }
+    }
+}
+
+impl Format for BinaryStringType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Binary);
+        match self {
+            Self::Fixed { length } => format_length(f, length),
+            Self::Varying { length } => {
+                f.space();
+                f.keyword(ReservedWord::Varying);
+                format_length(f, length);
+            }
+            Self::LargeObject { length } => format_large_object(f, length.as_ref()),
+        }
+    }
+}
+
+impl Format for BitStringType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Bit);
+        match self {
+            Self::Fixed { length } => format_length(f, length),
+            Self::Varying { length } => {
+                f.space();
+                f.keyword(ReservedWord::Varying);
+                format_length(f, length);
+            }
+        }
+    }
+}
+
+impl Format for NumericType {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Integer => f.keyword(ReservedWord::Integer),
+            Self::Float { precision } => {
+                f.keyword(ReservedWord::Float);
+                if let Some(precision) = precision {
+                    format_length(f, precision);
+                }
+            }
+            Self::Real => f.keyword(ReservedWord::Real),
+            Self::DoublePrecision => f.keywords(&[&ReservedWord::Double, &ReservedWord::Precision]),
+        }
+    }
+}
+
+impl Format for LargeObjectLength {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.text(self.value.to_string());
+        if let Some(multiplier) = self.multiplier {
+            f.format(&multiplier);
+        }
+    }
+}
+
+impl Format for LargeObjectLengthMultiplier {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        // The multiplier is part of the <large object length token>, so it
+        // isn't written as a keyword.
+        f.text(match self {
+            Self::K => "K",
+            Self::M => "M",
+            Self::G => "G",
+            Self::T => "T",
+            Self::P => "P",
+        });
+    }
+}
+
+impl Format for CharacterSetSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.text(self.name.as_str());
+    }
+}
+
+impl_display_using_format!(
+    ValueExpression,
+    BooleanExpression,
+    TruthValue,
+    Predicate,
+    BetweenPredicate,
+    ComparisonPredicate,
+    ComparisonOperator,
+    DistinctPredicate,
+    InPredicate,
+    LikePredicate,
+    NullPredicate,
+    QuantifiedComparisonPredicate,
+    Quantifier,
+    SimilarPredicate,
+    CaseExpression,
+    CaseResult,
+    SearchedWhenClause,
+    SimpleWhenClause,
+    CastSpecification,
+    CastOperand,
+    ColumnReference,
+    NumericValueExpression,
+    NumericOperator,
+    NumericValueFunction,
+    RoutineInvocation,
+    SetFunctionSpecification,
+    GeneralSetFunction,
+    ComputationalOperation,
+    SetQuantifier,
+    SimpleValueSpecification,
+    StringValueExpression,
+    StringValueFunction,
+    TrimSpecification,
+    WindowFunction,
+    WindowFunctionType,
+    RankFunctionType,
+    WindowNameOrSpecification,
+    DataType,
+    PredefinedType,
+    CharacterStringType,
+    NationalCharacterStringType,
+    BinaryStringType,
+    BitStringType,
+    NumericType,
+    LargeObjectLength,
+    LargeObjectLengthMultiplier,
+    CharacterSetSpecification,
+);
@@ -0,0 +1,564 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module formats queries and their clauses. A query is written on a
+//! single line when it fits, and otherwise with each clause on a line of its
+//! own.
+
+use crate::{
+    clause::{
+        fetch_first_clause::{
+            FetchFirstClause,
+            FetchFirstClauseOrigin,
+            FetchFirstQuantity,
+        },
+        order_by_clause::{
+            OrderByClause,
+            OrderingSpecification,
+            SortSpecification,
+        },
+        result_offset_clause::ResultOffsetClause,
+        window_clause::{
+            WindowDefinition,
+            WindowFrameBound,
+            WindowFrameClause,
+            WindowFrameUnits,
+            WindowSpecification,
+        },
+        FromClause,
+        GroupByClause,
+        HavingClause,
+        WhereClause,
+        WindowClause,
+    },
+    expression::{
+        query_expression::{
+            NonJoinQueryExpression,
+            NonJoinQueryPrimary,
+            NonJoinQueryTerm,
+            QueryExpressionBody,
+            QueryPrimary,
+            SimpleTable,
+        },
+        query_specification::{
+            DerivedColumn,
+            SelectList,
+            SelectSublist,
+        },
+        table_reference::{
+            TablePrimary,
+            TablePrimaryKind,
+        },
+        QueryExpression,
+        QuerySpecification,
+        SimpleValueSpecification,
+        TableExpression,
+        TableReference,
+    },
+    keyword::{
+        NonReservedWord,
+        ReservedWord,
+        VendorReservedWord,
+    },
+    set_function::SetQuantifier,
+};
+
+use super::{Format, SqlFormatter};
+
+/// Returns the query specification of the `body`, which is the only kind of
+/// query that can be expressed yet.
+fn query_specification_of(body: &QueryExpressionBody) -> &QuerySpecification {
+    match body {
+        QueryExpressionBody::NonJoinQueryExpression(NonJoinQueryExpression::NonJoinQueryTerm(
+            NonJoinQueryTerm::NonJoinQueryPrimary(NonJoinQueryPrimary::SimpleTable(SimpleTable::QuerySpecification(specification)))
+        )) => specification,
+        QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(specification)) => specification,
+    }
+}
+
+/// Checks whether the `fetch` clause can be written as a `LIMIT` clause,
+/// which can't express a percentage or ties.
+fn is_expressible_as_limit_clause(fetch: &FetchFirstClause) -> bool {
+    !fetch.quantity.is_percent && !fetch.with_ties
+}
+
+/// Writes the `ROW` or `ROWS` keyword after the `count`.
+fn format_rows_keyword(f: &mut SqlFormatter<'_>, count: &SimpleValueSpecification) {
+    f.keyword(match count {
+        SimpleValueSpecification::LiteralUnsigned(1) => ReservedWord::Row,
+        _ => ReservedWord::Rows,
+    });
+}
+
+impl Format for QueryExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        // The vendor-specific clauses are kept when they can express the
+        // clauses of the query. T-SQL doesn't allow `TOP` with an `OFFSET`.
+        let fetch = self.fetch.as_ref();
+        let top = fetch.filter(|fetch| fetch.origin == FetchFirstClauseOrigin::Top && self.offset.is_none());
+        let limit = fetch.filter(|fetch| fetch.origin == FetchFirstClauseOrigin::Limit && is_expressible_as_limit_clause(fetch));
+
+        f.group(|f| {
+            format_query_specification_clauses(f, query_specification_of(&self.body), top);
+
+            if let Some(order_by) = &self.order_by {
+                f.line();
+                f.format(order_by);
+            }
+
+            if let Some(limit) = limit {
+                f.line();
+                format_limit_clause(f, limit, self.offset.as_ref());
+                return;
+            }
+
+            if let Some(offset) = &self.offset {
+                f.line();
+                f.format(offset);
+            }
+
+            if let Some(fetch) = fetch.filter(|_| top.is_none()) {
+                f.line();
+                format_fetch_first_clause(f, fetch);
+            }
+        });
+    }
+}
+
+impl Format for QueryExpressionBody {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(query_specification_of(self));
+    }
+}
+
+impl Format for NonJoinQueryExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::NonJoinQueryTerm(term) => f.format(term),
+        }
+    }
+}
+
+impl Format for NonJoinQueryTerm {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::NonJoinQueryPrimary(primary) => f.format(primary),
+        }
+    }
+}
+
+impl Format for NonJoinQueryPrimary {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::SimpleTable(simple_table) => f.format(simple_table),
+        }
+    }
+}
+
+impl Format for QueryPrimary {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::NonJoinQueryPrimary(primary) => f.format(primary),
+        }
+    }
+}
+
+impl Format for SimpleTable {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::QuerySpecification(specification) => f.format(specification),
+        }
+    }
+}
+
+impl Format for QuerySpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.group(|f| format_query_specification_clauses(f, self, None));
+    }
+}
+
+/// Writes the clauses of the `specification` separated by lines, with the
+/// `top` clause after the `SELECT` keyword.
+fn format_query_specification_clauses(f: &mut SqlFormatter<'_>, specification: &QuerySpecification, top: Option<&FetchFirstClause>) {
+    f.group(|f| {
+        f.keyword(ReservedWord::Select);
+
+        if specification.set_quantifier == SetQuantifier::Distinct {
+            f.space();
+            f.format(&specification.set_quantifier);
+        }
+
+        if let Some(top) = top {
+            f.space();
+            format_top_clause(f, top);
+        }
+
+        f.indent(|f| {
+            f.line();
+            f.format(&specification.select_list);
+        });
+    });
+
+    if let Some(table_expression) = &specification.table_expression {
+        f.line();
+        format_table_expression_clauses(f, table_expression);
+    }
+}
+
+impl Format for SelectList {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Asterisk => f.text("*"),
+            Self::Sublist(sublist) => f.comma_separated(sublist, SqlFormatter::format),
+        }
+    }
+}
+
+impl Format for SelectSublist {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::DerivedColumn(derived_column) => f.format(derived_column),
+        }
+    }
+}
+
+impl Format for DerivedColumn {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.value_expression);
+        if let Some(alias) = &self.alias {
+            f.space();
+            f.keyword(ReservedWord::As);
+            f.space();
+            f.format(alias);
+        }
+    }
+}
+
+impl Format for TableExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.group(|f| format_table_expression_clauses(f, self));
+    }
+}
+
+/// Writes the clauses of the `table_expression` separated by lines.
+fn format_table_expression_clauses(f: &mut SqlFormatter<'_>, table_expression: &TableExpression) {
+    f.format(&table_expression.from_clause);
+
+    if let Some(where_clause) = &table_expression.where_clause {
+        f.line();
+        f.format(where_clause);
+    }
+
+    if let Some(group_by_clause) = &table_expression.group_by_clause {
+        f.line();
+        f.format(group_by_clause);
+    }
+
+    if let Some(having_clause) = &table_expression.having_clause {
+        f.line();
+        f.format(having_clause);
+    }
+
+    if let Some(window_clause) = &table_expression.window_clause {
+        f.line();
+        f.format(window_clause);
+    }
+}
+
+impl Format for FromClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.clause(&[&ReservedWord::From], |f| f.comma_separated(&self.table_references, SqlFormatter::format));
+    }
+}
+
+impl Format for TableReference {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Primary(primary) => f.format(primary),
+        }
+    }
+}
+
+impl Format for TablePrimary {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.kind);
+        if let Some(correlation_name) = &self.correlation_name {
+            f.space();
+            f.keyword(ReservedWord::As);
+            f.space();
+            f.name(correlation_name);
+        }
+    }
+}
+
+impl Format for TablePrimaryKind {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::TableOrQueryName(name) => f.format(name),
+        }
+    }
+}
+
+impl Format for WhereClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.clause(&[&ReservedWord::Where], |f| f.format(&self.search_condition));
+    }
+}
+
+/// The grouping columns aren't part of the syntax tree yet, so only the
+/// keywords are written.
+impl Format for GroupByClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keywords(&[&ReservedWord::Group, &ReservedWord::By]);
+    }
+}
+
+/// The search condition isn't part of the syntax tree yet, so only the
+/// keyword is written.
+impl Format for HavingClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Having);
+    }
+}
+
+impl Format for WindowClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.clause(&[&ReservedWord::Window], |f| f.comma_separated(&self.window_definitions, SqlFormatter::format));
+    }
+}
+
+impl Format for WindowDefinition {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.name);
+        f.space();
+        f.keyword(ReservedWord::As);
+        f.space();
+        f.format(&self.specification);
+    }
+}
+
+impl Format for WindowSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.parenthesized(|f| {
+            let mut is_first_part = true;
+            let mut separate = |f: &mut SqlFormatter<'_>| {
+                if !std::mem::take(&mut is_first_part) {
+                    f.line();
+                }
+            };
+
+            if let Some(existing_window_name) = &self.existing_window_name {
+                separate(f);
+                f.format(existing_window_name);
+            }
+
+            if !self.partition_clause.is_empty() {
+                separate(f);
+                f.clause(&[&ReservedWord::Partition, &ReservedWord::By], |f| {
+                    f.comma_separated(&self.partition_clause, SqlFormatter::format);
+                });
+            }
+
+            if let Some(order_clause) = &self.order_clause {
+                separate(f);
+                f.format(order_clause);
+            }
+
+            if let Some(frame_clause) = &self.frame_clause {
+                separate(f);
+                f.format(frame_clause);
+            }
+        });
+    }
+}
+
+impl Format for WindowFrameClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.units);
+        f.space();
+
+        match &self.end {
+            Some(end) => {
+                f.keyword(ReservedWord::Between);
+                f.space();
+                f.format(&self.start);
+                f.space();
+                f.keyword(ReservedWord::And);
+                f.space();
+                f.format(end);
+            }
+            None => f.format(&self.start),
+        }
+    }
+}
+
+impl Format for WindowFrameUnits {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::Rows => ReservedWord::Rows,
+            Self::Range => ReservedWord::Range,
+        });
+    }
+}
+
+impl Format for WindowFrameBound {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::UnboundedPreceding => f.keywords(&[&NonReservedWord::Unbounded, &NonReservedWord::Preceding]),
+            Self::CurrentRow => f.keywords(&[&ReservedWord::Current, &ReservedWord::Row]),
+            Self::UnboundedFollowing => f.keywords(&[&NonReservedWord::Unbounded, &NonReservedWord::Following]),
+
+            Self::Preceding(value) => {
+                f.format(value);
+                f.space();
+                f.keyword(NonReservedWord::Preceding);
+            }
+
+            Self::Following(value) => {
+                f.format(value);
+                f.space();
+                f.keyword(NonReservedWord::Following);
+            }
+        }
+    }
+}
+
+impl Format for OrderByClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.clause(&[&ReservedWord::Order, &ReservedWord::By], |f| {
+            f.comma_separated(&self.sort_specification_list, SqlFormatter::format);
+        });
+    }
+}
+
+impl Format for SortSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.sort_key);
+        if let Some(ordering_specification) = &self.ordering_specification {
+            f.space();
+            f.format(ordering_specification);
+        }
+    }
+}
+
+impl Format for OrderingSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(match self {
+            Self::Ascending => NonReservedWord::Asc,
+            Self::Descending => NonReservedWord::Desc,
+        });
+    }
+}
+
+impl Format for ResultOffsetClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Offset);
+        f.space();
+        f.format(&self.offset_row_count);
+        f.space();
+        format_rows_keyword(f, &self.offset_row_count);
+    }
+}
+
+/// Writes the clause in the syntax it was parsed from, when that syntax can
+/// express it.
+impl Format for FetchFirstClause {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self.origin {
+            FetchFirstClauseOrigin::Top => format_top_clause(f, self),
+            FetchFirstClauseOrigin::Limit if is_expressible_as_limit_clause(self) => format_limit_clause(f, self, None),
+            _ => format_fetch_first_clause(f, self),
+        }
+    }
+}
+
+impl Format for FetchFirstQuantity {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.value);
+        if self.is_percent {
+            f.space();
+            f.keyword(ReservedWord::Percent);
+        }
+    }
+}
+
+/// Writes the SQL-standard `FETCH FIRST` clause.
+fn format_fetch_first_clause(f: &mut SqlFormatter<'_>, fetch: &FetchFirstClause) {
+    f.keywords(&[&ReservedWord::Fetch, &NonReservedWord::First]);
+    f.space();
+    f.format(&fetch.quantity);
+    f.space();
+
+    if fetch.quantity.is_percent {
+        f.keyword(ReservedWord::Rows);
+    } else {
+        format_rows_keyword(f, &fetch.quantity.value);
+    }
+
+    f.space();
+    if fetch.with_ties {
+        f.keywords(&[&ReservedWord::With, &NonReservedWord::Ties]);
+    } else {
+        f.keyword(ReservedWord::Only);
+    }
+}
+
+/// Writes the vendor-specific `LIMIT` clause, including the `offset`.
+fn format_limit_clause(f: &mut SqlFormatter<'_>, fetch: &FetchFirstClause, offset: Option<&ResultOffsetClause>) {
+    f.keyword(VendorReservedWord::Limit);
+    f.space();
+    f.format(&fetch.quantity.value);
+
+    if let Some(offset) = offset {
+        f.space();
+        f.keyword(ReservedWord::Offset);
+        f.space();
+        f.format(&offset.offset_row_count);
+    }
+}
+
+/// Writes the vendor-specific `TOP` clause.
+fn format_top_clause(f: &mut SqlFormatter<'_>, fetch: &FetchFirstClause) {
+    f.keyword(VendorReservedWord::Top);
+    f.space();
+    f.format(&fetch.quantity);
+
+    if fetch.with_ties {
+        f.space();
+        f.keywords(&[&ReservedWord::With, &NonReservedWord::Ties]);
+    }
+}
+
+impl_display_using_format!(
+    QueryExpression,
+    QueryExpressionBody,
+    NonJoinQueryExpression,
+    NonJoinQueryTerm,
+    NonJoinQueryPrimary,
+    QueryPrimary,
+    SimpleTable,
+    QuerySpecification,
+    SelectList,
+    SelectSublist,
+    DerivedColumn,
+    TableExpression,
+    FromClause,
+    TableReference,
+    TablePrimary,
+    TablePrimaryKind,
+    WhereClause,
+    GroupByClause,
+    HavingClause,
+    WindowClause,
+    WindowDefinition,
+    WindowSpecification,
+    WindowFrameClause,
+    WindowFrameUnits,
+    WindowFrameBound,
+    OrderByClause,
+    SortSpecification,
+    OrderingSpecification,
+    ResultOffsetClause,
+    FetchFirstClause,
+    FetchFirstQuantity,
+);
@@ -0,0 +1,228 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module formats the statements and the schema definitions.
+
+use crate::{
+    common::TableName,
+    expression::{
+        row_value_constructor::{
+            ContextuallyTypedRowValueConstructor,
+            ContextuallyTypedRowValueConstructorElement,
+        },
+        row_value_expression::ContextuallyTypedRowValueExpression,
+        table_value_constructor::ContextuallyTypedTableValueConstructor,
+    },
+    keyword::{
+        NonReservedWord,
+        ReservedWord,
+    },
+    schema::definition::{
+        table_definition::{
+            ColumnConstraintDefinition,
+            ColumnDefinition,
+            TableElement,
+        },
+        TableDefinition,
+        UniqueSpecification,
+    },
+    set_function::SetQuantifier,
+    statement::{
+        insert_statement::InsertColumnsAndSource,
+        InsertStatement,
+        SelectStatementSingleRow,
+        SqlDataChangeStatement,
+        SqlDataStatement,
+        SqlExecutableStatement,
+        SqlSchemaDefinitionStatement,
+        SqlSchemaStatement,
+    },
+};
+
+use super::{Format, SqlFormatter};
+
+impl Format for SqlExecutableStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Schema(statement) => f.format(statement),
+            Self::SqlDataStatement(statement) => f.format(statement),
+        }
+    }
+}
+
+impl Format for SqlSchemaStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Definition(statement) => f.format(statement),
+        }
+    }
+}
+
+impl Format for SqlSchemaDefinitionStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Table(definition) => f.format(definition),
+        }
+    }
+}
+
+impl Format for SqlDataStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::ChangeStatement(statement) => f.format(statement),
+            Self::SelectStatement(query) => f.format(query.as_ref()),
+            Self::SelectStatementSingleRow(statement) => f.format(statement),
+        }
+    }
+}
+
+impl Format for SqlDataChangeStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Insert(statement) => f.format(statement),
+        }
+    }
+}
+
+impl Format for TableDefinition {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.group(|f| {
+            f.keywords(&[&ReservedWord::Create, &ReservedWord::Table]);
+            f.space();
+            f.format(&self.table_name);
+            f.space();
+            f.parenthesized(|f| f.comma_separated(&self.elements, SqlFormatter::format));
+        });
+    }
+}
+
+impl Format for TableElement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::ColumnDefinition(definition) => f.format(definition),
+        }
+    }
+}
+
+impl Format for ColumnDefinition {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.column_name);
+        f.space();
+        f.format(&self.data_type);
+
+        for constraint in &self.column_constraint_definitions {
+            f.space();
+            f.format(constraint);
+        }
+    }
+}
+
+impl Format for ColumnConstraintDefinition {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::NotNull => f.keywords(&[&ReservedWord::Not, &ReservedWord::Null]),
+            Self::UniqueSpecification(specification) => f.format(specification),
+        }
+    }
+}
+
+impl Format for UniqueSpecification {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::Unique => f.keyword(ReservedWord::Unique),
+            Self::PrimaryKey => f.keywords(&[&ReservedWord::Primary, &NonReservedWord::Key]),
+        }
+    }
+}
+
+impl Format for InsertStatement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.group(|f| {
+            f.keywords(&[&ReservedWord::Insert, &ReservedWord::Into]);
+            f.space();
+            f.format(&self.table_name);
+            f.line();
+            f.format(&self.insert_columns_and_source);
+        });
+    }
+}
+
+impl Format for InsertColumnsAndSource {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::FromConstructor { insert_column_list, constructor, .. } => {
+                if let Some(insert_column_list) = insert_column_list {
+                    f.parenthesized(|f| f.comma_separated(insert_column_list, |f, name| f.name(name)));
+                    f.line();
+                }
+
+                f.format(constructor);
+            }
+        }
+    }
+}
+
+impl Format for ContextuallyTypedTableValueConstructor {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.clause(&[&ReservedWord::Values], |f| f.comma_separated(&self.values, SqlFormatter::format));
+    }
+}
+
+impl Format for ContextuallyTypedRowValueExpression {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::ContextuallyTypedRowValueConstructor(constructor) => f.format(constructor),
+        }
+    }
+}
+
+impl Format for ContextuallyTypedRowValueConstructor {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.parenthesized(|f| f.comma_separated(&self.elements, SqlFormatter::format));
+    }
+}
+
+impl Format for ContextuallyTypedRowValueConstructorElement {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        match self {
+            Self::ValueExpression(expression) => f.format(expression),
+        }
+    }
+}
+
+impl Format for SelectStatementSingleRow {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.keyword(ReservedWord::Select);
+        if self.set_quantifier == SetQuantifier::Distinct {
+            f.space();
+            f.format(&self.set_quantifier);
+        }
+    }
+}
+
+impl Format for TableName {
+    fn format(&self, f: &mut SqlFormatter<'_>) {
+        f.format(&self.table_qualifier);
+    }
+}
+
+impl_display_using_format!(
+    SqlExecutableStatement,
+    SqlSchemaStatement,
+    SqlSchemaDefinitionStatement,
+    SqlDataStatement,
+    SqlDataChangeStatement,
+    TableDefinition,
+    TableElement,
+    ColumnDefinition,
+    ColumnConstraintDefinition,
+    UniqueSpecification,
+    InsertStatement,
+    InsertColumnsAndSource,
+    ContextuallyTypedTableValueConstructor,
+    ContextuallyTypedRowValueExpression,
+    ContextuallyTypedRowValueConstructor,
+    ContextuallyTypedRowValueConstructorElement,
+    SelectStatementSingleRow,
+    TableName,
+);
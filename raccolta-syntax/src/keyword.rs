@@ -4,8 +4,11 @@
 use strum::{
     AsRefStr,
     EnumIter,
+    IntoStaticStr,
 };
 
+use crate::dialect::DialectFeature;
+
 /// Non-reserved words (`<non-reserved word>`) are words that are allowed in
 /// some context as identifiers, and others as keywords. This is to avoid
 /// clashing with commonly used column or table names such as `NUMBER`.
@@ -16,7 +19,7 @@ use strum::{
 /// # About
 /// **Specification:** SQL 1992, SQL 1999, SQL 2003, SQL 2016
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-#[derive(AsRefStr, EnumIter, strum::Display)]
+#[derive(AsRefStr, EnumIter, IntoStaticStr, strum::Display)]
 #[non_exhaustive]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum NonReservedWord {
@@ -1175,7 +1178,7 @@ pub enum NonReservedWord {
 /// # About
 /// **Specification:** SQL 86, SQL 1992, SQL 1999, SQL 2003, SQL 2016
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-#[derive(AsRefStr, EnumIter, strum::Display)]
+#[derive(AsRefStr, EnumIter, IntoStaticStr, strum::Display)]
 #[non_exhaustive]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 pub enum ReservedWord {
@@ -1284,6 +1287,10 @@ pub enum ReservedWord {
     /// **Specification:** SQL 2016
     Binary,
 
+    /// # About
+    /// **Specification:** SQL 1992
+    Bit,
+
     /*
     /// # About
     /// **Specification:** SQL 1999
@@ -2696,9 +2703,8 @@ pub enum ReservedWord {
 }
 
 /// A reserved word that originated from some kind of extensions or otherwise
-/// implementation-defined `<reserved word>`s.
-///
-/// FIXME: Find a way to enable and disable these extensions at runtime.
+/// implementation-defined `<reserved word>`s. These are only reserved when
+/// the [`Dialect`](crate::Dialect) accepts the feature they introduce.
 ///
 /// # References
 /// * [MariaDB - Knowledge Base - Reserved Keywords](https://mariadb.com/kb/en/reserved-words/)
@@ -2708,8 +2714,7 @@ pub enum ReservedWord {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[derive(AsRefStr, EnumIter, strum::Display)]
 pub enum VendorReservedWord {
-    #[strum(props(DefinedBy="MySQL, MariaDB"))]
-    /// MySQL, MariaDB
+    /// MySQL, MariaDB, PostgreSQL, SQLite
     ///
     /// 20+ years old
     ///
@@ -2722,6 +2727,118 @@ pub enum VendorReservedWord {
     Top,
 }
 
+impl VendorReservedWord {
+    /// Returns the vendor-specific feature this keyword introduces.
+    pub const fn feature(self) -> DialectFeature {
+        match self {
+            Self::Limit => DialectFeature::LimitClause,
+            Self::Top => DialectFeature::TopClause,
+        }
+    }
+}
+
+/// A word that is recognized by the lexer as a keyword.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Keyword {
+    NonReserved(NonReservedWord),
+    Reserved(ReservedWord),
+    VendorReserved(VendorReservedWord),
+}
+
+include!(concat!(env!("OUT_DIR"), "/keyword_table.rs"));
+
+impl Keyword {
+    /// Looks up the keyword spelled as `word`, ignoring ASCII case. This uses
+    /// a perfect-hash table generated at compile time by `build.rs`, so the
+    /// cost doesn't grow with the number of keywords.
+    pub(crate) fn lookup(word: &str) -> Option<Self> {
+        if word.len() > MAX_KEYWORD_LENGTH || !word.is_ascii() {
+            return None;
+        }
+
+        let mut buffer = [0; MAX_KEYWORD_LENGTH];
+        let buffer = &mut buffer[..word.len()];
+        buffer.copy_from_slice(word.as_bytes());
+        buffer.make_ascii_uppercase();
+
+        // The buffer only contains ASCII characters, copied from a `str`.
+        let word = std::str::from_utf8(buffer).ok()?;
+        KEYWORDS.get(word).copied()
+    }
+}
+
+/// Keywords are serialized as they are spelled in SQL, e.g. `"SELECT"`, so
+/// that the representation doesn't depend on the names of the variants. Like
+/// the lexer, deserialization ignores ASCII case.
+#[cfg(feature = "serde")]
+mod serialization {
+    use serde::{
+        de::{
+            Error,
+            Unexpected,
+        },
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    use super::*;
+
+    fn deserialize_keyword<'de, D, T>(deserializer: D, expected: &str, extract: impl FnOnce(Keyword) -> Option<T>) -> Result<T, D::Error>
+            where D: Deserializer<'de> {
+        let word = String::deserialize(deserializer)?;
+        Keyword::lookup(&word)
+            .and_then(extract)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Str(&word), &expected))
+    }
+
+    impl Serialize for NonReservedWord {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_ref())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NonReservedWord {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_keyword(deserializer, "a non-reserved word", |keyword| match keyword {
+                Keyword::NonReserved(word) => Some(word),
+                _ => None,
+            })
+        }
+    }
+
+    impl Serialize for ReservedWord {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_ref())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for ReservedWord {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_keyword(deserializer, "a reserved word", |keyword| match keyword {
+                Keyword::Reserved(word) => Some(word),
+                _ => None,
+            })
+        }
+    }
+
+    impl Serialize for VendorReservedWord {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.as_ref().to_ascii_uppercase())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for VendorReservedWord {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize_keyword(deserializer, "a vendor-specific reserved word", |keyword| match keyword {
+                Keyword::VendorReserved(word) => Some(word),
+                _ => None,
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
@@ -2730,6 +2847,63 @@ mod tests {
 
     use super::*;
 
+    /// Ensure the generated keyword table agrees with the `strum`
+    /// representation of every keyword, in any case.
+    #[test]
+    fn keyword_lookup_finds_all_keywords() {
+        for word in ReservedWord::iter() {
+            assert_eq!(Keyword::lookup(word.as_ref()), Some(Keyword::Reserved(word)));
+            assert_eq!(Keyword::lookup(&word.as_ref().to_ascii_lowercase()), Some(Keyword::Reserved(word)));
+        }
+
+        for word in NonReservedWord::iter() {
+            assert_eq!(Keyword::lookup(word.as_ref()), Some(Keyword::NonReserved(word)));
+            assert_eq!(Keyword::lookup(&word.as_ref().to_ascii_lowercase()), Some(Keyword::NonReserved(word)));
+        }
+
+        for word in VendorReservedWord::iter() {
+            assert_eq!(Keyword::lookup(word.as_ref()), Some(Keyword::VendorReserved(word)));
+            assert_eq!(Keyword::lookup(&word.as_ref().to_ascii_uppercase()), Some(Keyword::VendorReserved(word)));
+        }
+    }
+
+    /// Ensure keywords are serialized as they are spelled in SQL, and that
+    /// every keyword survives a round trip.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn keyword_serialization_uses_sql_spelling() {
+        use serde_json::{from_value, json, to_value};
+
+        for word in ReservedWord::iter() {
+            assert_eq!(to_value(word).unwrap(), json!(word.as_ref()));
+            assert_eq!(from_value::<ReservedWord>(json!(word.as_ref())).unwrap(), word);
+        }
+
+        for word in NonReservedWord::iter() {
+            assert_eq!(to_value(word).unwrap(), json!(word.as_ref()));
+            assert_eq!(from_value::<NonReservedWord>(json!(word.as_ref())).unwrap(), word);
+        }
+
+        for word in VendorReservedWord::iter() {
+            assert_eq!(to_value(word).unwrap(), json!(word.as_ref().to_ascii_uppercase()));
+            assert_eq!(from_value::<VendorReservedWord>(json!(word.as_ref())).unwrap(), word);
+        }
+
+        assert_eq!(to_value(ReservedWord::EndExec).unwrap(), json!("END-EXEC"));
+        assert_eq!(from_value::<ReservedWord>(json!("select")).unwrap(), ReservedWord::Select);
+        assert!(from_value::<ReservedWord>(json!("FIRST")).is_err());
+        assert!(from_value::<NonReservedWord>(json!("persons")).is_err());
+    }
+
+    #[test]
+    fn keyword_lookup_rejects_non_keywords() {
+        assert_eq!(Keyword::lookup(""), None);
+        assert_eq!(Keyword::lookup("persons"), None);
+        assert_eq!(Keyword::lookup("SELECTS"), None);
+        assert_eq!(Keyword::lookup("séléct"), None);
+        assert_eq!(Keyword::lookup(&"a".repeat(100)), None);
+    }
+
     /// Checks for overlap between non-reserved words and reserved words. This
     /// is disallowed since the lexer will parse them incorrectly, and the
     /// parser depends on the output of the lexer.
@@ -2852,11 +3026,11 @@ mod tests {
 
         let enum_words: HashSet<&str> = enum_words.iter().map(|word| word.as_ref()).collect();
 
-        for word in words.difference(&enum_words) {
+        if let Some(word) = words.difference(&enum_words).next() {
             panic!("<non-reserved word> {word} from SQL 2016 not found in [`NonReservedWord`] enum!");
         }
 
-        let mut difference = enum_words.difference(&words).map(|word| *word).collect::<Vec<&str>>();
+        let mut difference = enum_words.difference(&words).copied().collect::<Vec<&str>>();
         difference.sort();
 
         if !difference.is_empty() {
@@ -2959,7 +3133,7 @@ mod tests {
 
         let enum_words: HashSet<&str> = enum_words.iter().map(|word| word.as_ref()).collect();
 
-        let mut difference = words.difference(&enum_words).map(|word| *word).collect::<Vec<&str>>();
+        let mut difference = words.difference(&enum_words).copied().collect::<Vec<&str>>();
         difference.sort();
 
         if !difference.is_empty() {
@@ -2967,7 +3141,7 @@ mod tests {
                 difference.join(", "));
         }
 
-        // let mut difference = enum_words.difference(&words).map(|word| *word).collect::<Vec<&str>>();
+        // let mut difference = enum_words.difference(&words).copied().collect::<Vec<&str>>();
         // difference.sort();
 
         // if !difference.is_empty() {
@@ -4,31 +4,48 @@
 pub mod characters;
 pub mod clause;
 pub mod common;
+pub mod dialect;
 pub mod expression;
+pub mod format;
 pub mod keyword;
 pub mod parse;
 pub mod predicate;
 pub mod schema;
 pub mod set_function;
+pub mod span;
 pub mod statement;
 pub mod token;
+pub mod visit;
+pub mod visit_mut;
 
 use characters::SqlCharacterExtensions;
-use keyword::{ReservedWord, NonReservedWord, VendorReservedWord};
-pub use token::{Token, TokenKind};
+use keyword::{Keyword, ReservedWord};
+pub use dialect::{Dialect, DialectFeature};
+pub use span::{Span, Spanned, WithoutSpans};
+pub use token::{IllegalTokenReason, StringLiteralKind, Token, TokenKind};
 
 pub use parse::{
     Parser,
     StatementParseError,
+    StatementResult,
 };
 
-use strum::IntoEnumIterator;
-
 #[derive(Clone, Debug)]
 pub struct Lexer<'a> {
     input: &'a str,
     character_byte_idx: usize,
-    allow_vendor_keywords: bool,
+
+    /// The dialect whose vendor-specific keywords and operators are
+    /// recognized. When `None`, those of all dialects are recognized.
+    dialect: Option<Dialect>,
+
+    /// Whether or not comments are produced as [`TokenKind::Comment`] tokens,
+    /// instead of being skipped as whitespace.
+    emit_comments: bool,
+
+    /// Set when an [`TokenKind::IllegalToken`] was produced, after which no
+    /// more tokens are produced.
+    encountered_illegal_token: bool,
 }
 
 impl<'a> Lexer<'a> {
@@ -36,12 +53,54 @@ impl<'a> Lexer<'a> {
         Self {
             input,
             character_byte_idx: 0,
-            allow_vendor_keywords: true,
+            dialect: None,
+            emit_comments: false,
+            encountered_illegal_token: false,
         }
     }
 
-    /// Consumes a token. If it returns `None`, there are no characters left.
+    /// Sets whether comments are produced as [`TokenKind::Comment`] tokens,
+    /// which is useful for tooling that preserves them, such as a syntax
+    /// highlighter. By default, comments are skipped like whitespace.
+    pub fn with_comments(self, emit_comments: bool) -> Self {
+        Self {
+            emit_comments,
+            ..self
+        }
+    }
+
+    /// Sets the dialect whose vendor-specific keywords and operators are
+    /// recognized, such as `LIMIT` and the `!=` alias of `<>`. By default,
+    /// those of all dialects are recognized.
+    pub fn with_dialect(self, dialect: Dialect) -> Self {
+        Self {
+            dialect: Some(dialect),
+            ..self
+        }
+    }
+
+    /// Starts lexing at `character_byte_idx` instead of at the start of the
+    /// input. The positions of the tokens are still relative to the start of
+    /// the input.
+    pub(crate) fn starting_at(self, character_byte_idx: usize) -> Self {
+        Self {
+            character_byte_idx,
+            ..self
+        }
+    }
+
+    /// Checks whether the `feature` is accepted by the dialect.
+    fn supports(&self, feature: DialectFeature) -> bool {
+        self.dialect.is_none_or(|dialect| dialect.supports(feature))
+    }
+
+    /// Consumes a token. If it returns `None`, there are no characters left,
+    /// or the previous token was an [`TokenKind::IllegalToken`].
     pub fn consume_token(&mut self) -> Option<Token> {
+        if self.encountered_illegal_token {
+            return None;
+        }
+
         self.skip_whitespace();
 
         if self.character_byte_idx >= self.input.len() {
@@ -50,112 +109,108 @@ impl<'a> Lexer<'a> {
 
         let first_character_byte_idx = self.character_byte_idx;
 
-        let Some(first_character) = self.input[first_character_byte_idx..].chars().next() else {
-            return None;
-        };
+        let first_character = self.input[first_character_byte_idx..].chars().next()?;
 
         self.next_character();
 
         let kind = match first_character {
+            '!' if self.supports(DialectFeature::ExclamationNotEqualsOperator) && self.consume_character_if('=') => TokenKind::NotEqualsOperator,
             '!' => TokenKind::ExclamationMark,
-            '"' => todo!(),
-            '#' => todo!("invalid token"),
+            '"' => self.consume_delimited_identifier('"'),
+            '#' => TokenKind::IllegalToken(IllegalTokenReason::UnexpectedCharacter(first_character)),
             '$' => TokenKind::DollarSign,
             '%' => TokenKind::PercentageSign,
             '&' => TokenKind::Ampersand,
-            '\'' => loop {
-                let Some(next_character) = self.current_character() else {
-                    break TokenKind::IllegalToken;
-                };
-
-                if next_character == '\'' {
-                    let last_character_byte_idx = self.character_byte_idx;
-                    // This is a valid string, but the first character points to
-                    // ' token, which is incorrect.
-                    self.next_character();
-
-                    break TokenKind::StringLiteral {
-                        first_character_byte_idx: first_character_byte_idx + 1,
-                        last_character_byte_idx
-                    };
-                }
-
-                self.next_character();
-            }
+            '\'' => self.consume_character_string_literal(StringLiteralKind::Character),
             '(' => TokenKind::LeftParenthesis,
             ')' => TokenKind::RightParenthesis,
             '*' => TokenKind::Asterisk,
             '+' => TokenKind::PlusSign,
             ',' => TokenKind::Comma,
+            '-' if self.current_character() == Some('-') => self.consume_simple_comment(),
+            '-' if self.consume_character_if('>') => TokenKind::RightArrow,
+            '-' if self.consume_character_if('}') => TokenKind::RightMinusBrace,
             '-' => TokenKind::MinusSign,
+            '.' if self.consume_character_if('.') => TokenKind::DoublePeriod,
+            '.' if self.is_current_character_digit() => self.consume_numeric_literal(first_character_byte_idx),
             '.' => TokenKind::FullStop,
+            '/' if self.current_character() == Some('*') => self.consume_bracketed_comment(),
             '/' => TokenKind::Solidus,
 
-            '0'..='9' => {
-                let mut value = ((first_character as u8) - b'0') as u64;
-
-                while self.is_current_character_digit() {
-                    value *= 10;
-                    value += ((self.current_character().unwrap() as u8) - b'0') as u64;
-
-                    self.next_character();
-                }
-
-                TokenKind::UnsignedInteger(value)
+            '0' if self.supports(DialectFeature::HexadecimalIntegerLiteral) && matches!(self.current_character(), Some('x' | 'X')) => {
+                self.next_character();
+                self.consume_hexadecimal_integer_literal()
             }
 
+            '0'..='9' => self.consume_numeric_literal(first_character_byte_idx),
+
+            ':' if self.consume_character_if(':') => TokenKind::DoubleColon,
             ':' => TokenKind::Colon,
             ';' => TokenKind::Semicolon,
+            '<' if self.consume_character_if('=') => TokenKind::LessThanOrEqualsOperator,
+            '<' if self.consume_character_if('>') => TokenKind::NotEqualsOperator,
             '<' => TokenKind::LessThanOperator,
+            '=' if self.consume_character_if('>') => TokenKind::NamedArgumentAssignmentOperator,
+            '=' if self.supports(DialectFeature::DoubleEqualsOperator) && self.consume_character_if('=') => TokenKind::EqualsSign,
             '=' => TokenKind::EqualsSign,
+            '>' if self.consume_character_if('=') => TokenKind::GreaterThanOrEqualsOperator,
             '>' => TokenKind::GreaterThanOperator,
             '?' => TokenKind::Question,
             '@' => TokenKind::AtSign,
+            '[' if self.supports(DialectFeature::BracketDelimitedIdentifier) => self.consume_delimited_identifier(']'),
+            '`' if self.supports(DialectFeature::BacktickDelimitedIdentifier) => self.consume_delimited_identifier('`'),
+
+            'b' | 'B' if self.current_character() == Some('\'') => {
+                self.consume_binary_or_bit_string_literal(false)
+            }
+
+            'n' | 'N' if self.current_character() == Some('\'') => {
+                self.next_character();
+                self.consume_character_string_literal(StringLiteralKind::National)
+            }
 
-            'a'..='z' | 'A'..='Z' => {
+            'u' | 'U' if self.input[self.character_byte_idx..].starts_with("&'") => {
+                self.next_character();
+                self.next_character();
+                self.consume_character_string_literal(StringLiteralKind::Unicode)
+            }
+
+            'x' | 'X' if self.current_character() == Some('\'') => {
+                self.consume_binary_or_bit_string_literal(true)
+            }
+
+            _ if first_character.is_alphabetic() => {
                 while self.is_current_character_identifier_body_character() {
                     self.next_character();
                 }
 
                 let str = &self.input[first_character_byte_idx..self.character_byte_idx];
 
-                match ReservedWord::iter().find(|reserved_word| reserved_word.as_ref().eq_ignore_ascii_case(str)) {
-                    Some(reserved_word) => TokenKind::ReservedWord(reserved_word),
-
-                    None => match NonReservedWord::iter().find(|non_reserved_word| non_reserved_word.as_ref().eq_ignore_ascii_case(str)) {
-                        Some(non_reserved_word) => TokenKind::NonReservedWord(non_reserved_word),
-                        None => {
-                            if self.allow_vendor_keywords {
-                                match VendorReservedWord::iter().find(|vendor_reserved_word| vendor_reserved_word.as_ref().eq_ignore_ascii_case(str)) {
-                                    Some(vendor_reserved_word) => TokenKind::VendorReservedWord(vendor_reserved_word),
-                                    None => TokenKind::Identifier
-                                }
-                            } else {
-                                TokenKind::Identifier
-                            }
-                        }
+                match Keyword::lookup(str) {
+                    Some(Keyword::Reserved(reserved_word)) => TokenKind::ReservedWord(reserved_word),
+                    Some(Keyword::NonReserved(non_reserved_word)) => TokenKind::NonReservedWord(non_reserved_word),
+                    Some(Keyword::VendorReserved(vendor_reserved_word)) if self.supports(vendor_reserved_word.feature()) => {
+                        TokenKind::VendorReservedWord(vendor_reserved_word)
                     }
+                    _ => TokenKind::Identifier,
                 }
             }
 
-            '[' => todo!(),
             '\\' => TokenKind::ReverseSolidus,
-            ']' => todo!(),
             '^' => TokenKind::Circumflex,
-            '_' => todo!(),
-            '`' => todo!(),
+            '{' if self.consume_character_if('-') => TokenKind::LeftBraceMinus,
             '{' => TokenKind::LeftBrace,
+            '|' if self.consume_character_if('|') => TokenKind::ConcatenationOperator,
             '|' => TokenKind::VerticalBar,
             '}' => TokenKind::RightBrace,
-            '~' => todo!(),
 
-            _ => {
-                println!("Unexpected character: '{first_character}' dec={} hex=0x{:X}",
-                    first_character as usize, first_character as usize);
-                todo!()
-            }
+            _ => TokenKind::IllegalToken(IllegalTokenReason::UnexpectedCharacter(first_character)),
         };
 
+        if let TokenKind::IllegalToken(..) = kind {
+            self.encountered_illegal_token = true;
+        }
+
         Some(Token::new(
             first_character_byte_idx,
             self.character_byte_idx,
@@ -163,6 +218,456 @@ impl<'a> Lexer<'a> {
         ))
     }
 
+    /// Consumes the rest of a `<bracketed comment>`, after the `/` was
+    /// consumed. Bracketed comments can be nested, as the standard allows.
+    ///
+    /// ```text
+    /// <bracketed comment> ::=
+    ///     <bracketed comment introducer>
+    ///     <bracketed comment contents>
+    ///     <bracketed comment terminator>
+    ///
+    /// <bracketed comment introducer> ::= /*
+    /// <bracketed comment terminator> ::= */
+    /// ```
+    fn consume_bracketed_comment(&mut self) -> TokenKind {
+        // Skip the `*` of the introducer.
+        self.next_character();
+
+        let mut depth = 1;
+        while depth != 0 {
+            let Some(character) = self.current_character() else {
+                return TokenKind::IllegalToken(IllegalTokenReason::UnterminatedBracketedComment);
+            };
+
+            self.next_character();
+
+            match character {
+                '/' if self.consume_character_if('*') => depth += 1,
+                '*' if self.consume_character_if('/') => depth -= 1,
+                _ => (),
+            }
+        }
+
+        TokenKind::Comment
+    }
+
+    /// Consumes the rest of a `<simple comment>`, after the first `-` was
+    /// consumed. The `<newline>` isn't part of the comment.
+    ///
+    /// ```text
+    /// <simple comment> ::=
+    ///     <simple comment introducer> [ <comment character>... ] <newline>
+    ///
+    /// <simple comment introducer> ::=
+    ///     <minus sign> <minus sign>
+    /// ```
+    fn consume_simple_comment(&mut self) -> TokenKind {
+        while let Some(character) = self.current_character() {
+            if character == '\n' || character == '\r' {
+                break;
+            }
+
+            self.next_character();
+        }
+
+        TokenKind::Comment
+    }
+
+    /// Consumes the rest of a `<character string literal>`,
+    /// `<national character string literal>` or
+    /// `<Unicode character string literal>`, after the opening quote was
+    /// consumed.
+    ///
+    /// ```text
+    /// <character string literal> ::=
+    ///     <quote> [ <character representation>... ] <quote>
+    ///     [ { <separator> <quote> [ <character representation>... ] <quote> }... ]
+    ///
+    /// <character representation> ::=
+    ///       <nonquote character>
+    ///     | <quote symbol>
+    ///
+    /// <quote symbol> ::= <quote> <quote>
+    /// ```
+    fn consume_character_string_literal(&mut self, kind: StringLiteralKind) -> TokenKind {
+        if kind != StringLiteralKind::Unicode {
+            return match self.consume_character_string_literal_body(None) {
+                Ok(()) => TokenKind::StringLiteral { kind },
+                Err(reason) => TokenKind::IllegalToken(reason),
+            };
+        }
+
+        // The escape character is only known after the body, so the escape
+        // values are validated afterwards.
+        let mut value = String::new();
+        let result = self.consume_character_string_literal_body(Some(&mut value))
+            .and_then(|()| self.consume_unicode_escape_specifier())
+            .and_then(|escape_character| decode_unicode_escape_values(&value, escape_character)
+                .map(|_| ())
+                .ok_or(IllegalTokenReason::InvalidUnicodeEscapeValue));
+
+        match result {
+            Ok(()) => TokenKind::StringLiteral { kind },
+            Err(reason) => TokenKind::IllegalToken(reason),
+        }
+    }
+
+    /// Consumes the `<character representation>`s after the opening quote,
+    /// up to and including the closing quote, including the literals that
+    /// continue this one. The characters are appended to the `value` with
+    /// the `<quote symbol>`s resolved, if requested.
+    ///
+    /// Continuing literals must be separated by a `<newline>`, as the
+    /// standard requires, e.g.:
+    /// ```sql
+    /// SELECT 'Hello, '
+    ///        'world!'
+    /// ```
+    fn consume_character_string_literal_body(&mut self, mut value: Option<&mut String>) -> Result<(), IllegalTokenReason> {
+        let mut opening_quote_byte_idx = self.character_byte_idx - '\''.len_utf8();
+
+        loop {
+            let Some(character) = self.current_character() else {
+                return Err(IllegalTokenReason::UnterminatedStringLiteral { opening_quote_byte_idx });
+            };
+
+            self.next_character();
+
+            if character == '\'' && !self.consume_character_if('\'') {
+                let end_of_literal_byte_idx = self.character_byte_idx;
+                self.skip_separator(true);
+
+                let separator = &self.input[end_of_literal_byte_idx..self.character_byte_idx];
+                if self.current_character() != Some('\'') || !separator.contains(['\n', '\r']) {
+                    self.character_byte_idx = end_of_literal_byte_idx;
+                    return Ok(());
+                }
+
+                opening_quote_byte_idx = self.character_byte_idx;
+                self.next_character();
+                continue;
+            }
+
+            if let Some(value) = value.as_mut() {
+                value.push(character);
+            }
+        }
+    }
+
+    /// Consumes the optional `<Unicode escape specifier>` and returns the
+    /// escape character, which is `\` by default.
+    ///
+    /// ```text
+    /// <Unicode escape specifier> ::=
+    ///     [ UESCAPE <quote> <Unicode escape character> <quote> ]
+    /// ```
+    fn consume_unicode_escape_specifier(&mut self) -> Result<char, IllegalTokenReason> {
+        const DEFAULT_ESCAPE_CHARACTER: char = '\\';
+
+        let end_of_literal_byte_idx = self.character_byte_idx;
+        self.skip_separator(true);
+
+        let keyword = ReservedWord::Uescape.as_ref();
+        let rest = &self.input[self.character_byte_idx..];
+        let is_uescape = rest.get(..keyword.len()).is_some_and(|word| word.eq_ignore_ascii_case(keyword))
+            && !rest[keyword.len()..].starts_with(|character: char| character.is_alphanumeric() || character == '_');
+
+        if !is_uescape {
+            self.character_byte_idx = end_of_literal_byte_idx;
+            return Ok(DEFAULT_ESCAPE_CHARACTER);
+        }
+
+        self.character_byte_idx += keyword.len();
+        self.skip_separator(true);
+
+        if !self.consume_character_if('\'') {
+            return Err(IllegalTokenReason::InvalidUnicodeEscapeCharacter);
+        }
+
+        let Some(escape_character) = self.current_character() else {
+            return Err(IllegalTokenReason::InvalidUnicodeEscapeCharacter);
+        };
+        self.next_character();
+
+        if !self.consume_character_if('\'') {
+            return Err(IllegalTokenReason::InvalidUnicodeEscapeCharacter);
+        }
+
+        // The <Unicode escape character> shall be a single character from
+        // the source language character set other than a <hexit>, <plus
+        // sign>, <quote>, <double quote>, or <white space>.
+        if escape_character.is_ascii_hexdigit()
+                || matches!(escape_character, '+' | '\'' | '"')
+                || escape_character.is_sql_whitespace() {
+            return Err(IllegalTokenReason::InvalidUnicodeEscapeCharacter);
+        }
+
+        Ok(escape_character)
+    }
+
+    /// Decodes the value of a [`TokenKind::StringLiteral`], given the
+    /// `literal` as returned by [`Token::as_string`].
+    pub(crate) fn decode_character_string_literal(literal: &str, kind: StringLiteralKind) -> Option<String> {
+        let mut lexer = Lexer::new(literal);
+
+        let introducer_length = match kind {
+            StringLiteralKind::Character => 0,
+            StringLiteralKind::National => 1,
+            StringLiteralKind::Unicode => 2,
+        };
+
+        lexer.character_byte_idx = introducer_length;
+        if !lexer.consume_character_if('\'') {
+            return None;
+        }
+
+        let mut value = String::new();
+        lexer.consume_character_string_literal_body(Some(&mut value)).ok()?;
+
+        if kind != StringLiteralKind::Unicode {
+            return Some(value);
+        }
+
+        let escape_character = lexer.consume_unicode_escape_specifier().ok()?;
+        decode_unicode_escape_values(&value, escape_character)
+    }
+
+    /// Consumes the rest of a vendor-specific hexadecimal integer literal,
+    /// e.g. `0x1F`, after the `0x` prefix was consumed.
+    fn consume_hexadecimal_integer_literal(&mut self) -> TokenKind {
+        let first_hexit_byte_idx = self.character_byte_idx;
+        while self.current_character().is_some_and(|character| character.is_ascii_hexdigit()) {
+            self.next_character();
+        }
+
+        let hexits = &self.input[first_hexit_byte_idx..self.character_byte_idx];
+        if hexits.is_empty() || self.consume_trailing_identifier_characters() {
+            return TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral);
+        }
+
+        match u64::from_str_radix(hexits, 16) {
+            Ok(value) => TokenKind::UnsignedInteger(value),
+            Err(..) => TokenKind::IllegalToken(IllegalTokenReason::IntegerOutOfRange),
+        }
+    }
+
+    /// Consumes the rest of an `<unsigned numeric literal>`, after its first
+    /// character (a `<digit>` or `<period>`) was consumed.
+    ///
+    /// ```text
+    /// <unsigned numeric literal> ::=
+    ///       <exact numeric literal>
+    ///     | <approximate numeric literal>
+    ///
+    /// <exact numeric literal> ::=
+    ///       <unsigned integer> [ <period> [ <unsigned integer> ] ]
+    ///     | <period> <unsigned integer>
+    ///
+    /// <approximate numeric literal> ::=
+    ///     <mantissa> E <exponent>
+    ///
+    /// <mantissa> ::= <exact numeric literal>
+    /// <exponent> ::= <signed integer>
+    /// ```
+    fn consume_numeric_literal(&mut self, first_character_byte_idx: usize) -> TokenKind {
+        let mut has_period = self.input[first_character_byte_idx..].starts_with('.');
+        self.skip_digits();
+
+        // Don't consume the period of `1..2`, which is a <double period>.
+        if !has_period && self.current_character() == Some('.') && !self.input[self.character_byte_idx + 1..].starts_with('.') {
+            self.next_character();
+            has_period = true;
+            self.skip_digits();
+        }
+
+        let has_exponent = matches!(self.current_character(), Some('e' | 'E'));
+        if has_exponent {
+            self.next_character();
+
+            if matches!(self.current_character(), Some('+' | '-')) {
+                self.next_character();
+            }
+
+            if !self.is_current_character_digit() {
+                self.consume_trailing_identifier_characters();
+                return TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral);
+            }
+
+            self.skip_digits();
+        }
+
+        // A <large object length token> such as `1M` is lexed as an integer
+        // followed by its <multiplier>, since the parser handles those.
+        let is_large_object_length = !has_period && !has_exponent && self.is_current_character_multiplier();
+        if !is_large_object_length && self.consume_trailing_identifier_characters() {
+            return TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral);
+        }
+
+        if has_exponent {
+            return TokenKind::ApproximateNumericLiteral;
+        }
+
+        if has_period {
+            return TokenKind::ExactNumericLiteral;
+        }
+
+        match self.input[first_character_byte_idx..self.character_byte_idx].parse() {
+            Ok(value) => TokenKind::UnsignedInteger(value),
+            Err(..) => TokenKind::IllegalToken(IllegalTokenReason::IntegerOutOfRange),
+        }
+    }
+
+    /// Checks whether the current character is a lone `<multiplier>`, i.e.
+    /// `K`, `M`, `G`, `T` or `P` not followed by other identifier characters.
+    fn is_current_character_multiplier(&self) -> bool {
+        let mut characters = self.input[self.character_byte_idx..].chars();
+        if !matches!(characters.next(), Some('K' | 'M' | 'G' | 'T' | 'P' | 'k' | 'm' | 'g' | 't' | 'p')) {
+            return false;
+        }
+
+        !characters.next().is_some_and(|c| c.is_alphanumeric() || c == '_')
+    }
+
+    /// Consumes the identifier characters directly following a numeric
+    /// literal, such as the `abc` of `123abc`, which make the literal
+    /// invalid. Returns `true` if there were any.
+    fn consume_trailing_identifier_characters(&mut self) -> bool {
+        if !self.is_current_character_identifier_body_character() {
+            return false;
+        }
+
+        while self.is_current_character_identifier_body_character() {
+            self.next_character();
+        }
+
+        true
+    }
+
+    /// Consumes the rest of a `<delimited identifier>`, after the opening
+    /// delimiter was consumed. Two consecutive closing delimiters stand for
+    /// the delimiter itself, e.g. `"a ""quoted"" name"`.
+    ///
+    /// ```text
+    /// <delimited identifier> ::=
+    ///     <double quote> <delimited identifier body> <double quote>
+    ///
+    /// <delimited identifier body> ::=
+    ///     <delimited identifier part>...
+    ///
+    /// <delimited identifier part> ::=
+    ///       <nondoublequote character>
+    ///     | <doublequote symbol>
+    /// ```
+    ///
+    /// The vendor-specific forms `` `name` `` (MySQL) and `[name]` (T-SQL)
+    /// are lexed the same way, with `` ` `` and `]` as closing delimiter.
+    fn consume_delimited_identifier(&mut self, closing_delimiter: char) -> TokenKind {
+        let first_character_byte_idx = self.character_byte_idx;
+
+        loop {
+            let Some(character) = self.current_character() else {
+                return TokenKind::IllegalToken(IllegalTokenReason::UnterminatedDelimitedIdentifier);
+            };
+
+            self.next_character();
+
+            if character != closing_delimiter {
+                continue;
+            }
+
+            if self.current_character() == Some(closing_delimiter) {
+                self.next_character();
+                continue;
+            }
+
+            break;
+        }
+
+        let last_character_byte_idx = self.character_byte_idx - closing_delimiter.len_utf8();
+        if first_character_byte_idx == last_character_byte_idx {
+            return TokenKind::IllegalToken(IllegalTokenReason::EmptyDelimitedIdentifier);
+        }
+
+        TokenKind::DelimitedIdentifier {
+            first_character_byte_idx,
+            last_character_byte_idx,
+        }
+    }
+
+    /// Consumes the rest of a `<binary string literal>` or a
+    /// `<bit string literal>`, after the `X` or `B` introducer was consumed.
+    ///
+    /// ```text
+    /// <binary string literal> ::=
+    ///     X <quote> [ <space>... ]
+    ///     [ { <hexit> [ <space>... ] <hexit> [ <space>... ] }... ] <quote>
+    ///
+    /// <bit string literal> ::=
+    ///     B <quote> [ <bit>... ] <quote>
+    /// ```
+    fn consume_binary_or_bit_string_literal(&mut self, is_binary: bool) -> TokenKind {
+        let opening_quote_byte_idx = self.character_byte_idx;
+
+        // Skip the opening quote.
+        self.next_character();
+
+        let first_character_byte_idx = self.character_byte_idx;
+        let mut digit_count = 0;
+        let mut is_valid = true;
+
+        loop {
+            let Some(character) = self.current_character() else {
+                return TokenKind::IllegalToken(IllegalTokenReason::UnterminatedStringLiteral { opening_quote_byte_idx });
+            };
+
+            match character {
+                '\'' => break,
+                ' ' if is_binary => (),
+                '0' | '1' => digit_count += 1,
+                _ if is_binary && character.is_ascii_hexdigit() => digit_count += 1,
+                _ => is_valid = false,
+            }
+
+            self.next_character();
+        }
+
+        let last_character_byte_idx = self.character_byte_idx;
+
+        // Skip the closing quote.
+        self.next_character();
+
+        if !is_valid {
+            return TokenKind::IllegalToken(if is_binary {
+                IllegalTokenReason::InvalidBinaryStringLiteral
+            } else {
+                IllegalTokenReason::InvalidBitStringLiteral
+            });
+        }
+
+        // Every octet is described by two hexits.
+        if is_binary && digit_count % 2 != 0 {
+            return TokenKind::IllegalToken(IllegalTokenReason::InvalidBinaryStringLiteral);
+        }
+
+        if is_binary {
+            TokenKind::BinaryStringLiteral { first_character_byte_idx, last_character_byte_idx }
+        } else {
+            TokenKind::BitStringLiteral { first_character_byte_idx, last_character_byte_idx }
+        }
+    }
+
+    /// Consumes the current character if it is the given `character`, which
+    /// is used to lex the longest possible operator (maximal munch).
+    fn consume_character_if(&mut self, character: char) -> bool {
+        if self.current_character() != Some(character) {
+            return false;
+        }
+
+        self.next_character();
+        true
+    }
+
     /// Gets the current character pointed to by the [`index`].
     fn current_character(&self) -> Option<char> {
         self.input[self.character_byte_idx..].chars().next()
@@ -183,7 +688,7 @@ impl<'a> Lexer<'a> {
             return false;
         };
 
-        matches!(character, 'a'..='z' | 'A'..='Z' | '0'..='9' | '_')
+        character.is_alphanumeric() || character == '_'
     }
 
     /// Checks if the character pointed to by [`index`] is whitespace.
@@ -197,19 +702,116 @@ impl<'a> Lexer<'a> {
 
     /// Advances the index to the next character.
     fn next_character(&mut self) {
-        if let Some(next_byte_offset) = self.input[self.character_byte_idx..].char_indices().nth(1) {
-            self.character_byte_idx += next_byte_offset.0;
-        } else {
-            self.character_byte_idx += 1;
+        if let Some(character) = self.current_character() {
+            self.character_byte_idx += character.len_utf8();
         }
     }
 
-    /// Skips the whitespace in the `input`.
-    fn skip_whitespace(&mut self) {
-        while self.is_current_character_whitespace() {
+    /// Skips the `<digit>`s at the current position.
+    fn skip_digits(&mut self) {
+        while self.is_current_character_digit() {
             self.next_character();
         }
     }
+
+    /// Skips the whitespace in the `input`. Comments are skipped too, unless
+    /// they are produced as tokens (see [`Lexer::with_comments`]).
+    fn skip_whitespace(&mut self) {
+        self.skip_separator(!self.emit_comments);
+    }
+
+    /// Skips a `<separator>`, which is whitespace and, if requested,
+    /// comments.
+    fn skip_separator(&mut self, skip_comments: bool) {
+        loop {
+            while self.is_current_character_whitespace() {
+                self.next_character();
+            }
+
+            if !skip_comments {
+                return;
+            }
+
+            let start_byte_idx = self.character_byte_idx;
+            let mut characters = self.input[start_byte_idx..].chars();
+            let kind = match (characters.next(), characters.next()) {
+                (Some('-'), Some('-')) => {
+                    self.next_character();
+                    self.consume_simple_comment()
+                }
+
+                (Some('/'), Some('*')) => {
+                    self.next_character();
+                    self.consume_bracketed_comment()
+                }
+
+                _ => return,
+            };
+
+            // Leave unterminated comments to `consume_token`, which reports
+            // them as an illegal token.
+            if kind != TokenKind::Comment {
+                self.character_byte_idx = start_byte_idx;
+                return;
+            }
+        }
+    }
+}
+
+/// Decodes the `<Unicode escape value>`s in the `value` of a
+/// `<Unicode character string literal>`. Returns `None` if an escape value is
+/// malformed or doesn't denote a Unicode scalar value.
+///
+/// ```text
+/// <Unicode escape value> ::=
+///       <Unicode 4 digit escape value>
+///     | <Unicode 6 digit escape value>
+///     | <Unicode character escape value>
+///
+/// <Unicode 4 digit escape value> ::=
+///     <Unicode escape character> <hexit> <hexit> <hexit> <hexit>
+///
+/// <Unicode 6 digit escape value> ::=
+///     <Unicode escape character> <plus sign>
+///     <hexit> <hexit> <hexit> <hexit> <hexit> <hexit>
+///
+/// <Unicode character escape value> ::=
+///     <Unicode escape character> <Unicode escape character>
+/// ```
+fn decode_unicode_escape_values(value: &str, escape_character: char) -> Option<String> {
+    let mut decoded = String::with_capacity(value.len());
+    let mut characters = value.chars();
+
+    while let Some(character) = characters.next() {
+        if character != escape_character {
+            decoded.push(character);
+            continue;
+        }
+
+        let digit_count = match characters.clone().next()? {
+            next_character if next_character == escape_character => {
+                characters.next();
+                decoded.push(escape_character);
+                continue;
+            }
+
+            '+' => {
+                characters.next();
+                6
+            }
+
+            _ => 4,
+        };
+
+        let mut code_point = 0;
+        for _ in 0..digit_count {
+            code_point = code_point * 16 + characters.next()?.to_digit(16)?;
+        }
+
+        decoded.push(char::from_u32(code_point)?);
+    }
+
+    Some(decoded)
 }
 
 impl<'input> Iterator for Lexer<'input> {
@@ -225,6 +827,8 @@ mod tests {
     use super::*;
 
     use super::Lexer;
+    use crate::common::{Identifier, IdentifierDelimiter};
+    use crate::keyword::VendorReservedWord;
 
     use pretty_assertions::{
         assert_eq
@@ -255,14 +859,376 @@ mod tests {
             Token::new(37, 38, TokenKind::EqualsSign),
 
             Token::new(39, 54, TokenKind::StringLiteral {
-                first_character_byte_idx: 40,
-                last_character_byte_idx: 53
+                kind: StringLiteralKind::Character,
             }),
 
             Token::new(54, 55, TokenKind::Semicolon),
         ]);
     }
 
+    #[rstest]
+    #[case("X'DEADBEEF'", TokenKind::BinaryStringLiteral { first_character_byte_idx: 2, last_character_byte_idx: 10 })]
+    #[case("x'de ad'", TokenKind::BinaryStringLiteral { first_character_byte_idx: 2, last_character_byte_idx: 7 })]
+    #[case("X''", TokenKind::BinaryStringLiteral { first_character_byte_idx: 2, last_character_byte_idx: 2 })]
+    #[case("B'1010'", TokenKind::BitStringLiteral { first_character_byte_idx: 2, last_character_byte_idx: 6 })]
+    #[case("b''", TokenKind::BitStringLiteral { first_character_byte_idx: 2, last_character_byte_idx: 2 })]
+    #[case("X'ABC'", TokenKind::IllegalToken(IllegalTokenReason::InvalidBinaryStringLiteral))]
+    #[case("X'XY'", TokenKind::IllegalToken(IllegalTokenReason::InvalidBinaryStringLiteral))]
+    #[case("X'AB", TokenKind::IllegalToken(IllegalTokenReason::UnterminatedStringLiteral { opening_quote_byte_idx: 1 }))]
+    #[case("B'102'", TokenKind::IllegalToken(IllegalTokenReason::InvalidBitStringLiteral))]
+    #[case("B'1 0'", TokenKind::IllegalToken(IllegalTokenReason::InvalidBitStringLiteral))]
+    fn lexer_binary_and_bit_string_literals(#[case] input: &str, #[case] kind: TokenKind) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens, vec![Token::new(0, input.len(), kind)]);
+    }
+
+    #[rstest]
+    #[case("||", &[TokenKind::ConcatenationOperator])]
+    #[case("|", &[TokenKind::VerticalBar])]
+    #[case("|||", &[TokenKind::ConcatenationOperator, TokenKind::VerticalBar])]
+    #[case("| |", &[TokenKind::VerticalBar, TokenKind::VerticalBar])]
+    #[case("::", &[TokenKind::DoubleColon])]
+    #[case(":", &[TokenKind::Colon])]
+    #[case(":::", &[TokenKind::DoubleColon, TokenKind::Colon])]
+    #[case("..", &[TokenKind::DoublePeriod])]
+    #[case(".", &[TokenKind::FullStop])]
+    #[case("...", &[TokenKind::DoublePeriod, TokenKind::FullStop])]
+    #[case(">=", &[TokenKind::GreaterThanOrEqualsOperator])]
+    #[case(">", &[TokenKind::GreaterThanOperator])]
+    #[case("> =", &[TokenKind::GreaterThanOperator, TokenKind::EqualsSign])]
+    #[case("<=", &[TokenKind::LessThanOrEqualsOperator])]
+    #[case("<>", &[TokenKind::NotEqualsOperator])]
+    #[case("<", &[TokenKind::LessThanOperator])]
+    #[case("<<", &[TokenKind::LessThanOperator, TokenKind::LessThanOperator])]
+    #[case("<=>", &[TokenKind::LessThanOrEqualsOperator, TokenKind::GreaterThanOperator])]
+    #[case("<>=", &[TokenKind::NotEqualsOperator, TokenKind::EqualsSign])]
+    #[case("->", &[TokenKind::RightArrow])]
+    #[case("-}", &[TokenKind::RightMinusBrace])]
+    #[case("-", &[TokenKind::MinusSign])]
+    #[case("=>", &[TokenKind::NamedArgumentAssignmentOperator])]
+    #[case("==", &[TokenKind::EqualsSign])]
+    #[case("=", &[TokenKind::EqualsSign])]
+    #[case("{-", &[TokenKind::LeftBraceMinus])]
+    #[case("{", &[TokenKind::LeftBrace])]
+    #[case("{-}", &[TokenKind::LeftBraceMinus, TokenKind::RightBrace])]
+    #[case("!=", &[TokenKind::NotEqualsOperator])]
+    #[case("!", &[TokenKind::ExclamationMark])]
+    #[case("x<=y", &[TokenKind::Identifier, TokenKind::LessThanOrEqualsOperator, TokenKind::Identifier])]
+    #[case("'a'||'b'", &[
+        TokenKind::StringLiteral { kind: StringLiteralKind::Character },
+        TokenKind::ConcatenationOperator,
+        TokenKind::StringLiteral { kind: StringLiteralKind::Character },
+    ])]
+    fn lexer_operators(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.kind()).collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case("!=", &[TokenKind::ExclamationMark, TokenKind::EqualsSign])]
+    #[case("<>", &[TokenKind::NotEqualsOperator])]
+    fn lexer_operators_in_standard_dialect(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens: Vec<_> = Lexer::new(input)
+            .with_dialect(Dialect::Standard)
+            .map(|token| token.kind())
+            .collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case(Dialect::MySql, "!=", &[TokenKind::NotEqualsOperator])]
+    #[case(Dialect::MySql, "==", &[TokenKind::EqualsSign, TokenKind::EqualsSign])]
+    #[case(Dialect::Sqlite, "==", &[TokenKind::EqualsSign])]
+    #[case(Dialect::Sqlite, "=>", &[TokenKind::NamedArgumentAssignmentOperator])]
+    #[case(Dialect::MySql, "LIMIT", &[TokenKind::VendorReservedWord(VendorReservedWord::Limit)])]
+    #[case(Dialect::MySql, "TOP", &[TokenKind::Identifier])]
+    #[case(Dialect::PostgreSql, "limit", &[TokenKind::VendorReservedWord(VendorReservedWord::Limit)])]
+    #[case(Dialect::TSql, "LIMIT", &[TokenKind::Identifier])]
+    #[case(Dialect::TSql, "top", &[TokenKind::VendorReservedWord(VendorReservedWord::Top)])]
+    #[case(Dialect::Standard, "TOP", &[TokenKind::Identifier])]
+    fn lexer_dialect_specific_tokens(#[case] dialect: Dialect, #[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens: Vec<_> = Lexer::new(input)
+            .with_dialect(dialect)
+            .map(|token| token.kind())
+            .collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case("SELECT # FROM", 7, '#')]
+    #[case("SELECT a]", 8, ']')]
+    #[case("SELECT _a", 7, '_')]
+    #[case("SELECT ~a", 7, '~')]
+    #[case("SELECT 🦀 FROM crabs", 7, '🦀')]
+    fn lexer_unexpected_character_ends_token_stream(#[case] input: &str, #[case] byte_idx: usize, #[case] character: char) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+
+        let last_token = tokens.last().unwrap();
+        assert_eq!(last_token.kind(), TokenKind::IllegalToken(IllegalTokenReason::UnexpectedCharacter(character)));
+        assert_eq!(last_token.first_character_byte_idx, byte_idx);
+        assert_eq!(last_token.as_string(input), character.to_string());
+
+        assert!(tokens[..tokens.len() - 1].iter().all(|token| !matches!(token.kind(), TokenKind::IllegalToken(..))));
+    }
+
+    #[rstest]
+    #[case("\"Name\"", "Name")]
+    #[case("\"SELECT\"", "SELECT")]
+    #[case("\"with space\"", "with space")]
+    #[case("\"a \"\"quoted\"\" name\"", "a \"\"quoted\"\" name")]
+    #[case("\"\"\"\"", "\"\"")]
+    #[case("\"café\"", "café")]
+    #[case("`Name`", "Name")]
+    #[case("`a``b`", "a``b")]
+    #[case("[Name]", "Name")]
+    #[case("[a]]b]", "a]]b")]
+    fn lexer_delimited_identifier(#[case] input: &str, #[case] body: &str) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens, vec![Token::new(0, input.len(), TokenKind::DelimitedIdentifier {
+            first_character_byte_idx: 1,
+            last_character_byte_idx: input.len() - 1,
+        })]);
+        assert_eq!(&input[1..input.len() - 1], body);
+    }
+
+    #[rstest]
+    #[case("\"Name\"", Identifier::delimited("Name"))]
+    #[case("\"a \"\"quoted\"\" name\"", Identifier::delimited("a \"quoted\" name"))]
+    #[case("\"\"\"\"", Identifier::delimited("\""))]
+    #[case("`a``b`", Identifier::delimited_by("a`b", IdentifierDelimiter::Backtick))]
+    #[case("[a]]b]", Identifier::delimited_by("a]b", IdentifierDelimiter::SquareBrackets))]
+    #[case("Name", Identifier::regular("Name"))]
+    #[case("name", Identifier::regular("name"))]
+    fn lexer_token_as_identifier(#[case] input: &str, #[case] identifier: Identifier) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].as_identifier(input), Some(identifier.with_span(Span::new(0, input.len()))));
+    }
+
+    #[rstest]
+    #[case("\"\"", IllegalTokenReason::EmptyDelimitedIdentifier)]
+    #[case("SELECT \"", IllegalTokenReason::UnterminatedDelimitedIdentifier)]
+    #[case("SELECT \"Name", IllegalTokenReason::UnterminatedDelimitedIdentifier)]
+    #[case("SELECT \"Name\"\"", IllegalTokenReason::UnterminatedDelimitedIdentifier)]
+    #[case("SELECT [Name", IllegalTokenReason::UnterminatedDelimitedIdentifier)]
+    #[case("SELECT ``", IllegalTokenReason::EmptyDelimitedIdentifier)]
+    fn lexer_delimited_identifier_erroneous(#[case] input: &str, #[case] reason: IllegalTokenReason) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens.last().unwrap().kind(), TokenKind::IllegalToken(reason));
+    }
+
+    #[rstest]
+    #[case(Dialect::Standard, "`Name`", '`')]
+    #[case(Dialect::Standard, "[Name]", '[')]
+    #[case(Dialect::TSql, "`Name`", '`')]
+    #[case(Dialect::MySql, "[Name]", '[')]
+    fn lexer_vendor_delimited_identifier_unsupported_by_dialect(#[case] dialect: Dialect, #[case] input: &str, #[case] character: char) {
+        let tokens: Vec<_> = Lexer::new(input).with_dialect(dialect).collect();
+        assert_eq!(tokens, vec![
+            Token::new(0, 1, TokenKind::IllegalToken(IllegalTokenReason::UnexpectedCharacter(character)))
+        ]);
+    }
+
+    #[rstest]
+    #[case("SELECT -- comment\n*", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT -- comment\r\n*", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT *-- comment", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT --\n--\n*", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("-- only a comment", &[])]
+    #[case("SELECT /* comment */ *", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT/**/*", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT /* multiple\nlines */ *", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT /* outer /* inner */ still outer */ *", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT /* -- not a simple comment */ *", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT -- /* not a bracketed comment\n*", &[TokenKind::ReservedWord(ReservedWord::Select), TokenKind::Asterisk])]
+    #[case("SELECT 1 - 2 / 3", &[
+        TokenKind::ReservedWord(ReservedWord::Select),
+        TokenKind::UnsignedInteger(1),
+        TokenKind::MinusSign,
+        TokenKind::UnsignedInteger(2),
+        TokenKind::Solidus,
+        TokenKind::UnsignedInteger(3),
+    ])]
+    fn lexer_comments_are_skipped(#[case] input: &str, #[case] expected: &[TokenKind]) {
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.kind()).collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case("SELECT -- comment\n*", &["SELECT", "-- comment", "*"])]
+    #[case("SELECT /* a /* b */ c */ *", &["SELECT", "/* a /* b */ c */", "*"])]
+    #[case("/**/--\n", &["/**/", "--"])]
+    fn lexer_comments_are_emitted(#[case] input: &str, #[case] expected: &[&str]) {
+        let tokens: Vec<_> = Lexer::new(input).with_comments(true).collect();
+        let strings: Vec<_> = tokens.iter().map(|token| token.as_string(input)).collect();
+        assert_eq!(strings, expected);
+
+        for token in tokens {
+            let is_comment = token.as_string(input).starts_with("--") || token.as_string(input).starts_with("/*");
+            assert_eq!(token.kind() == TokenKind::Comment, is_comment);
+        }
+    }
+
+    #[rstest]
+    #[case("SELECT /* unterminated", false, "/* unterminated")]
+    #[case("SELECT /* outer /* inner */", false, "/* outer /* inner */")]
+    #[case("SELECT /*/", false, "/*/")]
+    #[case("SELECT /* unterminated", true, "/* unterminated")]
+    fn lexer_unterminated_bracketed_comment(#[case] input: &str, #[case] emit_comments: bool, #[case] illegal_portion: &str) {
+        let tokens: Vec<_> = Lexer::new(input).with_comments(emit_comments).collect();
+
+        let last_token = tokens.last().unwrap();
+        assert_eq!(last_token.kind(), TokenKind::IllegalToken(IllegalTokenReason::UnterminatedBracketedComment));
+        assert_eq!(last_token.as_string(input), illegal_portion);
+    }
+
+    #[rstest]
+    #[case("0", TokenKind::UnsignedInteger(0))]
+    #[case("42", TokenKind::UnsignedInteger(42))]
+    #[case("007", TokenKind::UnsignedInteger(7))]
+    #[case("18446744073709551615", TokenKind::UnsignedInteger(u64::MAX))]
+    #[case("0x1F", TokenKind::UnsignedInteger(0x1F))]
+    #[case("0XdeadBEEF", TokenKind::UnsignedInteger(0xDEADBEEF))]
+    #[case("0xFFFFFFFFFFFFFFFF", TokenKind::UnsignedInteger(u64::MAX))]
+    #[case("3.14", TokenKind::ExactNumericLiteral)]
+    #[case(".5", TokenKind::ExactNumericLiteral)]
+    #[case("1.", TokenKind::ExactNumericLiteral)]
+    #[case("18446744073709551616.0", TokenKind::ExactNumericLiteral)]
+    #[case("1e10", TokenKind::ApproximateNumericLiteral)]
+    #[case("6.02E23", TokenKind::ApproximateNumericLiteral)]
+    #[case("1.5e-3", TokenKind::ApproximateNumericLiteral)]
+    #[case(".5E+2", TokenKind::ApproximateNumericLiteral)]
+    #[case("1.E5", TokenKind::ApproximateNumericLiteral)]
+    #[case("18446744073709551616", TokenKind::IllegalToken(IllegalTokenReason::IntegerOutOfRange))]
+    #[case("99999999999999999999999999", TokenKind::IllegalToken(IllegalTokenReason::IntegerOutOfRange))]
+    #[case("0x10000000000000000", TokenKind::IllegalToken(IllegalTokenReason::IntegerOutOfRange))]
+    #[case("0x", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("0x1G", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("1e", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("1E+", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("1e5x", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("123abc", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("3.14_", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("1.5M", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    #[case("1MB", TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral))]
+    fn lexer_numeric_literal(#[case] input: &str, #[case] kind: TokenKind) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens, vec![Token::new(0, input.len(), kind)]);
+    }
+
+    #[rstest]
+    #[case("1..2", &["1", "..", "2"])]
+    #[case("1.2.3", &["1.2", ".3"])]
+    #[case("t.a", &["t", ".", "a"])]
+    #[case("-1", &["-", "1"])]
+    #[case("1-2", &["1", "-", "2"])]
+    #[case("1e-2-3", &["1e-2", "-", "3"])]
+    #[case("(1.5,2)", &["(", "1.5", ",", "2", ")"])]
+    #[case("(1M)", &["(", "1", "M", ")"])]
+    #[case("2k", &["2", "k"])]
+    #[case("(4P)", &["(", "4", "P", ")"])]
+    fn lexer_numeric_literal_boundaries(#[case] input: &str, #[case] expected: &[&str]) {
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.as_string(input)).collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn lexer_hexadecimal_integer_in_standard_dialect() {
+        let input = "0x1F";
+        let tokens: Vec<_> = Lexer::new(input).with_dialect(Dialect::Standard).collect();
+        assert_eq!(tokens, vec![
+            Token::new(0, 4, TokenKind::IllegalToken(IllegalTokenReason::InvalidNumericLiteral)),
+        ]);
+    }
+
+    #[rstest]
+    #[case("'Hello", "'Hello", 0)]
+    #[case("SELECT 'Hello, world", "'Hello, world", 7)]
+    #[case("SELECT 'héllo wörld", "'héllo wörld", 7)]
+    #[case("SELECT 'O''Brien", "'O''Brien", 7)]
+    #[case("SELECT 'trailing quote''", "'trailing quote''", 7)]
+    #[case("SELECT N'national", "N'national", 8)]
+    #[case("SELECT U&'unicode", "U&'unicode", 9)]
+    #[case("SELECT 'first'\n'second", "'first'\n'second", 15)]
+    fn lexer_unterminated_string_literal(#[case] input: &str, #[case] illegal_portion: &str, #[case] opening_quote_byte_idx: usize) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+
+        let last_token = tokens.last().unwrap();
+        assert_eq!(last_token.kind(), TokenKind::IllegalToken(IllegalTokenReason::UnterminatedStringLiteral { opening_quote_byte_idx }));
+        assert_eq!(last_token.as_string(input), illegal_portion);
+    }
+
+    #[rstest]
+    #[case("'Hello'", StringLiteralKind::Character, "Hello")]
+    #[case("''", StringLiteralKind::Character, "")]
+    #[case("'O''Brien'", StringLiteralKind::Character, "O'Brien")]
+    #[case("''''", StringLiteralKind::Character, "'")]
+    #[case("'''quoted'''", StringLiteralKind::Character, "'quoted'")]
+    #[case("'héllo wörld'", StringLiteralKind::Character, "héllo wörld")]
+    #[case("'first'\n'second'", StringLiteralKind::Character, "firstsecond")]
+    #[case("'first'  \r\n  'second'\n'third'", StringLiteralKind::Character, "firstsecondthird")]
+    #[case("'first' -- comment\n'second'", StringLiteralKind::Character, "firstsecond")]
+    #[case("'first' /* comment\n */ 'second'", StringLiteralKind::Character, "firstsecond")]
+    #[case("N'Hello'", StringLiteralKind::National, "Hello")]
+    #[case("n'It''s'", StringLiteralKind::National, "It's")]
+    #[case("N'first'\n'second'", StringLiteralKind::National, "firstsecond")]
+    #[case("U&'Hello'", StringLiteralKind::Unicode, "Hello")]
+    #[case("U&'\\0041'", StringLiteralKind::Unicode, "A")]
+    #[case("u&'d\\0061t\\+000061'", StringLiteralKind::Unicode, "data")]
+    #[case("U&'\\+01F980'", StringLiteralKind::Unicode, "🦀")]
+    #[case("U&'\\\\'", StringLiteralKind::Unicode, "\\")]
+    #[case("U&'!0041' UESCAPE '!'", StringLiteralKind::Unicode, "A")]
+    #[case("U&'!!' uescape '!'", StringLiteralKind::Unicode, "!")]
+    #[case("U&'\\0041' UESCAPE '!'", StringLiteralKind::Unicode, "\\0041")]
+    #[case("U&'!00'\n'41' UESCAPE\n'!'", StringLiteralKind::Unicode, "A")]
+    fn lexer_string_literal(#[case] input: &str, #[case] kind: StringLiteralKind, #[case] value: &str) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens, vec![Token::new(0, input.len(), TokenKind::StringLiteral { kind })]);
+        assert_eq!(Lexer::decode_character_string_literal(input, kind).as_deref(), Some(value));
+    }
+
+    #[rstest]
+    #[case("'first' 'second'", &["'first'", "'second'"])]
+    #[case("'first'/**/'second'", &["'first'", "'second'"])]
+    #[case("'first'\n'second' 'third'", &["'first'\n'second'", "'third'"])]
+    #[case("'value' UESCAPE", &["'value'", "UESCAPE"])]
+    #[case("U&'value' UESCAPED", &["U&'value'", "UESCAPED"])]
+    #[case("N 'value'", &["N", "'value'"])]
+    #[case("U &'value'", &["U", "&", "'value'"])]
+    fn lexer_string_literal_boundaries(#[case] input: &str, #[case] expected: &[&str]) {
+        let tokens: Vec<_> = Lexer::new(input).map(|token| token.as_string(input)).collect();
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case("U&'\\004'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'\\00G1'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'\\+00041'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'\\D800'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'\\+110000'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'\\'", IllegalTokenReason::InvalidUnicodeEscapeValue)]
+    #[case("U&'a' UESCAPE '+'", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE 'A'", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE ' '", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE '\"'", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE '!!'", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    #[case("U&'a' UESCAPE !", IllegalTokenReason::InvalidUnicodeEscapeCharacter)]
+    fn lexer_unicode_string_literal_erroneous(#[case] input: &str, #[case] reason: IllegalTokenReason) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens.last().unwrap().kind(), TokenKind::IllegalToken(reason));
+    }
+
+    #[rstest]
+    #[case("café")]
+    #[case("Straße")]
+    #[case("日本語")]
+    #[case("naïve_column2")]
+    fn lexer_non_ascii_identifier(#[case] input: &str) {
+        let tokens: Vec<_> = Lexer::new(input).collect();
+        assert_eq!(tokens, vec![Token::new(0, input.len(), TokenKind::Identifier)]);
+    }
+
     #[rstest]
     #[case("HELLO\tWORLD", "U+0009, Horizontal Tab")]
     #[case("HELLO\nWORLD", "U+000A, Line Feed")]
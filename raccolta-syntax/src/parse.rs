@@ -3,14 +3,23 @@
 
 mod error;
 mod extensions;
+pub(crate) mod operator;
+mod recovery;
 
 pub use error::{
     ErrorFindLocation,
     ErrorTokenShouldBeMatching,
     StatementParseError,
 };
+pub use recovery::{
+    Diagnostic,
+    DiagnosticLabel,
+};
 
-use std::debug_assert;
+use std::{
+    debug_assert,
+    ops::Range,
+};
 
 use crate::{
     clause::{
@@ -25,19 +34,48 @@ use crate::{
             OrderingSpecification,
             SortSpecification,
         },
+        result_offset_clause::ResultOffsetClause,
         WhereClause,
+        window_clause::{
+            WindowClause,
+            WindowDefinition,
+            WindowFrameBound,
+            WindowFrameClause,
+            WindowFrameUnits,
+            WindowSpecification,
+        },
+    },
+    common::{
+        Identifier,
+        TableName,
     },
-    common::TableName,
     expression::{
+        boolean_expression::TruthValue,
         BooleanExpression,
+        case_expression::{
+            CaseExpression,
+            CaseResult,
+            SearchedWhenClause,
+            SimpleWhenClause,
+        },
+        cast_specification::{
+            CastOperand,
+            CastSpecification,
+        },
         ColumnReference,
         data_type::{
+            BinaryStringType,
+            BitStringType,
             CharacterStringType,
             DataType,
+            LargeObjectLength,
+            LargeObjectLengthMultiplier,
+            NationalCharacterStringType,
             NumericType,
             PredefinedType,
         },
         NumericValueExpression,
+        numeric_value_function::NumericValueFunction,
         query_specification::{
             QuerySpecification,
             DerivedColumn,
@@ -53,9 +91,14 @@ use crate::{
             ContextuallyTypedRowValueConstructor,
             ContextuallyTypedRowValueConstructorElement,
         },
+        RoutineInvocation,
         row_value_expression::ContextuallyTypedRowValueExpression,
         SimpleValueSpecification,
         string_value_expression::StringValueExpression,
+        string_value_function::{
+            StringValueFunction,
+            TrimSpecification,
+        },
         TableExpression,
         table_reference::{
             TablePrimary,
@@ -64,26 +107,44 @@ use crate::{
         },
         table_value_constructor::ContextuallyTypedTableValueConstructor,
         ValueExpression,
+        WindowFunction,
+        window_function::{
+            RankFunctionType,
+            WindowFunctionType,
+            WindowNameOrSpecification,
+        },
     },
+    Dialect,
+    DialectFeature,
     keyword::{
+        Keyword,
         NonReservedWord,
         ReservedWord,
         VendorReservedWord,
     },
+    IllegalTokenReason,
     Lexer,
-    predicate::{
-        comparison_predicate::{
-            ComparisonPredicate,
-            ComparisonOperator
-        },
-        Predicate,
-    },
     schema::definition::table_definition::{
         ColumnDefinition,
         TableDefinition,
         TableElement,
     },
+    predicate::{
+        between_predicate::BetweenPredicate,
+        distinct_predicate::DistinctPredicate,
+        in_predicate::InPredicate,
+        like_predicate::LikePredicate,
+        null_predicate::NullPredicate,
+        Predicate,
+        quantified_comparison_predicate::{
+            QuantifiedComparisonPredicate,
+            Quantifier,
+        },
+        similar_predicate::SimilarPredicate,
+    },
     set_function::{
+        ComputationalOperation,
+        GeneralSetFunction,
         SetFunctionSpecification,
         SetQuantifier,
     },
@@ -98,26 +159,27 @@ use crate::{
         SqlSchemaDefinitionStatement,
         SqlSchemaStatement,
     },
+    Span,
+    Spanned,
     Token,
     TokenKind,
 };
 
 use extensions::ParseArrayExtensions;
+use operator::{
+    BinaryOperator,
+    OperatorPrecedence,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct Parser {
-    allow_vendor_extensions: bool,
-}
-
-impl Default for Parser {
-    fn default() -> Self {
-        Self {
-            allow_vendor_extensions: true,
-        }
-    }
+    /// The dialect whose vendor-specific extensions are accepted. When
+    /// `None`, those of all dialects are accepted.
+    dialect: Option<Dialect>,
 }
 
-type StatementResult<'input> = Result<SqlExecutableStatement, StatementParseError<'input>>;
+/// The result of parsing a single statement.
+pub type StatementResult<'input> = Result<SqlExecutableStatement, StatementParseError<'input>>;
 
 /// Is the token list at the end of the statement? This is expressed through
 /// either EOF or the semicolon `;` token.
@@ -129,19 +191,228 @@ fn is_end_of_statement(tokens: &[Token]) -> bool {
     }
 }
 
+/// Returns the span of the tokens that were consumed since `start`, of which
+/// `tokens` is the remainder.
+fn consumed_span(start: &[Token], tokens: &[Token]) -> Span {
+    let consumed = &start[..start.len() - tokens.len()];
+    match (consumed.first(), consumed.last()) {
+        (Some(first), Some(last)) => first.span().to(last.span()),
+        _ => Span::default(),
+    }
+}
+
+/// Checks whether the `tokens` start a predicate that follows its first
+/// operand, i.e. `[ NOT ] BETWEEN`, `[ NOT ] IN`, `[ NOT ] LIKE` or
+/// `[ NOT ] SIMILAR`.
+fn is_postfix_predicate_ahead(tokens: &[Token]) -> bool {
+    let tokens = match tokens.first() {
+        Some(token) if token.kind() == TokenKind::ReservedWord(ReservedWord::Not) => &tokens[1..],
+        _ => tokens,
+    };
+
+    tokens.first().is_some_and(|token| matches!(
+        token.kind(),
+        TokenKind::ReservedWord(ReservedWord::Between | ReservedWord::In | ReservedWord::Like | ReservedWord::Similar)
+    ))
+}
+
+/// Checks whether the clause starting with a token of `token_kind` can't be
+/// combined with the `fetch` clause that was already parsed, since it would
+/// limit the number of rows again.
+fn is_conflicting_with_fetch_first_clause(fetch: &FetchFirstClause, token_kind: TokenKind) -> bool {
+    match token_kind {
+        TokenKind::ReservedWord(ReservedWord::Fetch) | TokenKind::VendorReservedWord(VendorReservedWord::Limit) => true,
+
+        // T-SQL doesn't allow combining `TOP` with `OFFSET` either.
+        TokenKind::ReservedWord(ReservedWord::Offset) => fetch.origin == FetchFirstClauseOrigin::Top,
+
+        _ => false,
+    }
+}
+
+/// Checks whether the `tokens` start a clause of the table expression that
+/// isn't supported yet, i.e. `GROUP BY` or `HAVING`, and returns the error to
+/// report if so.
+fn unsupported_table_expression_clause<'input>(input: &'input str, tokens: &[Token]) -> Option<StatementParseError<'input>> {
+    let first_token = tokens.first()?;
+
+    if first_token.kind() == TokenKind::ReservedWord(ReservedWord::Having) {
+        return Some(StatementParseError::UnsupportedFeature {
+            feature_name: "HAVING clause",
+            feature_description: "Filtering based on the GROUP BY clause",
+            found: first_token.as_string(input).into(),
+            token_kind: first_token.kind()
+        });
+    }
+
+    let mut toks = tokens.iter()
+        .map(|tok| tok.kind());
+
+    if toks.next() == Some(TokenKind::ReservedWord(ReservedWord::Group))
+         && toks.next() == Some(TokenKind::ReservedWord(ReservedWord::By)) {
+        return Some(StatementParseError::UnsupportedFeature {
+            feature_name: "GROUP BY clause",
+            feature_description: "Grouping the SELECT statement columns",
+            found: first_token.as_string(input).into(),
+            token_kind: first_token.kind(),
+        });
+    }
+
+    None
+}
+
+/// Converts the keyword after a `<comp op>` to the `<quantifier>` of a
+/// `<quantified comparison predicate>`, if it is one.
+fn quantifier_of(token_kind: TokenKind) -> Option<Quantifier> {
+    match token_kind {
+        TokenKind::ReservedWord(ReservedWord::All) => Some(Quantifier::All),
+        TokenKind::ReservedWord(ReservedWord::Any | ReservedWord::Some) => Some(Quantifier::Any),
+        _ => None,
+    }
+}
+
+/// Wraps the `predicate` in a [`ValueExpression`].
+fn predicate_expression(predicate: Predicate) -> ValueExpression {
+    ValueExpression::Boolean(BooleanExpression::Predicate(Box::new(predicate)))
+}
+
+/// Converts the [`IllegalTokenReason`] of an illegal token to the
+/// corresponding [`StatementParseError`]. When the token is only illegal
+/// because the `dialect` doesn't accept it, the error says so.
+fn lexical_error<'input>(input: &'input str, token: &Token, reason: IllegalTokenReason, dialect: Option<Dialect>) -> StatementParseError<'input> {
+    let found = token.as_string(input).into();
+
+    let feature = match reason {
+        IllegalTokenReason::InvalidNumericLiteral if token.as_string(input).get(..2).is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x")) => {
+            Some(DialectFeature::HexadecimalIntegerLiteral)
+        }
+        IllegalTokenReason::UnexpectedCharacter('`') => Some(DialectFeature::BacktickDelimitedIdentifier),
+        IllegalTokenReason::UnexpectedCharacter('[') => Some(DialectFeature::BracketDelimitedIdentifier),
+        _ => None,
+    };
+
+    if let (Some(feature), Some(dialect)) = (feature, dialect) {
+        if !dialect.supports(feature) {
+            return StatementParseError::DialectUnsupportedFeature { found, dialect, feature };
+        }
+    }
+
+    match reason {
+        IllegalTokenReason::EmptyDelimitedIdentifier => StatementParseError::LexicalEmptyDelimitedIdentifier { found },
+        IllegalTokenReason::IntegerOutOfRange => StatementParseError::LexicalIntegerOutOfRange { found },
+        IllegalTokenReason::InvalidBinaryStringLiteral => StatementParseError::LexicalInvalidBinaryStringLiteral { found },
+        IllegalTokenReason::InvalidBitStringLiteral => StatementParseError::LexicalInvalidBitStringLiteral { found },
+        IllegalTokenReason::InvalidNumericLiteral => StatementParseError::LexicalInvalidNumericLiteral { found },
+        IllegalTokenReason::InvalidUnicodeEscapeCharacter => StatementParseError::LexicalInvalidUnicodeEscapeCharacter { found },
+        IllegalTokenReason::InvalidUnicodeEscapeValue => StatementParseError::LexicalInvalidUnicodeEscapeValue { found },
+        IllegalTokenReason::UnexpectedCharacter(character) => StatementParseError::LexicalUnexpectedCharacter { found, character },
+        IllegalTokenReason::UnterminatedBracketedComment => StatementParseError::LexicalUnterminatedBracketedComment { found },
+        IllegalTokenReason::UnterminatedDelimitedIdentifier => StatementParseError::LexicalUnterminatedDelimitedIdentifier { found },
+        IllegalTokenReason::UnterminatedStringLiteral { opening_quote_byte_idx } => StatementParseError::LexicalUnterminatedStringLiteral {
+            found: input[opening_quote_byte_idx..opening_quote_byte_idx + 1].into(),
+        },
+    }
+}
+
+/// Is the token an `<unsigned numeric literal>`?
+fn is_unsigned_numeric_literal(token_kind: TokenKind) -> bool {
+    matches!(
+        token_kind,
+        TokenKind::ApproximateNumericLiteral | TokenKind::ExactNumericLiteral | TokenKind::UnsignedInteger(..)
+    )
+}
+
+/// Decodes the hexits of a `<binary string literal>`, which are validated by
+/// the [`Lexer`]. Spaces are allowed between the hexits.
+fn decode_binary_string_literal(hexits: &str) -> Vec<u8> {
+    let hexits: Vec<u8> = hexits.chars()
+        .filter_map(|hexit| hexit.to_digit(16))
+        .map(|hexit| hexit as u8)
+        .collect();
+
+    hexits.chunks(2)
+        .map(|pair| (pair[0] << 4) | pair[1])
+        .collect()
+}
+
 impl Parser {
     /// Creates a new [`Parser`] object.
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Sets the dialect whose vendor-specific extensions are accepted, such
+    /// as the `LIMIT` clause of **MySQL**. By default, the extensions of all
+    /// dialects are accepted.
+    pub fn with_dialect(self, dialect: Dialect) -> Self {
+        Self {
+            dialect: Some(dialect),
+        }
+    }
+
+    /// Returns the dialect chosen with [`Parser::with_dialect`], if any.
+    pub fn dialect(&self) -> Option<Dialect> {
+        self.dialect
+    }
+
+    /// Returns the chosen dialect if it doesn't accept the `feature`.
+    fn dialect_rejecting(&self, feature: DialectFeature) -> Option<Dialect> {
+        self.dialect.filter(|dialect| !dialect.supports(feature))
+    }
+
+    /// Checks whether the `token` is a vendor-specific keyword that the lexer
+    /// produced as an identifier, since the chosen dialect doesn't accept
+    /// the feature it introduces. Returns the error to report if so.
+    fn unsupported_vendor_keyword<'input>(&self, input: &'input str, token: &Token) -> Option<StatementParseError<'input>> {
+        if token.kind() != TokenKind::Identifier {
+            return None;
+        }
+
+        let Some(Keyword::VendorReserved(vendor_reserved_word)) = Keyword::lookup(token.as_string(input)) else {
+            return None;
+        };
+
+        let feature = vendor_reserved_word.feature();
+        self.dialect_rejecting(feature).map(|dialect| StatementParseError::DialectUnsupportedFeature {
+            found: token.as_string(input).into(),
+            dialect,
+            feature,
+        })
+    }
+
+    /// Checks whether the `tokens` start with the vendor-specific `!=` or
+    /// `==` operator, which the lexer produced as two separate tokens, since
+    /// the chosen dialect doesn't accept it. Returns the error to report if
+    /// so.
+    fn unsupported_operator_alias<'input>(&self, input: &'input str, tokens: &[Token]) -> Option<StatementParseError<'input>> {
+        let [first, second, ..] = tokens else {
+            return None;
+        };
+
+        if first.last_character_byte_idx != second.first_character_byte_idx || second.kind() != TokenKind::EqualsSign {
+            return None;
+        }
+
+        let feature = match first.kind() {
+            TokenKind::ExclamationMark => DialectFeature::ExclamationNotEqualsOperator,
+            TokenKind::EqualsSign => DialectFeature::DoubleEqualsOperator,
+            _ => return None,
+        };
+
+        self.dialect_rejecting(feature).map(|dialect| StatementParseError::DialectUnsupportedFeature {
+            found: input[first.first_character_byte_idx..second.last_character_byte_idx].into(),
+            dialect,
+            feature,
+        })
+    }
+
     /// Parse an optional `<as clause>`, which is colloquially known as an
     /// alias. See the example below:
     /// ```sql
     /// SELECT column_name AS alias_name
     /// FROM table_name
     /// ```
-    fn parse_as_clause_optional<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<Option<String>, StatementParseError<'input>> {
+    fn parse_as_clause_optional<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<Option<Identifier>, StatementParseError<'input>> {
         if tokens.len() < 2 {
             return Ok(None);
         }
@@ -155,11 +426,11 @@ impl Parser {
 
         *tokens = &tokens[2..];
 
-        match alias_token.kind() {
-            TokenKind::Identifier | TokenKind::NonReservedWord(..) => {
-                Ok(Some(alias.to_string()))
-            },
+        if let Some(identifier) = alias_token.as_identifier(input) {
+            return Ok(Some(identifier));
+        }
 
+        match alias_token.kind() {
             TokenKind::ReservedWord(reserved_word) => Err(StatementParseError::AsClauseUnexpectedReservedWord {
                 found: ErrorFindLocation::Position(alias),
                 reserved_word,
@@ -172,8 +443,115 @@ impl Parser {
         }
     }
 
+    /// Parses the **`FETCH FIRST`** clause.
+    ///
+    /// ```text
+    /// <fetch first clause> ::=
+    ///     FETCH { FIRST | NEXT } [ <fetch first quantity> ] { ROW | ROWS }
+    ///     { ONLY | WITH TIES }
+    ///
+    /// <fetch first quantity> ::=
+    ///       <fetch first row count>
+    ///     | <fetch first percentage>
+    ///
+    /// <fetch first percentage> ::=
+    ///     <simple value specification> PERCENT
+    /// ```
+    fn parse_clause_fetch_first<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<FetchFirstClause, StatementParseError<'input>> {
+        debug_assert_eq!(tokens.is_reserved_word(), Some(ReservedWord::Fetch));
+        let start = *tokens;
+        tokens.next();
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`FIRST` or `NEXT`",
+            });
+        }
+
+        if !tokens.consume_non_reserved_word(NonReservedWord::First) && !tokens.consume_non_reserved_word(NonReservedWord::Next) {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`FIRST` or `NEXT`",
+            });
+        }
+
+        // An omitted quantity spans the position where it would have been.
+        let quantity_start = *tokens;
+        let mut quantity = FetchFirstQuantity {
+            value: SimpleValueSpecification::LiteralUnsigned(1),
+            is_percent: false,
+            span: tokens.first().map_or_else(Span::default, |token| Span::new(token.first_character_byte_idx, token.first_character_byte_idx)),
+        };
+
+        if let Some(TokenKind::UnsignedInteger(integer)) = tokens.first().map(|token| token.kind()) {
+            tokens.next();
+            quantity.value = SimpleValueSpecification::LiteralUnsigned(integer);
+            quantity.is_percent = tokens.consume_reserved_word(ReservedWord::Percent);
+            quantity.span = consumed_span(quantity_start, tokens);
+        }
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`ROW` or `ROWS`",
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::Rows) && !tokens.consume_reserved_word(ReservedWord::Row) {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`ROW` or `ROWS`",
+            });
+        }
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`ONLY` or `WITH TIES`",
+            });
+        }
+
+        let with_ties = if tokens.consume_reserved_word(ReservedWord::Only) {
+            false
+        } else if tokens.consume_reserved_word(ReservedWord::With) {
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    expected: "`TIES` after `WITH`",
+                });
+            }
+
+            if !tokens.consume_non_reserved_word(NonReservedWord::Ties) {
+                return Err(StatementParseError::FetchFirstClauseUnexpectedToken {
+                    found: tokens[0].as_string(input).into(),
+                    token_kind: tokens[0].kind(),
+                    expected: "`TIES` after `WITH`",
+                });
+            }
+
+            true
+        } else {
+            return Err(StatementParseError::FetchFirstClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`ONLY` or `WITH TIES`",
+            });
+        };
+
+        Ok(FetchFirstClause {
+            quantity,
+            origin: FetchFirstClauseOrigin::FetchFirst,
+            with_ties,
+            span: consumed_span(start, tokens),
+        })
+    }
+
     /// Parses the **`ORDER BY`** statement.
     fn parse_clause_order_by<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<OrderByClause, StatementParseError<'input>> {
+        let start = *tokens;
         self.parse_clause_order_by_reserved_word_order(input, tokens)?;
         self.parse_clause_order_by_reserved_word_by(input, tokens)?;
 
@@ -188,15 +566,18 @@ impl Parser {
                 *tokens = &tokens[1..];
             }
 
+            let sort_specification_start = *tokens;
             sort_specification_list.push(SortSpecification {
                 sort_key: self.parse_column_reference(input, tokens)?,
                 ordering_specification: self.parse_sort_specification_optional(tokens),
+                span: consumed_span(sort_specification_start, tokens),
             });
         }
 
         debug_assert!(!sort_specification_list.is_empty());
         Ok(OrderByClause {
-            sort_specification_list
+            sort_specification_list,
+            span: consumed_span(start, tokens),
         })
     }
 
@@ -238,6 +619,54 @@ impl Parser {
         Ok(())
     }
 
+    /// Parses the **`OFFSET`** clause.
+    ///
+    /// ```text
+    /// <result offset clause> ::=
+    ///     OFFSET <offset row count> { ROW | ROWS }
+    /// ```
+    fn parse_clause_result_offset<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<ResultOffsetClause, StatementParseError<'input>> {
+        debug_assert_eq!(tokens.is_reserved_word(), Some(ReservedWord::Offset));
+        let start = *tokens;
+        tokens.next();
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::ResultOffsetClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "a number",
+            });
+        }
+
+        let TokenKind::UnsignedInteger(integer) = tokens[0].kind() else {
+            return Err(StatementParseError::ResultOffsetClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "a number",
+            });
+        };
+        tokens.next();
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::ResultOffsetClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`ROW` or `ROWS`",
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::Rows) && !tokens.consume_reserved_word(ReservedWord::Row) {
+            return Err(StatementParseError::ResultOffsetClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`ROW` or `ROWS`",
+            });
+        }
+
+        Ok(ResultOffsetClause {
+            offset_row_count: SimpleValueSpecification::LiteralUnsigned(integer),
+            span: consumed_span(start, tokens),
+        })
+    }
+
     fn parse_clause_where<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<WhereClause, StatementParseError<'input>> {
         if is_end_of_statement(tokens) {
             return Err(StatementParseError::WhereClauseUnexpectedEndOfFile {
@@ -252,10 +681,80 @@ impl Parser {
             });
         }
 
+        let start = *tokens;
         *tokens = &tokens[1..];
 
         Ok(WhereClause {
-            search_condition: self.parse_value_expression(input, tokens)?
+            search_condition: self.parse_value_expression(input, tokens)?,
+            span: consumed_span(start, tokens),
+        })
+    }
+
+    /// Parses the `<window clause>`.
+    ///
+    /// ```text
+    /// <window clause> ::=
+    ///     WINDOW <window definition list>
+    ///
+    /// <window definition> ::=
+    ///     <new window name> AS <window specification>
+    /// ```
+    fn parse_clause_window<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<WindowClause, StatementParseError<'input>> {
+        debug_assert_eq!(tokens.is_reserved_word(), Some(ReservedWord::Window));
+        let start = *tokens;
+        tokens.next();
+
+        let mut window_definitions = Vec::new();
+
+        loop {
+            let definition_start = *tokens;
+
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::WindowClauseUnexpectedEndOfFile {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    expected: "a window name",
+                });
+            }
+
+            let Some(name) = tokens.consume_identifier(input) else {
+                return Err(StatementParseError::WindowClauseUnexpectedToken {
+                    found: tokens[0].as_string(input).into(),
+                    token_kind: tokens[0].kind(),
+                    expected: "a window name",
+                });
+            };
+
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::WindowClauseUnexpectedEndOfFile {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    expected: "`AS` after the window name",
+                });
+            }
+
+            if !tokens.consume_reserved_word(ReservedWord::As) {
+                return Err(StatementParseError::WindowClauseUnexpectedToken {
+                    found: tokens[0].as_string(input).into(),
+                    token_kind: tokens[0].kind(),
+                    expected: "`AS` after the window name",
+                });
+            }
+
+            window_definitions.push(WindowDefinition {
+                name,
+                specification: self.parse_window_specification(input, tokens)?,
+                span: consumed_span(definition_start, tokens),
+            });
+
+            if tokens.first().map(|token| token.kind()) != Some(TokenKind::Comma) {
+                break;
+            }
+
+            tokens.next();
+        }
+
+        Ok(WindowClause {
+            window_definitions,
+            span: consumed_span(start, tokens),
         })
     }
 
@@ -266,19 +765,19 @@ impl Parser {
             });
         }
 
-        if !matches!(tokens[0].kind(), TokenKind::Identifier | TokenKind::NonReservedWord(_)) {
+        let Some(identifier) = tokens[0].as_identifier(input) else {
             return Err(StatementParseError::ColumnReferenceUnexpectedToken {
                 found: tokens[0].as_string(input).into(),
                 token_kind: tokens[0].kind(),
             });
-        }
+        };
 
-        let mut identifier_chain = vec![tokens[0].as_string(input).to_owned()];
+        let mut identifier_chain = vec![identifier];
         *tokens = &tokens[1..];
 
         while tokens.len() >= 2 && tokens[0].kind() == TokenKind::FullStop {
-            if matches!(tokens[1].kind(), TokenKind::Identifier | TokenKind::NonReservedWord(..)) {
-                identifier_chain.push(tokens[1].as_string(input).to_owned());
+            if let Some(identifier) = tokens[1].as_identifier(input) {
+                identifier_chain.push(identifier);
             }
             *tokens = &tokens[2..];
         }
@@ -315,10 +814,12 @@ impl Parser {
         // Store this in case the right parenthesis is missing
         let left_paren = tokens[0].as_string(input);
 
+        let start = *tokens;
         *tokens = &tokens[1..];
 
         let mut constructor = ContextuallyTypedRowValueConstructor {
             elements: Vec::new(),
+            span: Span::default(),
         };
 
         loop {
@@ -348,6 +849,7 @@ impl Parser {
             }
         }
 
+        constructor.span = consumed_span(start, tokens);
         Ok(constructor)
     }
 
@@ -377,8 +879,8 @@ impl Parser {
         ))
     }
 
-    /// After the `VALUES` keyword was consumed, this function is invoked by
-    /// [`Parser::parse_insert_columns_and_source()`].
+    /// Invoked by [`Parser::parse_insert_columns_and_source()`] at the
+    /// `VALUES` keyword.
     ///
     /// ```text
     /// <contextually typed table value constructor> ::=
@@ -389,8 +891,13 @@ impl Parser {
     ///     [ { <comma> <contextually typed row value expression> }... ]
     /// ```
     fn parse_contextually_typed_table_value_constructor<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<ContextuallyTypedTableValueConstructor, StatementParseError<'input>> {
+        debug_assert_eq!(tokens.is_reserved_word(), Some(ReservedWord::Values));
+        let start = *tokens;
+        tokens.next();
+
         let mut constructor = ContextuallyTypedTableValueConstructor {
             values: Vec::new(),
+            span: Span::default(),
         };
 
         loop {
@@ -407,6 +914,7 @@ impl Parser {
             *tokens = &tokens[1..];
         }
 
+        constructor.span = consumed_span(start, tokens);
         Ok(constructor)
     }
 
@@ -423,8 +931,8 @@ impl Parser {
             });
         }
 
-        if let Some(identifier) = tokens.consume_identifier_owned(input) {
-            Ok(Some(identifier))
+        if let Some(identifier) = tokens.consume_identifier(input) {
+            Ok(Some(identifier.value))
         } else {
             if let TokenKind::ReservedWord(reserved_word) = tokens[0].kind() {
                 return Err(StatementParseError::CorrelationNameUnexpectedReservedWord {
@@ -440,43 +948,368 @@ impl Parser {
         }
     }
 
-    /// Parse the `<data type>` when the token **`VARCHAR`** was consumed.
-    fn parse_data_type_varchar<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
-        let left_paren = self.parse_data_type_varchar_left_paren(input, tokens)?;
-        let length = self.parse_data_type_varchar_length(input, tokens)?;
-        self.parse_data_type_varchar_right_paren(input, tokens, left_paren, length)?;
-
-        Ok(DataType::Predefined(PredefinedType::CharacterString {
-            definition: CharacterStringType::Varying { length },
-            character_set: None
-        }))
-    }
-
-    /// Parse the left parenthesis `(` of a `VARCHAR` data type.
-    fn parse_data_type_varchar_left_paren<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<&'input str, StatementParseError<'input>> {
+    /// Parses a `<data type>`, e.g. of a column definition or the target of a
+    /// `<cast specification>`.
+    fn parse_data_type<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
         if is_end_of_statement(tokens) {
-            return Err(StatementParseError::DataTypeVarcharUnexpectedEndOfFileExpectedLeftParen {
-                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            return Err(StatementParseError::DataTypeUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input }
             });
         }
 
-        if tokens[0].kind() != TokenKind::LeftParenthesis {
-            return Err(StatementParseError::DataTypeVarcharUnexpectedTokenExpectedLeftParen {
-                found: ErrorFindLocation::EndOfFile { complete_input: input },
-                token_kind: tokens[0].kind(),
-            });
-        }
+        let data_type_reserved_word_token = tokens[0];
+        tokens.next();
 
-        let left_parenthesis = tokens[0].as_string(input);
+        let TokenKind::ReservedWord(data_type_reserved_word) = data_type_reserved_word_token.kind() else {
+            return Err(StatementParseError::DataTypeUnexpectedTokenExpectedKeyword {
+                found: data_type_reserved_word_token.as_string(input).into(),
+                token_kind: data_type_reserved_word_token.kind(),
+            })
+        };
 
-        *tokens = &tokens[1..];
-        Ok(left_parenthesis)
-    }
+        Ok(match data_type_reserved_word {
+            ReservedWord::Int | ReservedWord::Integer => DataType::Predefined(
+                PredefinedType::Numeric(NumericType::Integer)
+            ),
 
-    /// Parse the length of the `VARCHAR` data type.
-    fn parse_data_type_varchar_length<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<usize, StatementParseError<'input>> {
-        if is_end_of_statement(tokens) {
-            return Err(StatementParseError::DataTypeVarcharUnexpectedEndOfFileExpectedLength {
+            ReservedWord::Varchar => self.parse_data_type_varchar(input, tokens)?,
+
+            ReservedWord::Binary => self.parse_data_type_binary(input, tokens)?,
+
+            ReservedWord::Varbinary => DataType::Predefined(PredefinedType::BinaryString(
+                BinaryStringType::Varying {
+                    length: self.parse_data_type_length(input, tokens, "VARBINARY")?
+                }
+            )),
+
+            ReservedWord::Blob => DataType::Predefined(PredefinedType::BinaryString(
+                BinaryStringType::LargeObject {
+                    length: self.parse_data_type_large_object_length_optional(input, tokens, "BLOB")?
+                }
+            )),
+
+            ReservedWord::Char | ReservedWord::Character => self.parse_data_type_character(input, tokens)?,
+
+            ReservedWord::Clob => DataType::Predefined(PredefinedType::CharacterString {
+                definition: CharacterStringType::LargeObject {
+                    length: self.parse_data_type_large_object_length_optional(input, tokens, "CLOB")?
+                },
+                character_set: None,
+            }),
+
+            ReservedWord::National => {
+                if !tokens.consume_reserved_word(ReservedWord::Character) && !tokens.consume_reserved_word(ReservedWord::Char) {
+                    return Err(StatementParseError::DataTypeNationalExpectedCharacter {
+                        found: match tokens.first() {
+                            Some(token) => token.as_string(input).into(),
+                            None => ErrorFindLocation::EndOfFile { complete_input: input },
+                        },
+                    });
+                }
+
+                self.parse_data_type_national_character(input, tokens)?
+            }
+
+            ReservedWord::Nchar => self.parse_data_type_national_character(input, tokens)?,
+
+            ReservedWord::Nclob => DataType::Predefined(PredefinedType::NationalCharacterString(
+                NationalCharacterStringType::LargeObject {
+                    length: self.parse_data_type_large_object_length_optional(input, tokens, "NCLOB")?
+                }
+            )),
+
+            ReservedWord::Bit => self.parse_data_type_bit(input, tokens)?,
+
+            ReservedWord::Boolean => DataType::Predefined(
+                PredefinedType::Boolean
+            ),
+
+            _ => return Err(StatementParseError::DataTypeUnknownKeyword {
+                found: data_type_reserved_word_token.as_string(input).into(),
+                reserved_word: data_type_reserved_word,
+            })
+        })
+    }
+
+    /// Parse the `<binary string type>` when the token **`BINARY`** was
+    /// consumed.
+    fn parse_data_type_binary<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
+        let binary_string_type = if tokens.consume_reserved_word(ReservedWord::Varying) {
+            BinaryStringType::Varying {
+                length: self.parse_data_type_length(input, tokens, "BINARY VARYING")?
+            }
+        } else if tokens.consume_reserved_word(ReservedWord::Large) {
+            self.parse_data_type_large_object_keyword(input, tokens)?;
+            BinaryStringType::LargeObject {
+                length: self.parse_data_type_large_object_length_optional(input, tokens, "BINARY LARGE OBJECT")?
+            }
+        } else {
+            BinaryStringType::Fixed {
+                length: self.parse_data_type_length_optional(input, tokens, "BINARY")?.unwrap_or(1)
+            }
+        };
+
+        Ok(DataType::Predefined(PredefinedType::BinaryString(binary_string_type)))
+    }
+
+    /// Parse the `<bit string type>` when the token **`BIT`** was consumed.
+    fn parse_data_type_bit<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
+        let bit_string_type = if tokens.consume_reserved_word(ReservedWord::Varying) {
+            BitStringType::Varying {
+                length: self.parse_data_type_length(input, tokens, "BIT VARYING")?
+            }
+        } else {
+            BitStringType::Fixed {
+                length: self.parse_data_type_length_optional(input, tokens, "BIT")?.unwrap_or(1)
+            }
+        };
+
+        Ok(DataType::Predefined(PredefinedType::BitString(bit_string_type)))
+    }
+
+    /// Parse the **`OBJECT`** keyword after **`LARGE`** was consumed, e.g. in
+    /// `BINARY LARGE OBJECT`.
+    fn parse_data_type_large_object_keyword<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<(), StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeLargeObjectUnexpectedEndOfFileExpectedObject {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if !tokens.consume_non_reserved_word(NonReservedWord::Object) {
+            return Err(StatementParseError::DataTypeLargeObjectUnexpectedTokenExpectedObject {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parse the `<character string type>` when the token **`CHARACTER`** or
+    /// **`CHAR`** was consumed.
+    fn parse_data_type_character<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
+        let definition = if tokens.consume_reserved_word(ReservedWord::Varying) {
+            CharacterStringType::Varying {
+                length: self.parse_data_type_length(input, tokens, "CHARACTER VARYING")?
+            }
+        } else if tokens.consume_reserved_word(ReservedWord::Large) {
+            self.parse_data_type_large_object_keyword(input, tokens)?;
+            CharacterStringType::LargeObject {
+                length: self.parse_data_type_large_object_length_optional(input, tokens, "CHARACTER LARGE OBJECT")?
+            }
+        } else {
+            CharacterStringType::Fixed {
+                length: self.parse_data_type_length_optional(input, tokens, "CHARACTER")?.unwrap_or(1)
+            }
+        };
+
+        Ok(DataType::Predefined(PredefinedType::CharacterString {
+            definition,
+            character_set: None,
+        }))
+    }
+
+    /// Parse the `<left paren> <length> <right paren>` of a data type, e.g.
+    /// the `(16)` of `VARBINARY(16)`.
+    fn parse_data_type_length<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        data_type_name: &'static str,
+    ) -> Result<usize, StatementParseError<'input>> {
+        self.parse_data_type_length_with_multiplier(input, tokens, data_type_name, false)
+            .map(|length| length.value)
+    }
+
+    /// Parse the optional `[ <left paren> <length> <right paren> ]` of a data
+    /// type, e.g. both `BINARY` and `BINARY(16)` are valid.
+    fn parse_data_type_length_optional<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        data_type_name: &'static str,
+    ) -> Result<Option<usize>, StatementParseError<'input>> {
+        if tokens.first().map(|token| token.kind()) != Some(TokenKind::LeftParenthesis) {
+            return Ok(None);
+        }
+
+        self.parse_data_type_length(input, tokens, data_type_name).map(Some)
+    }
+
+    /// Parse the optional `[ <left paren> <large object length> <right paren> ]`
+    /// of a large object data type, e.g. `CLOB`, `CLOB(512)` or `CLOB(2M)`.
+    fn parse_data_type_large_object_length_optional<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        data_type_name: &'static str,
+    ) -> Result<Option<LargeObjectLength>, StatementParseError<'input>> {
+        if tokens.first().map(|token| token.kind()) != Some(TokenKind::LeftParenthesis) {
+            return Ok(None);
+        }
+
+        self.parse_data_type_length_with_multiplier(input, tokens, data_type_name, true).map(Some)
+    }
+
+    /// Parse the parenthesized length of a data type, which can optionally be
+    /// followed by a `<multiplier>` when `allow_multiplier` is set.
+    fn parse_data_type_length_with_multiplier<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        data_type_name: &'static str,
+        allow_multiplier: bool,
+    ) -> Result<LargeObjectLength, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeUnexpectedEndOfFileExpectedLeftParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                data_type_name,
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::LeftParenthesis {
+            return Err(StatementParseError::DataTypeUnexpectedTokenExpectedLeftParen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                data_type_name,
+            });
+        }
+
+        let left_paren = tokens[0].as_string(input);
+        *tokens = &tokens[1..];
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeUnexpectedEndOfFileExpectedLength {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                data_type_name,
+            });
+        }
+
+        let TokenKind::UnsignedInteger(length) = tokens[0].kind() else {
+            return Err(StatementParseError::DataTypeUnexpectedTokenExpectedLength {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                data_type_name,
+            });
+        };
+        if length == 0 {
+            return Err(StatementParseError::DataTypeLengthZero {
+                found: tokens[0].as_string(input).into(),
+                data_type_name,
+            });
+        }
+
+        let length = length as usize;
+        *tokens = &tokens[1..];
+
+        let multiplier = if allow_multiplier {
+            match tokens.is_non_reserved_word() {
+                Some(NonReservedWord::K) => Some(LargeObjectLengthMultiplier::K),
+                Some(NonReservedWord::M) => Some(LargeObjectLengthMultiplier::M),
+                Some(NonReservedWord::G) => Some(LargeObjectLengthMultiplier::G),
+                Some(NonReservedWord::T) => Some(LargeObjectLengthMultiplier::T),
+                Some(NonReservedWord::P) => Some(LargeObjectLengthMultiplier::P),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if multiplier.is_some() {
+            *tokens = &tokens[1..];
+        }
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeUnexpectedEndOfFileExpectedRightParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                data_type_name,
+                length,
+                should_be_matching: ErrorTokenShouldBeMatching {
+                    found: left_paren,
+                    token_kind: TokenKind::LeftParenthesis
+                },
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::RightParenthesis {
+            return Err(StatementParseError::DataTypeUnexpectedTokenExpectedRightParen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                data_type_name,
+                length,
+                should_be_matching: ErrorTokenShouldBeMatching {
+                    found: left_paren,
+                    token_kind: TokenKind::LeftParenthesis
+                },
+            });
+        }
+
+        *tokens = &tokens[1..];
+        Ok(LargeObjectLength {
+            value: length,
+            multiplier,
+        })
+    }
+
+    /// Parse the `<national character string type>` when the token
+    /// **`NCHAR`**, or the tokens **`NATIONAL CHARACTER`** were consumed.
+    fn parse_data_type_national_character<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
+        let definition = if tokens.consume_reserved_word(ReservedWord::Varying) {
+            NationalCharacterStringType::Varying {
+                length: self.parse_data_type_length(input, tokens, "NATIONAL CHARACTER VARYING")?
+            }
+        } else if tokens.consume_reserved_word(ReservedWord::Large) {
+            self.parse_data_type_large_object_keyword(input, tokens)?;
+            NationalCharacterStringType::LargeObject {
+                length: self.parse_data_type_large_object_length_optional(input, tokens, "NATIONAL CHARACTER LARGE OBJECT")?
+            }
+        } else {
+            NationalCharacterStringType::Fixed {
+                length: self.parse_data_type_length_optional(input, tokens, "NATIONAL CHARACTER")?.unwrap_or(1)
+            }
+        };
+
+        Ok(DataType::Predefined(PredefinedType::NationalCharacterString(definition)))
+    }
+
+    /// Parse the `<data type>` when the token **`VARCHAR`** was consumed.
+    fn parse_data_type_varchar<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DataType, StatementParseError<'input>> {
+        let left_paren = self.parse_data_type_varchar_left_paren(input, tokens)?;
+        let length = self.parse_data_type_varchar_length(input, tokens)?;
+        self.parse_data_type_varchar_right_paren(input, tokens, left_paren, length)?;
+
+        Ok(DataType::Predefined(PredefinedType::CharacterString {
+            definition: CharacterStringType::Varying { length },
+            character_set: None
+        }))
+    }
+
+    /// Parse the left parenthesis `(` of a `VARCHAR` data type.
+    fn parse_data_type_varchar_left_paren<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<&'input str, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeVarcharUnexpectedEndOfFileExpectedLeftParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::LeftParenthesis {
+            return Err(StatementParseError::DataTypeVarcharUnexpectedTokenExpectedLeftParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        let left_parenthesis = tokens[0].as_string(input);
+
+        *tokens = &tokens[1..];
+        Ok(left_parenthesis)
+    }
+
+    /// Parse the length of the `VARCHAR` data type.
+    fn parse_data_type_varchar_length<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<usize, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DataTypeVarcharUnexpectedEndOfFileExpectedLength {
                 found: ErrorFindLocation::EndOfFile { complete_input: input },
             });
         }
@@ -484,7 +1317,7 @@ impl Parser {
         let length: usize = match tokens[0].kind() {
             TokenKind::UnsignedInteger(integer) => integer as _,
             _ => return Err(StatementParseError::DataTypeVarcharUnexpectedTokenExpectedLength {
-                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                found: ErrorFindLocation::Position(tokens[0].as_string(input)),
                 token_kind: tokens[0].kind(),
             })
         };
@@ -539,11 +1372,12 @@ impl Parser {
 
         match tokens[0].kind() {
             TokenKind::ReservedWord(ReservedWord::Values) => {
-                *tokens = &tokens[1..];
+                let constructor = self.parse_contextually_typed_table_value_constructor(input, tokens)?;
 
                 Ok(InsertColumnsAndSource::FromConstructor {
                     insert_column_list: None,
-                    constructor: self.parse_contextually_typed_table_value_constructor(input, tokens)?,
+                    span: constructor.span,
+                    constructor,
                 })
             }
 
@@ -587,45 +1421,139 @@ impl Parser {
     /// Parses a statement and includes the tokens it has received from the
     /// lexer.
     pub fn parse_statement_extended<'input>(&self, input: &'input str) -> (StatementResult<'input>, Vec<Token>) {
-        let all_tokens: Vec<_> = Lexer::new(input).collect();
+        let all_tokens: Vec<_> = self.lexer(input).collect();
+        (self.parse_statement_tokens(input, &all_tokens), all_tokens)
+    }
+
+    /// Parses a script of statements separated by semicolons, such as a
+    /// schema file. Every statement is returned together with the byte range
+    /// it spans in the `input`, excluding the semicolon. Empty statements are
+    /// skipped.
+    ///
+    /// The script is split at the token level, so semicolons inside strings
+    /// and comments don't end a statement. After an erroneous statement,
+    /// parsing resumes at the next statement, so that all errors in a script
+    /// are reported in one pass.
+    pub fn parse_script<'input>(&self, input: &'input str) -> Vec<(Range<usize>, StatementResult<'input>)> {
+        let mut statements = Vec::new();
+        let mut start_byte_idx = 0;
+
+        'lexing: while start_byte_idx < input.len() {
+            let all_tokens: Vec<_> = self.lexer(input).starting_at(start_byte_idx).collect();
+
+            for tokens in all_tokens.split(|token| token.kind() == TokenKind::Semicolon) {
+                let (Some(first_token), Some(last_token)) = (tokens.first(), tokens.last()) else {
+                    continue;
+                };
+
+                let result = self.parse_statement_tokens(&input[..last_token.last_character_byte_idx], tokens);
+
+                let TokenKind::IllegalToken(..) = last_token.kind() else {
+                    statements.push((first_token.first_character_byte_idx..last_token.last_character_byte_idx, result));
+                    continue;
+                };
+
+                // The lexer stops after an illegal token, so lexing has to be
+                // resumed at the statement after it.
+                let (end_byte_idx, next_start_byte_idx) = self.skip_rest_of_statement(input, last_token.last_character_byte_idx);
+                statements.push((first_token.first_character_byte_idx..end_byte_idx, result));
+
+                match next_start_byte_idx {
+                    Some(next_start_byte_idx) => {
+                        start_byte_idx = next_start_byte_idx;
+                        continue 'lexing;
+                    }
+                    None => break 'lexing,
+                }
+            }
+
+            break;
+        }
+
+        statements
+    }
+
+    /// Skips the tokens of a statement after an illegal token that ends at
+    /// `byte_idx`, up to the semicolon that ends the statement. Returns the
+    /// end of the statement and the start of the statement after it, if any.
+    fn skip_rest_of_statement(&self, input: &str, mut byte_idx: usize) -> (usize, Option<usize>) {
+        let mut end_byte_idx = byte_idx;
+
+        'lexing: loop {
+            for token in self.lexer(input).starting_at(byte_idx) {
+                match token.kind() {
+                    TokenKind::Semicolon => return (end_byte_idx, Some(token.last_character_byte_idx)),
+                    TokenKind::IllegalToken(..) if token.last_character_byte_idx > byte_idx => {
+                        end_byte_idx = token.last_character_byte_idx;
+                        byte_idx = token.last_character_byte_idx;
+                        continue 'lexing;
+                    }
+                    _ => end_byte_idx = token.last_character_byte_idx,
+                }
+            }
+
+            return (end_byte_idx, None);
+        }
+    }
+
+    /// Creates the [`Lexer`] for the `input`, which recognizes the keywords
+    /// and operators of the chosen dialect.
+    fn lexer<'input>(&self, input: &'input str) -> Lexer<'input> {
+        let lexer = Lexer::new(input);
+        match self.dialect {
+            Some(dialect) => lexer.with_dialect(dialect),
+            None => lexer,
+        }
+    }
 
-        let Some(first_token) = all_tokens.get(0) else {
-            return (Err(StatementParseError::EmptyInput), all_tokens);
+    /// Parses a single statement from its tokens, which may end with a
+    /// semicolon.
+    fn parse_statement_tokens<'input>(&self, input: &'input str, all_tokens: &[Token]) -> StatementResult<'input> {
+        let Some(first_token) = all_tokens.first() else {
+            return Err(StatementParseError::EmptyInput);
         };
 
+        // The lexer stops after an illegal token, so only the last token has
+        // to be checked.
+        if let Some(last_token) = all_tokens.last() {
+            if let TokenKind::IllegalToken(reason) = last_token.kind() {
+                return Err(lexical_error(input, last_token, reason, self.dialect));
+            }
+        }
+
         let TokenKind::ReservedWord(reserved_word) = first_token.kind() else {
-            return (Err(StatementParseError::StartNotAToken {
+            return Err(StatementParseError::StartNotAToken {
                 found: first_token.as_string(input).into(),
                 token_kind: first_token.kind()
-            }), all_tokens);
+            });
         };
 
         let tokens = &all_tokens[1..];
 
-        (match reserved_word {
-            ReservedWord::Create => self.parse_statement_create(input, tokens),
-            ReservedWord::Insert => self.parse_statement_insert(input, tokens),
-            ReservedWord::Select => self.parse_statement_select(input, tokens),
+        match reserved_word {
+            ReservedWord::Create => self.parse_statement_create(input, all_tokens, tokens),
+            ReservedWord::Insert => self.parse_statement_insert(input, all_tokens, tokens),
+            ReservedWord::Select => self.parse_statement_select(input, all_tokens, tokens),
 
             _ => Err(StatementParseError::StartUnknownKeyword {
                 found: first_token.as_string(input).into(),
                 reserved_word,
             })
-        }, all_tokens)
+        }
     }
 
     /// Parses the rest of the statement when the first token was the
     /// **`CREATE`** identifier keyword.
-    fn parse_statement_create<'input>(&self, input: &'input str, tokens: &[Token]) -> StatementResult<'input> {
+    fn parse_statement_create<'input>(&self, input: &'input str, statement: &[Token], tokens: &[Token]) -> StatementResult<'input> {
         if tokens.is_empty() {
             return Err(StatementParseError::EofCreateKeywordOnlyToken(input))
         }
 
         match tokens[0].kind() {
             // TokenKind::NonReservedWord(NonReservedWord::Database) => return self.parse_statement_create_database(input, tokens),
-            TokenKind::NonReservedWord(NonReservedWord::Schema) => return self.parse_statement_create_schema(input, tokens),
-            TokenKind::ReservedWord(ReservedWord::Table) => return self.parse_statement_create_table(input, tokens),
-            TokenKind::NonReservedWord(NonReservedWord::View) => return self.parse_statement_create_view(input, tokens),
+            TokenKind::NonReservedWord(NonReservedWord::Schema) => self.parse_statement_create_schema(input, tokens),
+            TokenKind::ReservedWord(ReservedWord::Table) => self.parse_statement_create_table(input, statement, tokens),
+            TokenKind::NonReservedWord(NonReservedWord::View) => self.parse_statement_create_view(input, tokens),
 
             _ => Err(StatementParseError::CreateStatementUnexpectedFollowUpToken {
                 found: tokens[0].as_string(input).into(),
@@ -639,17 +1567,17 @@ impl Parser {
     fn parse_statement_create_schema<'input>(&self, input: &'input str, tokens: &[Token]) -> StatementResult<'input> {
         _ = input;
         _ = tokens;
-        return Err(StatementParseError::UnsupportedFeature {
+        Err(StatementParseError::UnsupportedFeature {
             feature_name: "CREATE SCHEMA",
             feature_description: "Creating new schemas",
             found: tokens[0].as_string(input).into(),
             token_kind: tokens[0].kind(),
-        });
+        })
     }
 
     /// Parses the rest of the statement when the first two tokens were
     /// **`CREATE TABLE`**.
-    fn parse_statement_create_table<'input>(&self, input: &'input str, mut tokens: &[Token]) -> StatementResult<'input> {
+    fn parse_statement_create_table<'input>(&self, input: &'input str, statement: &[Token], mut tokens: &[Token]) -> StatementResult<'input> {
         // Consume the `TABLE` keyword.
         tokens = &tokens[1..];
 
@@ -659,21 +1587,20 @@ impl Parser {
             });
         }
 
-        let table_name = match tokens[0].kind() {
-            TokenKind::ReservedWord(reserved_word) => return Err(StatementParseError::CreateTableStatementExpectedTableNameIdentifierUnexpectedKeyword {
+        let table_name = match (tokens[0].kind(), tokens[0].as_identifier(input)) {
+            (TokenKind::ReservedWord(reserved_word), _) => return Err(StatementParseError::CreateTableStatementExpectedTableNameIdentifierUnexpectedKeyword {
                 found: tokens[0].as_string(input).into(),
                 reserved_word,
             }),
 
-            TokenKind::Identifier | TokenKind::NonReservedWord(..) => {
-                let name = tokens[0].as_string(input);
+            (_, Some(identifier)) => {
                 tokens = &tokens[1..];
-                name
+                identifier
             },
 
-            _ => return Err(StatementParseError::CreateTableStatementExpectedTableNameIdentifierUnexpectedToken {
+            (token_kind, None) => return Err(StatementParseError::CreateTableStatementExpectedTableNameIdentifierUnexpectedToken {
                 found: tokens[0].as_string(input).into(),
-                token_kind: tokens[0].kind()
+                token_kind,
             })
         };
 
@@ -692,8 +1619,9 @@ impl Parser {
         }
 
         let mut definition = TableDefinition {
-            table_name: table_name.to_owned(),
-            elements: Vec::new()
+            table_name,
+            elements: Vec::new(),
+            span: Span::default(),
         };
 
         self.parse_table_elements(input, &mut tokens, &mut definition)?;
@@ -705,6 +1633,8 @@ impl Parser {
             });
         }
 
+        definition.span = consumed_span(statement, tokens);
+
         Ok(SqlExecutableStatement::Schema(
             SqlSchemaStatement::Definition(
                 SqlSchemaDefinitionStatement::Table(
@@ -719,17 +1649,17 @@ impl Parser {
     fn parse_statement_create_view<'input>(&self, input: &'input str, tokens: &[Token]) -> StatementResult<'input> {
         _ = input;
         _ = tokens;
-        return Err(StatementParseError::UnsupportedFeature {
+        Err(StatementParseError::UnsupportedFeature {
             feature_name: "CREATE VIEW",
             feature_description: "Creating new views",
             found: tokens[0].as_string(input).into(),
             token_kind: tokens[0].kind(),
-        });
+        })
     }
 
     /// Parses the rest of the statement when the first token was the
     /// **`INSERT`** reserved word.
-    fn parse_statement_insert<'input>(&self, input: &'input str, tokens: &[Token]) -> StatementResult<'input> {
+    fn parse_statement_insert<'input>(&self, input: &'input str, statement: &[Token], tokens: &[Token]) -> StatementResult<'input> {
         if is_end_of_statement(tokens) {
             return Err(StatementParseError::InsertStatementEndOfFile {
                 found: ErrorFindLocation::EndOfFile { complete_input: input }
@@ -737,7 +1667,7 @@ impl Parser {
         }
 
         match tokens[0].kind() {
-            TokenKind::ReservedWord(ReservedWord::Into) => self.parse_statement_insert_into(input, &tokens[1..]),
+            TokenKind::ReservedWord(ReservedWord::Into) => self.parse_statement_insert_into(input, statement, &tokens[1..]),
 
             TokenKind::ReservedWord(reserved_word) => Err(StatementParseError::InsertStatementUnexpectedKeyword {
                 found: tokens[0].as_string(input).into(),
@@ -753,21 +1683,20 @@ impl Parser {
 
     /// Parses the rest of the statement when the first tokens were the
     /// **`INSERT INTO`** identifier keywords.
-    fn parse_statement_insert_into<'input>(&self, input: &'input str, mut tokens: &[Token]) -> StatementResult<'input> {
+    fn parse_statement_insert_into<'input>(&self, input: &'input str, statement: &[Token], mut tokens: &[Token]) -> StatementResult<'input> {
         if is_end_of_statement(tokens) {
             return Err(StatementParseError::InsertIntoStatementEndOfFile {
                 found: ErrorFindLocation::EndOfFile { complete_input: input },
             });
         }
 
-        if !matches!(tokens[0].kind(), TokenKind::Identifier | TokenKind::NonReservedWord(..)) {
+        let Some(table_name) = tokens[0].as_identifier(input) else {
             return Err(StatementParseError::InsertIntoStatementUnexpectedToken {
                 found: tokens[0].as_string(input).into(),
                 token_kind: tokens[0].kind()
             })
-        }
+        };
 
-        let table_name = tokens[0].as_string(input);
         tokens = &tokens[1..];
 
         let insert_columns_and_source = self.parse_insert_columns_and_source(input, &mut tokens)?;
@@ -781,9 +1710,10 @@ impl Parser {
 
         let statement = InsertStatement {
             table_name: TableName {
-                table_qualifier: table_name.to_owned()
+                table_qualifier: table_name
             },
-            insert_columns_and_source
+            insert_columns_and_source,
+            span: consumed_span(statement, tokens),
         };
 
         Ok(SqlExecutableStatement::SqlDataStatement(
@@ -797,28 +1727,45 @@ impl Parser {
 
     /// Parses the rest of the statement when the first token was the
     /// **`SELECT`** identifier keyword.
-    fn parse_statement_select<'input>(&self, input: &'input str, tokens: &[Token]) -> StatementResult<'input> {
+    fn parse_statement_select<'input>(&self, input: &'input str, statement: &[Token], tokens: &[Token]) -> StatementResult<'input> {
         if tokens.is_empty() {
             return Err(StatementParseError::EofSelectKeywordOnlyToken(input))
         }
 
         match tokens[0].kind() {
-            TokenKind::ReservedWord(ReservedWord::Distinct) => return self.parse_statement_select_set_quantifier(input, &tokens[1..], SetQuantifier::Distinct),
-            TokenKind::ReservedWord(ReservedWord::All) => return self.parse_statement_select_set_quantifier(input, &tokens[1..], SetQuantifier::All),
-            _ => self.parse_statement_select_set_quantifier(input, tokens, SetQuantifier::default()),
+            TokenKind::ReservedWord(ReservedWord::Distinct) => self.parse_statement_select_set_quantifier(input, statement, &tokens[1..], SetQuantifier::Distinct),
+            TokenKind::ReservedWord(ReservedWord::All) => self.parse_statement_select_set_quantifier(input, statement, &tokens[1..], SetQuantifier::All),
+            _ => self.parse_statement_select_set_quantifier(input, statement, tokens, SetQuantifier::default()),
         }
     }
 
     /// Parses the rest of the statement when the first token was the
     /// **`SELECT`** reserved word and the `<set quantifier>` portion
     /// was parsed.
-    fn parse_statement_select_set_quantifier<'input>(&self, input: &'input str, mut tokens: &[Token], set_quantifier: SetQuantifier) -> StatementResult<'input> {
+    fn parse_statement_select_set_quantifier<'input>(&self, input: &'input str, statement: &[Token], mut tokens: &[Token], set_quantifier: SetQuantifier) -> StatementResult<'input> {
+        let mut fetch = None;
+        let mut fetch_token = None;
+
+        if let [token, next, ..] = tokens {
+            if matches!(next.kind(), TokenKind::UnsignedInteger(..) | TokenKind::LeftParenthesis) {
+                if let Some(error) = self.unsupported_vendor_keyword(input, token) {
+                    return Err(error);
+                }
+            }
+        }
+
+        if tokens.first().is_some_and(|token| token.kind() == TokenKind::VendorReservedWord(VendorReservedWord::Top)) {
+            fetch_token = Some(tokens[0]);
+            fetch = Some(self.parse_vendor_top_clause(input, &mut tokens)?);
+        }
+
         let select_list = self.parse_select_list(input, &mut tokens)?;
 
         let mut query_specification = QuerySpecification {
             select_list,
             set_quantifier,
-            table_expression: None
+            table_expression: None,
+            span: Span::default(),
         };
 
         if !is_end_of_statement(tokens) && tokens[0].kind() == TokenKind::ReservedWord(ReservedWord::From) {
@@ -827,85 +1774,105 @@ impl Parser {
             )?);
         }
 
+        query_specification.span = consumed_span(statement, tokens);
+
         let mut order_by = None;
-        let mut fetch = None;
+        let mut offset = None;
 
         while !is_end_of_statement(tokens) {
+            if let (Some(fetch), Some(fetch_token)) = (&fetch, fetch_token) {
+                if is_conflicting_with_fetch_first_clause(fetch, tokens[0].kind()) {
+                    return Err(StatementParseError::FetchFirstClauseConflicting {
+                        found: tokens[0].as_string(input).into(),
+                        conflicting_clause: fetch_token.as_string(input).into(),
+                    });
+                }
+            }
+
             match tokens[0].kind() {
                 TokenKind::ReservedWord(ReservedWord::Order) if order_by.is_none() => {
                     order_by = Some(self.parse_clause_order_by(input, &mut tokens)?);
                     continue;
                 }
 
-                TokenKind::VendorReservedWord(VendorReservedWord::Limit) if fetch.is_none() => {
-                    fetch = self.parse_vendor_limit_clause(input, &mut tokens)?;
-                    if fetch.is_some() {
-                        continue;
-                    }
+
+                TokenKind::ReservedWord(ReservedWord::Offset) if offset.is_none() && fetch.is_none() => {
+                    offset = Some(self.parse_clause_result_offset(input, &mut tokens)?);
+                    continue;
+                }
+
+                TokenKind::ReservedWord(ReservedWord::Fetch) if fetch.is_none() => {
+                    fetch_token = Some(tokens[0]);
+                    fetch = Some(self.parse_clause_fetch_first(input, &mut tokens)?);
+                    continue;
+                }
+
+                TokenKind::VendorReservedWord(VendorReservedWord::Limit) if fetch.is_none() => {
+                    fetch_token = Some(tokens[0]);
+                    fetch = Some(self.parse_vendor_limit_clause(input, &mut tokens, &mut offset)?);
+                    continue;
                 }
 
                 _ => (),
             }
 
+            if let Some(error) = self.unsupported_vendor_keyword(input, &tokens[0]) {
+                return Err(error);
+            }
+
             return Err(StatementParseError::SelectStatementUnexpectedToken{
                 found: tokens[0].as_string(input).into(),
                 token_kind: tokens[0].kind(),
             });
         }
 
+        if let (Some(FetchFirstClause { with_ties: true, .. }), None, Some(fetch_token)) = (&fetch, &order_by, fetch_token) {
+            return Err(StatementParseError::FetchFirstClauseWithTiesWithoutOrderBy {
+                found: fetch_token.as_string(input).into(),
+            });
+        }
+
         // TODO this sucks. Can't we take a shortcut without code duplication?
         Ok(SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression{
+                Box::new(QueryExpression{
                     body: QueryExpressionBody::SimpleTable(
                         SimpleTable::QuerySpecification(
                             query_specification
                         )
                     ),
                     order_by,
+                    offset,
                     fetch,
-                }
+                    span: consumed_span(statement, tokens),
+                })
             )
         ))
     }
 
-    /// Parse the table expression (FROM, WHERE, GROUP BY and HAVING).
+    /// Parse the table expression (FROM, WHERE, GROUP BY, HAVING and WINDOW).
     /// ```text
     /// <table expression> ::=
     ///     <from clause>
     ///     [ <where clause> ]
     ///     [ <group by clause> ]
     ///     [ <having clause> ]
+    ///     [ <window clause> ]
     /// ```
     fn parse_statement_select_table_expression<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<TableExpression, StatementParseError<'input>> {
+        let start = *tokens;
         let mut table_expression = TableExpression {
             from_clause: self.parse_statement_select_table_expression_from_clause(input, tokens)?,
             group_by_clause: None,
             having_clause: None,
             where_clause: None,
+            window_clause: None,
+            span: Span::default(),
         };
 
         if !is_end_of_statement(tokens) {
-            if tokens[0].kind() == TokenKind::ReservedWord(ReservedWord::Having) {
-                return Err(StatementParseError::UnsupportedFeature {
-                    feature_name: "HAVING clause",
-                    feature_description: "Filtering based on the GROUP BY clause",
-                    found: tokens[0].as_string(input).into(),
-                    token_kind: tokens[0].kind()
-                });
-            }
-
-            let mut toks = tokens.iter()
-                .map(|tok| tok.kind());
-
-            if toks.next() == Some(TokenKind::ReservedWord(ReservedWord::Group))
-                 && toks.next() == Some(TokenKind::ReservedWord(ReservedWord::By)) {
-                return Err(StatementParseError::UnsupportedFeature {
-                    feature_name: "GROUP BY clause",
-                    feature_description: "Grouping the SELECT statement columns",
-                    found: tokens[0].as_string(input).into(),
-                    token_kind: tokens[0].kind(),
-                });
+            if let Some(error) = unsupported_table_expression_clause(input, tokens) {
+                return Err(error);
             }
 
             if tokens[0].kind() == TokenKind::ReservedWord(ReservedWord::Where) {
@@ -913,8 +1880,15 @@ impl Parser {
                     self.parse_clause_where(input, tokens)?
                 );
             }
+
+            if tokens.is_reserved_word() == Some(ReservedWord::Window) {
+                table_expression.window_clause = Some(
+                    self.parse_clause_window(input, tokens)?
+                );
+            }
         }
 
+        table_expression.span = consumed_span(start, tokens);
         Ok(table_expression)
     }
 
@@ -933,12 +1907,14 @@ impl Parser {
             });
         }
 
+        let start = *tokens;
         *tokens = &tokens[1..];
 
         let mut from_clause = FromClause {
             table_references: vec![
                 self.parse_table_reference(input, tokens)?
             ],
+            span: Span::default(),
         };
 
         loop {
@@ -952,6 +1928,7 @@ impl Parser {
             from_clause.table_references.push(self.parse_table_reference(input, tokens)?);
         }
 
+        from_clause.span = consumed_span(start, tokens);
         Ok(from_clause)
     }
 
@@ -985,19 +1962,54 @@ impl Parser {
                 tokens.next();
             }
 
-            let value_expression = self.parse_value_expression(input, tokens)?;
-            let alias = self.parse_as_clause_optional(input, tokens)?;
+            sublist.push(SelectSublist::DerivedColumn(self.parse_derived_column(input, tokens)?));
+        }
+    }
+
+    /// Parses a `<derived column>` of the select list.
+    ///
+    /// ```text
+    /// <derived column> ::=
+    ///     <value expression> [ <as clause> ]
+    /// ```
+    fn parse_derived_column<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<DerivedColumn, StatementParseError<'input>> {
+        let start = *tokens;
+        let value_expression = self.parse_value_expression(input, tokens)?;
+        let alias = self.parse_as_clause_optional(input, tokens)?;
+
+        Ok(DerivedColumn {
+            value_expression,
+            alias,
+            span: consumed_span(start, tokens),
+        })
+    }
 
-            sublist.push(SelectSublist::DerivedColumn(DerivedColumn {
-                value_expression,
-                alias
-            }));
+    /// Parses the `OVER` clause that turns the `set_function` into an
+    /// `<aggregate function>` of a `<window function>`, if there is one.
+    fn parse_set_function_window_optional<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        name_token: Token,
+        set_function: SetFunctionSpecification,
+    ) -> Result<ValueExpression, StatementParseError<'input>> {
+        if tokens.is_reserved_word() != Some(ReservedWord::Over) {
+            return Ok(ValueExpression::SetFunctionSpecification(set_function));
         }
+
+        Ok(ValueExpression::WindowFunction(Box::new(
+            self.parse_window_function_over(input, tokens, name_token, WindowFunctionType::Aggregate(set_function))?
+        )))
     }
 
     /// Parses the `<set function specification>` when the **`COUNT`** keyword
     /// was consumed.
-    fn parse_set_function_specification_count<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<SetFunctionSpecification, StatementParseError<'input>> {
+    fn parse_set_function_specification_count<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        count_token: Token,
+    ) -> Result<SetFunctionSpecification, StatementParseError<'input>> {
         if is_end_of_statement(tokens) {
             return Err(StatementParseError::SetFunctionSpecificationCountUnexpectedEofExpectedLeftParen {
                 found: ErrorFindLocation::EndOfFile { complete_input: input },
@@ -1012,20 +2024,12 @@ impl Parser {
         }
 
         // Store this in case the right parenthesis is missing
-        let left_paren = tokens[0].as_string(input);
+        let left_paren_token = tokens[0];
+        let left_paren = left_paren_token.as_string(input);
         *tokens = &tokens[1..];
 
-        if is_end_of_statement(tokens) {
-            return Err(StatementParseError::SetFunctionSpecificationCountUnexpectedEofExpectedAsterisk {
-                found: ErrorFindLocation::EndOfFile { complete_input: input },
-            });
-        }
-
-        if tokens[0].kind() != TokenKind::Asterisk {
-            return Err(StatementParseError::SetFunctionSpecificationCountUnexpectedTokenExpectedAsterisk {
-                found: tokens[0].as_string(input).into(),
-                token_kind: tokens[0].kind()
-            });
+        if tokens.first().map(|token| token.kind()) != Some(TokenKind::Asterisk) {
+            return self.parse_general_set_function_operand(input, tokens, ComputationalOperation::Count, count_token, left_paren_token);
         }
 
         *tokens = &tokens[1..];
@@ -1051,9 +2055,57 @@ impl Parser {
             });
         }
 
+        let span = count_token.span().to(tokens[0].span());
         *tokens = &tokens[1..];
 
-        Ok(SetFunctionSpecification::Count)
+        Ok(SetFunctionSpecification::Count(span))
+    }
+
+    /// Parses a `<general set function>` when the keyword of the
+    /// `function_type` was consumed.
+    ///
+    /// ```text
+    /// <general set function> ::=
+    ///     <set function type>
+    ///     <left paren> [ <set quantifier> ] <value expression> <right paren>
+    /// ```
+    fn parse_general_set_function<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_type: ComputationalOperation,
+        function_token: Token,
+    ) -> Result<SetFunctionSpecification, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, function_type.as_str())?;
+        self.parse_general_set_function_operand(input, tokens, function_type, function_token, left_paren)
+    }
+
+    /// Parses the operand and `<right paren>` of a `<general set function>`,
+    /// of which the `<left paren>` was consumed.
+    fn parse_general_set_function_operand<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_type: ComputationalOperation,
+        function_token: Token,
+        left_paren: Token,
+    ) -> Result<SetFunctionSpecification, StatementParseError<'input>> {
+        let set_quantifier = if tokens.consume_reserved_word(ReservedWord::Distinct) {
+            SetQuantifier::Distinct
+        } else {
+            tokens.consume_reserved_word(ReservedWord::All);
+            SetQuantifier::All
+        };
+
+        let value_expression = self.parse_value_expression(input, tokens)?;
+        let right_paren = self.parse_function_right_paren(input, tokens, function_type.as_str(), left_paren)?;
+
+        Ok(SetFunctionSpecification::General(GeneralSetFunction {
+            function_type,
+            set_quantifier,
+            value_expression: Box::new(value_expression),
+            span: function_token.span().to(right_paren.span()),
+        }))
     }
 
     /// Parses a single table element, but does not consume a comma `,` or
@@ -1075,14 +2127,14 @@ impl Parser {
             });
         }
 
-        if !matches!(tokens[0].kind(), TokenKind::Identifier | TokenKind::NonReservedWord(..)) {
+        let start = tokens;
+        let Some(column_name) = tokens[0].as_identifier(input) else {
             return Err(StatementParseError::TableElementSingleExpectedIdentifierAsColumnName {
                 found: tokens[0].as_string(input).into(),
                 token_kind: tokens[0].kind()
             });
-        }
+        };
 
-        let column_name = tokens[0].as_string(input);
         tokens = &tokens[1..];
 
         if is_end_of_statement(tokens) {
@@ -1091,37 +2143,13 @@ impl Parser {
             });
         }
 
-        let data_type_reserved_word_token = tokens[0];
-        tokens = &tokens[1..];
-
-        let TokenKind::ReservedWord(data_type_reserved_word) = data_type_reserved_word_token.kind() else {
-            return Err(StatementParseError::TableElementSingleExpectedKeywordAsDataType {
-                found: data_type_reserved_word_token.as_string(input).into(),
-                token_kind: data_type_reserved_word_token.kind(),
-            })
-        };
-
-        let data_type = match data_type_reserved_word {
-            ReservedWord::Int | ReservedWord::Integer => DataType::Predefined(
-                PredefinedType::Numeric(NumericType::Integer)
-            ),
-
-            ReservedWord::Varchar => self.parse_data_type_varchar(input, &mut tokens)?,
-
-            ReservedWord::Boolean => DataType::Predefined(
-                PredefinedType::Boolean
-            ),
-
-            _ => return Err(StatementParseError::TableElementSingleUnknownDataTypeKeyword {
-                found: data_type_reserved_word_token.as_string(input).into(),
-                reserved_word: data_type_reserved_word,
-            })
-        };
+        let data_type = self.parse_data_type(input, &mut tokens)?;
 
         let column_definition = ColumnDefinition {
-            column_name: column_name.to_owned(),
+            column_name,
             data_type,
             column_constraint_definitions: Vec::new(),
+            span: consumed_span(start, tokens),
         };
 
         Ok((tokens, TableElement::ColumnDefinition(column_definition)))
@@ -1213,21 +2241,23 @@ impl Parser {
             });
         }
 
+        let start = *tokens;
         let first_token = tokens[0];
 
         *tokens = &tokens[1..];
 
-        match first_token.kind() {
-            TokenKind::Identifier | TokenKind::NonReservedWord(..) => {
-                let correlation_name = self.parse_correlation_name_optional(input, tokens)?;
-                return Ok(TableReference::Primary(
-                    TablePrimary {
-                        kind: TablePrimaryKind::TableOrQueryName(first_token.as_string(input).to_owned()),
-                        correlation_name,
-                    }
-                ))
-            }
+        if let Some(table_or_query_name) = first_token.as_identifier(input) {
+            let correlation_name = self.parse_correlation_name_optional(input, tokens)?;
+            return Ok(TableReference::Primary(
+                TablePrimary {
+                    kind: TablePrimaryKind::TableOrQueryName(table_or_query_name),
+                    correlation_name,
+                    span: consumed_span(start, tokens),
+                }
+            ));
+        }
 
+        match first_token.kind() {
             TokenKind::ReservedWord(reserved_word) => Err(StatementParseError::TableReferenceUnexpectedKeyword {
                 found: first_token.as_string(input).into(),
                 reserved_word
@@ -1240,7 +2270,9 @@ impl Parser {
         }
     }
 
-    /// Parse a `<value expression>`.
+    /// Parse a `<value expression>`. Operators are grouped by their
+    /// [`OperatorPrecedence`], e.g. `NOT a = 1 OR b < 2 * c` is parsed as
+    /// `(NOT (a = 1)) OR (b < (2 * c))`.
     ///
     /// ```text
     /// <value expression> ::=
@@ -1255,6 +2287,88 @@ impl Parser {
     //      | <collection value expression>
     /// ```
     fn parse_value_expression<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<ValueExpression, StatementParseError<'input>> {
+        self.parse_value_expression_with_precedence(input, tokens, OperatorPrecedence::Or)
+    }
+
+    /// Parse a `<value expression>`, only consuming the infix operators that
+    /// bind at least as tight as `minimum_precedence`.
+    fn parse_value_expression_with_precedence<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        minimum_precedence: OperatorPrecedence,
+    ) -> Result<ValueExpression, StatementParseError<'input>> {
+        let mut value_expression = self.parse_value_expression_primary(input, tokens)?;
+
+        while let Some(token) = tokens.first() {
+            if token.kind() == TokenKind::ReservedWord(ReservedWord::Is) {
+                if OperatorPrecedence::Is < minimum_precedence {
+                    break;
+                }
+
+                tokens.next();
+                value_expression = self.parse_boolean_test(input, tokens, value_expression)?;
+                continue;
+            }
+
+            if is_postfix_predicate_ahead(tokens) {
+                if OperatorPrecedence::Comparison < minimum_precedence {
+                    break;
+                }
+
+                value_expression = self.parse_postfix_predicate(input, tokens, value_expression)?;
+                continue;
+            }
+
+            if let Some(error) = self.unsupported_operator_alias(input, tokens) {
+                return Err(error);
+            }
+
+            let Some(operator) = BinaryOperator::from_token_kind(token.kind()) else {
+                break;
+            };
+
+            if operator.precedence() < minimum_precedence {
+                break;
+            }
+
+            tokens.next();
+
+            if let BinaryOperator::Comparison(operator) = operator {
+                if let Some(quantifier) = tokens.first().and_then(|token| quantifier_of(token.kind())) {
+                    tokens.next();
+                    let values_start = *tokens;
+                    let values = self.parse_in_value_list(input, tokens)?;
+                    value_expression = predicate_expression(Predicate::QuantifiedComparison(QuantifiedComparisonPredicate {
+                        span: value_expression.span().to(consumed_span(values_start, tokens)),
+                        left_hand_side: value_expression,
+                        operator,
+                        quantifier,
+                        values,
+                    }));
+                    continue;
+                }
+            }
+
+            let right_hand_side = self.parse_value_expression_with_precedence(input, tokens, operator.precedence().tighter())?;
+            value_expression = operator.apply(value_expression, right_hand_side);
+        }
+
+        Ok(value_expression)
+    }
+
+    /// Parse the operand of a value expression, including its prefix
+    /// operators.
+    ///
+    /// ```text
+    /// <value expression primary> ::=
+    ///       <parenthesized value expression>
+    ///     | <nonparenthesized value expression primary>
+    ///
+    /// <parenthesized value expression> ::=
+    ///     <left paren> <value expression> <right paren>
+    /// ```
+    fn parse_value_expression_primary<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<ValueExpression, StatementParseError<'input>> {
         if is_end_of_statement(tokens) {
             return Err(StatementParseError::ValueExpressionUnexpectedEndOfFile {
                 found: ErrorFindLocation::EndOfFile { complete_input: input }
@@ -1266,7 +2380,7 @@ impl Parser {
         let first_token = tokens[0];
         *tokens = &tokens[1..];
 
-        let mut value_expression = match first_token.kind() {
+        Ok(match first_token.kind() {
             // ```text
             // <identifier chain> ::=
             //     <identifier> [ { <period> <identifier> }... ]
@@ -1274,7 +2388,14 @@ impl Parser {
             // <basic identifier chain> ::=
             //     <identifier chain>
             // ```
-            TokenKind::Identifier | TokenKind::NonReservedWord(..) => {
+            TokenKind::Identifier | TokenKind::NonReservedWord(..) | TokenKind::DelimitedIdentifier { .. }
+                    if tokens.first().is_some_and(|token| token.kind() == TokenKind::LeftParenthesis) => {
+                ValueExpression::RoutineInvocation(
+                    self.parse_routine_invocation(input, tokens, first_token)?
+                )
+            }
+
+            TokenKind::Identifier | TokenKind::NonReservedWord(..) | TokenKind::DelimitedIdentifier { .. } => {
                 *tokens = original_tokens;
                 ValueExpression::ColumnReference(
                     self.parse_column_reference(input, tokens)?
@@ -1282,174 +2403,1639 @@ impl Parser {
             }
 
             TokenKind::ReservedWord(ReservedWord::False) => ValueExpression::Boolean(
-                BooleanExpression::Literal(false)
+                BooleanExpression::Literal(false, first_token.span())
             ),
 
             TokenKind::ReservedWord(ReservedWord::True) => ValueExpression::Boolean(
-                BooleanExpression::Literal(true)
+                BooleanExpression::Literal(true, first_token.span())
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Unknown) => ValueExpression::Boolean(
+                BooleanExpression::Unknown(first_token.span())
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Case) => ValueExpression::Case(
+                self.parse_case_specification(input, tokens, first_token)?
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Cast) => ValueExpression::Cast(
+                self.parse_cast_specification(input, tokens, first_token)?
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Coalesce) => ValueExpression::Case(CaseExpression::Coalesce(
+                self.parse_function_arguments(input, tokens, "COALESCE")?,
+                consumed_span(original_tokens, tokens),
+            )),
+
+            TokenKind::ReservedWord(ReservedWord::Nullif) => ValueExpression::Case(
+                self.parse_case_abbreviation_nullif(input, tokens, first_token)?
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Count) => {
+                let set_function = self.parse_set_function_specification_count(input, tokens, first_token)?;
+                self.parse_set_function_window_optional(input, tokens, first_token, set_function)?
+            }
+
+            TokenKind::ReservedWord(
+                reserved_word @ (ReservedWord::Avg | ReservedWord::Every | ReservedWord::Max
+                    | ReservedWord::Min | ReservedWord::Sum)
+            ) => {
+                let function_type = match reserved_word {
+                    ReservedWord::Avg => ComputationalOperation::Avg,
+                    ReservedWord::Every => ComputationalOperation::Every,
+                    ReservedWord::Max => ComputationalOperation::Max,
+                    ReservedWord::Min => ComputationalOperation::Min,
+                    _ => ComputationalOperation::Sum,
+                };
+
+                let set_function = self.parse_general_set_function(input, tokens, function_type, first_token)?;
+                self.parse_set_function_window_optional(input, tokens, first_token, set_function)?
+            }
+
+            TokenKind::ReservedWord(ReservedWord::RowNumber) => ValueExpression::WindowFunction(Box::new(
+                self.parse_window_function_without_arguments(input, tokens, first_token, WindowFunctionType::RowNumber)?
+            )),
+
+            TokenKind::ReservedWord(
+                reserved_word @ (ReservedWord::Rank | ReservedWord::DenseRank | ReservedWord::PercentRank
+                    | ReservedWord::CumeDist)
+            ) => {
+                let rank_function_type = match reserved_word {
+                    ReservedWord::Rank => RankFunctionType::Rank,
+                    ReservedWord::DenseRank => RankFunctionType::DenseRank,
+                    ReservedWord::PercentRank => RankFunctionType::PercentRank,
+                    _ => RankFunctionType::CumeDist,
+                };
+
+                ValueExpression::WindowFunction(Box::new(
+                    self.parse_window_function_without_arguments(input, tokens, first_token, WindowFunctionType::Rank(rank_function_type))?
+                ))
+            }
+
+            TokenKind::ReservedWord(ReservedWord::Overlay) => ValueExpression::StringValueExpression(
+                StringValueExpression::Function(self.parse_string_value_function_overlay(input, tokens, first_token)?)
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Position) => ValueExpression::Numeric(
+                NumericValueExpression::Function(self.parse_numeric_value_function_position(input, tokens, first_token)?)
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Substring) => ValueExpression::StringValueExpression(
+                StringValueExpression::Function(self.parse_string_value_function_substring(input, tokens, first_token)?)
+            ),
+
+            TokenKind::ReservedWord(ReservedWord::Trim) => ValueExpression::StringValueExpression(
+                StringValueExpression::Function(self.parse_string_value_function_trim(input, tokens, first_token)?)
             ),
 
-            TokenKind::ReservedWord(ReservedWord::Count) => ValueExpression::SetFunctionSpecification(
-                self.parse_set_function_specification_count(input, tokens)?
+            // The functions that are reserved words, but have no special
+            // syntax for their arguments.
+            TokenKind::ReservedWord(
+                ReservedWord::Abs | ReservedWord::Ceil | ReservedWord::Ceiling | ReservedWord::CharLength
+                    | ReservedWord::CharacterLength | ReservedWord::Exp | ReservedWord::Floor | ReservedWord::Ln
+                    | ReservedWord::Log10 | ReservedWord::Lower | ReservedWord::Mod | ReservedWord::OctetLength
+                    | ReservedWord::Power | ReservedWord::Sqrt | ReservedWord::Upper
+            ) => ValueExpression::RoutineInvocation(
+                self.parse_routine_invocation(input, tokens, first_token)?
             ),
 
-            TokenKind::StringLiteral { first_character_byte_idx, last_character_byte_idx } => {
+            TokenKind::BinaryStringLiteral { first_character_byte_idx, last_character_byte_idx } => {
+                ValueExpression::StringValueExpression(
+                    StringValueExpression::BinaryLiteral(
+                        decode_binary_string_literal(&input[first_character_byte_idx..last_character_byte_idx]),
+                        first_token.span(),
+                    )
+                )
+            }
+
+            TokenKind::BitStringLiteral { first_character_byte_idx, last_character_byte_idx } => {
+                ValueExpression::StringValueExpression(
+                    StringValueExpression::BitLiteral(
+                        input[first_character_byte_idx..last_character_byte_idx]
+                            .chars()
+                            .map(|bit| bit == '1')
+                            .collect(),
+                        first_token.span(),
+                    )
+                )
+            }
+
+            TokenKind::StringLiteral { kind } => {
                 ValueExpression::StringValueExpression(
                     StringValueExpression::Literal(
-                        input[first_character_byte_idx..last_character_byte_idx].to_string()
+                        Lexer::decode_character_string_literal(first_token.as_string(input), kind)
+                            .expect("string literal should be validated by the lexer"),
+                        kind,
+                        first_token.span(),
                     )
                 )
             },
 
-            TokenKind::UnsignedInteger(integer) => ValueExpression::Numeric(
-                NumericValueExpression::SimpleU64(integer)
+            TokenKind::ApproximateNumericLiteral => ValueExpression::Numeric(
+                NumericValueExpression::ApproximateNumericLiteral(first_token.as_string(input).to_owned(), first_token.span())
             ),
 
-            _ => return Err(StatementParseError::ValueExpressionUnexpectedToken {
-                found: first_token.as_string(input).into(),
-                token_kind: first_token.kind(),
-            })
-        };
-
-        while !is_end_of_statement(tokens) {
-            match tokens[0].kind() {
-                TokenKind::EqualsSign => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::EqualTo
-                    )?;
-                }
-
-                TokenKind::GreaterThanOperator => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::GreaterThan
-                    )?;
-                }
+            TokenKind::ExactNumericLiteral => ValueExpression::Numeric(
+                NumericValueExpression::ExactNumericLiteral(first_token.as_string(input).to_owned(), first_token.span())
+            ),
 
-                TokenKind::GreaterThanOrEqualsOperator => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::GreaterThanOrEqualTo
-                    )?;
-                }
+            TokenKind::UnsignedInteger(integer) => ValueExpression::Numeric(
+                NumericValueExpression::SimpleU64(integer, first_token.span())
+            ),
 
-                TokenKind::LessThanOperator => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::LessThan
-                    )?;
-                }
+            TokenKind::MinusSign | TokenKind::PlusSign if tokens.first().is_some_and(|token| is_unsigned_numeric_literal(token.kind())) => {
+                ValueExpression::Numeric(
+                    self.parse_signed_numeric_literal(input, tokens, first_token)?
+                )
+            }
 
-                TokenKind::LessThanOrEqualsOperator => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::LessThanOrEqualTo
-                    )?;
-                }
+            TokenKind::MinusSign => {
+                let operand = self.parse_value_expression_with_precedence(input, tokens, OperatorPrecedence::Sign)?;
+                let span = first_token.span().to(operand.span());
+                ValueExpression::Numeric(NumericValueExpression::Negation(Box::new(operand), span))
+            }
 
-                TokenKind::NotEqualsOperator => {
-                    *tokens = &tokens[1..];
-                    value_expression = self.parse_value_expression_continuation_comparison_predicate(
-                        input,
-                        tokens,
-                        value_expression,
-                        ComparisonOperator::NotEqualTo
-                    )?;
-                }
+            // The unary plus doesn't change the value of its operand.
+            TokenKind::PlusSign => self.parse_value_expression_with_precedence(input, tokens, OperatorPrecedence::Sign)?,
 
-                _ => break,
+            TokenKind::ReservedWord(ReservedWord::Not) => {
+                let operand = self.parse_value_expression_with_precedence(input, tokens, OperatorPrecedence::Not.tighter())?;
+                let span = first_token.span().to(operand.span());
+                ValueExpression::Boolean(BooleanExpression::Not(Box::new(operand), span))
             }
-        }
 
-        Ok(value_expression)
-    }
+            TokenKind::LeftParenthesis => {
+                let value_expression = self.parse_value_expression(input, tokens)?;
+                self.parse_value_expression_right_paren(input, tokens, first_token)?;
+                value_expression
+            }
 
-    /// A continuation of `parse_value_expression` when an comparison operator
-    /// is consumed.
-    fn parse_value_expression_continuation_comparison_predicate<'input>(
-        &self,
-        input: &'input str,
-        tokens: &mut &[Token],
-        left_hand_side: ValueExpression,
-        operator: ComparisonOperator
-    ) -> Result<ValueExpression, StatementParseError<'input>> {
-        let right_hand_side = self.parse_value_expression(input, tokens)?;
-        Ok(ValueExpression::Boolean(
-            BooleanExpression::Predicate(Box::new(
-                Predicate::Comparison(ComparisonPredicate{
-                    left_hand_side,
-                    right_hand_side,
-                    operator
-                })
-            ))
-        ))
+            _ => return Err(StatementParseError::ValueExpressionUnexpectedToken {
+                found: first_token.as_string(input).into(),
+                token_kind: first_token.kind(),
+            })
+        })
     }
 
-    /// Parse the vendor-specific `LIMIT` clause. It originated from **MySQL**,
-    /// and acts as the SQL-standard `FETCH FIRST` clause.
-    fn parse_vendor_limit_clause<'input>(
+    /// Consumes the `<right paren>` that closes a parenthesized value
+    /// expression, which was opened by `left_paren`.
+    fn parse_value_expression_right_paren<'input>(
         &self,
         input: &'input str,
         tokens: &mut &[Token],
-    ) -> Result<Option<FetchFirstClause>, StatementParseError<'input>> {
-        if !self.allow_vendor_extensions {
-            return Ok(None);
-        }
+        left_paren: Token,
+    ) -> Result<(), StatementParseError<'input>> {
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: left_paren.as_string(input),
+            token_kind: TokenKind::LeftParenthesis,
+        };
 
         if is_end_of_statement(tokens) {
-            return Err(StatementParseError::VendorLimitClauseUnexpectedEndOfFileExpectedIntroductionKeyword {
+            return Err(StatementParseError::ValueExpressionUnexpectedEndOfFileExpectedRightParen {
                 found: ErrorFindLocation::EndOfFile { complete_input: input },
+                should_be_matching,
             });
         }
 
-        if tokens[0].kind() != TokenKind::VendorReservedWord(VendorReservedWord::Limit) {
-            return Err(StatementParseError::VendorLimitClauseUnexpectedTokenExpectedIntroductionKeyword {
-                found: ErrorFindLocation::EndOfFile { complete_input: input },
+        if tokens[0].kind() != TokenKind::RightParenthesis {
+            return Err(StatementParseError::ValueExpressionUnexpectedTokenExpectedRightParen {
+                found: tokens[0].as_string(input).into(),
                 token_kind: tokens[0].kind(),
+                should_be_matching,
             });
         }
 
-        *tokens = &tokens[1..];
+        tokens.next();
+        Ok(())
+    }
+
+    /// Parses the remainder of a `<boolean test>`, `<null predicate>` or
+    /// `<distinct predicate>`, after the `IS` keyword following the `operand`
+    /// was consumed.
+    ///
+    /// ```text
+    /// <boolean test> ::=
+    ///     <boolean primary> [ IS [ NOT ] <truth value> ]
+    ///
+    /// <null predicate> ::=
+    ///     <row value expression> IS [ NOT ] NULL
+    ///
+    /// <distinct predicate> ::=
+    ///     <row value expression> IS [ NOT ] DISTINCT FROM <row value expression>
+    /// ```
+    fn parse_boolean_test<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        operand: ValueExpression,
+    ) -> Result<ValueExpression, StatementParseError<'input>> {
+        let negated = tokens.first().is_some_and(|token| token.kind() == TokenKind::ReservedWord(ReservedWord::Not));
+        if negated {
+            tokens.next();
+        }
 
         if is_end_of_statement(tokens) {
-            return Err(StatementParseError::VendorLimitClauseUnexpectedEndOfFileExpectedCount {
+            return Err(StatementParseError::BooleanTestUnexpectedEndOfFile {
                 found: ErrorFindLocation::EndOfFile { complete_input: input },
             });
         }
 
-        let token = tokens[0];
-        *tokens = &tokens[1..];
+        let truth_value = match tokens[0].kind() {
+            TokenKind::ReservedWord(ReservedWord::False) => TruthValue::False,
+            TokenKind::ReservedWord(ReservedWord::True) => TruthValue::True,
+            TokenKind::ReservedWord(ReservedWord::Unknown) => TruthValue::Unknown,
 
-        match token.kind() {
-            TokenKind::UnsignedInteger(integer) => Ok(Some(FetchFirstClause {
-                quantity: FetchFirstQuantity {
-                    is_percent: false,
-                    value: SimpleValueSpecification::LiteralUnsigned(integer),
-                },
-                origin: FetchFirstClauseOrigin::Limit,
-            })),
+            TokenKind::ReservedWord(ReservedWord::Null) => {
+                let span = operand.span().to(tokens[0].span());
+                tokens.next();
+                return Ok(predicate_expression(Predicate::Null(NullPredicate { operand, negated, span })));
+            }
 
-            _ => Err(StatementParseError::VendorLimitClauseUnexpectedTokenExpectedCount {
-                found: ErrorFindLocation::Position(token.as_string(input)),
-                token_kind: token.kind(),
-            })
-        }
+            TokenKind::ReservedWord(ReservedWord::Distinct) => {
+                tokens.next();
+                self.parse_distinct_predicate_from(input, tokens)?;
+                let right_hand_side = self.parse_predicate_operand(input, tokens)?;
+                return Ok(predicate_expression(Predicate::Distinct(DistinctPredicate {
+                    span: operand.span().to(right_hand_side.span()),
+                    left_hand_side: operand,
+                    negated,
+                    right_hand_side,
+                })));
+            }
+
+            token_kind => return Err(StatementParseError::BooleanTestUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind,
+            }),
+        };
+
+        let span = operand.span().to(tokens[0].span());
+        tokens.next();
+        Ok(ValueExpression::Boolean(BooleanExpression::Is {
+            operand: Box::new(operand),
+            negated,
+            truth_value,
+            span,
+        }))
+    }
+
+    /// Parses a `<case specification>`, after the `CASE` keyword was consumed.
+    ///
+    /// ```text
+    /// <simple case> ::=
+    ///     CASE <case operand> <simple when clause>... [ <else clause> ] END
+    ///
+    /// <searched case> ::=
+    ///     CASE <searched when clause>... [ <else clause> ] END
+    /// ```
+    fn parse_case_specification<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        case_token: Token,
+    ) -> Result<CaseExpression, StatementParseError<'input>> {
+        let operand = match tokens.first() {
+            Some(token) if matches!(token.kind(), TokenKind::ReservedWord(ReservedWord::When | ReservedWord::End)) => None,
+            _ if is_end_of_statement(tokens) => None,
+            _ => Some(self.parse_value_expression(input, tokens)?),
+        };
+
+        let mut when_clauses = Vec::new();
+        while tokens.first().is_some_and(|token| token.kind() == TokenKind::ReservedWord(ReservedWord::When)) {
+            let when_start = *tokens;
+            tokens.next();
+
+            let when_operand = self.parse_value_expression(input, tokens)?;
+            self.parse_case_specification_then(input, tokens)?;
+            let result = self.parse_case_result(input, tokens)?;
+            when_clauses.push((when_operand, result, consumed_span(when_start, tokens)));
+        }
+
+        if when_clauses.is_empty() {
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::CaseExpressionUnexpectedEndOfFileExpectedWhen {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                });
+            }
+
+            return Err(StatementParseError::CaseExpressionUnexpectedTokenExpectedWhen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        let else_clause = if tokens.consume_reserved_word(ReservedWord::Else) {
+            Some(Box::new(self.parse_case_result(input, tokens)?))
+        } else {
+            None
+        };
+
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: case_token.as_string(input),
+            token_kind: case_token.kind(),
+        };
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::CaseExpressionUnexpectedEndOfFileExpectedEnd {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                should_be_matching,
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::ReservedWord(ReservedWord::End) {
+            return Err(StatementParseError::CaseExpressionUnexpectedTokenExpectedEnd {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                should_be_matching,
+            });
+        }
+
+        let span = case_token.span().to(tokens[0].span());
+        tokens.next();
+
+        Ok(match operand {
+            Some(operand) => CaseExpression::Simple {
+                operand: Box::new(operand),
+                when_clauses: when_clauses.into_iter()
+                    .map(|(when_operand, result, span)| SimpleWhenClause { when_operand, result, span })
+                    .collect(),
+                else_clause,
+                span,
+            },
+            None => CaseExpression::Searched {
+                when_clauses: when_clauses.into_iter()
+                    .map(|(search_condition, result, span)| SearchedWhenClause { search_condition, result, span })
+                    .collect(),
+                else_clause,
+                span,
+            },
+        })
+    }
+
+    /// Consumes the `THEN` keyword of a `<simple when clause>` or
+    /// `<searched when clause>`.
+    fn parse_case_specification_then<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<(), StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::CaseExpressionUnexpectedEndOfFileExpectedThen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::Then) {
+            return Err(StatementParseError::CaseExpressionUnexpectedTokenExpectedThen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses the `<result>` of a `WHEN` or `ELSE` clause.
+    ///
+    /// ```text
+    /// <result> ::=
+    ///       <result expression>
+    ///     | NULL
+    /// ```
+    fn parse_case_result<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<CaseResult, StatementParseError<'input>> {
+        if let Some(token) = tokens.first().filter(|token| token.kind() == TokenKind::ReservedWord(ReservedWord::Null)) {
+            let span = token.span();
+            tokens.next();
+            return Ok(CaseResult::Null(span));
+        }
+
+        Ok(CaseResult::ValueExpression(self.parse_value_expression(input, tokens)?))
+    }
+
+    /// Parses a `NULLIF` abbreviation, after the `NULLIF` keyword was
+    /// consumed.
+    fn parse_case_abbreviation_nullif<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        nullif_token: Token,
+    ) -> Result<CaseExpression, StatementParseError<'input>> {
+        let start = *tokens;
+        let mut arguments = self.parse_function_arguments(input, tokens, "NULLIF")?;
+        if arguments.len() != 2 {
+            return Err(StatementParseError::FunctionUnexpectedArgumentCount {
+                found: nullif_token.as_string(input).into(),
+                function_name: "NULLIF",
+                expected_argument_count: 2,
+                argument_count: arguments.len(),
+            });
+        }
+
+        let right_hand_side = arguments.pop().unwrap();
+        let left_hand_side = arguments.pop().unwrap();
+        Ok(CaseExpression::NullIf {
+            left_hand_side: Box::new(left_hand_side),
+            right_hand_side: Box::new(right_hand_side),
+            span: nullif_token.span().to(consumed_span(start, tokens)),
+        })
+    }
+
+    /// Parses a `<cast specification>`, after the `CAST` keyword was consumed.
+    ///
+    /// ```text
+    /// <cast specification> ::=
+    ///     CAST <left paren> <cast operand> AS <cast target> <right paren>
+    /// ```
+    fn parse_cast_specification<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        cast_token: Token,
+    ) -> Result<CastSpecification, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, "CAST")?;
+
+        let operand = if tokens.consume_reserved_word(ReservedWord::Null) {
+            CastOperand::Null
+        } else {
+            CastOperand::ValueExpression(Box::new(self.parse_value_expression(input, tokens)?))
+        };
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::CastSpecificationUnexpectedEndOfFileExpectedAs {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::As) {
+            return Err(StatementParseError::CastSpecificationUnexpectedTokenExpectedAs {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        let target = self.parse_data_type(input, tokens)?;
+        let right_paren = self.parse_function_right_paren(input, tokens, "CAST", left_paren)?;
+
+        Ok(CastSpecification {
+            operand,
+            target,
+            span: cast_token.span().to(right_paren.span()),
+        })
+    }
+
+    /// Consumes the `FROM` keyword of `IS [ NOT ] DISTINCT FROM`.
+    fn parse_distinct_predicate_from<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<(), StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::DistinctPredicateUnexpectedEndOfFileExpectedFrom {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::From) {
+            return Err(StatementParseError::DistinctPredicateUnexpectedTokenExpectedFrom {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses the parenthesized, comma-separated arguments of a function-like
+    /// construct, e.g. `COALESCE`, after its keyword was consumed.
+    fn parse_function_arguments<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+    ) -> Result<Vec<ValueExpression>, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, function_name)?;
+        self.parse_function_argument_list(input, tokens, function_name, left_paren)
+    }
+
+    /// Parses the comma-separated arguments and the closing `<right paren>`
+    /// of a function-like construct, of which the arguments were opened by
+    /// `left_paren`.
+    fn parse_function_argument_list<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+        left_paren: Token,
+    ) -> Result<Vec<ValueExpression>, StatementParseError<'input>> {
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: left_paren.as_string(input),
+            token_kind: TokenKind::LeftParenthesis,
+        };
+
+        let mut arguments = Vec::new();
+        loop {
+            arguments.push(self.parse_value_expression(input, tokens)?);
+
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::FunctionUnexpectedEndOfFileExpectedCommaOrRightParen {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    function_name,
+                    should_be_matching,
+                });
+            }
+
+            let separator_token = tokens[0];
+            tokens.next();
+
+            match separator_token.kind() {
+                TokenKind::Comma => continue,
+                TokenKind::RightParenthesis => break,
+                token_kind => return Err(StatementParseError::FunctionUnexpectedTokenExpectedCommaOrRightParen {
+                    found: separator_token.as_string(input).into(),
+                    token_kind,
+                    function_name,
+                    should_be_matching,
+                }),
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    /// Consumes the `<left paren>` following the keyword of a function-like
+    /// construct, and returns it to match the `<right paren>` against.
+    fn parse_function_left_paren<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+    ) -> Result<Token, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FunctionUnexpectedEndOfFileExpectedLeftParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                function_name,
+            });
+        }
+
+        let left_paren = tokens[0];
+        if left_paren.kind() != TokenKind::LeftParenthesis {
+            return Err(StatementParseError::FunctionUnexpectedTokenExpectedLeftParen {
+                found: left_paren.as_string(input).into(),
+                token_kind: left_paren.kind(),
+                function_name,
+            });
+        }
+
+        tokens.next();
+        Ok(left_paren)
+    }
+
+    /// Consumes the `<right paren>` closing the arguments of a function-like
+    /// construct, which were opened by `left_paren`, and returns it.
+    fn parse_function_right_paren<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+        left_paren: Token,
+    ) -> Result<Token, StatementParseError<'input>> {
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: left_paren.as_string(input),
+            token_kind: TokenKind::LeftParenthesis,
+        };
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FunctionUnexpectedEndOfFileExpectedRightParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                function_name,
+                should_be_matching,
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::RightParenthesis {
+            return Err(StatementParseError::FunctionUnexpectedTokenExpectedRightParen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                function_name,
+                should_be_matching,
+            });
+        }
+
+        let right_paren = tokens[0];
+        tokens.next();
+        Ok(right_paren)
+    }
+
+    /// Consumes the `keyword` separating the arguments of a function with
+    /// keywords between its arguments, e.g. the `FROM` of `SUBSTRING`.
+    fn parse_function_keyword<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+        keyword: ReservedWord,
+    ) -> Result<(), StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FunctionUnexpectedEndOfFileExpectedKeyword {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                function_name,
+                keyword: keyword.into(),
+            });
+        }
+
+        if !tokens.consume_reserved_word(keyword) {
+            return Err(StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                function_name,
+                keyword: keyword.into(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses an `<in predicate value>`, which is also used for the values of
+    /// a `<quantified comparison predicate>`, since subqueries aren't
+    /// supported yet.
+    ///
+    /// ```text
+    /// <in predicate value> ::=
+    ///       <table subquery>
+    ///     | <left paren> <in value list> <right paren>
+    ///
+    /// <in value list> ::=
+    ///     <row value expression> { <comma> <row value expression> }...
+    /// ```
+    fn parse_in_value_list<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<Vec<ValueExpression>, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::InValueListUnexpectedEndOfFileExpectedLeftParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::LeftParenthesis {
+            return Err(StatementParseError::InValueListUnexpectedTokenExpectedLeftParen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: tokens[0].as_string(input),
+            token_kind: TokenKind::LeftParenthesis,
+        };
+
+        tokens.next();
+
+        if let Some(token) = tokens.first().filter(|token| token.kind() == TokenKind::ReservedWord(ReservedWord::Select)) {
+            return Err(StatementParseError::UnsupportedFeature {
+                feature_name: "subqueries",
+                feature_description: "Using the rows of a query as the list of values",
+                found: token.as_string(input).into(),
+                token_kind: token.kind(),
+            });
+        }
+
+        let mut values = Vec::new();
+        loop {
+            values.push(self.parse_value_expression(input, tokens)?);
+
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::InValueListUnexpectedEndOfFileExpectedCommaOrRightParen {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    should_be_matching,
+                });
+            }
+
+            let separator_token = tokens[0];
+            tokens.next();
+
+            match separator_token.kind() {
+                TokenKind::Comma => continue,
+                TokenKind::RightParenthesis => break,
+                token_kind => return Err(StatementParseError::InValueListUnexpectedTokenExpectedCommaOrRightParen {
+                    found: separator_token.as_string(input).into(),
+                    token_kind,
+                    should_be_matching,
+                }),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Parses a predicate of which the first operand was already parsed, and
+    /// which is introduced by a keyword, optionally preceded by `NOT`.
+    ///
+    /// ```text
+    /// <between predicate> ::=
+    ///     <row value expression> [ NOT ] BETWEEN
+    ///     [ ASYMMETRIC | SYMMETRIC ]
+    ///     <row value expression> AND <row value expression>
+    ///
+    /// <in predicate> ::=
+    ///     <row value expression> [ NOT ] IN <in predicate value>
+    ///
+    /// <like predicate> ::=
+    ///     <character match value> [ NOT ] LIKE <character pattern>
+    ///     [ ESCAPE <escape character> ]
+    ///
+    /// <similar predicate> ::=
+    ///     <character match value> [ NOT ] SIMILAR TO <similar pattern>
+    ///     [ ESCAPE <escape character> ]
+    /// ```
+    fn parse_postfix_predicate<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        operand: ValueExpression,
+    ) -> Result<ValueExpression, StatementParseError<'input>> {
+        let start = *tokens;
+        let negated = tokens.consume_reserved_word(ReservedWord::Not);
+
+        let keyword_token = tokens[0];
+        tokens.next();
+
+        let predicate = match keyword_token.kind() {
+            TokenKind::ReservedWord(ReservedWord::Between) => {
+                let symmetric = if tokens.consume_reserved_word(ReservedWord::Symmetric) {
+                    true
+                } else {
+                    tokens.consume_reserved_word(ReservedWord::Asymmetric);
+                    false
+                };
+
+                let lower_bound = self.parse_predicate_operand(input, tokens)?;
+
+                if is_end_of_statement(tokens) {
+                    return Err(StatementParseError::BetweenPredicateUnexpectedEndOfFileExpectedAnd {
+                        found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    });
+                }
+
+                if !tokens.consume_reserved_word(ReservedWord::And) {
+                    return Err(StatementParseError::BetweenPredicateUnexpectedTokenExpectedAnd {
+                        found: tokens[0].as_string(input).into(),
+                        token_kind: tokens[0].kind(),
+                    });
+                }
+
+                let upper_bound = self.parse_predicate_operand(input, tokens)?;
+                Predicate::Between(BetweenPredicate {
+                    span: operand.span().to(consumed_span(start, tokens)),
+                    operand,
+                    negated,
+                    symmetric,
+                    lower_bound,
+                    upper_bound,
+                })
+            }
+
+            TokenKind::ReservedWord(ReservedWord::In) => {
+                let values = self.parse_in_value_list(input, tokens)?;
+                Predicate::In(InPredicate {
+                    span: operand.span().to(consumed_span(start, tokens)),
+                    operand,
+                    negated,
+                    values,
+                })
+            }
+
+            TokenKind::ReservedWord(ReservedWord::Like) => {
+                let pattern = self.parse_predicate_operand(input, tokens)?;
+                let escape = self.parse_escape_character_optional(input, tokens)?;
+                Predicate::Like(LikePredicate {
+                    span: operand.span().to(consumed_span(start, tokens)),
+                    operand,
+                    negated,
+                    pattern,
+                    escape,
+                })
+            }
+
+            TokenKind::ReservedWord(ReservedWord::Similar) => {
+                if is_end_of_statement(tokens) {
+                    return Err(StatementParseError::SimilarPredicateUnexpectedEndOfFileExpectedTo {
+                        found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    });
+                }
+
+                if !tokens.consume_reserved_word(ReservedWord::To) {
+                    return Err(StatementParseError::SimilarPredicateUnexpectedTokenExpectedTo {
+                        found: tokens[0].as_string(input).into(),
+                        token_kind: tokens[0].kind(),
+                    });
+                }
+
+                let pattern = self.parse_predicate_operand(input, tokens)?;
+                let escape = self.parse_escape_character_optional(input, tokens)?;
+                Predicate::Similar(SimilarPredicate {
+                    span: operand.span().to(consumed_span(start, tokens)),
+                    operand,
+                    negated,
+                    pattern,
+                    escape,
+                })
+            }
+
+            token_kind => unreachable!("checked by is_postfix_predicate_ahead, but got {token_kind}"),
+        };
+
+        Ok(predicate_expression(predicate))
+    }
+
+    /// Parses the `[ ESCAPE <escape character> ]` of a `<like predicate>` or
+    /// `<similar predicate>`.
+    fn parse_escape_character_optional<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<Option<ValueExpression>, StatementParseError<'input>> {
+        if !tokens.consume_reserved_word(ReservedWord::Escape) {
+            return Ok(None);
+        }
+
+        self.parse_predicate_operand(input, tokens).map(Some)
+    }
+
+    /// Parses an operand of a predicate following its first operand, e.g. the
+    /// bounds of `BETWEEN`. These bind tighter than comparisons, such that the
+    /// `AND` of `x BETWEEN 1 AND 2` isn't mistaken for a boolean operator.
+    fn parse_predicate_operand<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<ValueExpression, StatementParseError<'input>> {
+        self.parse_value_expression_with_precedence(input, tokens, OperatorPrecedence::Comparison.tighter())
+    }
+
+    /// Parses a `<position expression>`, after the `POSITION` keyword was
+    /// consumed. The needle binds tighter than comparisons, such that its `IN`
+    /// isn't mistaken for an `<in predicate>`.
+    ///
+    /// ```text
+    /// <position expression> ::=
+    ///     POSITION <left paren> <string value expression>
+    ///     IN <string value expression> <right paren>
+    /// ```
+    fn parse_numeric_value_function_position<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        position_token: Token,
+    ) -> Result<NumericValueFunction, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, "POSITION")?;
+        let needle = self.parse_predicate_operand(input, tokens)?;
+        self.parse_function_keyword(input, tokens, "POSITION", ReservedWord::In)?;
+        let haystack = self.parse_value_expression(input, tokens)?;
+        let right_paren = self.parse_function_right_paren(input, tokens, "POSITION", left_paren)?;
+
+        Ok(NumericValueFunction::Position {
+            needle: Box::new(needle),
+            haystack: Box::new(haystack),
+            span: position_token.span().to(right_paren.span()),
+        })
+    }
+
+    /// Parses a `<routine invocation>`, after the `<routine name>` was
+    /// consumed. Functions that are reserved words, e.g. `UPPER`, are named by
+    /// their keyword.
+    ///
+    /// ```text
+    /// <routine invocation> ::=
+    ///     <routine name> <SQL argument list>
+    /// ```
+    fn parse_routine_invocation<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        name_token: Token,
+    ) -> Result<RoutineInvocation, StatementParseError<'input>> {
+        let name = match name_token.kind() {
+            TokenKind::ReservedWord(reserved_word) => Identifier::regular(reserved_word.to_string()),
+            _ => name_token.as_identifier(input).expect("routine name should be an identifier"),
+        };
+
+        let function_name = name_token.as_string(input);
+        let start = *tokens;
+        let left_paren = self.parse_function_left_paren(input, tokens, function_name)?;
+
+        let arguments = if tokens.first().is_some_and(|token| token.kind() == TokenKind::RightParenthesis) {
+            tokens.next();
+            Vec::new()
+        } else {
+            self.parse_function_argument_list(input, tokens, function_name, left_paren)?
+        };
+
+        Ok(RoutineInvocation {
+            name,
+            arguments,
+            span: name_token.span().to(consumed_span(start, tokens)),
+        })
+    }
+
+    /// Parses a `<character overlay function>`, after the `OVERLAY` keyword was
+    /// consumed.
+    ///
+    /// ```text
+    /// <character overlay function> ::=
+    ///     OVERLAY <left paren> <character value expression>
+    ///     PLACING <character value expression>
+    ///     FROM <start position> [ FOR <string length> ] <right paren>
+    /// ```
+    fn parse_string_value_function_overlay<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        overlay_token: Token,
+    ) -> Result<StringValueFunction, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, "OVERLAY")?;
+        let operand = self.parse_value_expression(input, tokens)?;
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::FunctionUnexpectedEndOfFileExpectedKeyword {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                function_name: "OVERLAY",
+                keyword: NonReservedWord::Placing.into(),
+            });
+        }
+
+        if !tokens.consume_non_reserved_word(NonReservedWord::Placing) {
+            return Err(StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                function_name: "OVERLAY",
+                keyword: NonReservedWord::Placing.into(),
+            });
+        }
+
+        let placing = self.parse_value_expression(input, tokens)?;
+        let (start_position, string_length) = self.parse_string_value_function_from_for(input, tokens, "OVERLAY")?;
+        let right_paren = self.parse_function_right_paren(input, tokens, "OVERLAY", left_paren)?;
+
+        Ok(StringValueFunction::Overlay {
+            operand: Box::new(operand),
+            placing: Box::new(placing),
+            start_position,
+            string_length,
+            span: overlay_token.span().to(right_paren.span()),
+        })
+    }
+
+    /// Parses the `FROM <start position> [ FOR <string length> ]` of the
+    /// `SUBSTRING` and `OVERLAY` functions.
+    fn parse_string_value_function_from_for<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        function_name: &'input str,
+    ) -> Result<(Box<ValueExpression>, Option<Box<ValueExpression>>), StatementParseError<'input>> {
+        self.parse_function_keyword(input, tokens, function_name, ReservedWord::From)?;
+        let start_position = Box::new(self.parse_value_expression(input, tokens)?);
+
+        let string_length = if tokens.consume_reserved_word(ReservedWord::For) {
+            Some(Box::new(self.parse_value_expression(input, tokens)?))
+        } else {
+            None
+        };
+
+        Ok((start_position, string_length))
+    }
+
+    /// Parses a `<character substring function>`, after the `SUBSTRING`
+    /// keyword was consumed.
+    ///
+    /// ```text
+    /// <character substring function> ::=
+    ///     SUBSTRING <left paren> <character value expression>
+    ///     FROM <start position> [ FOR <string length> ] <right paren>
+    /// ```
+    fn parse_string_value_function_substring<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        substring_token: Token,
+    ) -> Result<StringValueFunction, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, "SUBSTRING")?;
+        let operand = self.parse_value_expression(input, tokens)?;
+        let (start_position, string_length) = self.parse_string_value_function_from_for(input, tokens, "SUBSTRING")?;
+        let right_paren = self.parse_function_right_paren(input, tokens, "SUBSTRING", left_paren)?;
+
+        Ok(StringValueFunction::Substring {
+            operand: Box::new(operand),
+            start_position,
+            string_length,
+            span: substring_token.span().to(right_paren.span()),
+        })
+    }
+
+    /// Parses a `<trim function>`, after the `TRIM` keyword was consumed.
+    ///
+    /// ```text
+    /// <trim function> ::=
+    ///     TRIM <left paren> <trim operands> <right paren>
+    ///
+    /// <trim operands> ::=
+    ///     [ [ <trim specification> ] [ <trim character> ] FROM ] <trim source>
+    /// ```
+    fn parse_string_value_function_trim<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        trim_token: Token,
+    ) -> Result<StringValueFunction, StatementParseError<'input>> {
+        let left_paren = self.parse_function_left_paren(input, tokens, "TRIM")?;
+
+        let specification = match tokens.is_reserved_word() {
+            Some(ReservedWord::Both) => Some(TrimSpecification::Both),
+            Some(ReservedWord::Leading) => Some(TrimSpecification::Leading),
+            Some(ReservedWord::Trailing) => Some(TrimSpecification::Trailing),
+            _ => None,
+        };
+
+        if specification.is_some() {
+            tokens.next();
+        }
+
+        let (character, source) = if tokens.consume_reserved_word(ReservedWord::From) {
+            (None, self.parse_value_expression(input, tokens)?)
+        } else {
+            let operand = self.parse_value_expression(input, tokens)?;
+
+            // The `FROM` is required after a `<trim specification>`.
+            if specification.is_none() && tokens.is_reserved_word() != Some(ReservedWord::From) {
+                (None, operand)
+            } else {
+                self.parse_function_keyword(input, tokens, "TRIM", ReservedWord::From)?;
+                (Some(Box::new(operand)), self.parse_value_expression(input, tokens)?)
+            }
+        };
+
+        let right_paren = self.parse_function_right_paren(input, tokens, "TRIM", left_paren)?;
+
+        Ok(StringValueFunction::Trim {
+            specification: specification.unwrap_or_default(),
+            character,
+            source: Box::new(source),
+            span: trim_token.span().to(right_paren.span()),
+        })
+    }
+
+    /// Parses a `<signed numeric literal>`, after the `<sign>` was consumed.
+    ///
+    /// ```text
+    /// <signed numeric literal> ::=
+    ///     [ <sign> ] <unsigned numeric literal>
+    /// ```
+    fn parse_signed_numeric_literal<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        sign_token: Token,
+    ) -> Result<NumericValueExpression, StatementParseError<'input>> {
+        let literal_token = tokens[0];
+        *tokens = &tokens[1..];
+
+        let span = sign_token.span().to(literal_token.span());
+        let is_negative = sign_token.kind() == TokenKind::MinusSign;
+        let signed_literal = &input[sign_token.first_character_byte_idx..literal_token.last_character_byte_idx];
+        let literal = if is_negative {
+            format!("-{}", literal_token.as_string(input))
+        } else {
+            literal_token.as_string(input).to_owned()
+        };
+
+        Ok(match literal_token.kind() {
+            TokenKind::ApproximateNumericLiteral => NumericValueExpression::ApproximateNumericLiteral(literal, span),
+            TokenKind::ExactNumericLiteral => NumericValueExpression::ExactNumericLiteral(literal, span),
+            TokenKind::UnsignedInteger(integer) if !is_negative => NumericValueExpression::SimpleU64(integer, span),
+            TokenKind::UnsignedInteger(integer) => match 0i64.checked_sub_unsigned(integer) {
+                Some(integer) => NumericValueExpression::SimpleI64(integer, span),
+                None => return Err(StatementParseError::ValueExpressionSignedIntegerOutOfRange {
+                    found: signed_literal.into(),
+                }),
+            },
+            _ => unreachable!("checked by is_unsigned_numeric_literal"),
+        })
+    }
+
+    /// Parse the vendor-specific `LIMIT` clause. It originated from **MySQL**,
+    /// and acts as the SQL-standard `FETCH FIRST` clause. The clause can also
+    /// specify the `offset`, which acts as the SQL-standard `OFFSET` clause.
+    ///
+    /// ```text
+    /// <vendor limit clause> ::=
+    ///       LIMIT <count> [ OFFSET <offset> ]
+    ///     | LIMIT <offset> <comma> <count>
+    /// ```
+    fn parse_vendor_limit_clause<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        offset: &mut Option<ResultOffsetClause>,
+    ) -> Result<FetchFirstClause, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::VendorLimitClauseUnexpectedEndOfFileExpectedIntroductionKeyword {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::VendorReservedWord(VendorReservedWord::Limit) {
+            return Err(StatementParseError::VendorLimitClauseUnexpectedTokenExpectedIntroductionKeyword {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                token_kind: tokens[0].kind(),
+            });
+        }
+
+        let start = *tokens;
+        *tokens = &tokens[1..];
+
+        let mut count_start = *tokens;
+        let mut count = self.parse_vendor_limit_clause_number(input, tokens)?;
+        let mut count_span = consumed_span(count_start, tokens);
+
+        if offset.is_none() && !is_end_of_statement(tokens) {
+            if tokens[0].kind() == TokenKind::Comma {
+                if let Some(dialect) = self.dialect_rejecting(DialectFeature::LimitClauseWithComma) {
+                    return Err(StatementParseError::DialectUnsupportedFeature {
+                        found: tokens[0].as_string(input).into(),
+                        dialect,
+                        feature: DialectFeature::LimitClauseWithComma,
+                    });
+                }
+
+                *tokens = &tokens[1..];
+
+                count_start = *tokens;
+                let offset_row_count = std::mem::replace(&mut count, self.parse_vendor_limit_clause_number(input, tokens)?);
+                *offset = Some(ResultOffsetClause {
+                    offset_row_count,
+                    span: count_span,
+                });
+                count_span = consumed_span(count_start, tokens);
+            } else if tokens.first().is_some_and(|token| token.kind() == TokenKind::ReservedWord(ReservedWord::Offset)) {
+                let offset_start = *tokens;
+                tokens.next();
+
+                let offset_row_count = self.parse_vendor_limit_clause_number(input, tokens)?;
+                *offset = Some(ResultOffsetClause {
+                    offset_row_count,
+                    span: consumed_span(offset_start, tokens),
+                });
+            }
+        }
+
+        Ok(FetchFirstClause {
+            quantity: FetchFirstQuantity {
+                is_percent: false,
+                value: count,
+                span: count_span,
+            },
+            origin: FetchFirstClauseOrigin::Limit,
+            with_ties: false,
+            span: consumed_span(start, tokens),
+        })
+    }
+
+    /// Parse a number in the vendor-specific `LIMIT` clause, which is either
+    /// the maximum row count or the offset.
+    fn parse_vendor_limit_clause_number<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+    ) -> Result<SimpleValueSpecification, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::VendorLimitClauseUnexpectedEndOfFileExpectedCount {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+            });
+        }
+
+        let token = tokens[0];
+        *tokens = &tokens[1..];
+
+        match token.kind() {
+            TokenKind::UnsignedInteger(integer) => Ok(SimpleValueSpecification::LiteralUnsigned(integer)),
+
+            _ => Err(StatementParseError::VendorLimitClauseUnexpectedTokenExpectedCount {
+                found: ErrorFindLocation::Position(token.as_string(input)),
+                token_kind: token.kind(),
+            })
+        }
+    }
+
+    /// Parse the vendor-specific `TOP` clause. It originated from **T-SQL**,
+    /// and acts as the SQL-standard `FETCH FIRST` clause.
+    ///
+    /// ```text
+    /// <vendor top clause> ::=
+    ///     TOP { <count> | <left paren> <count> <right paren> }
+    ///     [ PERCENT ] [ WITH TIES ]
+    /// ```
+    fn parse_vendor_top_clause<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+    ) -> Result<FetchFirstClause, StatementParseError<'input>> {
+        debug_assert_eq!(tokens.first().map(|token| token.kind()), Some(TokenKind::VendorReservedWord(VendorReservedWord::Top)));
+        let start = *tokens;
+        tokens.next();
+
+        let mut clause = self.parse_vendor_top_clause_after_keyword(input, tokens)?;
+        clause.span = consumed_span(start, tokens);
+        Ok(clause)
+    }
+
+    /// Parses the rest of the vendor-specific `TOP` clause, after the `TOP`
+    /// keyword was consumed. The span of the clause excludes the keyword.
+    fn parse_vendor_top_clause_after_keyword<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+    ) -> Result<FetchFirstClause, StatementParseError<'input>> {
+        let start = *tokens;
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::VendorTopClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "a number",
+            });
+        }
+
+        let left_paren = tokens[0];
+        let is_parenthesized = left_paren.kind() == TokenKind::LeftParenthesis;
+        if is_parenthesized {
+            tokens.next();
+        }
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::VendorTopClauseUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "a number",
+            });
+        }
+
+        let TokenKind::UnsignedInteger(integer) = tokens[0].kind() else {
+            return Err(StatementParseError::VendorTopClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "a number",
+            });
+        };
+        let quantity_span = tokens[0].span();
+        tokens.next();
+
+        if is_parenthesized {
+            let should_be_matching = ErrorTokenShouldBeMatching {
+                found: left_paren.as_string(input),
+                token_kind: left_paren.kind(),
+            };
+
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::VendorTopClauseUnexpectedEndOfFileExpectedRightParen {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    should_be_matching,
+                });
+            }
+
+            if tokens[0].kind() != TokenKind::RightParenthesis {
+                return Err(StatementParseError::VendorTopClauseUnexpectedTokenExpectedRightParen {
+                    found: tokens[0].as_string(input).into(),
+                    token_kind: tokens[0].kind(),
+                    should_be_matching,
+                });
+            }
+
+            tokens.next();
+        }
+
+        let is_percent = tokens.consume_reserved_word(ReservedWord::Percent);
+
+        let with_ties = tokens.consume_reserved_word(ReservedWord::With);
+        if with_ties && !tokens.consume_non_reserved_word(NonReservedWord::Ties) {
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::VendorTopClauseUnexpectedEndOfFile {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    expected: "`TIES` after `WITH`",
+                });
+            }
+
+            return Err(StatementParseError::VendorTopClauseUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`TIES` after `WITH`",
+            });
+        }
+
+        Ok(FetchFirstClause {
+            quantity: FetchFirstQuantity {
+                value: SimpleValueSpecification::LiteralUnsigned(integer),
+                is_percent,
+                span: quantity_span,
+            },
+            origin: FetchFirstClauseOrigin::Top,
+            with_ties,
+            span: consumed_span(start, tokens),
+        })
+    }
+
+    /// Parses a `<window frame bound>`.
+    ///
+    /// ```text
+    /// <window frame bound> ::=
+    ///       UNBOUNDED PRECEDING
+    ///     | <unsigned value specification> PRECEDING
+    ///     | CURRENT ROW
+    ///     | <unsigned value specification> FOLLOWING
+    ///     | UNBOUNDED FOLLOWING
+    /// ```
+    fn parse_window_frame_bound<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<WindowFrameBound, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowFrameUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`UNBOUNDED`, `CURRENT ROW` or a number",
+            });
+        }
+
+        let token = tokens[0];
+        tokens.next();
+
+        let (preceding, following) = match token.kind() {
+            TokenKind::NonReservedWord(NonReservedWord::Unbounded) => {
+                (WindowFrameBound::UnboundedPreceding, WindowFrameBound::UnboundedFollowing)
+            }
+
+            TokenKind::UnsignedInteger(integer) => {
+                let value = SimpleValueSpecification::LiteralUnsigned(integer);
+                (WindowFrameBound::Preceding(value), WindowFrameBound::Following(value))
+            }
+
+            TokenKind::ReservedWord(ReservedWord::Current) => {
+                if is_end_of_statement(tokens) {
+                    return Err(StatementParseError::WindowFrameUnexpectedEndOfFile {
+                        found: ErrorFindLocation::EndOfFile { complete_input: input },
+                        expected: "`ROW` after `CURRENT`",
+                    });
+                }
+
+                if !tokens.consume_reserved_word(ReservedWord::Row) {
+                    return Err(StatementParseError::WindowFrameUnexpectedToken {
+                        found: tokens[0].as_string(input).into(),
+                        token_kind: tokens[0].kind(),
+                        expected: "`ROW` after `CURRENT`",
+                    });
+                }
+
+                return Ok(WindowFrameBound::CurrentRow);
+            }
+
+            token_kind => return Err(StatementParseError::WindowFrameUnexpectedToken {
+                found: token.as_string(input).into(),
+                token_kind,
+                expected: "`UNBOUNDED`, `CURRENT ROW` or a number",
+            }),
+        };
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowFrameUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`PRECEDING` or `FOLLOWING`",
+            });
+        }
+
+        if tokens.consume_non_reserved_word(NonReservedWord::Preceding) {
+            Ok(preceding)
+        } else if tokens.consume_non_reserved_word(NonReservedWord::Following) {
+            Ok(following)
+        } else {
+            Err(StatementParseError::WindowFrameUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`PRECEDING` or `FOLLOWING`",
+            })
+        }
+    }
+
+    /// Parses a `<window frame clause>` at the keyword of its `units`.
+    ///
+    /// ```text
+    /// <window frame clause> ::=
+    ///     <window frame units> <window frame extent>
+    ///
+    /// <window frame extent> ::=
+    ///       <window frame start>
+    ///     | BETWEEN <window frame bound 1> AND <window frame bound 2>
+    /// ```
+    fn parse_window_frame_clause<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        units: WindowFrameUnits,
+    ) -> Result<WindowFrameClause, StatementParseError<'input>> {
+        let clause_start = *tokens;
+        tokens.next();
+
+        if !tokens.consume_reserved_word(ReservedWord::Between) {
+            let start = self.parse_window_frame_bound(input, tokens)?;
+            return Ok(WindowFrameClause {
+                units,
+                start,
+                end: None,
+                span: consumed_span(clause_start, tokens),
+            });
+        }
+
+        let start = self.parse_window_frame_bound(input, tokens)?;
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowFrameUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`AND` between the bounds of the frame",
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::And) {
+            return Err(StatementParseError::WindowFrameUnexpectedToken {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                expected: "`AND` between the bounds of the frame",
+            });
+        }
+
+        let end = self.parse_window_frame_bound(input, tokens)?;
+        Ok(WindowFrameClause {
+            units,
+            start,
+            end: Some(end),
+            span: consumed_span(clause_start, tokens),
+        })
+    }
+
+    /// Parses the `OVER` keyword and the window of the `<window function>`
+    /// that is named by the `name_token`.
+    ///
+    /// ```text
+    /// <window function> ::=
+    ///     <window function type> OVER <window name or specification>
+    ///
+    /// <window name or specification> ::=
+    ///       <window name>
+    ///     | <in-line window specification>
+    /// ```
+    fn parse_window_function_over<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        name_token: Token,
+        function_type: WindowFunctionType,
+    ) -> Result<WindowFunction, StatementParseError<'input>> {
+        let function_name = name_token.as_string(input);
+        let start = *tokens;
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowFunctionUnexpectedEndOfFileExpectedOver {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                function_name,
+            });
+        }
+
+        if !tokens.consume_reserved_word(ReservedWord::Over) {
+            return Err(StatementParseError::WindowFunctionUnexpectedTokenExpectedOver {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                function_name,
+            });
+        }
+
+        let window = match tokens.first().copied() {
+            Some(token) if token.kind() == TokenKind::LeftParenthesis => {
+                WindowNameOrSpecification::Specification(self.parse_window_specification(input, tokens)?)
+            }
+
+            Some(token) => match tokens.consume_identifier(input) {
+                Some(name) => WindowNameOrSpecification::Name(name),
+                None => return Err(StatementParseError::WindowSpecificationUnexpectedToken {
+                    found: token.as_string(input).into(),
+                    token_kind: token.kind(),
+                    expected: "a window name or `(` after `OVER`",
+                }),
+            },
+
+            None => return Err(StatementParseError::WindowSpecificationUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "a window name or `(` after `OVER`",
+            }),
+        };
+
+        Ok(WindowFunction {
+            function_type,
+            window,
+            span: name_token.span().to(consumed_span(start, tokens)),
+        })
+    }
+
+    /// Parses a `<window function>` such as `RANK() OVER w`, of which the
+    /// keyword in `name_token` was consumed.
+    fn parse_window_function_without_arguments<'input>(
+        &self,
+        input: &'input str,
+        tokens: &mut &[Token],
+        name_token: Token,
+        function_type: WindowFunctionType,
+    ) -> Result<WindowFunction, StatementParseError<'input>> {
+        let function_name = name_token.as_string(input);
+        let left_paren = self.parse_function_left_paren(input, tokens, function_name)?;
+        self.parse_function_right_paren(input, tokens, function_name, left_paren)?;
+
+        self.parse_window_function_over(input, tokens, name_token, function_type)
+    }
+
+    /// Parses a parenthesized `<window specification>`.
+    ///
+    /// ```text
+    /// <window specification> ::=
+    ///     <left paren> <window specification details> <right paren>
+    ///
+    /// <window specification details> ::=
+    ///     [ <existing window name> ]
+    ///     [ <window partition clause> ]
+    ///     [ <window order clause> ]
+    ///     [ <window frame clause> ]
+    ///
+    /// <window partition clause> ::=
+    ///     PARTITION BY <window partition column reference list>
+    /// ```
+    fn parse_window_specification<'input>(&self, input: &'input str, tokens: &mut &[Token]) -> Result<WindowSpecification, StatementParseError<'input>> {
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowSpecificationUnexpectedEndOfFile {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                expected: "`(`",
+            });
+        }
+
+        let left_paren = tokens[0];
+        if left_paren.kind() != TokenKind::LeftParenthesis {
+            return Err(StatementParseError::WindowSpecificationUnexpectedToken {
+                found: left_paren.as_string(input).into(),
+                token_kind: left_paren.kind(),
+                expected: "`(`",
+            });
+        }
+        tokens.next();
+
+        let mut specification = WindowSpecification {
+            existing_window_name: tokens.consume_identifier(input),
+            ..Default::default()
+        };
+
+        if tokens.consume_reserved_word(ReservedWord::Partition) {
+            if is_end_of_statement(tokens) {
+                return Err(StatementParseError::WindowSpecificationUnexpectedEndOfFile {
+                    found: ErrorFindLocation::EndOfFile { complete_input: input },
+                    expected: "`BY` after `PARTITION`",
+                });
+            }
+
+            if !tokens.consume_reserved_word(ReservedWord::By) {
+                return Err(StatementParseError::WindowSpecificationUnexpectedToken {
+                    found: tokens[0].as_string(input).into(),
+                    token_kind: tokens[0].kind(),
+                    expected: "`BY` after `PARTITION`",
+                });
+            }
+
+            loop {
+                specification.partition_clause.push(self.parse_column_reference(input, tokens)?);
+
+                if tokens.first().map(|token| token.kind()) != Some(TokenKind::Comma) {
+                    break;
+                }
+
+                tokens.next();
+            }
+        }
+
+        if tokens.is_reserved_word() == Some(ReservedWord::Order) {
+            specification.order_clause = Some(self.parse_clause_order_by(input, tokens)?);
+        }
+
+        let frame_units = match tokens.is_reserved_word() {
+            Some(ReservedWord::Rows) => Some(WindowFrameUnits::Rows),
+            Some(ReservedWord::Range) => Some(WindowFrameUnits::Range),
+            _ => None,
+        };
+
+        if let Some(units) = frame_units {
+            specification.frame_clause = Some(self.parse_window_frame_clause(input, tokens, units)?);
+        }
+
+        let should_be_matching = ErrorTokenShouldBeMatching {
+            found: left_paren.as_string(input),
+            token_kind: TokenKind::LeftParenthesis,
+        };
+
+        if is_end_of_statement(tokens) {
+            return Err(StatementParseError::WindowSpecificationUnexpectedEndOfFileExpectedRightParen {
+                found: ErrorFindLocation::EndOfFile { complete_input: input },
+                should_be_matching,
+            });
+        }
+
+        if tokens[0].kind() != TokenKind::RightParenthesis {
+            return Err(StatementParseError::WindowSpecificationUnexpectedTokenExpectedRightParen {
+                found: tokens[0].as_string(input).into(),
+                token_kind: tokens[0].kind(),
+                should_be_matching,
+            });
+        }
+
+        specification.span = left_paren.span().to(tokens[0].span());
+        tokens.next();
+        Ok(specification)
     }
 }
 
@@ -1461,10 +4047,53 @@ mod tests {
     };
 
     use super::*;
+    use crate::{
+        common::{Identifier, IdentifierDelimiter},
+        format::{
+            Format,
+            FormatOptions,
+            KeywordCase,
+        },
+        expression::{
+            boolean_expression::TruthValue,
+            NumericOperator,
+        },
+        predicate::{
+            comparison_predicate::{
+                ComparisonOperator,
+                ComparisonPredicate,
+            },
+            Predicate,
+        },
+        StringLiteralKind,
+        WithoutSpans,
+    };
     use rstest::rstest;
 
     use pretty_assertions::assert_eq;
 
+    /// Parses the `input`, and checks that a successfully parsed statement
+    /// survives a round trip through the formatter, which makes every parser
+    /// test a test of the formatter as well.
+    fn parse_statement_round_trip<'input>(parser: &Parser, input: &'input str) -> StatementResult<'input> {
+        let result = parser.parse_statement(input);
+
+        if let Ok(statement) = &result {
+            let narrow = FormatOptions {
+                keyword_case: KeywordCase::Lower,
+                indentation: 2,
+                line_width: 20,
+            };
+
+            for options in [FormatOptions::single_line(), FormatOptions::default(), narrow] {
+                let formatted = statement.to_sql(&options);
+                assert_eq!(parser.parse_statement(&formatted).without_spans(), Ok(statement.without_spans()), "formatted as:\n{formatted}");
+            }
+        }
+
+        result
+    }
+
     #[rstest]
     #[case("CREATE TABLE table (id INT);", ReservedWord::Table, 13..18)]
     #[case("CREATE TABLE character (value INT);", ReservedWord::Character, 13..22)]
@@ -1474,7 +4103,7 @@ mod tests {
             reserved_word,
         };
 
-        assert_eq!(Parser::new().parse_statement(input), Err(expected));
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
     }
 
     #[rstest]
@@ -1483,7 +4112,105 @@ mod tests {
     fn parser_simple_create_table_statement(#[case] input: &str) {
         let parser = Parser::new();
 
-        parser.parse_statement(input).unwrap();
+        parse_statement_round_trip(&parser, input).unwrap();
+    }
+
+    #[rstest]
+    #[case("BINARY", PredefinedType::BinaryString(BinaryStringType::Fixed { length: 1 }))]
+    #[case("BINARY(16)", PredefinedType::BinaryString(BinaryStringType::Fixed { length: 16 }))]
+    #[case("BINARY VARYING(32)", PredefinedType::BinaryString(BinaryStringType::Varying { length: 32 }))]
+    #[case("VARBINARY(32)", PredefinedType::BinaryString(BinaryStringType::Varying { length: 32 }))]
+    #[case("BINARY LARGE OBJECT", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: None }))]
+    #[case("BINARY LARGE OBJECT(512)", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: Some(large_object_length(512, None)) }))]
+    #[case("BLOB", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: None }))]
+    #[case("blob(1024)", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: Some(large_object_length(1024, None)) }))]
+    #[case("BLOB(1G)", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: Some(large_object_length(1, Some(LargeObjectLengthMultiplier::G))) }))]
+    #[case("BIT", PredefinedType::BitString(BitStringType::Fixed { length: 1 }))]
+    #[case("BIT(8)", PredefinedType::BitString(BitStringType::Fixed { length: 8 }))]
+    #[case("BIT VARYING(64)", PredefinedType::BitString(BitStringType::Varying { length: 64 }))]
+    #[case("CHAR(5)", PredefinedType::CharacterString { definition: CharacterStringType::Fixed { length: 5 }, character_set: None })]
+    #[case("CHARACTER VARYING(20)", PredefinedType::CharacterString { definition: CharacterStringType::Varying { length: 20 }, character_set: None })]
+    #[case("CLOB", PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length: None }, character_set: None })]
+    #[case("CLOB(2M)", PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length: Some(large_object_length(2, Some(LargeObjectLengthMultiplier::M))) }, character_set: None })]
+    #[case("CHARACTER LARGE OBJECT(10 K)", PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length: Some(large_object_length(10, Some(LargeObjectLengthMultiplier::K))) }, character_set: None })]
+    #[case("CHAR LARGE OBJECT", PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length: None }, character_set: None })]
+    #[case("CLOB(1T)", PredefinedType::CharacterString { definition: CharacterStringType::LargeObject { length: Some(large_object_length(1, Some(LargeObjectLengthMultiplier::T))) }, character_set: None })]
+    #[case("BLOB(2p)", PredefinedType::BinaryString(BinaryStringType::LargeObject { length: Some(large_object_length(2, Some(LargeObjectLengthMultiplier::P))) }))]
+    #[case("NCLOB(1G)", PredefinedType::NationalCharacterString(NationalCharacterStringType::LargeObject { length: Some(large_object_length(1, Some(LargeObjectLengthMultiplier::G))) }))]
+    #[case("NCHAR LARGE OBJECT", PredefinedType::NationalCharacterString(NationalCharacterStringType::LargeObject { length: None }))]
+    #[case("NATIONAL CHARACTER LARGE OBJECT(300)", PredefinedType::NationalCharacterString(NationalCharacterStringType::LargeObject { length: Some(large_object_length(300, None)) }))]
+    #[case("NATIONAL CHAR VARYING(8)", PredefinedType::NationalCharacterString(NationalCharacterStringType::Varying { length: 8 }))]
+    fn parser_create_table_statement_data_type(#[case] data_type_input: &str, #[case] data_type: PredefinedType) {
+        let input = format!("CREATE TABLE t (c {data_type_input})");
+
+        let expected = SqlExecutableStatement::Schema(
+            SqlSchemaStatement::Definition(
+                SqlSchemaDefinitionStatement::Table(
+                    TableDefinition {
+                        table_name: "t".into(),
+                        elements: vec![
+                            TableElement::ColumnDefinition(ColumnDefinition {
+                                column_name: "c".into(),
+                                data_type: DataType::Predefined(data_type),
+                                column_constraint_definitions: Vec::new(),
+                                span: Span::default(),
+                            })
+                        ],
+                        span: Span::default(),
+                    }
+                )
+            )
+        );
+
+        assert_eq!(parse_statement_round_trip(&Parser::new(), &input).without_spans(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t (c VARBINARY)", StatementParseError::DataTypeUnexpectedTokenExpectedLeftParen {
+        found: ")".into(),
+        token_kind: TokenKind::RightParenthesis,
+        data_type_name: "VARBINARY",
+    })]
+    #[case("CREATE TABLE t (c BIT VARYING(len))", StatementParseError::DataTypeUnexpectedTokenExpectedLength {
+        found: "len".into(),
+        token_kind: TokenKind::Identifier,
+        data_type_name: "BIT VARYING",
+    })]
+    #[case("CREATE TABLE t (c BINARY(0))", StatementParseError::DataTypeLengthZero {
+        found: "0".into(),
+        data_type_name: "BINARY",
+    })]
+    #[case("CREATE TABLE t (c BIT(0))", StatementParseError::DataTypeLengthZero {
+        found: "0".into(),
+        data_type_name: "BIT",
+    })]
+    #[case("CREATE TABLE t (c BINARY LARGE)", StatementParseError::DataTypeLargeObjectUnexpectedTokenExpectedObject {
+        found: ")".into(),
+        token_kind: TokenKind::RightParenthesis,
+    })]
+    #[case("CREATE TABLE t (c NATIONAL LARGE OBJECT)", StatementParseError::DataTypeNationalExpectedCharacter {
+        found: "LARGE".into(),
+    })]
+    #[case("CREATE TABLE t (c CLOB(2 X))", StatementParseError::DataTypeUnexpectedTokenExpectedRightParen {
+        found: "X".into(),
+        token_kind: TokenKind::Identifier,
+        data_type_name: "CLOB",
+        length: 2,
+        should_be_matching: ErrorTokenShouldBeMatching {
+            found: "(",
+            token_kind: TokenKind::LeftParenthesis,
+        },
+    })]
+    #[case("CREATE TABLE t (c 1)", StatementParseError::DataTypeUnexpectedTokenExpectedKeyword {
+        found: "1".into(),
+        token_kind: TokenKind::UnsignedInteger(1),
+    })]
+    #[case("CREATE TABLE t (c SELECT)", StatementParseError::DataTypeUnknownKeyword {
+        found: "SELECT".into(),
+        reserved_word: ReservedWord::Select,
+    })]
+    fn parser_create_table_statement_data_type_erroneous(#[case] input: &str, #[case] expected: StatementParseError) {
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
     }
 
     #[rstest]
@@ -1498,23 +4225,26 @@ mod tests {
 
         let expected = SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression{
+                Box::new(QueryExpression{
                     body: QueryExpressionBody::SimpleTable(
                         SimpleTable::QuerySpecification(
                             QuerySpecification {
                                 set_quantifier: SetQuantifier::All,
                                 select_list: SelectList::Asterisk,
                                 table_expression: None,
+                                span: Span::default(),
                             }
                         )
                     ),
                     order_by: None,
+                    offset: None,
                     fetch: None,
-                }
+                    span: Span::default(),
+                })
             )
         );
 
-        assert_eq!(parser.parse_statement(input), Ok(expected));
+        assert_eq!(parse_statement_round_trip(&parser, input).without_spans(), Ok(expected));
     }
 
     #[rstest]
@@ -1529,55 +4259,62 @@ mod tests {
 
         let expected = SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression{
+                Box::new(QueryExpression{
                     body: QueryExpressionBody::SimpleTable(
                         SimpleTable::QuerySpecification(
                             QuerySpecification {
                                 set_quantifier: SetQuantifier::All,
                                 select_list: SelectList::Sublist(columns
-                                    .into_iter()
+                                    .iter()
                                     .map(|column| {
                                         SelectSublist::DerivedColumn(
                                             DerivedColumn {
                                                 value_expression: ValueExpression::ColumnReference(
                                                     ColumnReference::BasicIdentifierChain(
                                                         vec![
-                                                            column.to_string()
+                                                            (*column).into()
                                                         ]
                                                     )
                                                 ),
-                                                alias: None
+                                                alias: None,
+                                                span: Span::default(),
                                             }
                                         )
                                     })
                                     .collect()
                                 ),
                                 table_expression: None,
+                                span: Span::default(),
                             }
                         )
                     ),
                     order_by: None,
+                    offset: None,
                     fetch: None,
-                }
+                    span: Span::default(),
+                })
             )
         );
 
-        assert_eq!(parser.parse_statement(input), Ok(expected));
+        assert_eq!(parse_statement_round_trip(&parser, input).without_spans(), Ok(expected));
     }
 
     #[rstest]
     #[case("SELECT 1", 1)]
     #[case("SELECT 1;", 1)]
+    #[case("-- The answer\nSELECT 42 -- to everything\n;", 42)]
+    #[case("SELECT /* inline /* nested */ comment */ 693", 693)]
     #[case("SELECT 693", 693)]
     fn parser_simple_select_number_literal_statement(#[case] input: &str, #[case] numeric_value: u64) {
         let derived_column = DerivedColumn {
-            value_expression: ValueExpression::Numeric(NumericValueExpression::SimpleU64(numeric_value)),
-            alias: None
+            value_expression: ValueExpression::Numeric(NumericValueExpression::SimpleU64(numeric_value, Span::default())),
+            alias: None,
+            span: Span::default(),
         };
 
         let expected = SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression{
+                Box::new(QueryExpression{
                     body: QueryExpressionBody::SimpleTable(
                         SimpleTable::QuerySpecification(
                             QuerySpecification {
@@ -1588,20 +4325,397 @@ mod tests {
                                     )
                                 ]),
                                 table_expression: None,
+                                span: Span::default(),
                             }
                         )
                     ),
                     order_by: None,
+                    offset: None,
                     fetch: None,
-                }
+                    span: Span::default(),
+                })
+            )
+        );
+
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input).without_spans(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("SELECT 1 = 2", ComparisonOperator::EqualTo)]
+    #[case("SELECT 1 > 2", ComparisonOperator::GreaterThan)]
+    #[case("SELECT 1 >= 2", ComparisonOperator::GreaterThanOrEqualTo)]
+    #[case("SELECT 1 < 2", ComparisonOperator::LessThan)]
+    #[case("SELECT 1 <= 2", ComparisonOperator::LessThanOrEqualTo)]
+    #[case("SELECT 1 <> 2", ComparisonOperator::NotEqualTo)]
+    #[case("SELECT 1 != 2", ComparisonOperator::NotEqualTo)]
+    #[case("SELECT 1<>2", ComparisonOperator::NotEqualTo)]
+    fn parser_select_comparison_operators(#[case] input: &str, #[case] operator: ComparisonOperator) {
+        let derived_column = DerivedColumn {
+            value_expression: ValueExpression::Boolean(BooleanExpression::Predicate(Box::new(
+                Predicate::Comparison(ComparisonPredicate {
+                    left_hand_side: ValueExpression::Numeric(NumericValueExpression::SimpleU64(1, Span::default())),
+                    right_hand_side: ValueExpression::Numeric(NumericValueExpression::SimpleU64(2, Span::default())),
+                    operator,
+                    span: Span::default(),
+                })
+            ))),
+            alias: None,
+            span: Span::default(),
+        };
+
+        let expected = SqlExecutableStatement::SqlDataStatement(
+            SqlDataStatement::SelectStatement(
+                Box::new(QueryExpression{
+                    body: QueryExpressionBody::SimpleTable(
+                        SimpleTable::QuerySpecification(
+                            QuerySpecification {
+                                set_quantifier: SetQuantifier::All,
+                                select_list: SelectList::Sublist(vec![
+                                    SelectSublist::DerivedColumn(
+                                        derived_column
+                                    )
+                                ]),
+                                table_expression: None,
+                                span: Span::default(),
+                            }
+                        )
+                    ),
+                    order_by: None,
+                    offset: None,
+                    fetch: None,
+                    span: Span::default(),
+                })
             )
         );
 
-        assert_eq!(Parser::new().parse_statement(input), Ok(expected));
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input).without_spans(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case("SELECT \"Order\" FROM t", &[Identifier::delimited("Order")])]
+    #[case("SELECT \"a\"\"b\" FROM t", &[Identifier::delimited("a\"b")])]
+    #[case("SELECT t.\"Order\" FROM t", &[Identifier::regular("t"), Identifier::delimited("Order")])]
+    #[case("SELECT [Order] FROM t", &[Identifier::delimited_by("Order", IdentifierDelimiter::SquareBrackets)])]
+    #[case("SELECT `Order` FROM t", &[Identifier::delimited_by("Order", IdentifierDelimiter::Backtick)])]
+    fn parser_select_delimited_identifier(#[case] input: &str, #[case] chain: &[Identifier]) {
+        let Ok(SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query_expression))) = parse_statement_round_trip(&Parser::new(), input).without_spans() else {
+            panic!("failed to parse: {input}");
+        };
+
+        let QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(query_specification)) = query_expression.body else {
+            panic!("unexpected query expression body");
+        };
+
+        assert_eq!(query_specification.select_list, SelectList::Sublist(vec![
+            SelectSublist::DerivedColumn(DerivedColumn {
+                value_expression: ValueExpression::ColumnReference(ColumnReference::BasicIdentifierChain(chain.to_vec())),
+                alias: None,
+                span: Span::default(),
+            })
+        ]));
+    }
+
+    #[rstest]
+    #[case("SELECT \"", StatementParseError::LexicalUnterminatedDelimitedIdentifier { found: "\"".into() })]
+    #[case("SELECT \"\" FROM t", StatementParseError::LexicalEmptyDelimitedIdentifier { found: "\"\"".into() })]
+    fn parser_select_delimited_identifier_erroneous(#[case] input: &str, #[case] expected: StatementParseError) {
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
+    }
+
+    fn fetch_first(count: u64, is_percent: bool, with_ties: bool) -> Option<FetchFirstClause> {
+        Some(FetchFirstClause {
+            quantity: FetchFirstQuantity {
+                value: SimpleValueSpecification::LiteralUnsigned(count),
+                is_percent,
+                span: Span::default(),
+            },
+            origin: FetchFirstClauseOrigin::FetchFirst,
+            with_ties,
+            span: Span::default(),
+        })
+    }
+
+    fn limit(count: u64) -> Option<FetchFirstClause> {
+        Some(FetchFirstClause {
+            quantity: FetchFirstQuantity {
+                value: SimpleValueSpecification::LiteralUnsigned(count),
+                is_percent: false,
+                span: Span::default(),
+            },
+            origin: FetchFirstClauseOrigin::Limit,
+            with_ties: false,
+            span: Span::default(),
+        })
+    }
+
+    fn top(count: u64, is_percent: bool, with_ties: bool) -> Option<FetchFirstClause> {
+        Some(FetchFirstClause {
+            quantity: FetchFirstQuantity {
+                value: SimpleValueSpecification::LiteralUnsigned(count),
+                is_percent,
+                span: Span::default(),
+            },
+            origin: FetchFirstClauseOrigin::Top,
+            with_ties,
+            span: Span::default(),
+        })
+    }
+
+    fn offset(count: u64) -> Option<ResultOffsetClause> {
+        Some(ResultOffsetClause {
+            offset_row_count: SimpleValueSpecification::LiteralUnsigned(count),
+            span: Span::default(),
+        })
+    }
+
+    #[rstest]
+    #[case("SELECT a FROM t OFFSET 5 ROWS", offset(5), None)]
+    #[case("SELECT a FROM t OFFSET 1 ROW", offset(1), None)]
+    #[case("SELECT a FROM t FETCH FIRST 10 ROWS ONLY", None, fetch_first(10, false, false))]
+    #[case("SELECT a FROM t FETCH NEXT ROW ONLY", None, fetch_first(1, false, false))]
+    #[case("SELECT a FROM t ORDER BY a FETCH FIRST 3 ROWS WITH TIES", None, fetch_first(3, false, true))]
+    #[case("SELECT a FROM t FETCH FIRST 25 PERCENT ROWS ONLY", None, fetch_first(25, true, false))]
+    #[case("SELECT a FROM t ORDER BY a OFFSET 2 ROWS FETCH NEXT 50 PERCENT ROWS WITH TIES", offset(2), fetch_first(50, true, true))]
+    #[case("SELECT a FROM t LIMIT 10", None, limit(10))]
+    #[case("SELECT a FROM t LIMIT 10 OFFSET 20", offset(20), limit(10))]
+    #[case("SELECT a FROM t LIMIT 20, 10", offset(20), limit(10))]
+    #[case("SELECT a FROM t OFFSET 20 ROWS LIMIT 10", offset(20), limit(10))]
+    #[case("SELECT TOP 10 a FROM t", None, top(10, false, false))]
+    #[case("SELECT TOP (10) * FROM t", None, top(10, false, false))]
+    #[case("SELECT DISTINCT TOP 5 PERCENT a FROM t", None, top(5, true, false))]
+    #[case("SELECT TOP 3 WITH TIES a FROM t ORDER BY a", None, top(3, false, true))]
+    #[case("SELECT TOP (50) PERCENT WITH TIES a FROM t ORDER BY a", None, top(50, true, true))]
+    fn parser_select_offset_fetch(
+        #[case] input: &str,
+        #[case] expected_offset: Option<ResultOffsetClause>,
+        #[case] expected_fetch: Option<FetchFirstClause>,
+    ) {
+        let Ok(SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query_expression))) = parse_statement_round_trip(&Parser::new(), input).without_spans() else {
+            panic!("failed to parse: {input}");
+        };
+
+        assert_eq!(query_expression.offset, expected_offset);
+        assert_eq!(query_expression.fetch, expected_fetch);
+    }
+
+    #[rstest]
+    #[case("SELECT a FROM t OFFSET", StatementParseError::ResultOffsetClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t OFFSET" },
+        expected: "a number",
+    })]
+    #[case("SELECT a FROM t OFFSET 5", StatementParseError::ResultOffsetClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t OFFSET 5" },
+        expected: "`ROW` or `ROWS`",
+    })]
+    #[case("SELECT a FROM t OFFSET x ROWS", StatementParseError::ResultOffsetClauseUnexpectedToken {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+        expected: "a number",
+    })]
+    #[case("SELECT a FROM t FETCH 5 ROWS ONLY", StatementParseError::FetchFirstClauseUnexpectedToken {
+        found: "5".into(),
+        token_kind: TokenKind::UnsignedInteger(5),
+        expected: "`FIRST` or `NEXT`",
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5", StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t FETCH FIRST 5" },
+        expected: "`ROW` or `ROWS`",
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5 ROWS", StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t FETCH FIRST 5 ROWS" },
+        expected: "`ONLY` or `WITH TIES`",
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5 ROWS WITH", StatementParseError::FetchFirstClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t FETCH FIRST 5 ROWS WITH" },
+        expected: "`TIES` after `WITH`",
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5 PERCENT ONLY", StatementParseError::FetchFirstClauseUnexpectedToken {
+        found: "ONLY".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Only),
+        expected: "`ROW` or `ROWS`",
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5 ROWS WITH TIES", StatementParseError::FetchFirstClauseWithTiesWithoutOrderBy {
+        found: "FETCH".into(),
+    })]
+    #[case("SELECT a FROM t FETCH FIRST 5 ROWS ONLY OFFSET 2 ROWS", StatementParseError::SelectStatementUnexpectedToken {
+        found: "OFFSET".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Offset),
+    })]
+    #[case("SELECT a FROM t OFFSET 1 ROW LIMIT 2, 3", StatementParseError::SelectStatementUnexpectedToken {
+        found: ",".into(),
+        token_kind: TokenKind::Comma,
+    })]
+    #[case("SELECT a FROM t LIMIT 10 OFFSET", StatementParseError::VendorLimitClauseUnexpectedEndOfFileExpectedCount {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT a FROM t LIMIT 10 OFFSET" },
+    })]
+    #[case("SELECT a FROM t LIMIT 10, x", StatementParseError::VendorLimitClauseUnexpectedTokenExpectedCount {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+    })]
+    #[case("SELECT TOP", StatementParseError::VendorTopClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT TOP" },
+        expected: "a number",
+    })]
+    #[case("SELECT TOP x FROM t", StatementParseError::VendorTopClauseUnexpectedToken {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+        expected: "a number",
+    })]
+    #[case("SELECT TOP (10 a FROM t", StatementParseError::VendorTopClauseUnexpectedTokenExpectedRightParen {
+        found: "a".into(),
+        token_kind: TokenKind::NonReservedWord(NonReservedWord::A),
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT TOP 10 WITH x FROM t", StatementParseError::VendorTopClauseUnexpectedToken {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+        expected: "`TIES` after `WITH`",
+    })]
+    #[case("SELECT TOP 3 WITH TIES a FROM t", StatementParseError::FetchFirstClauseWithTiesWithoutOrderBy {
+        found: "TOP".into(),
+    })]
+    #[case("SELECT TOP 3 a FROM t LIMIT 5", StatementParseError::FetchFirstClauseConflicting {
+        found: "LIMIT".into(),
+        conflicting_clause: "TOP".into(),
+    })]
+    #[case("SELECT TOP 3 a FROM t ORDER BY a FETCH FIRST 5 ROWS ONLY", StatementParseError::FetchFirstClauseConflicting {
+        found: "FETCH".into(),
+        conflicting_clause: "TOP".into(),
+    })]
+    #[case("SELECT TOP 3 a FROM t ORDER BY a OFFSET 5 ROWS", StatementParseError::FetchFirstClauseConflicting {
+        found: "OFFSET".into(),
+        conflicting_clause: "TOP".into(),
+    })]
+    #[case("SELECT a FROM t LIMIT 3 FETCH FIRST 5 ROWS ONLY", StatementParseError::FetchFirstClauseConflicting {
+        found: "FETCH".into(),
+        conflicting_clause: "LIMIT".into(),
+    })]
+    fn parser_select_offset_fetch_erroneous(#[case] input: &str, #[case] expected: StatementParseError) {
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
+    }
+
+    #[rstest]
+    #[case(Dialect::MySql, "SELECT x FROM t LIMIT 3, 2")]
+    #[case(Dialect::MySql, "SELECT `x` FROM t WHERE x != 0x1F")]
+    #[case(Dialect::PostgreSql, "SELECT x FROM t LIMIT 2 OFFSET 3")]
+    #[case(Dialect::Sqlite, "SELECT [x] FROM t WHERE x == 1")]
+    #[case(Dialect::Standard, "SELECT limit, top FROM t ORDER BY limit FETCH FIRST 2 ROWS ONLY")]
+    #[case(Dialect::TSql, "SELECT TOP (2) [x] FROM t")]
+    #[case(Dialect::TSql, "SELECT limit FROM t")]
+    fn parser_dialect_accepted(#[case] dialect: Dialect, #[case] input: &str) {
+        let parser = Parser::new().with_dialect(dialect);
+        assert_eq!(parser.dialect(), Some(dialect));
+        assert!(parse_statement_round_trip(&parser, input).is_ok(), "{input}: {:?}", parse_statement_round_trip(&parser, input));
+    }
+
+    #[rstest]
+    #[case(Dialect::Standard, "SELECT x FROM t LIMIT 2", "LIMIT", DialectFeature::LimitClause)]
+    #[case(Dialect::TSql, "SELECT x FROM t ORDER BY x LIMIT 2", "LIMIT", DialectFeature::LimitClause)]
+    #[case(Dialect::PostgreSql, "SELECT x FROM t LIMIT 3, 2", ",", DialectFeature::LimitClauseWithComma)]
+    #[case(Dialect::MySql, "SELECT TOP 2 x FROM t", "TOP", DialectFeature::TopClause)]
+    #[case(Dialect::Sqlite, "SELECT TOP (2) x FROM t", "TOP", DialectFeature::TopClause)]
+    #[case(Dialect::Standard, "SELECT x FROM t WHERE x != 1", "!=", DialectFeature::ExclamationNotEqualsOperator)]
+    #[case(Dialect::MySql, "SELECT x FROM t WHERE x == 1", "==", DialectFeature::DoubleEqualsOperator)]
+    #[case(Dialect::Standard, "SELECT 0x1F", "0x1F", DialectFeature::HexadecimalIntegerLiteral)]
+    #[case(Dialect::PostgreSql, "SELECT `x` FROM t", "`", DialectFeature::BacktickDelimitedIdentifier)]
+    #[case(Dialect::MySql, "SELECT [x] FROM t", "[", DialectFeature::BracketDelimitedIdentifier)]
+    fn parser_dialect_unsupported_feature(
+        #[case] dialect: Dialect,
+        #[case] input: &str,
+        #[case] found: &str,
+        #[case] feature: DialectFeature,
+    ) {
+        assert_eq!(
+            parse_statement_round_trip(&Parser::new().with_dialect(dialect), input),
+            Err(StatementParseError::DialectUnsupportedFeature {
+                found: found.into(),
+                dialect,
+                feature,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case(Dialect::TSql, "SELECT x FROM t LIMIT 2", "the `LIMIT` clause isn't supported in T-SQL, but is in MySQL, PostgreSQL or SQLite")]
+    #[case(Dialect::Standard, "SELECT 0x1F", "writing integers in hexadecimal (`0x1F`) isn't supported in standard SQL, but is in MySQL, PostgreSQL, T-SQL or SQLite")]
+    fn parser_dialect_unsupported_feature_message(#[case] dialect: Dialect, #[case] input: &str, #[case] message: &str) {
+        let error = parse_statement_round_trip(&Parser::new().with_dialect(dialect), input).unwrap_err();
+        assert_eq!(error.to_string(), message);
+    }
+
+    #[rstest]
+    #[case("", &[])]
+    #[case("-- only a comment;\n", &[])]
+    #[case(";;", &[])]
+    #[case("SELECT 1", &[(0, 8)])]
+    #[case("SELECT 1;", &[(0, 8)])]
+    #[case("SELECT 1; SELECT 2;\n", &[(0, 8), (10, 18)])]
+    #[case("SELECT ';' ; SELECT 2", &[(0, 10), (13, 21)])]
+    #[case("SELECT 1 /* ; */; SELECT \"a;b\"", &[(0, 8), (18, 30)])]
+    #[case("SELECT 1 -- ;\n; ;; SELECT 2", &[(0, 8), (19, 27)])]
+    fn parser_script(#[case] input: &str, #[case] ranges: &[(usize, usize)]) {
+        let statements = Parser::new().parse_script(input);
+
+        assert_eq!(statements.iter().map(|(range, _)| (range.start, range.end)).collect::<Vec<_>>(), ranges);
+        for (range, result) in statements {
+            assert!(result.is_ok(), "{}: {result:?}", &input[range]);
+        }
+    }
+
+    #[test]
+    fn parser_script_resumes_after_errors() {
+        let input = "SELECT 1;\nSELECT FROM;\nCREATE TABLE t (x INT);\nSELECT #;\nSELECT 2;\nSELECT";
+        let statements = Parser::new().parse_script(input);
+
+        assert_eq!(statements.len(), 6);
+        assert_eq!(
+            statements.iter().map(|(range, _)| &input[range.clone()]).collect::<Vec<_>>(),
+            ["SELECT 1", "SELECT FROM", "CREATE TABLE t (x INT)", "SELECT #", "SELECT 2", "SELECT"]
+        );
+
+        assert!(statements[0].1.is_ok());
+        assert!(statements[1].1.is_err());
+        assert!(statements[2].1.is_ok());
+        assert_eq!(statements[3].1, Err(StatementParseError::LexicalUnexpectedCharacter {
+            found: "#".into(),
+            character: '#',
+        }));
+        assert!(statements[4].1.is_ok());
+        assert!(matches!(statements[5].1, Err(StatementParseError::EofSelectKeywordOnlyToken(..))));
+    }
+
+    #[test]
+    fn parser_script_resumes_after_errors_in_strings() {
+        let input = "SELECT # 'a;b'; SELECT 1; SELECT 2";
+        let statements = Parser::new().parse_script(input);
+
+        assert_eq!(
+            statements.iter().map(|(range, _)| &input[range.clone()]).collect::<Vec<_>>(),
+            ["SELECT # 'a;b'", "SELECT 1", "SELECT 2"]
+        );
+
+        assert_eq!(statements[0].1, Err(StatementParseError::LexicalUnexpectedCharacter {
+            found: "#".into(),
+            character: '#',
+        }));
+        assert!(statements[1].1.is_ok());
+        assert!(statements[2].1.is_ok());
+    }
+
+    #[test]
+    fn parser_script_end_of_file_errors_point_at_statement_end() {
+        let input = "SELECT 1 FETCH; SELECT 2";
+        let statements = Parser::new().parse_script(input);
+
+        assert_eq!(statements.len(), 2);
+        let Err(error) = &statements[0].1 else {
+            panic!("expected an error, got {:?}", statements[0].1);
+        };
+        assert_eq!(error.found(), Some(&ErrorFindLocation::EndOfFile { complete_input: "SELECT 1 FETCH" }));
     }
 
     fn parser_select_statement_erroneous_base<'input>(input: &'input str, expected: StatementParseError<'input>) {
-        assert_eq!(Parser::new().parse_statement(input), Err(expected));
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
     }
 
     /// Can't select every column from every table unfortunately.
@@ -1677,12 +4791,55 @@ mod tests {
             ]
         ]
     )]
+    #[case(
+        "INSERT INTO people VALUES ('O''Brien', N'Zoë', U&'Zo\\00EB', U&'Zo#+0000EB' UESCAPE '#', 'Mc'\n'Donald')",
+        "people",
+        vec![
+            vec![
+                value_expression_string_literal("O'Brien"),
+                value_expression_string_literal_of_kind("Zoë", StringLiteralKind::National),
+                value_expression_string_literal_of_kind("Zoë", StringLiteralKind::Unicode),
+                value_expression_string_literal_of_kind("Zoë", StringLiteralKind::Unicode),
+                value_expression_string_literal("McDonald"),
+            ]
+        ]
+    )]
+    #[case(
+        "INSERT INTO numbers VALUES (-5, +5, - 0, -9223372036854775808, 3.14, -.5, 6.02E23, -1e-3, 0x1F)",
+        "numbers",
+        vec![
+            vec![
+                ValueExpression::Numeric(NumericValueExpression::SimpleI64(-5, Span::default())),
+                value_expression_simple_u64(5),
+                ValueExpression::Numeric(NumericValueExpression::SimpleI64(0, Span::default())),
+                ValueExpression::Numeric(NumericValueExpression::SimpleI64(i64::MIN, Span::default())),
+                ValueExpression::Numeric(NumericValueExpression::ExactNumericLiteral("3.14".into(), Span::default())),
+                ValueExpression::Numeric(NumericValueExpression::ExactNumericLiteral("-.5".into(), Span::default())),
+                ValueExpression::Numeric(NumericValueExpression::ApproximateNumericLiteral("6.02E23".into(), Span::default())),
+                ValueExpression::Numeric(NumericValueExpression::ApproximateNumericLiteral("-1e-3".into(), Span::default())),
+                value_expression_simple_u64(31),
+            ]
+        ]
+    )]
+    #[case(
+        "INSERT INTO hashes VALUES (X'DEADBEEF', x'00 ff', X'', B'1010', b'')",
+        "hashes",
+        vec![
+            vec![
+                ValueExpression::StringValueExpression(StringValueExpression::BinaryLiteral(vec![0xDE, 0xAD, 0xBE, 0xEF], Span::default())),
+                ValueExpression::StringValueExpression(StringValueExpression::BinaryLiteral(vec![0x00, 0xFF], Span::default())),
+                ValueExpression::StringValueExpression(StringValueExpression::BinaryLiteral(Vec::new(), Span::default())),
+                ValueExpression::StringValueExpression(StringValueExpression::BitLiteral(vec![true, false, true, false], Span::default())),
+                ValueExpression::StringValueExpression(StringValueExpression::BitLiteral(Vec::new(), Span::default())),
+            ]
+        ]
+    )]
     fn parser_simple_insert_into_statement(#[case] input: &str, #[case] table_name: &str, #[case] rows: Vec<Vec<ValueExpression>>) {
-        let result = Parser::new().parse_statement(input);
+        let result = parse_statement_round_trip(&Parser::new(), input).without_spans();
 
         let statement = InsertStatement {
             table_name: TableName {
-                table_qualifier: table_name.to_owned(),
+                table_qualifier: table_name.into(),
             },
             insert_columns_and_source: InsertColumnsAndSource::FromConstructor {
                 insert_column_list: None,
@@ -1693,13 +4850,17 @@ mod tests {
                                 ContextuallyTypedRowValueConstructor {
                                     elements: row.iter()
                                         .map(|value| ContextuallyTypedRowValueConstructorElement::ValueExpression(value.clone()))
-                                        .collect()
+                                        .collect(),
+                                    span: Span::default(),
                                 }
                             )
                         })
-                        .collect()
-                }
-            }
+                        .collect(),
+                    span: Span::default(),
+                },
+                span: Span::default(),
+            },
+            span: Span::default(),
         };
 
         let statement = SqlExecutableStatement::SqlDataStatement(
@@ -1724,7 +4885,7 @@ mod tests {
     #[case("SELECT * FROM table1 AS table3, table2", &["table1", "table2"], &[Some("table3"), None])]
     #[case("SELECT * FROM t1 AS t5, t2 as t78", &["t1", "t2"], &[Some("t5"), Some("t78")])]
     fn parser_simple_select_from_statement(#[case] input: &str, #[case] table_names: &[&str], #[case] correlation_names: &[Option<&str>]) {
-        let result = Parser::new().parse_statement(input);
+        let result = parse_statement_round_trip(&Parser::new(), input).without_spans();
         if let Err(e) = &result {
             println!("Failed: {}", e);
         }
@@ -1736,15 +4897,17 @@ mod tests {
             table_references.push(
                 TableReference::Primary(
                     TablePrimary {
-                        kind: TablePrimaryKind::TableOrQueryName(name.to_string()),
-                        correlation_name: correlation_name.map(|s| s.to_string())
+                        kind: TablePrimaryKind::TableOrQueryName((*name).into()),
+                        correlation_name: correlation_name.map(|s| s.to_string()),
+                        span: Span::default(),
                     }
                 )
             );
         }
 
         let from_clause = FromClause {
-            table_references
+            table_references,
+            span: Span::default(),
         };
 
         let body = QueryExpressionBody::SimpleTable(
@@ -1756,19 +4919,24 @@ mod tests {
                         from_clause,
                         where_clause: None,
                         group_by_clause: None,
-                        having_clause: None
-                    })
+                        having_clause: None,
+                        window_clause: None,
+                        span: Span::default(),
+                    }),
+                    span: Span::default(),
                 }
             )
         );
 
         let expected = SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression {
+                Box::new(QueryExpression {
                     body,
                     order_by: None,
+                    offset: None,
                     fetch: None,
-                }
+                    span: Span::default(),
+                })
             )
         );
 
@@ -1783,7 +4951,7 @@ mod tests {
         let parser = Parser::new();
 
         assert_eq!(
-            parser.parse_statement(input),
+            parse_statement_round_trip(&parser, input),
             Err(
                 StatementParseError::SelectStatementUnexpectedToken {
                     found: input[range].into(),
@@ -1793,21 +4961,1017 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("SELECT # FROM t", StatementParseError::LexicalUnexpectedCharacter { found: "#".into(), character: '#' })]
+    #[case("SELECT * FROM t WHERE name = 'unterminated", StatementParseError::LexicalUnterminatedStringLiteral { found: "'".into() })]
+    #[case("SELECT U&'\\00G1'", StatementParseError::LexicalInvalidUnicodeEscapeValue { found: "U&'\\00G1'".into() })]
+    #[case("SELECT U&'a' UESCAPE '+'", StatementParseError::LexicalInvalidUnicodeEscapeCharacter { found: "U&'a' UESCAPE '+'".into() })]
+    #[case("INSERT INTO t VALUES (X'ABC')", StatementParseError::LexicalInvalidBinaryStringLiteral { found: "X'ABC'".into() })]
+    #[case("INSERT INTO t VALUES (B'12')", StatementParseError::LexicalInvalidBitStringLiteral { found: "B'12'".into() })]
+    #[case("SELECT 1 /* unterminated", StatementParseError::LexicalUnterminatedBracketedComment { found: "/* unterminated".into() })]
+    #[case("SELECT 18446744073709551616", StatementParseError::LexicalIntegerOutOfRange { found: "18446744073709551616".into() })]
+    #[case("SELECT 12abc FROM t", StatementParseError::LexicalInvalidNumericLiteral { found: "12abc".into() })]
+    #[case("SELECT -9223372036854775809", StatementParseError::ValueExpressionSignedIntegerOutOfRange { found: "-9223372036854775809".into() })]
+    fn parser_lexical_errors(#[case] input: &str, #[case] expected: StatementParseError) {
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
+    }
+
+    #[rstest]
+    #[case("SELECT 'unterminated", 7)]
+    #[case("SELECT 'it''s", 7)]
+    #[case("SELECT N'unterminated", 8)]
+    #[case("SELECT 'first'\n 'unterminated", 16)]
+    fn parser_unterminated_string_literal_points_at_opening_quote(#[case] input: &str, #[case] opening_quote_byte_idx: usize) {
+        let Err(StatementParseError::LexicalUnterminatedStringLiteral { found: ErrorFindLocation::Position(found) }) = parse_statement_round_trip(&Parser::new(), input).without_spans() else {
+            panic!("expected an unterminated string literal error");
+        };
+
+        assert_eq!(found, "'");
+        assert_eq!(found.as_ptr() as usize - input.as_ptr() as usize, opening_quote_byte_idx);
+    }
+
+    const fn large_object_length(value: usize, multiplier: Option<LargeObjectLengthMultiplier>) -> LargeObjectLength {
+        LargeObjectLength { value, multiplier }
+    }
+
     /// Create a simple u64 `<value expression>`
     const fn value_expression_simple_u64(value: u64) -> ValueExpression {
         ValueExpression::Numeric(
-            NumericValueExpression::SimpleU64(value)
+            NumericValueExpression::SimpleU64(value, Span::new(0, 0))
         )
     }
 
     fn value_expression_string_literal(value: impl Into<std::borrow::Cow<'static, str>>) -> ValueExpression {
+        value_expression_string_literal_of_kind(value, StringLiteralKind::Character)
+    }
+
+    fn value_expression_string_literal_of_kind(value: impl Into<std::borrow::Cow<'static, str>>, kind: StringLiteralKind) -> ValueExpression {
         ValueExpression::StringValueExpression(
             StringValueExpression::Literal(
-                value.into().into_owned()
+                value.into().into_owned(),
+                kind,
+                Span::default(),
             )
         )
     }
 
+    fn value_expression_column(name: &str) -> ValueExpression {
+        ValueExpression::ColumnReference(ColumnReference::BasicIdentifierChain(vec![Identifier::regular(name)]))
+    }
+
+    fn value_expression_numeric(left_hand_side: ValueExpression, operator: NumericOperator, right_hand_side: ValueExpression) -> ValueExpression {
+        ValueExpression::Numeric(NumericValueExpression::BinaryOperation {
+            left_hand_side: Box::new(left_hand_side),
+            operator,
+            right_hand_side: Box::new(right_hand_side),
+        })
+    }
+
+    fn value_expression_comparison(left_hand_side: ValueExpression, operator: ComparisonOperator, right_hand_side: ValueExpression) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::Predicate(Box::new(
+            Predicate::Comparison(ComparisonPredicate { left_hand_side, right_hand_side, operator, span: Span::default() })
+        )))
+    }
+
+    fn value_expression_and(left_hand_side: ValueExpression, right_hand_side: ValueExpression) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::And {
+            left_hand_side: Box::new(left_hand_side),
+            right_hand_side: Box::new(right_hand_side),
+        })
+    }
+
+    fn value_expression_or(left_hand_side: ValueExpression, right_hand_side: ValueExpression) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::Or {
+            left_hand_side: Box::new(left_hand_side),
+            right_hand_side: Box::new(right_hand_side),
+        })
+    }
+
+    fn value_expression_not(operand: ValueExpression) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::Not(Box::new(operand), Span::default()))
+    }
+
+    fn value_expression_is(operand: ValueExpression, negated: bool, truth_value: TruthValue) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::Is {
+            operand: Box::new(operand),
+            negated,
+            truth_value,
+            span: Span::default(),
+        })
+    }
+
+    fn value_expression_predicate(predicate: Predicate) -> ValueExpression {
+        ValueExpression::Boolean(BooleanExpression::Predicate(Box::new(predicate)))
+    }
+
+    fn value_expression_concatenation(left_hand_side: ValueExpression, right_hand_side: ValueExpression) -> ValueExpression {
+        ValueExpression::StringValueExpression(StringValueExpression::Concatenation {
+            left_hand_side: Box::new(left_hand_side),
+            right_hand_side: Box::new(right_hand_side),
+        })
+    }
+
+    /// Parses `SELECT <value expression>` and returns the value expression.
+    fn parse_select_value_expression(input: &str) -> ValueExpression {
+        let statement = parse_statement_round_trip(&Parser::new(), input).unwrap();
+        let SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query)) = statement else {
+            panic!("not a SELECT statement: {statement:#?}");
+        };
+        let QueryExpression {
+            body: QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(specification)),
+            ..
+        } = *query else {
+            panic!("not a simple SELECT statement: {query:#?}");
+        };
+
+        let SelectList::Sublist(mut sublist) = specification.select_list else {
+            panic!("SELECT list without derived columns");
+        };
+
+        let SelectSublist::DerivedColumn(derived_column) = sublist.remove(0);
+        derived_column.value_expression
+    }
+
+    #[rstest]
+    #[case("SELECT 1 + 2 * 3", "1 + 2 * 3")]
+    #[case("SELECT -x * 2 FROM t", "-x * 2")]
+    #[case("SELECT -5", "-5")]
+    #[case("SELECT (1 + 2)", "1 + 2")]
+    #[case("SELECT s.\"Name\" AS n", "s.\"Name\"")]
+    #[case("SELECT 'a' || 'b'", "'a' || 'b'")]
+    #[case("SELECT NOT a AND b", "NOT a AND b")]
+    #[case("SELECT x IS NOT NULL", "x IS NOT NULL")]
+    #[case("SELECT x BETWEEN 1 AND 2", "x BETWEEN 1 AND 2")]
+    #[case("SELECT x NOT IN (1, 2)", "x NOT IN (1, 2)")]
+    #[case("SELECT x LIKE 'a%' ESCAPE '!'", "x LIKE 'a%' ESCAPE '!'")]
+    #[case("SELECT x = ANY (1, 2)", "x = ANY (1, 2)")]
+    #[case("SELECT CASE WHEN a THEN 1 ELSE NULL END", "CASE WHEN a THEN 1 ELSE NULL END")]
+    #[case("SELECT COALESCE(a, 1)", "COALESCE(a, 1)")]
+    #[case("SELECT NULLIF(a, 1)", "NULLIF(a, 1)")]
+    #[case("SELECT CAST(a AS INT)", "CAST(a AS INT)")]
+    #[case("SELECT UPPER(name)", "UPPER(name)")]
+    #[case("SELECT TRIM(LEADING 'x' FROM y)", "TRIM(LEADING 'x' FROM y)")]
+    #[case("SELECT POSITION('a' IN b)", "POSITION('a' IN b)")]
+    #[case("SELECT COUNT(*)", "COUNT(*)")]
+    #[case("SELECT SUM(DISTINCT a)", "SUM(DISTINCT a)")]
+    #[case("SELECT RANK() OVER (ORDER BY a ROWS UNBOUNDED PRECEDING)", "RANK() OVER (ORDER BY a ROWS UNBOUNDED PRECEDING)")]
+    #[case("SELECT MAX(a) OVER w", "MAX(a) OVER w")]
+    fn value_expression_span(#[case] input: &str, #[case] expected: &str) {
+        let value_expression = parse_select_value_expression(input);
+        assert_eq!(&input[value_expression.span().range()], expected);
+    }
+
+    #[test]
+    fn select_statement_spans() {
+        let input = "SELECT DISTINCT a AS x, b FROM t AS u WHERE a > 1 ORDER BY b DESC OFFSET 2 ROWS;";
+        let statement = parse_statement_round_trip(&Parser::new(), input).unwrap();
+        assert_eq!(&input[statement.span().range()], "SELECT DISTINCT a AS x, b FROM t AS u WHERE a > 1 ORDER BY b DESC OFFSET 2 ROWS");
+
+        let SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query)) = statement else {
+            panic!("not a SELECT statement: {statement:#?}");
+        };
+        let QueryExpression {
+            body: QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(specification)),
+            order_by: Some(order_by),
+            offset: Some(offset),
+            ..
+        } = *query else {
+            panic!("not a simple SELECT statement: {query:#?}");
+        };
+
+        assert_eq!(&input[specification.span().range()], "SELECT DISTINCT a AS x, b FROM t AS u WHERE a > 1");
+
+        let SelectList::Sublist(sublist) = &specification.select_list else {
+            panic!("SELECT list without derived columns");
+        };
+        assert_eq!(&input[sublist[0].span().range()], "a AS x");
+
+        let table_expression = specification.table_expression.unwrap();
+        assert_eq!(&input[table_expression.span().range()], "FROM t AS u WHERE a > 1");
+        assert_eq!(&input[table_expression.from_clause.span().range()], "FROM t AS u");
+        assert_eq!(&input[table_expression.from_clause.table_references[0].span().range()], "t AS u");
+        assert_eq!(&input[table_expression.where_clause.unwrap().span().range()], "WHERE a > 1");
+
+        assert_eq!(&input[order_by.span().range()], "ORDER BY b DESC");
+        assert_eq!(&input[order_by.sort_specification_list[0].span().range()], "b DESC");
+        assert_eq!(&input[offset.span().range()], "OFFSET 2 ROWS");
+    }
+
+    #[rstest]
+    #[case("CREATE TABLE t (a INT, b VARCHAR(20))", "b VARCHAR(20)")]
+    #[case("INSERT INTO t VALUES (1, 'x'), (2, 'y');", "VALUES (1, 'x'), (2, 'y')")]
+    #[case("SELECT TOP (5) PERCENT a FROM t", "TOP (5) PERCENT")]
+    #[case("SELECT a FROM t LIMIT 5 OFFSET 2", "LIMIT 5 OFFSET 2")]
+    #[case("SELECT a FROM t ORDER BY a FETCH FIRST 5 ROWS WITH TIES", "FETCH FIRST 5 ROWS WITH TIES")]
+    #[case("SELECT a FROM t WINDOW w AS (PARTITION BY a)", "WINDOW w AS (PARTITION BY a)")]
+    fn statement_part_span(#[case] input: &str, #[case] expected: &str) {
+        let statement = parse_statement_round_trip(&Parser::new(), input).unwrap();
+        assert_eq!(&input[statement.span().range()], input.trim_end_matches(';'));
+
+        let span = match statement {
+            SqlExecutableStatement::Schema(SqlSchemaStatement::Definition(SqlSchemaDefinitionStatement::Table(definition))) => {
+                definition.elements.last().unwrap().span()
+            }
+            SqlExecutableStatement::SqlDataStatement(SqlDataStatement::ChangeStatement(SqlDataChangeStatement::Insert(statement))) => {
+                statement.insert_columns_and_source.span()
+            }
+            SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query)) => {
+                let QueryExpression {
+                    body: QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(specification)),
+                    fetch,
+                    ..
+                } = *query else {
+                    panic!("not a simple SELECT statement: {query:#?}");
+                };
+
+                match fetch {
+                    Some(fetch) => fetch.span(),
+                    None => specification.table_expression.unwrap().window_clause.unwrap().span(),
+                }
+            }
+            _ => panic!("unexpected statement: {statement:#?}"),
+        };
+
+        assert_eq!(&input[span.range()], expected);
+    }
+
+    #[rstest]
+    #[case("SELECT 1 + 2 * 3", value_expression_numeric(
+        value_expression_simple_u64(1),
+        NumericOperator::Addition,
+        value_expression_numeric(value_expression_simple_u64(2), NumericOperator::Multiplication, value_expression_simple_u64(3)),
+    ))]
+    #[case("SELECT 1 * 2 + 3", value_expression_numeric(
+        value_expression_numeric(value_expression_simple_u64(1), NumericOperator::Multiplication, value_expression_simple_u64(2)),
+        NumericOperator::Addition,
+        value_expression_simple_u64(3),
+    ))]
+    #[case("SELECT 1 - 2 - 3", value_expression_numeric(
+        value_expression_numeric(value_expression_simple_u64(1), NumericOperator::Subtraction, value_expression_simple_u64(2)),
+        NumericOperator::Subtraction,
+        value_expression_simple_u64(3),
+    ))]
+    #[case("SELECT 8 / 4 / 2", value_expression_numeric(
+        value_expression_numeric(value_expression_simple_u64(8), NumericOperator::Division, value_expression_simple_u64(4)),
+        NumericOperator::Division,
+        value_expression_simple_u64(2),
+    ))]
+    #[case("SELECT (1 + 2) * 3", value_expression_numeric(
+        value_expression_numeric(value_expression_simple_u64(1), NumericOperator::Addition, value_expression_simple_u64(2)),
+        NumericOperator::Multiplication,
+        value_expression_simple_u64(3),
+    ))]
+    #[case("SELECT 1 - -2", value_expression_numeric(
+        value_expression_simple_u64(1),
+        NumericOperator::Subtraction,
+        ValueExpression::Numeric(NumericValueExpression::SimpleI64(-2, Span::default())),
+    ))]
+    #[case("SELECT -x * 2", value_expression_numeric(
+        ValueExpression::Numeric(NumericValueExpression::Negation(Box::new(value_expression_column("x")), Span::default())),
+        NumericOperator::Multiplication,
+        value_expression_simple_u64(2),
+    ))]
+    #[case("SELECT -(1 + x)", ValueExpression::Numeric(NumericValueExpression::Negation(Box::new(
+        value_expression_numeric(value_expression_simple_u64(1), NumericOperator::Addition, value_expression_column("x")),
+    ), Span::default())))]
+    #[case("SELECT +x", value_expression_column("x"))]
+    #[case("SELECT ((x))", value_expression_column("x"))]
+    #[case("SELECT x || 'y' || z", value_expression_concatenation(
+        value_expression_concatenation(value_expression_column("x"), value_expression_string_literal("y")),
+        value_expression_column("z"),
+    ))]
+    #[case("SELECT x || y = z", value_expression_comparison(
+        value_expression_concatenation(value_expression_column("x"), value_expression_column("y")),
+        ComparisonOperator::EqualTo,
+        value_expression_column("z"),
+    ))]
+    #[case("SELECT x + 1 < y * 2", value_expression_comparison(
+        value_expression_numeric(value_expression_column("x"), NumericOperator::Addition, value_expression_simple_u64(1)),
+        ComparisonOperator::LessThan,
+        value_expression_numeric(value_expression_column("y"), NumericOperator::Multiplication, value_expression_simple_u64(2)),
+    ))]
+    #[case("SELECT NOT x = 1", value_expression_not(
+        value_expression_comparison(value_expression_column("x"), ComparisonOperator::EqualTo, value_expression_simple_u64(1)),
+    ))]
+    #[case("SELECT NOT x AND y", value_expression_and(
+        value_expression_not(value_expression_column("x")),
+        value_expression_column("y"),
+    ))]
+    #[case("SELECT x = 1 OR y = 2 AND z = 3", value_expression_or(
+        value_expression_comparison(value_expression_column("x"), ComparisonOperator::EqualTo, value_expression_simple_u64(1)),
+        value_expression_and(
+            value_expression_comparison(value_expression_column("y"), ComparisonOperator::EqualTo, value_expression_simple_u64(2)),
+            value_expression_comparison(value_expression_column("z"), ComparisonOperator::EqualTo, value_expression_simple_u64(3)),
+        ),
+    ))]
+    #[case("SELECT (x OR y) AND z", value_expression_and(
+        value_expression_or(value_expression_column("x"), value_expression_column("y")),
+        value_expression_column("z"),
+    ))]
+    #[case("SELECT x OR y OR z", value_expression_or(
+        value_expression_or(value_expression_column("x"), value_expression_column("y")),
+        value_expression_column("z"),
+    ))]
+    #[case("SELECT x IS TRUE", value_expression_is(value_expression_column("x"), false, TruthValue::True))]
+    #[case("SELECT x IS NOT UNKNOWN", value_expression_is(value_expression_column("x"), true, TruthValue::Unknown))]
+    #[case("SELECT UNKNOWN", ValueExpression::Boolean(BooleanExpression::Unknown(Span::default())))]
+    #[case("SELECT x = 1 IS FALSE", value_expression_is(
+        value_expression_comparison(value_expression_column("x"), ComparisonOperator::EqualTo, value_expression_simple_u64(1)),
+        false,
+        TruthValue::False,
+    ))]
+    #[case("SELECT NOT x IS TRUE", value_expression_not(
+        value_expression_is(value_expression_column("x"), false, TruthValue::True),
+    ))]
+    #[case("SELECT x AND y IS NOT FALSE", value_expression_and(
+        value_expression_column("x"),
+        value_expression_is(value_expression_column("y"), true, TruthValue::False),
+    ))]
+    #[case("SELECT (x OR y) IS UNKNOWN IS FALSE", value_expression_is(
+        value_expression_is(value_expression_or(value_expression_column("x"), value_expression_column("y")), false, TruthValue::Unknown),
+        false,
+        TruthValue::False,
+    ))]
+    fn parser_value_expression_precedence(#[case] input: &str, #[case] expected: ValueExpression) {
+        assert_eq!(parse_select_value_expression(input).without_spans(), expected);
+    }
+
+    #[rstest]
+    #[case("SELECT x IS NULL", value_expression_predicate(Predicate::Null(NullPredicate {
+        operand: value_expression_column("x"),
+        negated: false,
+        span: Span::default(),
+    })))]
+    #[case("SELECT x + 1 IS NOT NULL", value_expression_predicate(Predicate::Null(NullPredicate {
+        operand: value_expression_numeric(value_expression_column("x"), NumericOperator::Addition, value_expression_simple_u64(1)),
+        negated: true,
+        span: Span::default(),
+    })))]
+    #[case("SELECT x IS DISTINCT FROM y || 'z'", value_expression_predicate(Predicate::Distinct(DistinctPredicate {
+        left_hand_side: value_expression_column("x"),
+        negated: false,
+        right_hand_side: value_expression_concatenation(value_expression_column("y"), value_expression_string_literal("z")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT NOT x IS NOT DISTINCT FROM y", value_expression_not(value_expression_predicate(Predicate::Distinct(DistinctPredicate {
+        left_hand_side: value_expression_column("x"),
+        negated: true,
+        right_hand_side: value_expression_column("y"),
+        span: Span::default(),
+    }))))]
+    #[case("SELECT x BETWEEN 1 AND y + 2 AND z", value_expression_and(
+        value_expression_predicate(Predicate::Between(BetweenPredicate {
+            operand: value_expression_column("x"),
+            negated: false,
+            symmetric: false,
+            lower_bound: value_expression_simple_u64(1),
+            upper_bound: value_expression_numeric(value_expression_column("y"), NumericOperator::Addition, value_expression_simple_u64(2)),
+            span: Span::default(),
+        })),
+        value_expression_column("z"),
+    ))]
+    #[case("SELECT x NOT BETWEEN SYMMETRIC 1 AND 2", value_expression_predicate(Predicate::Between(BetweenPredicate {
+        operand: value_expression_column("x"),
+        negated: true,
+        symmetric: true,
+        lower_bound: value_expression_simple_u64(1),
+        upper_bound: value_expression_simple_u64(2),
+        span: Span::default(),
+    })))]
+    #[case("SELECT x BETWEEN ASYMMETRIC 1 AND 2", value_expression_predicate(Predicate::Between(BetweenPredicate {
+        operand: value_expression_column("x"),
+        negated: false,
+        symmetric: false,
+        lower_bound: value_expression_simple_u64(1),
+        upper_bound: value_expression_simple_u64(2),
+        span: Span::default(),
+    })))]
+    #[case("SELECT x IN (1, y, 'z')", value_expression_predicate(Predicate::In(InPredicate {
+        operand: value_expression_column("x"),
+        negated: false,
+        values: vec![value_expression_simple_u64(1), value_expression_column("y"), value_expression_string_literal("z")],
+        span: Span::default(),
+    })))]
+    #[case("SELECT NOT x NOT IN (1) OR y", value_expression_or(
+        value_expression_not(value_expression_predicate(Predicate::In(InPredicate {
+            operand: value_expression_column("x"),
+            negated: true,
+            values: vec![value_expression_simple_u64(1)],
+            span: Span::default(),
+        }))),
+        value_expression_column("y"),
+    ))]
+    #[case("SELECT x LIKE 'a%'", value_expression_predicate(Predicate::Like(LikePredicate {
+        operand: value_expression_column("x"),
+        negated: false,
+        pattern: value_expression_string_literal("a%"),
+        escape: None,
+        span: Span::default(),
+    })))]
+    #[case("SELECT x || y NOT LIKE '%!%' ESCAPE '!'", value_expression_predicate(Predicate::Like(LikePredicate {
+        operand: value_expression_concatenation(value_expression_column("x"), value_expression_column("y")),
+        negated: true,
+        pattern: value_expression_string_literal("%!%"),
+        escape: Some(value_expression_string_literal("!")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT x SIMILAR TO '(a|b)%' AND y NOT SIMILAR TO 'c' ESCAPE '#'", value_expression_and(
+        value_expression_predicate(Predicate::Similar(SimilarPredicate {
+            operand: value_expression_column("x"),
+            negated: false,
+            pattern: value_expression_string_literal("(a|b)%"),
+            escape: None,
+            span: Span::default(),
+        })),
+        value_expression_predicate(Predicate::Similar(SimilarPredicate {
+            operand: value_expression_column("y"),
+            negated: true,
+            pattern: value_expression_string_literal("c"),
+            escape: Some(value_expression_string_literal("#")),
+            span: Span::default(),
+        })),
+    ))]
+    #[case("SELECT x = ANY (1, 2)", value_expression_predicate(Predicate::QuantifiedComparison(QuantifiedComparisonPredicate {
+        left_hand_side: value_expression_column("x"),
+        operator: ComparisonOperator::EqualTo,
+        quantifier: Quantifier::Any,
+        values: vec![value_expression_simple_u64(1), value_expression_simple_u64(2)],
+        span: Span::default(),
+    })))]
+    #[case("SELECT x + 1 > ALL (y) AND x < SOME (z)", value_expression_and(
+        value_expression_predicate(Predicate::QuantifiedComparison(QuantifiedComparisonPredicate {
+            left_hand_side: value_expression_numeric(value_expression_column("x"), NumericOperator::Addition, value_expression_simple_u64(1)),
+            operator: ComparisonOperator::GreaterThan,
+            quantifier: Quantifier::All,
+            values: vec![value_expression_column("y")],
+            span: Span::default(),
+        })),
+        value_expression_predicate(Predicate::QuantifiedComparison(QuantifiedComparisonPredicate {
+            left_hand_side: value_expression_column("x"),
+            operator: ComparisonOperator::LessThan,
+            quantifier: Quantifier::Any,
+            values: vec![value_expression_column("z")],
+            span: Span::default(),
+        })),
+    ))]
+    fn parser_predicates(#[case] input: &str, #[case] expected: ValueExpression) {
+        assert_eq!(parse_select_value_expression(input).without_spans(), expected);
+    }
+
+    #[rstest]
+    #[case("SELECT CASE WHEN x > 1 THEN 'many' WHEN x = 1 THEN 'one' END", ValueExpression::Case(CaseExpression::Searched {
+        when_clauses: vec![
+            SearchedWhenClause {
+                search_condition: value_expression_comparison(value_expression_column("x"), ComparisonOperator::GreaterThan, value_expression_simple_u64(1)),
+                result: CaseResult::ValueExpression(value_expression_string_literal("many")),
+                span: Span::default(),
+            },
+            SearchedWhenClause {
+                search_condition: value_expression_comparison(value_expression_column("x"), ComparisonOperator::EqualTo, value_expression_simple_u64(1)),
+                result: CaseResult::ValueExpression(value_expression_string_literal("one")),
+                span: Span::default(),
+            },
+        ],
+        else_clause: None,
+        span: Span::default(),
+    }))]
+    #[case("SELECT CASE x + 1 WHEN 2 THEN NULL ELSE x END", ValueExpression::Case(CaseExpression::Simple {
+        operand: Box::new(value_expression_numeric(value_expression_column("x"), NumericOperator::Addition, value_expression_simple_u64(1))),
+        when_clauses: vec![
+            SimpleWhenClause {
+                when_operand: value_expression_simple_u64(2),
+                result: CaseResult::Null(Span::default()),
+                span: Span::default(),
+            },
+        ],
+        else_clause: Some(Box::new(CaseResult::ValueExpression(value_expression_column("x")))),
+        span: Span::default(),
+    }))]
+    #[case("SELECT CASE WHEN x IS NULL THEN 0 ELSE NULL END", ValueExpression::Case(CaseExpression::Searched {
+        when_clauses: vec![
+            SearchedWhenClause {
+                search_condition: value_expression_predicate(Predicate::Null(NullPredicate {
+                    operand: value_expression_column("x"),
+                    negated: false,
+                    span: Span::default(),
+                })),
+                result: CaseResult::ValueExpression(value_expression_simple_u64(0)),
+                span: Span::default(),
+            },
+        ],
+        else_clause: Some(Box::new(CaseResult::Null(Span::default()))),
+        span: Span::default(),
+    }))]
+    #[case("SELECT COALESCE(x, y || 'z', 'none')", ValueExpression::Case(CaseExpression::Coalesce(vec![
+        value_expression_column("x"),
+        value_expression_concatenation(value_expression_column("y"), value_expression_string_literal("z")),
+        value_expression_string_literal("none"),
+    ], Span::default())))]
+    #[case("SELECT NULLIF(x, 0) + 1", value_expression_numeric(
+        ValueExpression::Case(CaseExpression::NullIf {
+            left_hand_side: Box::new(value_expression_column("x")),
+            right_hand_side: Box::new(value_expression_simple_u64(0)),
+            span: Span::default(),
+        }),
+        NumericOperator::Addition,
+        value_expression_simple_u64(1),
+    ))]
+    #[case("SELECT CAST(x AS VARCHAR(10))", ValueExpression::Cast(CastSpecification {
+        operand: CastOperand::ValueExpression(Box::new(value_expression_column("x"))),
+        target: DataType::Predefined(PredefinedType::CharacterString {
+            definition: CharacterStringType::Varying { length: 10 },
+            character_set: None,
+        }),
+        span: Span::default(),
+    }))]
+    #[case("SELECT CAST(NULL AS INT) IS NULL", value_expression_predicate(Predicate::Null(NullPredicate {
+        operand: ValueExpression::Cast(CastSpecification {
+            operand: CastOperand::Null,
+            target: DataType::Predefined(PredefinedType::Numeric(NumericType::Integer)),
+            span: Span::default(),
+        }),
+        negated: false,
+        span: Span::default(),
+    })))]
+    fn parser_case_and_cast_expressions(#[case] input: &str, #[case] expected: ValueExpression) {
+        assert_eq!(parse_select_value_expression(input).without_spans(), expected);
+    }
+
+    #[rstest]
+    #[case("SELECT UPPER(name)", ValueExpression::RoutineInvocation(RoutineInvocation {
+        name: Identifier::regular("UPPER"),
+        arguments: vec![value_expression_column("name")],
+        span: Span::default(),
+    }))]
+    #[case("SELECT mod(x, 2) = 0", value_expression_comparison(
+        ValueExpression::RoutineInvocation(RoutineInvocation {
+            name: Identifier::regular("MOD"),
+            arguments: vec![value_expression_column("x"), value_expression_simple_u64(2)],
+            span: Span::default(),
+        }),
+        ComparisonOperator::EqualTo,
+        value_expression_simple_u64(0),
+    ))]
+    #[case("SELECT \"Reverse\"(name), now()", ValueExpression::RoutineInvocation(RoutineInvocation {
+        name: Identifier::delimited("Reverse"),
+        arguments: vec![value_expression_column("name")],
+        span: Span::default(),
+    }))]
+    #[case("SELECT TRIM(name)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Trim {
+        specification: TrimSpecification::Both,
+        character: None,
+        source: Box::new(value_expression_column("name")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT TRIM(FROM name)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Trim {
+        specification: TrimSpecification::Both,
+        character: None,
+        source: Box::new(value_expression_column("name")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT TRIM(LEADING '0' FROM code)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Trim {
+        specification: TrimSpecification::Leading,
+        character: Some(Box::new(value_expression_string_literal("0"))),
+        source: Box::new(value_expression_column("code")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT TRIM(TRAILING FROM name)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Trim {
+        specification: TrimSpecification::Trailing,
+        character: None,
+        source: Box::new(value_expression_column("name")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT TRIM('x' FROM name)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Trim {
+        specification: TrimSpecification::Both,
+        character: Some(Box::new(value_expression_string_literal("x"))),
+        source: Box::new(value_expression_column("name")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT SUBSTRING(name FROM 2 FOR x + 1)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Substring {
+        operand: Box::new(value_expression_column("name")),
+        start_position: Box::new(value_expression_simple_u64(2)),
+        string_length: Some(Box::new(value_expression_numeric(value_expression_column("x"), NumericOperator::Addition, value_expression_simple_u64(1)))),
+        span: Span::default(),
+    })))]
+    #[case("SELECT SUBSTRING(name FROM 2)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Substring {
+        operand: Box::new(value_expression_column("name")),
+        start_position: Box::new(value_expression_simple_u64(2)),
+        string_length: None,
+        span: Span::default(),
+    })))]
+    #[case("SELECT OVERLAY(name PLACING 'ab' FROM 3 FOR 1)", ValueExpression::StringValueExpression(StringValueExpression::Function(StringValueFunction::Overlay {
+        operand: Box::new(value_expression_column("name")),
+        placing: Box::new(value_expression_string_literal("ab")),
+        start_position: Box::new(value_expression_simple_u64(3)),
+        string_length: Some(Box::new(value_expression_simple_u64(1))),
+        span: Span::default(),
+    })))]
+    #[case("SELECT POSITION('a' || 'b' IN name) > 0", value_expression_comparison(
+        ValueExpression::Numeric(NumericValueExpression::Function(NumericValueFunction::Position {
+            needle: Box::new(value_expression_concatenation(value_expression_string_literal("a"), value_expression_string_literal("b"))),
+            haystack: Box::new(value_expression_column("name")),
+            span: Span::default(),
+        })),
+        ComparisonOperator::GreaterThan,
+        value_expression_simple_u64(0),
+    ))]
+    fn parser_function_expressions(#[case] input: &str, #[case] expected: ValueExpression) {
+        assert_eq!(parse_select_value_expression(input).without_spans(), expected);
+    }
+
+    fn sort_specification(name: &str, ordering_specification: Option<OrderingSpecification>) -> SortSpecification {
+        SortSpecification {
+            sort_key: ColumnReference::BasicIdentifierChain(vec![Identifier::regular(name)]),
+            ordering_specification,
+            span: Span::default(),
+        }
+    }
+
+    fn set_function_general(function_type: ComputationalOperation, set_quantifier: SetQuantifier, name: &str) -> SetFunctionSpecification {
+        SetFunctionSpecification::General(GeneralSetFunction {
+            function_type,
+            set_quantifier,
+            value_expression: Box::new(value_expression_column(name)),
+            span: Span::default(),
+        })
+    }
+
+    #[rstest]
+    #[case("SELECT MAX(x)", ValueExpression::SetFunctionSpecification(
+        set_function_general(ComputationalOperation::Max, SetQuantifier::All, "x")
+    ))]
+    #[case("SELECT COUNT(DISTINCT x)", ValueExpression::SetFunctionSpecification(
+        set_function_general(ComputationalOperation::Count, SetQuantifier::Distinct, "x")
+    ))]
+    #[case("SELECT ROW_NUMBER() OVER ()", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::RowNumber,
+        window: WindowNameOrSpecification::Specification(WindowSpecification::default()),
+        span: Span::default(),
+    })))]
+    #[case("SELECT COUNT(*) OVER w", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::Aggregate(SetFunctionSpecification::Count(Span::default())),
+        window: WindowNameOrSpecification::Name(Identifier::regular("w")),
+        span: Span::default(),
+    })))]
+    #[case("SELECT DENSE_RANK() OVER (PARTITION BY a, b ORDER BY c DESC)", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::Rank(RankFunctionType::DenseRank),
+        window: WindowNameOrSpecification::Specification(WindowSpecification {
+            existing_window_name: None,
+            partition_clause: vec![
+                ColumnReference::BasicIdentifierChain(vec![Identifier::regular("a")]),
+                ColumnReference::BasicIdentifierChain(vec![Identifier::regular("b")]),
+            ],
+            order_clause: Some(OrderByClause {
+                sort_specification_list: vec![sort_specification("c", Some(OrderingSpecification::Descending))],
+                span: Span::default(),
+            }),
+            frame_clause: None,
+            span: Span::default(),
+        }),
+        span: Span::default(),
+    })))]
+    #[case("SELECT SUM(x) OVER (ORDER BY d ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::Aggregate(set_function_general(ComputationalOperation::Sum, SetQuantifier::All, "x")),
+        window: WindowNameOrSpecification::Specification(WindowSpecification {
+            existing_window_name: None,
+            partition_clause: Vec::new(),
+            order_clause: Some(OrderByClause {
+                sort_specification_list: vec![sort_specification("d", None)],
+                span: Span::default(),
+            }),
+            frame_clause: Some(WindowFrameClause {
+                units: WindowFrameUnits::Rows,
+                start: WindowFrameBound::Preceding(SimpleValueSpecification::LiteralUnsigned(2)),
+                end: Some(WindowFrameBound::CurrentRow),
+                span: Span::default(),
+            }),
+            span: Span::default(),
+        }),
+        span: Span::default(),
+    })))]
+    #[case("SELECT avg(x) OVER (w RANGE UNBOUNDED PRECEDING)", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::Aggregate(set_function_general(ComputationalOperation::Avg, SetQuantifier::All, "x")),
+        window: WindowNameOrSpecification::Specification(WindowSpecification {
+            existing_window_name: Some(Identifier::regular("w")),
+            partition_clause: Vec::new(),
+            order_clause: None,
+            frame_clause: Some(WindowFrameClause {
+                units: WindowFrameUnits::Range,
+                start: WindowFrameBound::UnboundedPreceding,
+                end: None,
+                span: Span::default(),
+            }),
+            span: Span::default(),
+        }),
+        span: Span::default(),
+    })))]
+    #[case("SELECT MIN(x) OVER (RANGE BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING)", ValueExpression::WindowFunction(Box::new(WindowFunction {
+        function_type: WindowFunctionType::Aggregate(set_function_general(ComputationalOperation::Min, SetQuantifier::All, "x")),
+        window: WindowNameOrSpecification::Specification(WindowSpecification {
+            frame_clause: Some(WindowFrameClause {
+                units: WindowFrameUnits::Range,
+                start: WindowFrameBound::Following(SimpleValueSpecification::LiteralUnsigned(1)),
+                end: Some(WindowFrameBound::UnboundedFollowing),
+                span: Span::default(),
+            }),
+            ..Default::default()
+        }),
+        span: Span::default(),
+    })))]
+    fn parser_window_functions(#[case] input: &str, #[case] expected: ValueExpression) {
+        assert_eq!(parse_select_value_expression(input).without_spans(), expected);
+    }
+
+    #[test]
+    fn parser_window_clause() {
+        let statement = parse_statement_round_trip(&Parser::new(), "SELECT RANK() OVER v FROM t WINDOW w AS (PARTITION BY a), v AS (w ORDER BY b)").unwrap().without_spans();
+        let SqlExecutableStatement::SqlDataStatement(SqlDataStatement::SelectStatement(query)) = statement else {
+            panic!("not a SELECT statement: {statement:#?}");
+        };
+        let QueryExpression {
+            body: QueryExpressionBody::SimpleTable(SimpleTable::QuerySpecification(specification)),
+            ..
+        } = *query else {
+            panic!("not a simple SELECT statement: {query:#?}");
+        };
+
+        assert_eq!(
+            specification.table_expression.unwrap().window_clause,
+            Some(WindowClause {
+                window_definitions: vec![
+                    WindowDefinition {
+                        name: Identifier::regular("w"),
+                        specification: WindowSpecification {
+                            partition_clause: vec![ColumnReference::BasicIdentifierChain(vec![Identifier::regular("a")])],
+                            ..Default::default()
+                        },
+                        span: Span::default(),
+                    },
+                    WindowDefinition {
+                        name: Identifier::regular("v"),
+                        specification: WindowSpecification {
+                            existing_window_name: Some(Identifier::regular("w")),
+                            order_clause: Some(OrderByClause {
+                                sort_specification_list: vec![sort_specification("b", None)],
+                                span: Span::default(),
+                            }),
+                            ..Default::default()
+                        },
+                        span: Span::default(),
+                    },
+                ],
+                span: Span::default(),
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("SELECT (1 + 2", StatementParseError::ValueExpressionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT (1 + 2" },
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT (1 + 2 FROM t", StatementParseError::ValueExpressionUnexpectedTokenExpectedRightParen {
+        found: "FROM".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::From),
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT 1 +", StatementParseError::ValueExpressionUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT 1 +" },
+    })]
+    #[case("SELECT 1 * FROM t", StatementParseError::ValueExpressionUnexpectedToken {
+        found: "FROM".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::From),
+    })]
+    #[case("SELECT NOT", StatementParseError::ValueExpressionUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT NOT" },
+    })]
+    #[case("SELECT x IS", StatementParseError::BooleanTestUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x IS" },
+    })]
+    #[case("SELECT x IS NOT;", StatementParseError::BooleanTestUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x IS NOT;" },
+    })]
+    #[case("SELECT x IS 1", StatementParseError::BooleanTestUnexpectedToken {
+        found: "1".into(),
+        token_kind: TokenKind::UnsignedInteger(1),
+    })]
+    #[case("SELECT x IS DISTINCT y", StatementParseError::DistinctPredicateUnexpectedTokenExpectedFrom {
+        found: "y".into(),
+        token_kind: TokenKind::Identifier,
+    })]
+    #[case("SELECT x IS NOT DISTINCT", StatementParseError::DistinctPredicateUnexpectedEndOfFileExpectedFrom {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x IS NOT DISTINCT" },
+    })]
+    #[case("SELECT x BETWEEN 1 OR 2", StatementParseError::BetweenPredicateUnexpectedTokenExpectedAnd {
+        found: "OR".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Or),
+    })]
+    #[case("SELECT x BETWEEN 1", StatementParseError::BetweenPredicateUnexpectedEndOfFileExpectedAnd {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x BETWEEN 1" },
+    })]
+    #[case("SELECT x IN 1", StatementParseError::InValueListUnexpectedTokenExpectedLeftParen {
+        found: "1".into(),
+        token_kind: TokenKind::UnsignedInteger(1),
+    })]
+    #[case("SELECT x NOT IN", StatementParseError::InValueListUnexpectedEndOfFileExpectedLeftParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x NOT IN" },
+    })]
+    #[case("SELECT x IN (1, 2", StatementParseError::InValueListUnexpectedEndOfFileExpectedCommaOrRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x IN (1, 2" },
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT x IN (1 2)", StatementParseError::InValueListUnexpectedTokenExpectedCommaOrRightParen {
+        found: "2".into(),
+        token_kind: TokenKind::UnsignedInteger(2),
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT x = ANY (SELECT y FROM t)", StatementParseError::UnsupportedFeature {
+        feature_name: "subqueries",
+        feature_description: "Using the rows of a query as the list of values",
+        found: "SELECT".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Select),
+    })]
+    #[case("SELECT x SIMILAR 'a'", StatementParseError::SimilarPredicateUnexpectedTokenExpectedTo {
+        found: "'a'".into(),
+        token_kind: TokenKind::StringLiteral { kind: StringLiteralKind::Character },
+    })]
+    #[case("SELECT x NOT SIMILAR", StatementParseError::SimilarPredicateUnexpectedEndOfFileExpectedTo {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x NOT SIMILAR" },
+    })]
+    #[case("SELECT x LIKE 'a' ESCAPE", StatementParseError::ValueExpressionUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x LIKE 'a' ESCAPE" },
+    })]
+    #[case("SELECT CASE END", StatementParseError::CaseExpressionUnexpectedTokenExpectedWhen {
+        found: "END".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::End),
+    })]
+    #[case("SELECT CASE x", StatementParseError::CaseExpressionUnexpectedEndOfFileExpectedWhen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT CASE x" },
+    })]
+    #[case("SELECT CASE WHEN x 1 END", StatementParseError::CaseExpressionUnexpectedTokenExpectedThen {
+        found: "1".into(),
+        token_kind: TokenKind::UnsignedInteger(1),
+    })]
+    #[case("SELECT CASE WHEN x THEN 1", StatementParseError::CaseExpressionUnexpectedEndOfFileExpectedEnd {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT CASE WHEN x THEN 1" },
+        should_be_matching: ErrorTokenShouldBeMatching { found: "CASE", token_kind: TokenKind::ReservedWord(ReservedWord::Case) },
+    })]
+    #[case("SELECT CASE WHEN x THEN 1 ELSE 2 FROM t", StatementParseError::CaseExpressionUnexpectedTokenExpectedEnd {
+        found: "FROM".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::From),
+        should_be_matching: ErrorTokenShouldBeMatching { found: "CASE", token_kind: TokenKind::ReservedWord(ReservedWord::Case) },
+    })]
+    #[case("SELECT COALESCE x", StatementParseError::FunctionUnexpectedTokenExpectedLeftParen {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+        function_name: "COALESCE",
+    })]
+    #[case("SELECT COALESCE(x, y", StatementParseError::FunctionUnexpectedEndOfFileExpectedCommaOrRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT COALESCE(x, y" },
+        function_name: "COALESCE",
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT NULLIF(x, y, z)", StatementParseError::FunctionUnexpectedArgumentCount {
+        found: "NULLIF".into(),
+        function_name: "NULLIF",
+        expected_argument_count: 2,
+        argument_count: 3,
+    })]
+    #[case("SELECT CAST", StatementParseError::FunctionUnexpectedEndOfFileExpectedLeftParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT CAST" },
+        function_name: "CAST",
+    })]
+    #[case("SELECT CAST(x INT)", StatementParseError::CastSpecificationUnexpectedTokenExpectedAs {
+        found: "INT".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Int),
+    })]
+    #[case("SELECT CAST(x AS", StatementParseError::DataTypeUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT CAST(x AS" },
+    })]
+    #[case("SELECT CAST(x AS 'a')", StatementParseError::DataTypeUnexpectedTokenExpectedKeyword {
+        found: "'a'".into(),
+        token_kind: TokenKind::StringLiteral { kind: StringLiteralKind::Character },
+    })]
+    #[case("SELECT CAST(x AS INT", StatementParseError::FunctionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT CAST(x AS INT" },
+        function_name: "CAST",
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT UPPER s", StatementParseError::FunctionUnexpectedTokenExpectedLeftParen {
+        found: "s".into(),
+        token_kind: TokenKind::Identifier,
+        function_name: "UPPER",
+    })]
+    #[case("SELECT lower(name,", StatementParseError::ValueExpressionUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT lower(name," },
+    })]
+    #[case("SELECT f(1 2)", StatementParseError::FunctionUnexpectedTokenExpectedCommaOrRightParen {
+        found: "2".into(),
+        token_kind: TokenKind::UnsignedInteger(2),
+        function_name: "f",
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT SUBSTRING(name, 1)", StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+        found: ",".into(),
+        token_kind: TokenKind::Comma,
+        function_name: "SUBSTRING",
+        keyword: "FROM",
+    })]
+    #[case("SELECT SUBSTRING(name FROM 1 FOR 2", StatementParseError::FunctionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT SUBSTRING(name FROM 1 FOR 2" },
+        function_name: "SUBSTRING",
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT TRIM(LEADING 'x' s)", StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+        found: "s".into(),
+        token_kind: TokenKind::Identifier,
+        function_name: "TRIM",
+        keyword: "FROM",
+    })]
+    #[case("SELECT POSITION('a' s)", StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+        found: "s".into(),
+        token_kind: TokenKind::Identifier,
+        function_name: "POSITION",
+        keyword: "IN",
+    })]
+    #[case("SELECT OVERLAY(name FROM 1)", StatementParseError::FunctionUnexpectedTokenExpectedKeyword {
+        found: "FROM".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::From),
+        function_name: "OVERLAY",
+        keyword: "PLACING",
+    })]
+    #[case("SELECT OVERLAY(name PLACING 'a'", StatementParseError::FunctionUnexpectedEndOfFileExpectedKeyword {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT OVERLAY(name PLACING 'a'" },
+        function_name: "OVERLAY",
+        keyword: "FROM",
+    })]
+    #[case("SELECT RANK()", StatementParseError::WindowFunctionUnexpectedEndOfFileExpectedOver {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT RANK()" },
+        function_name: "RANK",
+    })]
+    #[case("SELECT row_number() FROM t", StatementParseError::WindowFunctionUnexpectedTokenExpectedOver {
+        found: "FROM".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::From),
+        function_name: "row_number",
+    })]
+    #[case("SELECT SUM(x) OVER", StatementParseError::WindowSpecificationUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT SUM(x) OVER" },
+        expected: "a window name or `(` after `OVER`",
+    })]
+    #[case("SELECT SUM(x) OVER (PARTITION x)", StatementParseError::WindowSpecificationUnexpectedToken {
+        found: "x".into(),
+        token_kind: TokenKind::Identifier,
+        expected: "`BY` after `PARTITION`",
+    })]
+    #[case("SELECT SUM(x) OVER (ORDER BY x", StatementParseError::WindowSpecificationUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT SUM(x) OVER (ORDER BY x" },
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT SUM(x) OVER (ORDER BY x PARTITION BY y)", StatementParseError::WindowSpecificationUnexpectedTokenExpectedRightParen {
+        found: "PARTITION".into(),
+        token_kind: TokenKind::ReservedWord(ReservedWord::Partition),
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    #[case("SELECT SUM(x) OVER (ROWS 1)", StatementParseError::WindowFrameUnexpectedToken {
+        found: ")".into(),
+        token_kind: TokenKind::RightParenthesis,
+        expected: "`PRECEDING` or `FOLLOWING`",
+    })]
+    #[case("SELECT SUM(x) OVER (ROWS CURRENT s)", StatementParseError::WindowFrameUnexpectedToken {
+        found: "s".into(),
+        token_kind: TokenKind::Identifier,
+        expected: "`ROW` after `CURRENT`",
+    })]
+    #[case("SELECT SUM(x) OVER (RANGE BETWEEN CURRENT ROW UNBOUNDED FOLLOWING)", StatementParseError::WindowFrameUnexpectedToken {
+        found: "UNBOUNDED".into(),
+        token_kind: TokenKind::NonReservedWord(NonReservedWord::Unbounded),
+        expected: "`AND` between the bounds of the frame",
+    })]
+    #[case("SELECT SUM(x) OVER (ROWS", StatementParseError::WindowFrameUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT SUM(x) OVER (ROWS" },
+        expected: "`UNBOUNDED`, `CURRENT ROW` or a number",
+    })]
+    #[case("SELECT x FROM t WINDOW w (ORDER BY x)", StatementParseError::WindowClauseUnexpectedToken {
+        found: "(".into(),
+        token_kind: TokenKind::LeftParenthesis,
+        expected: "`AS` after the window name",
+    })]
+    #[case("SELECT x FROM t WINDOW", StatementParseError::WindowClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT x FROM t WINDOW" },
+        expected: "a window name",
+    })]
+    #[case("SELECT COUNT(", StatementParseError::ValueExpressionUnexpectedEndOfFile {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT COUNT(" },
+    })]
+    #[case("SELECT MAX(x", StatementParseError::FunctionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation::EndOfFile { complete_input: "SELECT MAX(x" },
+        function_name: "MAX",
+        should_be_matching: ErrorTokenShouldBeMatching { found: "(", token_kind: TokenKind::LeftParenthesis },
+    })]
+    fn parser_value_expression_erroneous(#[case] input: &str, #[case] expected: StatementParseError) {
+        assert_eq!(parse_statement_round_trip(&Parser::new(), input), Err(expected));
+    }
+
     #[rstest]
     #[case(
         "SELECT * FROM numbers",
@@ -1823,6 +5987,7 @@ mod tests {
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["number".into()]),
                 ordering_specification: None,
+                span: Span::default(),
             }
         ]
     )]
@@ -1834,6 +5999,7 @@ mod tests {
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["number".into()]),
                 ordering_specification: Some(OrderingSpecification::Ascending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1845,6 +6011,7 @@ mod tests {
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["number".into()]),
                 ordering_specification: Some(OrderingSpecification::Descending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1854,25 +6021,29 @@ mod tests {
         SelectList::Sublist(vec![
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["FirstName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["FirstName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             }),
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["LastName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["LastName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             })
         ]),
         &[
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["LastName".into()]),
                 ordering_specification: None,
+                span: Span::default(),
             },
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["FirstName".into()]),
                 ordering_specification: None,
+                span: Span::default(),
             }
         ]
     )]
@@ -1882,25 +6053,29 @@ mod tests {
         SelectList::Sublist(vec![
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["FirstName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["FirstName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             }),
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["LastName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["LastName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             })
         ]),
         &[
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["LastName".into()]),
                 ordering_specification: Some(OrderingSpecification::Ascending),
+                span: Span::default(),
             },
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["FirstName".into()]),
                 ordering_specification: Some(OrderingSpecification::Ascending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1910,25 +6085,29 @@ mod tests {
         SelectList::Sublist(vec![
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["FirstName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["FirstName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             }),
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["LastName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["LastName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             })
         ]),
         &[
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["LastName".into()]),
                 ordering_specification: Some(OrderingSpecification::Descending),
+                span: Span::default(),
             },
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["FirstName".into()]),
                 ordering_specification: Some(OrderingSpecification::Descending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1938,25 +6117,29 @@ mod tests {
         SelectList::Sublist(vec![
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["FirstName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["FirstName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             }),
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["LastName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["LastName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             })
         ]),
         &[
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["LastName".into()]),
                 ordering_specification: Some(OrderingSpecification::Ascending),
+                span: Span::default(),
             },
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["FirstName".into()]),
                 ordering_specification: Some(OrderingSpecification::Descending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1966,25 +6149,29 @@ mod tests {
         SelectList::Sublist(vec![
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["FirstName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["FirstName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             }),
             SelectSublist::DerivedColumn(DerivedColumn {
                 value_expression: ValueExpression::ColumnReference(
-                    ColumnReference::BasicIdentifierChain(vec!["LastName".to_string()])
+                    ColumnReference::BasicIdentifierChain(vec!["LastName".into()])
                 ),
                 alias: None,
+                span: Span::default(),
             })
         ]),
         &[
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["LastName".into()]),
                 ordering_specification: Some(OrderingSpecification::Descending),
+                span: Span::default(),
             },
             SortSpecification {
                 sort_key: ColumnReference::BasicIdentifierChain(vec!["FirstName".into()]),
                 ordering_specification: Some(OrderingSpecification::Ascending),
+                span: Span::default(),
             }
         ]
     )]
@@ -1994,7 +6181,8 @@ mod tests {
         #[case] select_list: SelectList,
         #[case] specifications: &[SortSpecification]
     ) {
-        let actual_statement = Parser::new().parse_statement(input)
+        let actual_statement = parse_statement_round_trip(&Parser::new(), input)
+            .without_spans()
             .expect("failed to parse statement");
 
         let query_spec = QuerySpecification {
@@ -2008,20 +6196,25 @@ mod tests {
                                 kind: TablePrimaryKind::TableOrQueryName(
                                     table_name.into()
                                 ),
-                                correlation_name: None
+                                correlation_name: None,
+                                span: Span::default(),
                             }
                         )
-                    ]
+                    ],
+                    span: Span::default(),
                 },
                 where_clause: None,
                 group_by_clause: None,
-                having_clause: None
-            })
+                having_clause: None,
+                window_clause: None,
+                span: Span::default(),
+            }),
+            span: Span::default(),
         };
 
         let expected_statement = SqlExecutableStatement::SqlDataStatement(
             SqlDataStatement::SelectStatement(
-                QueryExpression {
+                Box::new(QueryExpression {
                     body: QueryExpressionBody::SimpleTable(
                         SimpleTable::QuerySpecification(query_spec)
                     ),
@@ -2029,11 +6222,14 @@ mod tests {
                         None
                     } else {
                         Some(OrderByClause {
-                            sort_specification_list: specifications.into()
+                            sort_specification_list: specifications.into(),
+                            span: Span::default(),
                         })
                     },
+                    offset: None,
                     fetch: None,
-                }
+                    span: Span::default(),
+                })
             )
         );
 
@@ -9,6 +9,8 @@ use strum::{
 use thiserror::Error;
 
 use crate::{
+    Dialect,
+    DialectFeature,
     ReservedWord,
     TokenKind,
 };
@@ -55,7 +57,7 @@ pub struct ErrorTokenShouldBeMatching<'input> {
 #[derive(Copy, Clone, Debug, Error, PartialEq, EnumProperty, AsRefStr, enum_fields::EnumFields)]
 pub enum StatementParseError<'input> {
     #[error("unexpected reserved identifier: {reserved_word}, expected an identifier as alias")]
-    #[strum(props(Hint="Did you forget to escape the identifier?"))]
+    #[strum(props(Hint="Did you forget to escape the identifier with double quotes `\"`?"))]
     AsClauseUnexpectedReservedWord {
         found: ErrorFindLocation<'input>,
         reserved_word: ReservedWord,
@@ -67,6 +69,84 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("unexpected end-of-file: expected AND between the bounds of BETWEEN")]
+    BetweenPredicateUnexpectedEndOfFileExpectedAnd {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected AND between the bounds of BETWEEN")]
+    #[strum(props(Hint="The range of BETWEEN is written as `lower AND upper`, e.g. `x BETWEEN 1 AND 10`."))]
+    BetweenPredicateUnexpectedTokenExpectedAnd {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("unexpected end-of-file: expected TRUE, FALSE, UNKNOWN, NULL or DISTINCT FROM after IS")]
+    BooleanTestUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected TRUE, FALSE, UNKNOWN, NULL or DISTINCT FROM after IS")]
+    #[strum(props(Hint="Use `=` to compare two values, or `IS [NOT] DISTINCT FROM` to compare values that may be null."))]
+    BooleanTestUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("unexpected end-of-file: expected END to close the CASE expression")]
+    #[strum(props(Hint="Did you forget to close the CASE expression with END?"))]
+    CaseExpressionUnexpectedEndOfFileExpectedEnd {
+        found: ErrorFindLocation<'input>,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected THEN after the WHEN operand")]
+    #[strum(props(Help="Each WHEN clause needs a result, e.g. `WHEN x > 1 THEN 'many'`"))]
+    CaseExpressionUnexpectedEndOfFileExpectedThen {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected WHEN in CASE expression")]
+    #[strum(props(Help="A CASE expression needs at least one WHEN clause, e.g. `CASE WHEN x > 1 THEN 'many' END`"))]
+    CaseExpressionUnexpectedEndOfFileExpectedWhen {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected WHEN, ELSE or END in CASE expression")]
+    #[strum(props(Hint="Did you forget to close the CASE expression with END?"))]
+    CaseExpressionUnexpectedTokenExpectedEnd {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected THEN after the WHEN operand")]
+    #[strum(props(Help="Each WHEN clause needs a result, e.g. `WHEN x > 1 THEN 'many'`"))]
+    CaseExpressionUnexpectedTokenExpectedThen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected WHEN in CASE expression")]
+    #[strum(props(Help="A CASE expression needs at least one WHEN clause, e.g. `CASE WHEN x > 1 THEN 'many' END`"))]
+    CaseExpressionUnexpectedTokenExpectedWhen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("unexpected end-of-file: expected AS followed by the target data type of CAST")]
+    #[strum(props(Help="Specify the type to convert to, e.g. `CAST(x AS INTEGER)`"))]
+    CastSpecificationUnexpectedEndOfFileExpectedAs {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected AS followed by the target data type of CAST")]
+    #[strum(props(Help="Specify the type to convert to, e.g. `CAST(x AS INTEGER)`"))]
+    CastSpecificationUnexpectedTokenExpectedAs {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
     #[error("unexpected end-of-file: expected column reference")]
     ColumnReferenceUnexpectedEndOfFile {
         found: ErrorFindLocation<'input>,
@@ -111,7 +191,7 @@ pub enum StatementParseError<'input> {
     },
 
     #[error("unexpected keyword: `{reserved_word}` (`{found}`): expected an identifier as the name of the correlation name (alias)")]
-    #[strum(props(Hint="Did you forget to escape the identifier?"))]
+    #[strum(props(Hint="Did you forget to escape the identifier with double quotes `\"`?"))]
     CorrelationNameUnexpectedReservedWord {
         found: ErrorFindLocation<'input>,
         reserved_word: ReservedWord,
@@ -137,7 +217,7 @@ pub enum StatementParseError<'input> {
 
     #[error("unexpected keyword: `{reserved_word}` (`{found}`): expected an identifier as the name of the table to create.")]
     #[strum(props(Help="The identifier you provided cannot be used as a table name."))]
-    #[strum(props(Hint="Did you forget to escape the identifier?"))]
+    #[strum(props(Hint="Did you forget to escape the identifier with double quotes `\"`?"))]
     CreateTableStatementExpectedTableNameIdentifierUnexpectedKeyword {
         found: ErrorFindLocation<'input>,
         reserved_word: ReservedWord,
@@ -166,6 +246,94 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("unexpected end-of-file: expected `OBJECT` after `LARGE`")]
+    #[strum(props(Help="Complete the large object data type, e.g. `BINARY LARGE OBJECT`"))]
+    DataTypeLargeObjectUnexpectedEndOfFileExpectedObject {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected `OBJECT` after `LARGE`")]
+    #[strum(props(Help="Complete the large object data type, e.g. `BINARY LARGE OBJECT`"))]
+    DataTypeLargeObjectUnexpectedTokenExpectedObject {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("the length of `{data_type_name}` must be at least 1")]
+    #[strum(props(Help="Specify a positive length, e.g. `BINARY(1)`"))]
+    DataTypeLengthZero {
+        found: ErrorFindLocation<'input>,
+        data_type_name: &'static str,
+    },
+
+    #[error("expected `CHARACTER` or `CHAR` after `NATIONAL`")]
+    #[strum(props(Help="Complete the national character data type, e.g. `NATIONAL CHARACTER LARGE OBJECT`"))]
+    DataTypeNationalExpectedCharacter {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected a data type")]
+    #[strum(props(Help="Specify a data type, e.g. `INT`, `VARCHAR(50)`, etc."))]
+    DataTypeUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected `(` after `{data_type_name}`")]
+    DataTypeUnexpectedEndOfFileExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+        data_type_name: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected a number indicating the length of `{data_type_name}`")]
+    DataTypeUnexpectedEndOfFileExpectedLength {
+        found: ErrorFindLocation<'input>,
+        data_type_name: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected closing parenthesis `)` after `{data_type_name}({length}`")]
+    DataTypeUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        data_type_name: &'static str,
+        length: usize,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected keyword as data type")]
+    #[strum(props(Help="Specify a data type instead of this token, e.g. `INT`, `NVARCHAR`, etc."))]
+    DataTypeUnexpectedTokenExpectedKeyword {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected `(` after `{data_type_name}`")]
+    DataTypeUnexpectedTokenExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        data_type_name: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected a number indicating the length of `{data_type_name}`")]
+    DataTypeUnexpectedTokenExpectedLength {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        data_type_name: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected `)` after `{data_type_name}({length}`")]
+    DataTypeUnexpectedTokenExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        data_type_name: &'static str,
+        length: usize,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unknown keyword {reserved_word} (`{found}`), expected data type")]
+    DataTypeUnknownKeyword {
+        found: ErrorFindLocation<'input>,
+        reserved_word: ReservedWord,
+    },
+
     #[error("unexpected end-of-file: expected `(` after `VARCHAR`")]
     #[strum(props(Help="Complete the VARCHAR data type: `VARCHAR( <maximum length> )`"))]
     DataTypeVarcharUnexpectedEndOfFileExpectedLeftParen {
@@ -209,6 +377,26 @@ pub enum StatementParseError<'input> {
         should_be_matching: ErrorTokenShouldBeMatching<'input>,
     },
 
+    #[error("{} isn't supported in {dialect}, but is in {}", .feature.description(), .feature.dialect_names())]
+    #[strum(props(Help="Choose a dialect that accepts this construct, or rewrite it in standard SQL"))]
+    DialectUnsupportedFeature {
+        found: ErrorFindLocation<'input>,
+        dialect: Dialect,
+        feature: DialectFeature,
+    },
+
+    #[error("unexpected end-of-file: expected FROM after IS DISTINCT")]
+    DistinctPredicateUnexpectedEndOfFileExpectedFrom {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected FROM after IS DISTINCT")]
+    #[strum(props(Hint="The distinct predicate is written as `x IS [NOT] DISTINCT FROM y`."))]
+    DistinctPredicateUnexpectedTokenExpectedFrom {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
     #[error("empty input provided for statement")]
     EmptyInput,
 
@@ -221,6 +409,34 @@ pub enum StatementParseError<'input> {
     #[error("unexpected end-of-file: expected a <select list>, but end of statement reached. Expected either the wildcard '*' expression or a <value expression>")]
     EofSelectList(&'input str),
 
+    #[error("`{found}` conflicts with `{conflicting_clause}`, as the number of rows can only be limited once")]
+    #[strum(props(Help="Use only one of `TOP`, `FETCH FIRST` or `LIMIT` to limit the number of rows"))]
+    FetchFirstClauseConflicting {
+        found: ErrorFindLocation<'input>,
+        conflicting_clause: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected {expected} in the FETCH clause")]
+    #[strum(props(Help="A fetch first clause looks like `FETCH FIRST 10 ROWS ONLY`"))]
+    FetchFirstClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} in the FETCH clause")]
+    #[strum(props(Help="A fetch first clause looks like `FETCH FIRST 10 ROWS ONLY`"))]
+    FetchFirstClauseUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        expected: &'static str,
+    },
+
+    #[error("`WITH TIES` requires an `ORDER BY` clause")]
+    #[strum(props(Hint="Rows can only tie when they are sorted, e.g. `ORDER BY score DESC FETCH FIRST 3 ROWS WITH TIES`"))]
+    FetchFirstClauseWithTiesWithoutOrderBy {
+        found: ErrorFindLocation<'input>,
+    },
+
     #[error("unexpected end-of-file, expected FROM clause")]
     FromClauseUnexpectedEof  {
         found: ErrorFindLocation<'input>,
@@ -232,6 +448,99 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("`{function_name}` expects {expected_argument_count} arguments, but got {argument_count}")]
+    FunctionUnexpectedArgumentCount {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+        expected_argument_count: usize,
+        argument_count: usize,
+    },
+
+    #[error("unexpected end-of-file: expected comma `,` or closing parenthesis `)` in the arguments of `{function_name}`")]
+    FunctionUnexpectedEndOfFileExpectedCommaOrRightParen {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected keyword `{keyword}` in the arguments of `{function_name}`")]
+    FunctionUnexpectedEndOfFileExpectedKeyword {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+        keyword: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected `(` after `{function_name}`")]
+    FunctionUnexpectedEndOfFileExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+    },
+
+    #[error("unexpected end-of-file: expected closing parenthesis `)` after the arguments of `{function_name}`")]
+    FunctionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected comma `,` or closing parenthesis `)` in the arguments of `{function_name}`")]
+    FunctionUnexpectedTokenExpectedCommaOrRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        function_name: &'input str,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected keyword `{keyword}` in the arguments of `{function_name}`")]
+    FunctionUnexpectedTokenExpectedKeyword {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        function_name: &'input str,
+        keyword: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected `(` after `{function_name}`")]
+    FunctionUnexpectedTokenExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        function_name: &'input str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected closing parenthesis `)` after the arguments of `{function_name}`")]
+    FunctionUnexpectedTokenExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        function_name: &'input str,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected `(` to start the list of values")]
+    InValueListUnexpectedEndOfFileExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected end-of-file: expected comma `,` or closing parenthesis `)` in the list of values")]
+    #[strum(props(Hint="Did you forget to close the list of values?"))]
+    InValueListUnexpectedEndOfFileExpectedCommaOrRightParen {
+        found: ErrorFindLocation<'input>,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected comma `,` or closing parenthesis `)` in the list of values")]
+    #[strum(props(Hint="Did you forget a comma `,` between the values, or `)` to close the list?"))]
+    InValueListUnexpectedTokenExpectedCommaOrRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected `(` to start the list of values")]
+    #[strum(props(Hint="The values must be parenthesized, e.g. `x IN (1, 2, 3)`."))]
+    InValueListUnexpectedTokenExpectedLeftParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
     /// TODO: when column source information is parsed, this should be changed
     ///       to also hint at the insertion of `( column name, ... )`
     #[error("unexpected end-of-file, expected `VALUES` keyword")]
@@ -298,6 +607,72 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("delimited identifier `{found}` is empty")]
+    #[strum(props(Help="Delimited identifiers must contain at least one character, e.g. `\"Name\"`"))]
+    LexicalEmptyDelimitedIdentifier {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("integer literal `{found}` is out of range")]
+    #[strum(props(Help="Integer literals must fit in 64 bits, use an exact numeric literal with a period for larger values, e.g. `18446744073709551616.0`"))]
+    LexicalIntegerOutOfRange {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("invalid binary string literal `{found}`")]
+    #[strum(props(Help="Binary string literals consist of pairs of hexadecimal digits, e.g. `X'DEADBEEF'`"))]
+    LexicalInvalidBinaryStringLiteral {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("invalid bit string literal `{found}`")]
+    #[strum(props(Help="Bit string literals consist of zeroes and ones, e.g. `B'1010'`"))]
+    LexicalInvalidBitStringLiteral {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("invalid numeric literal `{found}`")]
+    #[strum(props(Help="Numeric literals look like `42`, `3.14`, `.5` or `6.02E23`, and must be separated from identifiers by whitespace"))]
+    LexicalInvalidNumericLiteral {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("invalid Unicode escape character in `{found}`")]
+    #[strum(props(Help="The escape character given by `UESCAPE` must be a single quoted character, other than a hexadecimal digit, `+`, `'`, `\"` or whitespace, e.g. `UESCAPE '!'`"))]
+    LexicalInvalidUnicodeEscapeCharacter {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("invalid Unicode escape value in `{found}`")]
+    #[strum(props(Help="Unicode escape values consist of the escape character followed by four hexadecimal digits, or `+` and six hexadecimal digits, e.g. `U&'\\0041'` or `U&'\\+01F980'`"))]
+    LexicalInvalidUnicodeEscapeValue {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected character `{character}` (U+{:04X})", *character as u32)]
+    LexicalUnexpectedCharacter {
+        found: ErrorFindLocation<'input>,
+        character: char,
+    },
+
+    #[error("unterminated bracketed comment")]
+    #[strum(props(Hint="Did you forget to close the comment with `*/`? Nested comments need to be closed too."))]
+    LexicalUnterminatedBracketedComment {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unterminated delimited identifier")]
+    #[strum(props(Hint="Did you forget to close the identifier with a double quote `\"`?"))]
+    LexicalUnterminatedDelimitedIdentifier {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unterminated string literal")]
+    #[strum(props(Hint="Did you forget to close the string with a quote `'`? Quotes inside a string are escaped by doubling them, e.g. `'O''Brien'`"))]
+    LexicalUnterminatedStringLiteral {
+        found: ErrorFindLocation<'input>,
+    },
+
     #[error("unexpected end-of-file after `ORDER`, expected `BY`")]
     OrderByClauseUnexpectedEndOfFileExpectedBy {
         found: ErrorFindLocation<'input>,
@@ -320,15 +695,23 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind
     },
 
-    #[error("unexpected token {token_kind}: `{found}`")]
-    SelectStatementUnexpectedToken {
+    #[error("unexpected end-of-file: expected {expected} in the OFFSET clause")]
+    #[strum(props(Help="An offset clause looks like `OFFSET 10 ROWS`"))]
+    ResultOffsetClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} in the OFFSET clause")]
+    #[strum(props(Help="An offset clause looks like `OFFSET 10 ROWS`"))]
+    ResultOffsetClauseUnexpectedToken {
         found: ErrorFindLocation<'input>,
         token_kind: TokenKind,
+        expected: &'static str,
     },
 
-    #[error("unexpected token: {token_kind} (`{found}`), expected `*` after `COUNT(`")]
-    #[strum(props(Help="Complete the COUNT set function specification: `COUNT(*)`"))]
-    SetFunctionSpecificationCountUnexpectedTokenExpectedAsterisk {
+    #[error("unexpected token {token_kind}: `{found}`")]
+    SelectStatementUnexpectedToken {
         found: ErrorFindLocation<'input>,
         token_kind: TokenKind,
     },
@@ -348,12 +731,6 @@ pub enum StatementParseError<'input> {
         should_be_matching: ErrorTokenShouldBeMatching<'input>,
     },
 
-    #[error("unexpected end-of-file, expected `*` after `COUNT(`")]
-    #[strum(props(Help="Complete the COUNT set function specification: `COUNT(*)`"))]
-    SetFunctionSpecificationCountUnexpectedEofExpectedAsterisk {
-        found: ErrorFindLocation<'input>,
-    },
-
     #[error("unexpected end-of-file, expected `(` after `COUNT`")]
     #[strum(props(Help="Complete the COUNT set function specification: `COUNT(*)`"))]
     SetFunctionSpecificationCountUnexpectedEofExpectedLeftParen {
@@ -367,6 +744,18 @@ pub enum StatementParseError<'input> {
         should_be_matching: ErrorTokenShouldBeMatching<'input>,
     },
 
+    #[error("unexpected end-of-file: expected TO after SIMILAR")]
+    SimilarPredicateUnexpectedEndOfFileExpectedTo {
+        found: ErrorFindLocation<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected TO after SIMILAR")]
+    #[strum(props(Hint="The similar predicate is written as `x SIMILAR TO pattern`."))]
+    SimilarPredicateUnexpectedTokenExpectedTo {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+    },
+
     #[error("statement doesn't start with a keyword, but a {token_kind:?}: `{found}`")]
     StartNotAToken {
         found: ErrorFindLocation<'input>,
@@ -379,13 +768,6 @@ pub enum StatementParseError<'input> {
         reserved_word: ReservedWord,
     },
 
-    #[error("unexpected token: {token_kind} (`{found}`), expected keyword as column data type")]
-    #[strum(props(Help="Follow the column name with the column type instead of this token, e.g. `INT`, `NVARCHAR`, etc."))]
-    TableElementSingleExpectedKeywordAsDataType {
-        found: ErrorFindLocation<'input>,
-        token_kind: TokenKind,
-    },
-
     #[error("unexpected token: {token_kind} (`{found}`), expected identifier as column name")]
     #[strum(props(Help="A column definition must start with the name of the column"))]
     TableElementSingleExpectedIdentifierAsColumnName {
@@ -394,7 +776,7 @@ pub enum StatementParseError<'input> {
     },
 
     #[error("unexpected keyword: {reserved_word} (`{found}`), expected identifier as column name")]
-    #[strum(props(Hint="Did you forget to escape the column name?"))]
+    #[strum(props(Hint="Did you forget to escape the column name with double quotes `\"`?"))]
     #[strum(props(Help="`{reserved_word}` is reserved as a keyword"))]
     TableElementSingleExpectedIdentifierAsColumnNameButGotKeyword {
         found: ErrorFindLocation<'input>,
@@ -412,12 +794,6 @@ pub enum StatementParseError<'input> {
         found: ErrorFindLocation<'input>,
     },
 
-    #[error("unknown keyword {reserved_word} (`{found}`), expected data type for column definition")]
-    TableElementSingleUnknownDataTypeKeyword {
-        found: ErrorFindLocation<'input>,
-        reserved_word: ReservedWord,
-    },
-
     #[error("unexpected token {token_kind} (`{found}`), expected opening parenthesis `(` for opening the column list")]
     #[strum(props(Help="The column list was expected and is started by a parenthesis `(`, followed by one or more columns"))]
     TableElementsExpectedLeftParenthesis {
@@ -470,7 +846,7 @@ pub enum StatementParseError<'input> {
     },
 
     #[error("unexpected keyword: {reserved_word} (`{found}`), expected a table reference")]
-    #[strum(props(Hint="Did you forget to escape the table or schema name?"))]
+    #[strum(props(Hint="Did you forget to escape the table or schema name with double quotes `\"`?"))]
     #[strum(props(Help="`{reserved_word}` is reserved as a keyword"))]
     TableReferenceUnexpectedKeyword {
         found: ErrorFindLocation<'input>,
@@ -492,17 +868,38 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("integer literal `{found}` is out of range")]
+    #[strum(props(Help="Negative integer literals must fit in 64 bits, use an exact numeric literal with a period for smaller values, e.g. `-9223372036854775809.0`"))]
+    ValueExpressionSignedIntegerOutOfRange {
+        found: ErrorFindLocation<'input>,
+    },
+
     #[error("unexpected end-of-file: expected a column name, value or expression")]
     ValueExpressionUnexpectedEndOfFile {
         found: ErrorFindLocation<'input>,
     },
 
+    #[error("unexpected end-of-file, expected closing parenthesis `)`")]
+    #[strum(props(Hint="Did you forget to close the parenthesized expression?"))]
+    ValueExpressionUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
     #[error("unexpected token: {token_kind} (`{found}`), expected a column name, value or expression")]
     ValueExpressionUnexpectedToken {
         found: ErrorFindLocation<'input>,
         token_kind: TokenKind,
     },
 
+    #[error("unexpected token: {token_kind} (`{found}`), expected closing parenthesis `)`")]
+    #[strum(props(Hint="Did you forget an operator, or to close the parenthesized expression?"))]
+    ValueExpressionUnexpectedTokenExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
     #[error("unexpected end-of-file, expected `LIMIT`")]
     VendorLimitClauseUnexpectedEndOfFileExpectedIntroductionKeyword {
         found: ErrorFindLocation<'input>,
@@ -527,6 +924,34 @@ pub enum StatementParseError<'input> {
         token_kind: TokenKind,
     },
 
+    #[error("unexpected end-of-file: expected {expected} after `TOP`")]
+    #[strum(props(Help="`TOP` should be followed by the maximum rows to SELECT, for example: `SELECT TOP 10 name FROM person`"))]
+    VendorTopClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected closing parenthesis `)` after the number of rows in `TOP`")]
+    VendorTopClauseUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} after `TOP`")]
+    #[strum(props(Help="`TOP` should be followed by the maximum rows to SELECT, for example: `SELECT TOP 10 name FROM person`"))]
+    VendorTopClauseUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected closing parenthesis `)` after the number of rows in `TOP`")]
+    VendorTopClauseUnexpectedTokenExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
     #[error("unexpected end-of-file: expected a `WHERE` clause")]
     WhereClauseUnexpectedEndOfFile {
         found: ErrorFindLocation<'input>,
@@ -537,4 +962,76 @@ pub enum StatementParseError<'input> {
         found: ErrorFindLocation<'input>,
         token_kind: TokenKind,
     },
+
+    #[error("unexpected end-of-file: expected {expected} in the WINDOW clause")]
+    #[strum(props(Help="Windows are defined as `WINDOW name AS (PARTITION BY ... ORDER BY ...)`"))]
+    WindowClauseUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} in the WINDOW clause")]
+    #[strum(props(Help="Windows are defined as `WINDOW name AS (PARTITION BY ... ORDER BY ...)`"))]
+    WindowClauseUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        expected: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected {expected} in the window frame")]
+    #[strum(props(Help="A window frame looks like `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`"))]
+    WindowFrameUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} in the window frame")]
+    #[strum(props(Help="A window frame looks like `ROWS BETWEEN 2 PRECEDING AND CURRENT ROW`"))]
+    WindowFrameUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        expected: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected OVER after `{function_name}()`")]
+    #[strum(props(Hint="Window functions are computed over a window, e.g. `RANK() OVER (ORDER BY score DESC)`"))]
+    WindowFunctionUnexpectedEndOfFileExpectedOver {
+        found: ErrorFindLocation<'input>,
+        function_name: &'input str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected OVER after `{function_name}()`")]
+    #[strum(props(Hint="Window functions are computed over a window, e.g. `RANK() OVER (ORDER BY score DESC)`"))]
+    WindowFunctionUnexpectedTokenExpectedOver {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        function_name: &'input str,
+    },
+
+    #[error("unexpected end-of-file: expected {expected} in the window specification")]
+    WindowSpecificationUnexpectedEndOfFile {
+        found: ErrorFindLocation<'input>,
+        expected: &'static str,
+    },
+
+    #[error("unexpected end-of-file: expected closing parenthesis `)` after the window specification")]
+    WindowSpecificationUnexpectedEndOfFileExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected {expected} in the window specification")]
+    WindowSpecificationUnexpectedToken {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        expected: &'static str,
+    },
+
+    #[error("unexpected token: {token_kind} (`{found}`), expected closing parenthesis `)` after the window specification")]
+    #[strum(props(Hint="The parts of a window specification are written in the order: window name, PARTITION BY, ORDER BY, frame."))]
+    WindowSpecificationUnexpectedTokenExpectedRightParen {
+        found: ErrorFindLocation<'input>,
+        token_kind: TokenKind,
+        should_be_matching: ErrorTokenShouldBeMatching<'input>,
+    },
 }
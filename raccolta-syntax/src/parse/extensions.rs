@@ -2,22 +2,17 @@
 // All Rights Reserved.
 
 use crate::{
+    common::Identifier,
     keyword::{
         ReservedWord,
         NonReservedWord,
     },
     Token,
-    TokenKind,
 };
 
 pub(super) trait ParseArrayExtensions {
-    /// Consumes an identifier and returns a borrowed [`str`].
-    fn consume_identifier<'input>(self: &mut &Self, input: &'input str) -> Option<&'input str>;
-
-    /// Consumes an identifier and returns an owned [`String`].
-    fn consume_identifier_owned<'input>(self: &mut &Self, input: &'input str) -> Option<String> {
-        self.consume_identifier(input).map(|str| str.to_owned())
-    }
+    /// Consumes a regular or delimited identifier.
+    fn consume_identifier(self: &mut &Self, input: &str) -> Option<Identifier>;
 
     /// Consumes a specific [`Keyword`].
     fn consume_reserved_word(self: &mut &Self, reserved_word: ReservedWord) -> bool;
@@ -35,15 +30,10 @@ pub(super) trait ParseArrayExtensions {
 }
 
 impl ParseArrayExtensions for [Token] {
-    fn consume_identifier<'input>(self: &mut &Self, input: &'input str) -> Option<&'input str> {
-        if let Some(token) = self.get(0) {
-            if let TokenKind::Identifier = token.kind() {
-                self.next();
-                return Some(token.as_string(input));
-            }
-        }
-
-        None
+    fn consume_identifier(self: &mut &Self, input: &str) -> Option<Identifier> {
+        let identifier = self.first()?.as_identifier(input)?;
+        self.next();
+        Some(identifier)
     }
 
     fn consume_reserved_word(self: &mut &Self, reserved_word: ReservedWord) -> bool {
@@ -65,12 +55,12 @@ impl ParseArrayExtensions for [Token] {
     }
 
     fn is_reserved_word(&self) -> Option<ReservedWord> {
-        self.get(0)
+        self.first()
             .and_then(|token| token.kind().to_reserved_word())
     }
 
     fn is_non_reserved_word(&self) -> Option<NonReservedWord> {
-        self.get(0)
+        self.first()
             .and_then(|token| token.kind().to_non_reserved_word())
     }
 
@@ -80,8 +70,8 @@ impl ParseArrayExtensions for [Token] {
 }
 
 pub(super) trait ParseStringExtensions {
-    /// Get an empty slice, pointing at the end of the string, past the last
-    /// character.
+    /// Get a slice of the last character of the string, or an empty slice if
+    /// the string is empty.
     ///
     /// This is useful for getting a lifetime to `self` and marking an EOF
     /// point.
@@ -90,6 +80,9 @@ pub(super) trait ParseStringExtensions {
 
 impl ParseStringExtensions for str {
     fn slice_empty_end(&self) -> &Self {
-        &self[(self.len() - 1)..]
+        match self.char_indices().last() {
+            Some((byte_idx, _)) => &self[byte_idx..],
+            None => self,
+        }
     }
 }
@@ -0,0 +1,158 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the operators of value expressions and their
+//! precedence, which the parser uses for precedence climbing.
+
+use crate::{
+    expression::{
+        BooleanExpression,
+        NumericOperator,
+        NumericValueExpression,
+        string_value_expression::StringValueExpression,
+        ValueExpression,
+    },
+    keyword::ReservedWord,
+    predicate::{
+        comparison_predicate::{
+            ComparisonOperator,
+            ComparisonPredicate,
+        },
+        Predicate,
+    },
+    Spanned,
+    TokenKind,
+};
+
+/// The binding strength of an operator, from loosest to tightest. Operators
+/// with a higher precedence are grouped first, e.g. `a + b * c` is parsed as
+/// `a + (b * c)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum OperatorPrecedence {
+    /// `OR`
+    Or,
+
+    /// `AND`
+    And,
+
+    /// The prefix operator `NOT`
+    Not,
+
+    /// The postfix operator `IS [ NOT ] <truth value>`
+    Is,
+
+    /// `=`, `<>`, `<`, `>`, `<=` and `>=`
+    Comparison,
+
+    /// `||`
+    Concatenation,
+
+    /// `+` and `-`
+    Additive,
+
+    /// `*` and `/`
+    Multiplicative,
+
+    /// The prefix operators `+` and `-`
+    Sign,
+}
+
+impl OperatorPrecedence {
+    /// Returns the precedence that binds one step tighter than this one. The
+    /// right-hand side of a left-associative operator is parsed with this,
+    /// such that `a - b - c` is parsed as `(a - b) - c`.
+    pub(crate) const fn tighter(self) -> Self {
+        match self {
+            Self::Or => Self::And,
+            Self::And => Self::Not,
+            Self::Not => Self::Is,
+            Self::Is => Self::Comparison,
+            Self::Comparison => Self::Concatenation,
+            Self::Concatenation => Self::Additive,
+            Self::Additive => Self::Multiplicative,
+            Self::Multiplicative | Self::Sign => Self::Sign,
+        }
+    }
+}
+
+/// An infix operator of a value expression.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum BinaryOperator {
+    And,
+    Comparison(ComparisonOperator),
+    Concatenation,
+    Numeric(NumericOperator),
+    Or,
+}
+
+impl BinaryOperator {
+    /// Converts the token to the operator it denotes, if any.
+    pub(super) const fn from_token_kind(token_kind: TokenKind) -> Option<Self> {
+        Some(match token_kind {
+            TokenKind::ReservedWord(ReservedWord::And) => Self::And,
+            TokenKind::ReservedWord(ReservedWord::Or) => Self::Or,
+
+            TokenKind::EqualsSign => Self::Comparison(ComparisonOperator::EqualTo),
+            TokenKind::GreaterThanOperator => Self::Comparison(ComparisonOperator::GreaterThan),
+            TokenKind::GreaterThanOrEqualsOperator => Self::Comparison(ComparisonOperator::GreaterThanOrEqualTo),
+            TokenKind::LessThanOperator => Self::Comparison(ComparisonOperator::LessThan),
+            TokenKind::LessThanOrEqualsOperator => Self::Comparison(ComparisonOperator::LessThanOrEqualTo),
+            TokenKind::NotEqualsOperator => Self::Comparison(ComparisonOperator::NotEqualTo),
+
+            TokenKind::ConcatenationOperator => Self::Concatenation,
+
+            TokenKind::PlusSign => Self::Numeric(NumericOperator::Addition),
+            TokenKind::MinusSign => Self::Numeric(NumericOperator::Subtraction),
+            TokenKind::Asterisk => Self::Numeric(NumericOperator::Multiplication),
+            TokenKind::Solidus => Self::Numeric(NumericOperator::Division),
+
+            _ => return None,
+        })
+    }
+
+    pub(super) const fn precedence(self) -> OperatorPrecedence {
+        match self {
+            Self::And => OperatorPrecedence::And,
+            Self::Comparison(..) => OperatorPrecedence::Comparison,
+            Self::Concatenation => OperatorPrecedence::Concatenation,
+            Self::Numeric(NumericOperator::Addition | NumericOperator::Subtraction) => OperatorPrecedence::Additive,
+            Self::Numeric(NumericOperator::Division | NumericOperator::Multiplication) => OperatorPrecedence::Multiplicative,
+            Self::Or => OperatorPrecedence::Or,
+        }
+    }
+
+    /// Creates the expression that applies this operator to the operands.
+    pub(super) fn apply(self, left_hand_side: ValueExpression, right_hand_side: ValueExpression) -> ValueExpression {
+        match self {
+            Self::And => ValueExpression::Boolean(BooleanExpression::And {
+                left_hand_side: Box::new(left_hand_side),
+                right_hand_side: Box::new(right_hand_side),
+            }),
+
+            Self::Comparison(operator) => ValueExpression::Boolean(BooleanExpression::Predicate(Box::new(
+                Predicate::Comparison(ComparisonPredicate {
+                    span: left_hand_side.span().to(right_hand_side.span()),
+                    operator,
+                    left_hand_side,
+                    right_hand_side,
+                })
+            ))),
+
+            Self::Concatenation => ValueExpression::StringValueExpression(StringValueExpression::Concatenation {
+                left_hand_side: Box::new(left_hand_side),
+                right_hand_side: Box::new(right_hand_side),
+            }),
+
+            Self::Numeric(operator) => ValueExpression::Numeric(NumericValueExpression::BinaryOperation {
+                left_hand_side: Box::new(left_hand_side),
+                operator,
+                right_hand_side: Box::new(right_hand_side),
+            }),
+
+            Self::Or => ValueExpression::Boolean(BooleanExpression::Or {
+                left_hand_side: Box::new(left_hand_side),
+                right_hand_side: Box::new(right_hand_side),
+            }),
+        }
+    }
+}
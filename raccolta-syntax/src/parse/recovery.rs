@@ -0,0 +1,417 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! This module contains the diagnostics-collecting parse mode. Instead of
+//! stopping at the first error, the parser records it as a [`Diagnostic`],
+//! skips the tokens around it up to a point where parsing can resume, such as
+//! the next clause keyword, comma or parenthesis, and parses the rest of the
+//! statement again.
+
+use std::ops::Range;
+
+use crate::{
+    keyword::{
+        ReservedWord,
+        VendorReservedWord,
+    },
+    statement::SqlExecutableStatement,
+    Token,
+    TokenKind,
+};
+
+use super::{
+    ErrorFindLocation,
+    Parser,
+    StatementParseError,
+};
+
+/// A problem found by [`Parser::parse_statement_with_diagnostics`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic<'input> {
+    /// The error that was found.
+    pub error: StatementParseError<'input>,
+
+    /// The location where the error was found.
+    pub primary_span: ErrorFindLocation<'input>,
+
+    /// Other locations that explain the error, such as the opening
+    /// parenthesis that wasn't closed.
+    pub secondary_spans: Vec<DiagnosticLabel<'input>>,
+}
+
+impl<'input> Diagnostic<'input> {
+    /// Creates the diagnostic of an `error` found in the `input`. The spans
+    /// are taken from the fields of the error.
+    pub fn new(input: &'input str, error: StatementParseError<'input>) -> Self {
+        let primary_span = match error {
+            StatementParseError::EofCreateKeywordOnlyToken(complete_input)
+                | StatementParseError::EofSelectKeywordOnlyToken(complete_input)
+                | StatementParseError::EofSelectList(complete_input) => ErrorFindLocation::EndOfFile { complete_input },
+
+            _ => error.found().copied().unwrap_or(ErrorFindLocation::EndOfFile { complete_input: input }),
+        };
+
+        let mut secondary_spans = Vec::new();
+
+        if let Some(should_be_matching) = error.should_be_matching() {
+            secondary_spans.push(DiagnosticLabel {
+                location: ErrorFindLocation::Position(should_be_matching.found),
+                message: match should_be_matching.token_kind {
+                    TokenKind::LeftParenthesis => "this opening parenthesis isn't closed",
+                    _ => "this token isn't matched",
+                },
+            });
+        }
+
+        if let Some(conflicting_clause) = error.conflicting_clause() {
+            secondary_spans.push(DiagnosticLabel {
+                location: *conflicting_clause,
+                message: "conflicts with this clause",
+            });
+        }
+
+        Self {
+            error,
+            primary_span,
+            secondary_spans,
+        }
+    }
+}
+
+/// A location that is related to a [`Diagnostic`], with a message explaining
+/// how.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticLabel<'input> {
+    pub location: ErrorFindLocation<'input>,
+    pub message: &'static str,
+}
+
+/// Checks whether a token of `token_kind` starts a clause of a statement.
+fn is_clause_keyword(token_kind: TokenKind) -> bool {
+    matches!(
+        token_kind,
+        TokenKind::ReservedWord(
+            ReservedWord::Fetch | ReservedWord::From | ReservedWord::Group | ReservedWord::Having
+                | ReservedWord::Offset | ReservedWord::Order | ReservedWord::Values | ReservedWord::Where
+                | ReservedWord::Window
+        ) | TokenKind::VendorReservedWord(VendorReservedWord::Limit)
+    )
+}
+
+/// Checks whether a token of `token_kind` separates the parts of a statement
+/// at which parsing can resume, i.e. a comma or the keyword of a clause.
+fn is_separator(token_kind: TokenKind) -> bool {
+    token_kind == TokenKind::Comma || is_clause_keyword(token_kind)
+}
+
+/// Returns the index of the separator or opening parenthesis that precedes
+/// the token at `index`. Parenthesized tokens are skipped as a whole, so that
+/// e.g. the `ORDER` of a window specification isn't mistaken for an
+/// `ORDER BY` clause. The first token, which starts the statement, is
+/// returned when there is none.
+fn previous_boundary(tokens: &[Token], index: usize) -> usize {
+    let mut depth = 0_usize;
+
+    for boundary in (1..index).rev() {
+        match tokens[boundary].kind() {
+            TokenKind::RightParenthesis => depth += 1,
+            TokenKind::LeftParenthesis if depth == 0 => return boundary,
+            TokenKind::LeftParenthesis => depth -= 1,
+            token_kind if depth == 0 && is_separator(token_kind) => return boundary,
+            _ => (),
+        }
+    }
+
+    0
+}
+
+/// Returns the index of the first separator or closing parenthesis from
+/// `index` onwards, skipping parenthesized tokens as a whole. This is `end`
+/// when there is none.
+fn next_boundary(tokens: &[Token], index: usize, end: usize) -> usize {
+    let mut depth = 0_usize;
+
+    for (boundary, token) in tokens.iter().enumerate().take(end).skip(index) {
+        match token.kind() {
+            TokenKind::LeftParenthesis => depth += 1,
+            TokenKind::RightParenthesis if depth == 0 => return boundary,
+            TokenKind::RightParenthesis => depth -= 1,
+            token_kind if depth == 0 && is_separator(token_kind) => return boundary,
+            _ => (),
+        }
+    }
+
+    end
+}
+
+/// Returns the range of the list element between the boundaries `start` and
+/// `end`, together with one of the commas around it, so that the list stays
+/// well-formed when the range is removed.
+fn element_range(tokens: &[Token], start: usize, end: usize) -> Range<usize> {
+    if tokens[start].kind() == TokenKind::Comma {
+        start..end
+    } else if tokens.get(end).is_some_and(|token| token.kind() == TokenKind::Comma) {
+        start + 1..end + 1
+    } else {
+        start + 1..end
+    }
+}
+
+/// Returns the range of tokens to skip after an error was found at the token
+/// at `index`, of which `end` is the end of the statement. This is the
+/// clause or list element around it, or the rest of it when the error was
+/// found in its middle.
+fn skipped_range(tokens: &[Token], index: usize, end: usize) -> Range<usize> {
+    let start = previous_boundary(tokens, index);
+
+    // A separator without anything after it, e.g. the comma of `a, FROM` or
+    // the keyword of `WHERE ORDER BY a`.
+    if start != 0 && start + 1 == index && is_separator(tokens[start].kind()) {
+        return start..index;
+    }
+
+    if index == end {
+        return if start != 0 && is_separator(tokens[start].kind()) { start..end } else { end..end };
+    }
+
+    let token_kind = tokens[index].kind();
+
+    if is_clause_keyword(token_kind) {
+        return index..next_boundary(tokens, index + 1, end);
+    }
+
+    if !matches!(token_kind, TokenKind::Comma | TokenKind::RightParenthesis) {
+        return index..next_boundary(tokens, index, end);
+    }
+
+    // The element before the separator couldn't be parsed, e.g. `a +` of
+    // `a +, b`.
+    let range = element_range(tokens, start, index);
+    if !range.is_empty() || tokens[start].kind() != TokenKind::LeftParenthesis {
+        return range;
+    }
+
+    // The parentheses are empty, e.g. `VARCHAR()`, so the element around them
+    // is skipped.
+    element_range(tokens, previous_boundary(tokens, start), next_boundary(tokens, index + 1, end))
+}
+
+/// Skips the tokens around the token at `index` at which an error was found,
+/// so that the statement can be parsed again. When the error is at the end
+/// of the statement, an opening parenthesis that wasn't closed is closed if
+/// `closes_parenthesis`. Returns the index at which parsing resumes, or
+/// `None` if the statement can't be recovered.
+fn synchronize(tokens: &mut Vec<Token>, index: usize, end: usize, closes_parenthesis: bool) -> Option<usize> {
+    if index == 0 {
+        return None;
+    }
+
+    if index == end && closes_parenthesis {
+        let byte_idx = tokens[end - 1].last_character_byte_idx;
+        tokens.insert(end, Token::new(byte_idx, byte_idx, TokenKind::RightParenthesis));
+        return Some(end + 1);
+    }
+
+    let range = skipped_range(tokens, index, end);
+    if range.is_empty() || range.start == 0 {
+        return None;
+    }
+
+    let resumption_index = range.start;
+    tokens.drain(range);
+    Some(resumption_index)
+}
+
+/// Returns the index of the token at which the `error` was found, which is
+/// `end` when it was found at the end of the statement.
+fn error_index(input: &str, tokens: &[Token], end: usize, error: &StatementParseError<'_>) -> Option<usize> {
+    let Some(ErrorFindLocation::Position(found)) = error.found() else {
+        return Some(end);
+    };
+
+    let byte_idx = (found.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    tokens[..end].iter().position(|token| token.first_character_byte_idx == byte_idx)
+}
+
+/// Returns the index of the token that ends the `tokens` of a statement,
+/// i.e. of its semicolon if it has one.
+fn statement_end(tokens: &[Token]) -> usize {
+    match tokens.last() {
+        Some(token) if token.kind() == TokenKind::Semicolon => tokens.len() - 1,
+        _ => tokens.len(),
+    }
+}
+
+impl Parser {
+    /// Parses a statement like [`Parser::parse_statement`], but doesn't stop
+    /// at the first error. Every error is recorded as a [`Diagnostic`], after
+    /// which the clause or list element containing it is skipped, up to the
+    /// next clause keyword (e.g. `FROM`, `WHERE`, `ORDER` or `VALUES`), comma
+    /// or parenthesis, and the statement is parsed again. This way, all
+    /// errors in a statement can be reported at once.
+    ///
+    /// Returns the statement with its erroneous parts left out, which is
+    /// `None` when too little of it could be parsed, together with the
+    /// diagnostics. The statement shouldn't be executed when there are any
+    /// diagnostics.
+    pub fn parse_statement_with_diagnostics<'input>(&self, input: &'input str) -> (Option<SqlExecutableStatement>, Vec<Diagnostic<'input>>) {
+        let mut tokens: Vec<_> = self.lexer(input).collect();
+        let mut diagnostics = Vec::new();
+
+        // The lexer stops after an illegal token, so the rest of the statement
+        // can't be recovered.
+        let is_lexically_valid = !tokens.last().is_some_and(|token| matches!(token.kind(), TokenKind::IllegalToken(..)));
+
+        // Every skipped parenthesis can be closed at most once.
+        let mut unclosed_parentheses = tokens.iter().filter(|token| token.kind() == TokenKind::LeftParenthesis).count();
+
+        // An error at the token where parsing resumed is caused by the skipped
+        // tokens, and was already reported.
+        let mut resumption_byte_idx = None;
+
+        loop {
+            let error = match self.parse_statement_tokens(input, &tokens) {
+                Ok(statement) => return (Some(statement), diagnostics),
+                Err(error) => error,
+            };
+
+            let end = statement_end(&tokens);
+            let index = error_index(input, &tokens, end, &error);
+            let byte_idx = index.map(|index| tokens.get(index).map_or(input.len(), |token| token.first_character_byte_idx));
+
+            let closes_parenthesis = unclosed_parentheses != 0 && error.should_be_matching()
+                .is_some_and(|should_be_matching| should_be_matching.token_kind == TokenKind::LeftParenthesis);
+
+            if byte_idx.is_none() || byte_idx != resumption_byte_idx {
+                diagnostics.push(Diagnostic::new(input, error));
+            }
+
+            let Some(index) = index.filter(|_| is_lexically_valid) else {
+                return (None, diagnostics);
+            };
+
+            let Some(resumption_index) = synchronize(&mut tokens, index, end, closes_parenthesis) else {
+                return (None, diagnostics);
+            };
+
+            if closes_parenthesis && index == end {
+                unclosed_parentheses -= 1;
+            }
+
+            // A clause following a skipped one is parsed on its own, so errors
+            // in it aren't caused by the skipped tokens.
+            resumption_byte_idx = match tokens.get(resumption_index) {
+                Some(token) if is_clause_keyword(token.kind()) => None,
+                Some(token) if token.kind() != TokenKind::Semicolon => Some(token.first_character_byte_idx),
+                _ => Some(input.len()),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WithoutSpans;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    /// Describes a location as the text it points at, or `EOF`.
+    fn describe(location: ErrorFindLocation<'_>) -> &str {
+        match location {
+            ErrorFindLocation::EndOfFile { .. } => "EOF",
+            ErrorFindLocation::Position(found) => found,
+        }
+    }
+
+    /// Parses the `input` with diagnostics, and describes the primary and
+    /// secondary spans of every diagnostic.
+    fn diagnostic_spans(input: &str) -> (Option<SqlExecutableStatement>, Vec<(&str, Vec<&str>)>) {
+        let (statement, diagnostics) = Parser::new().parse_statement_with_diagnostics(input);
+        let spans = diagnostics.into_iter()
+            .map(|diagnostic| (
+                describe(diagnostic.primary_span),
+                diagnostic.secondary_spans.into_iter().map(|label| describe(label.location)).collect(),
+            ))
+            .collect();
+        (statement, spans)
+    }
+
+    #[rstest]
+    #[case("SELECT DISTINCT a, b + 1 AS c FROM t, u WHERE a = 1 ORDER BY a DESC OFFSET 1 ROW FETCH FIRST 2 ROWS ONLY")]
+    #[case("SELECT TOP 2 * FROM t ORDER BY a")]
+    #[case("SELECT RANK() OVER (ORDER BY a) FROM t WINDOW w AS (PARTITION BY b);")]
+    #[case("INSERT INTO t VALUES (1, 'a'), (2, 'b')")]
+    #[case("CREATE TABLE t (a INT, b VARCHAR(10))")]
+    #[case("SELECT a FROM t LIMIT 2, 3")]
+    fn parse_statement_with_diagnostics_valid(#[case] input: &str) {
+        let parser = Parser::new();
+        let expected = parser.parse_statement(input).unwrap();
+        let (statement, diagnostics) = parser.parse_statement_with_diagnostics(input);
+
+        assert_eq!((statement, diagnostics), (Some(expected), Vec::new()));
+    }
+
+    #[rstest]
+    #[case("SELECT a +, b, FROM t WHERE = 1 ORDER BY x", &[(",", &[][..]), ("FROM", &[]), ("=", &[])])]
+    #[case("SELECT a b, c FROM t AS x y, u ORDER x", &[("b", &[][..]), ("y", &[]), ("x", &[])])]
+    #[case("SELECT a FROM t GROUP BY a HAVING a > 1 ORDER BY a", &[("GROUP", &[][..]), ("HAVING", &[])])]
+    #[case("SELECT TOP 1 x FROM t LIMIT 2 ORDER BY x FETCH FIRST ROW ONLY", &[("LIMIT", &["TOP"][..]), ("FETCH", &["TOP"])])]
+    #[case("SELECT x FROM t FETCH FIRST 1 ROW WITH TIES", &[("FETCH", &[][..])])]
+    #[case("INSERT INTO t VALUES (1, +), 2, (3 4), (5, 6", &[(")", &[][..]), ("2", &[]), ("4", &[]), ("EOF", &["("])])]
+    #[case("INSERT INTO t VALUES (1) WHERE", &[("WHERE", &[][..])])]
+    #[case("CREATE TABLE t (a INT, b NOTHING, SELECT INT, c VARCHAR(10)", &[("NOTHING", &[][..]), ("SELECT", &[]), ("EOF", &["("])])]
+    #[case("CREATE TABLE t (, a INT) x", &[(",", &[][..]), ("x", &[])])]
+    #[case("DROP TABLE t", &[("DROP", &[][..])])]
+    #[case("SELECT", &[("EOF", &[][..])])]
+    #[case("SELECT # FROM t", &[("#", &[][..])])]
+    fn parse_statement_with_diagnostics_erroneous(#[case] input: &str, #[case] expected: &[(&str, &[&str])]) {
+        let (_, spans) = diagnostic_spans(input);
+        assert_eq!(
+            spans,
+            expected.iter()
+                .map(|(primary, secondary)| (*primary, secondary.to_vec()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parse_statement_with_diagnostics_partial_select() {
+        let parser = Parser::new();
+        let (statement, diagnostics) = parser.parse_statement_with_diagnostics("SELECT a, b +, c FROM t WHERE ORDER BY c");
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(statement.without_spans(), Some(parser.parse_statement("SELECT a, c FROM t ORDER BY c").unwrap().without_spans()));
+    }
+
+    #[test]
+    fn parse_statement_with_diagnostics_partial_insert() {
+        let parser = Parser::new();
+        let (statement, diagnostics) = parser.parse_statement_with_diagnostics("INSERT INTO t VALUES (1, +), x, (3, 4");
+
+        assert_eq!(diagnostics.len(), 3);
+        assert_eq!(statement.without_spans(), Some(parser.parse_statement("INSERT INTO t VALUES (1), (3, 4)").unwrap().without_spans()));
+    }
+
+    #[test]
+    fn parse_statement_with_diagnostics_partial_create_table() {
+        let parser = Parser::new();
+        let (statement, diagnostics) = parser.parse_statement_with_diagnostics("CREATE TABLE t (a INT, b VARCHAR(x), c INT)");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(statement.without_spans(), Some(parser.parse_statement("CREATE TABLE t (a INT, c INT)").unwrap().without_spans()));
+    }
+
+    #[test]
+    fn diagnostic_of_unclosed_parenthesis() {
+        let input = "SELECT (1 + 2";
+        let error = Parser::new().parse_statement(input).unwrap_err();
+        let diagnostic = Diagnostic::new(input, error);
+
+        assert_eq!(diagnostic.primary_span, ErrorFindLocation::EndOfFile { complete_input: input });
+        assert_eq!(diagnostic.secondary_spans, vec![DiagnosticLabel {
+            location: ErrorFindLocation::Position(&input[7..8]),
+            message: "this opening parenthesis isn't closed",
+        }]);
+    }
+}
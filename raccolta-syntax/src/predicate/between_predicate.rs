@@ -0,0 +1,49 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.3 `<between predicate>`
+//! Definition
+//! ```text
+//! <between predicate> ::=
+//!     <row value expression> [ NOT ] BETWEEN
+//!     [ ASYMMETRIC | SYMMETRIC ]
+//!     <row value expression> AND <row value expression>
+//! ```
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that checks whether a value lies within a range, including the
+/// bounds themselves.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetweenPredicate {
+    /// The value that is checked against the range.
+    pub operand: ValueExpression,
+
+    /// Whether `NOT BETWEEN` was used, which checks whether the value lies
+    /// outside the range instead.
+    pub negated: bool,
+
+    /// Whether `SYMMETRIC` was specified, which means that the bounds may be
+    /// specified in any order. By default (`ASYMMETRIC`), the range is empty
+    /// when the lower bound is greater than the upper bound.
+    pub symmetric: bool,
+
+    /// The lower bound of the range, before the `AND` keyword.
+    pub lower_bound: ValueExpression,
+
+    /// The upper bound of the range, after the `AND` keyword.
+    pub upper_bound: ValueExpression,
+
+    pub span: Span,
+}
+
+impl Spanned for BetweenPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
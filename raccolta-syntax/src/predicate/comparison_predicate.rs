@@ -16,7 +16,11 @@
 //!     | <greater than or equals operator>
 //! ```
 
-use crate::expression::ValueExpression;
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
 
 /// The operator which is used for comparison. These operators define how two
 /// values should be compared, and are functionally equivalent in many
@@ -32,6 +36,7 @@ use crate::expression::ValueExpression;
 /// 6. Not equal to `!=` \
 ///    **NOTE** again that in *standard* SQL, `<>` is used.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ComparisonOperator {
     /// This is the `<` token, denoting that the left-hand-side is less than
     /// the right-hand-side.
@@ -58,8 +63,23 @@ pub enum ComparisonOperator {
     NotEqualTo,
 }
 
+impl ComparisonOperator {
+    /// Returns the standard SQL spelling of this operator, e.g. `<>`.
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::LessThan => "<",
+            Self::LessThanOrEqualTo => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqualTo => ">=",
+            Self::EqualTo => "=",
+            Self::NotEqualTo => "<>",
+        }
+    }
+}
+
 /// A predicate that compares two values using a specified operator.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ComparisonPredicate {
     /// The operator which is used for comparison, which define how two values
     /// should be compared.
@@ -84,4 +104,12 @@ pub struct ComparisonPredicate {
     /// WHERE left_hand_side > right_hand_side
     /// ```
     pub right_hand_side: ValueExpression,
+
+    pub span: Span,
+}
+
+impl Spanned for ComparisonPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
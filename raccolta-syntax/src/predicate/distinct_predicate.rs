@@ -0,0 +1,39 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.13 `<distinct predicate>`
+//! Definition
+//! ```text
+//! <distinct predicate> ::=
+//!     <row value expression> IS [ NOT ] DISTINCT FROM <row value expression>
+//! ```
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that checks whether two values are distinct. Unlike the `<>`
+/// operator, two null values aren't distinct from each other, and the result
+/// is never `UNKNOWN`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DistinctPredicate {
+    /// The value before the `IS` keyword.
+    pub left_hand_side: ValueExpression,
+
+    /// Whether `IS NOT DISTINCT FROM` was used.
+    pub negated: bool,
+
+    /// The value after the `FROM` keyword.
+    pub right_hand_side: ValueExpression,
+
+    pub span: Span,
+}
+
+impl Spanned for DistinctPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
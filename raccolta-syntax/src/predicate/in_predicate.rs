@@ -0,0 +1,46 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.4 `<in predicate>`
+//! Definition
+//! ```text
+//! <in predicate> ::=
+//!     <row value expression> [ NOT ] IN <in predicate value>
+//!
+//! <in predicate value> ::=
+//!       <table subquery>
+//!     | <left paren> <in value list> <right paren>
+//!
+//! <in value list> ::=
+//!     <row value expression> { <comma> <row value expression> }...
+//! ```
+//!
+//! **NOTE** that subqueries aren't supported yet.
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that checks whether a value equals any of the values in a list.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InPredicate {
+    /// The value that is searched for in the list.
+    pub operand: ValueExpression,
+
+    /// Whether `NOT IN` was used.
+    pub negated: bool,
+
+    /// The `<in value list>`, which contains at least one value.
+    pub values: Vec<ValueExpression>,
+
+    pub span: Span,
+}
+
+impl Spanned for InPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
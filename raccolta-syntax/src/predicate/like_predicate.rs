@@ -0,0 +1,43 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.5 `<like predicate>`
+//! Definition
+//! ```text
+//! <like predicate> ::=
+//!     <character match value> [ NOT ] LIKE <character pattern>
+//!     [ ESCAPE <escape character> ]
+//! ```
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that matches a character string against a pattern, in which
+/// `%` matches any sequence of characters and `_` matches a single character.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LikePredicate {
+    /// The `<character match value>`, i.e. the string to match.
+    pub operand: ValueExpression,
+
+    /// Whether `NOT LIKE` was used.
+    pub negated: bool,
+
+    /// The `<character pattern>`.
+    pub pattern: ValueExpression,
+
+    /// The `<escape character>`, which makes the following `%`, `_` or escape
+    /// character in the pattern match itself.
+    pub escape: Option<ValueExpression>,
+
+    pub span: Span,
+}
+
+impl Spanned for LikePredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
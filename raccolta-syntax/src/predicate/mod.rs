@@ -3,13 +3,70 @@
 
 //! This module contains the structures for predicates in SQL.
 
+pub mod between_predicate;
 pub mod comparison_predicate;
+pub mod distinct_predicate;
+pub mod in_predicate;
+pub mod like_predicate;
+pub mod null_predicate;
+pub mod quantified_comparison_predicate;
+pub mod similar_predicate;
 
-use self::comparison_predicate::ComparisonPredicate;
+use crate::{Span, Spanned};
+
+use self::{
+    between_predicate::BetweenPredicate,
+    comparison_predicate::ComparisonPredicate,
+    distinct_predicate::DistinctPredicate,
+    in_predicate::InPredicate,
+    like_predicate::LikePredicate,
+    null_predicate::NullPredicate,
+    quantified_comparison_predicate::QuantifiedComparisonPredicate,
+    similar_predicate::SimilarPredicate,
+};
 
 /// The predicate defines a condition that can be evaluated to a boolean value.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Predicate {
+    /// A predicate that checks whether a value lies within a range.
+    Between(BetweenPredicate),
+
     /// A predicate that compares two values using a specified operator.
     Comparison(ComparisonPredicate),
+
+    /// A predicate that checks whether two values are distinct, treating null
+    /// values as equal.
+    Distinct(DistinctPredicate),
+
+    /// A predicate that checks whether a value is in a list of values.
+    In(InPredicate),
+
+    /// A predicate that matches a string against a `LIKE` pattern.
+    Like(LikePredicate),
+
+    /// A predicate that checks whether a value is null.
+    Null(NullPredicate),
+
+    /// A predicate that compares a value with all or any value of a list.
+    QuantifiedComparison(QuantifiedComparisonPredicate),
+
+    /// A predicate that matches a string against a `SIMILAR TO` regular
+    /// expression.
+    Similar(SimilarPredicate),
+}
+
+impl Spanned for Predicate {
+    fn span(&self) -> Span {
+        match self {
+            Self::Between(predicate) => predicate.span(),
+            Self::Comparison(predicate) => predicate.span(),
+            Self::Distinct(predicate) => predicate.span(),
+            Self::In(predicate) => predicate.span(),
+            Self::Like(predicate) => predicate.span(),
+            Self::Null(predicate) => predicate.span(),
+            Self::QuantifiedComparison(predicate) => predicate.span(),
+            Self::Similar(predicate) => predicate.span(),
+        }
+    }
 }
@@ -0,0 +1,34 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.7 `<null predicate>`
+//! Definition
+//! ```text
+//! <null predicate> ::=
+//!     <row value expression> IS [ NOT ] NULL
+//! ```
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that checks whether a value is the null value.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NullPredicate {
+    /// The value that is checked.
+    pub operand: ValueExpression,
+
+    /// Whether `IS NOT NULL` was used.
+    pub negated: bool,
+
+    pub span: Span,
+}
+
+impl Spanned for NullPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
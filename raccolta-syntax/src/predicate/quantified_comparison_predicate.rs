@@ -0,0 +1,69 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.8 `<quantified comparison predicate>`
+//! Definition
+//! ```text
+//! <quantified comparison predicate> ::=
+//!     <row value expression> <comp op> <quantifier> <table subquery>
+//!
+//! <quantifier> ::=
+//!       <all>
+//!     | <some>
+//!
+//! <all> ::= ALL
+//!
+//! <some> ::=
+//!       SOME
+//!     | ANY
+//! ```
+//!
+//! **NOTE** that subqueries aren't supported yet, so the values are specified
+//! as a parenthesized list instead, like the `<in value list>` of the
+//! `<in predicate>`, e.g. `price > ALL (10, 20)`.
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+use super::comparison_predicate::ComparisonOperator;
+
+/// A predicate that compares a value with each value of a list.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantifiedComparisonPredicate {
+    /// The value before the operator.
+    pub left_hand_side: ValueExpression,
+
+    /// The operator which is used to compare the value with each value of
+    /// the list.
+    pub operator: ComparisonOperator,
+
+    /// Whether all comparisons or at least one comparison must hold.
+    pub quantifier: Quantifier,
+
+    /// The values after the quantifier, of which there is at least one.
+    pub values: Vec<ValueExpression>,
+
+    pub span: Span,
+}
+
+impl Spanned for QuantifiedComparisonPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// The `<quantifier>` of a [`QuantifiedComparisonPredicate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Quantifier {
+    /// `ALL`, which holds when the comparison holds for every value.
+    All,
+
+    /// `ANY` or `SOME`, which holds when the comparison holds for at least one
+    /// value.
+    Any,
+}
@@ -0,0 +1,45 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! SQL 1999 - 8.6 `<similar predicate>`
+//! Definition
+//! ```text
+//! <similar predicate> ::=
+//!     <character match value> [ NOT ] SIMILAR TO <similar pattern>
+//!     [ ESCAPE <escape character> ]
+//! ```
+
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
+/// A predicate that matches a character string against a regular expression,
+/// which uses the `%` and `_` wildcards of `LIKE`, and supports alternation
+/// `|`, repetition `*`, `+`, `?` and `{m,n}`, grouping `()` and character
+/// classes `[...]`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimilarPredicate {
+    /// The `<character match value>`, i.e. the string to match.
+    pub operand: ValueExpression,
+
+    /// Whether `NOT SIMILAR TO` was used.
+    pub negated: bool,
+
+    /// The `<similar pattern>`.
+    pub pattern: ValueExpression,
+
+    /// The `<escape character>`, which makes the following special character
+    /// in the pattern match itself.
+    pub escape: Option<ValueExpression>,
+
+    pub span: Span,
+}
+
+impl Spanned for SimilarPredicate {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
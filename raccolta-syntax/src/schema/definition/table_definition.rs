@@ -1,7 +1,12 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::data_type::DataType;
+use crate::{
+    common::Identifier,
+    expression::data_type::DataType,
+    Span,
+    Spanned,
+};
 
 use super::UniqueSpecification;
 
@@ -17,6 +22,7 @@ use super::UniqueSpecification;
 ///     | <check constraint definition>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnConstraintDefinition {
     NotNull,
     UniqueSpecification(UniqueSpecification),
@@ -32,13 +38,21 @@ pub enum ColumnConstraintDefinition {
 ///     [ <collate clause> ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnDefinition {
-    pub column_name: String,
+    pub column_name: Identifier,
     pub data_type: DataType,
     pub column_constraint_definitions: Vec<ColumnConstraintDefinition>,
+    pub span: Span,
 }
 
-/// ```text
+impl Spanned for ColumnDefinition {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+// ```text
 // <reference scope check> ::=
 //      REFERENCES ARE [ NOT ] CHECKED
 //      [ ON DELETE <reference scope check action> ]
@@ -51,10 +65,19 @@ pub struct ColumnDefinition {
 ///       [ ON COMMIT <table commit action> ROWS ]
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableDefinition {
-    pub table_name: String,
+    pub table_name: Identifier,
 
     pub elements: Vec<TableElement>,
+
+    pub span: Span,
+}
+
+impl Spanned for TableDefinition {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
 
 /// ```text
@@ -66,6 +89,15 @@ pub struct TableDefinition {
 ///     | <column options>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableElement {
     ColumnDefinition(ColumnDefinition),
 }
+
+impl Spanned for TableElement {
+    fn span(&self) -> Span {
+        match self {
+            Self::ColumnDefinition(column_definition) => column_definition.span(),
+        }
+    }
+}
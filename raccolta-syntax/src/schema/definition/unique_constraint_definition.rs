@@ -7,6 +7,7 @@
 ///     | PRIMARY KEY
 /// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UniqueSpecification {
     Unique,
     PrimaryKey,
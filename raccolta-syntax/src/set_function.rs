@@ -1,6 +1,12 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{
+    expression::ValueExpression,
+    Span,
+    Spanned,
+};
+
 /// ```text
 /// <set function specification> ::=
 ///       COUNT <left paren> <asterisk> <right paren>
@@ -21,10 +27,76 @@
 /// <grouping operation> ::=
 ///     GROUPING <left paren> <column reference> <right paren>
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SetFunctionSpecification {
     /// `COUNT(*)` i.e. `COUNT <left paren> <asterisk> <right paren>`
+    Count(Span),
+
+    /// `<general set function>`, e.g. `SUM(price)` or `COUNT(DISTINCT name)`
+    General(GeneralSetFunction),
+}
+
+impl Spanned for SetFunctionSpecification {
+    fn span(&self) -> Span {
+        match self {
+            Self::Count(span) => *span,
+            Self::General(function) => function.span(),
+        }
+    }
+}
+
+/// ```text
+/// <general set function> ::=
+///     <set function type>
+///     <left paren> [ <set quantifier> ] <value expression> <right paren>
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GeneralSetFunction {
+    pub function_type: ComputationalOperation,
+    pub set_quantifier: SetQuantifier,
+    pub value_expression: Box<ValueExpression>,
+    pub span: Span,
+}
+
+impl Spanned for GeneralSetFunction {
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
+/// The aggregate computed by a [`GeneralSetFunction`].
+///
+/// ```text
+/// <computational operation> ::=
+///       AVG | MAX | MIN | SUM
+///     | EVERY | ANY | SOME
+///     | COUNT
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComputationalOperation {
+    Avg,
     Count,
+    Every,
+    Max,
+    Min,
+    Sum,
+}
+
+impl ComputationalOperation {
+    /// Returns the keyword of this operation, e.g. `SUM`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Avg => "AVG",
+            Self::Count => "COUNT",
+            Self::Every => "EVERY",
+            Self::Max => "MAX",
+            Self::Min => "MIN",
+            Self::Sum => "SUM",
+        }
+    }
 }
 
 /// The `<set quantifier>` specifies a quantification method of e.g. an
@@ -32,6 +104,7 @@ pub enum SetFunctionSpecification {
 ///
 /// **`ALL`** is implied if no `<set quantifier>` is provided.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SetQuantifier {
     /// The **`ALL`** quantifier doesn't process the rows in the query result,
     /// and just returns all the rows, even if there might be redundant
@@ -0,0 +1,556 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Every syntax node knows which part of the input it was parsed from, as a
+//! [`Span`]. This allows later stages, such as the engine, to point at the
+//! part of a statement their messages are about.
+
+use std::{
+    fmt::Debug,
+    ops::Range,
+};
+
+use crate::{
+    clause::{
+        fetch_first_clause::{
+            FetchFirstClause,
+            FetchFirstQuantity,
+        },
+        order_by_clause::{
+            OrderByClause,
+            SortSpecification,
+        },
+        result_offset_clause::ResultOffsetClause,
+        window_clause::{
+            WindowDefinition,
+            WindowFrameClause,
+            WindowSpecification,
+        },
+        FromClause,
+        GroupByClause,
+        HavingClause,
+        WhereClause,
+        WindowClause,
+    },
+    common::Identifier,
+    expression::{
+        case_expression::{
+            CaseResult,
+            SearchedWhenClause,
+            SimpleWhenClause,
+        },
+        numeric_value_function::NumericValueFunction,
+        query_specification::DerivedColumn,
+        row_value_constructor::ContextuallyTypedRowValueConstructor,
+        string_value_expression::StringValueExpression,
+        string_value_function::StringValueFunction,
+        table_reference::TablePrimary,
+        table_value_constructor::ContextuallyTypedTableValueConstructor,
+        BooleanExpression,
+        CaseExpression,
+        CastSpecification,
+        NumericValueExpression,
+        QueryExpression,
+        QuerySpecification,
+        RoutineInvocation,
+        TableExpression,
+        ValueExpression,
+        WindowFunction,
+    },
+    predicate::{
+        between_predicate::BetweenPredicate,
+        comparison_predicate::ComparisonPredicate,
+        distinct_predicate::DistinctPredicate,
+        in_predicate::InPredicate,
+        like_predicate::LikePredicate,
+        null_predicate::NullPredicate,
+        quantified_comparison_predicate::QuantifiedComparisonPredicate,
+        similar_predicate::SimilarPredicate,
+    },
+    schema::definition::{
+        table_definition::ColumnDefinition,
+        TableDefinition,
+    },
+    set_function::{
+        GeneralSetFunction,
+        SetFunctionSpecification,
+    },
+    statement::{
+        insert_statement::InsertColumnsAndSource,
+        InsertStatement,
+        SelectStatementSingleRow,
+        SqlExecutableStatement,
+    },
+    visit_mut::{
+        VisitMut,
+        walk_between_predicate_mut,
+        walk_boolean_expression_mut,
+        walk_case_expression_mut,
+        walk_case_result_mut,
+        walk_cast_specification_mut,
+        walk_column_definition_mut,
+        walk_comparison_predicate_mut,
+        walk_contextually_typed_row_value_constructor_mut,
+        walk_contextually_typed_table_value_constructor_mut,
+        walk_derived_column_mut,
+        walk_distinct_predicate_mut,
+        walk_fetch_first_clause_mut,
+        walk_fetch_first_quantity_mut,
+        walk_from_clause_mut,
+        walk_general_set_function_mut,
+        walk_in_predicate_mut,
+        walk_insert_columns_and_source_mut,
+        walk_insert_statement_mut,
+        walk_like_predicate_mut,
+        walk_null_predicate_mut,
+        walk_numeric_value_expression_mut,
+        walk_numeric_value_function_mut,
+        walk_order_by_clause_mut,
+        walk_quantified_comparison_predicate_mut,
+        walk_query_expression_mut,
+        walk_query_specification_mut,
+        walk_result_offset_clause_mut,
+        walk_routine_invocation_mut,
+        walk_searched_when_clause_mut,
+        walk_select_statement_single_row_mut,
+        walk_set_function_specification_mut,
+        walk_similar_predicate_mut,
+        walk_simple_when_clause_mut,
+        walk_sort_specification_mut,
+        walk_string_value_expression_mut,
+        walk_string_value_function_mut,
+        walk_table_definition_mut,
+        walk_table_expression_mut,
+        walk_table_primary_mut,
+        walk_where_clause_mut,
+        walk_window_clause_mut,
+        walk_window_definition_mut,
+        walk_window_frame_clause_mut,
+        walk_window_function_mut,
+        walk_window_specification_mut,
+    },
+};
+
+/// A range of bytes in the input of the [`Parser`](crate::Parser). For
+/// example, the `price` of `SELECT price FROM product` spans the bytes
+/// `7..12`.
+///
+/// Spans are compared like the other fields of syntax nodes, so the same
+/// syntax written at different positions isn't equal. Use
+/// [`WithoutSpans::eq_ignoring_spans`] to compare the syntax only.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// The index of the first byte.
+    pub start: usize,
+
+    /// The index after the last byte.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates the span of the bytes `start..end`.
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the span from the start of this span to the end of `other`,
+    /// including everything in between.
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// Returns the range of bytes, e.g. to slice the input with.
+    pub const fn range(self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl Debug for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(value: Range<usize>) -> Self {
+        Self::new(value.start, value.end)
+    }
+}
+
+/// A syntax node that can be compared regardless of where it was written,
+/// e.g. to check that a formatted statement parses to the same syntax.
+pub trait WithoutSpans: Clone + PartialEq {
+    /// Returns a copy of this node, of which the spans of the node and of all
+    /// its children are reset to [`Span::default`].
+    fn without_spans(&self) -> Self;
+
+    /// Checks whether this node is the same syntax as `other`, ignoring where
+    /// both were written.
+    fn eq_ignoring_spans(&self, other: &Self) -> bool {
+        self.without_spans() == other.without_spans()
+    }
+}
+
+macro_rules! impl_without_spans {
+    ($($node:ty => $visit:ident),* $(,)?) => {
+        $(
+            impl WithoutSpans for $node {
+                fn without_spans(&self) -> Self {
+                    let mut node = self.clone();
+                    SpanEraser.$visit(&mut node);
+                    node
+                }
+            }
+        )*
+    };
+}
+
+impl_without_spans! {
+    SqlExecutableStatement => visit_sql_executable_statement_mut,
+    InsertStatement => visit_insert_statement_mut,
+    TableDefinition => visit_table_definition_mut,
+    QueryExpression => visit_query_expression_mut,
+    QuerySpecification => visit_query_specification_mut,
+    WindowClause => visit_window_clause_mut,
+    ValueExpression => visit_value_expression_mut,
+    WindowFunction => visit_window_function_mut,
+    Identifier => visit_identifier_mut,
+}
+
+impl<T: WithoutSpans> WithoutSpans for Option<T> {
+    fn without_spans(&self) -> Self {
+        self.as_ref().map(T::without_spans)
+    }
+}
+
+impl<T: WithoutSpans> WithoutSpans for Vec<T> {
+    fn without_spans(&self) -> Self {
+        self.iter().map(T::without_spans).collect()
+    }
+}
+
+impl<T: WithoutSpans, E: Clone + PartialEq> WithoutSpans for Result<T, E> {
+    fn without_spans(&self) -> Self {
+        self.as_ref().map(T::without_spans).map_err(E::clone)
+    }
+}
+
+/// Resets the spans of every node it visits.
+struct SpanEraser;
+
+impl VisitMut for SpanEraser {
+    fn visit_insert_statement_mut(&mut self, insert_statement: &mut InsertStatement) {
+        insert_statement.span = Span::default();
+        walk_insert_statement_mut(self, insert_statement);
+    }
+
+    fn visit_insert_columns_and_source_mut(&mut self, insert_columns_and_source: &mut InsertColumnsAndSource) {
+        let InsertColumnsAndSource::FromConstructor { span, .. } = insert_columns_and_source;
+        *span = Span::default();
+        walk_insert_columns_and_source_mut(self, insert_columns_and_source);
+    }
+
+    fn visit_contextually_typed_table_value_constructor_mut(&mut self, contextually_typed_table_value_constructor: &mut ContextuallyTypedTableValueConstructor) {
+        contextually_typed_table_value_constructor.span = Span::default();
+        walk_contextually_typed_table_value_constructor_mut(self, contextually_typed_table_value_constructor);
+    }
+
+    fn visit_contextually_typed_row_value_constructor_mut(&mut self, contextually_typed_row_value_constructor: &mut ContextuallyTypedRowValueConstructor) {
+        contextually_typed_row_value_constructor.span = Span::default();
+        walk_contextually_typed_row_value_constructor_mut(self, contextually_typed_row_value_constructor);
+    }
+
+    fn visit_select_statement_single_row_mut(&mut self, select_statement_single_row: &mut SelectStatementSingleRow) {
+        select_statement_single_row.span = Span::default();
+        walk_select_statement_single_row_mut(self, select_statement_single_row);
+    }
+
+    fn visit_table_definition_mut(&mut self, table_definition: &mut TableDefinition) {
+        table_definition.span = Span::default();
+        walk_table_definition_mut(self, table_definition);
+    }
+
+    fn visit_column_definition_mut(&mut self, column_definition: &mut ColumnDefinition) {
+        column_definition.span = Span::default();
+        walk_column_definition_mut(self, column_definition);
+    }
+
+    fn visit_query_expression_mut(&mut self, query_expression: &mut QueryExpression) {
+        query_expression.span = Span::default();
+        walk_query_expression_mut(self, query_expression);
+    }
+
+    fn visit_query_specification_mut(&mut self, query_specification: &mut QuerySpecification) {
+        query_specification.span = Span::default();
+        walk_query_specification_mut(self, query_specification);
+    }
+
+    fn visit_derived_column_mut(&mut self, derived_column: &mut DerivedColumn) {
+        derived_column.span = Span::default();
+        walk_derived_column_mut(self, derived_column);
+    }
+
+    fn visit_table_expression_mut(&mut self, table_expression: &mut TableExpression) {
+        table_expression.span = Span::default();
+        walk_table_expression_mut(self, table_expression);
+    }
+
+    fn visit_table_primary_mut(&mut self, table_primary: &mut TablePrimary) {
+        table_primary.span = Span::default();
+        walk_table_primary_mut(self, table_primary);
+    }
+
+    fn visit_from_clause_mut(&mut self, from_clause: &mut FromClause) {
+        from_clause.span = Span::default();
+        walk_from_clause_mut(self, from_clause);
+    }
+
+    fn visit_where_clause_mut(&mut self, where_clause: &mut WhereClause) {
+        where_clause.span = Span::default();
+        walk_where_clause_mut(self, where_clause);
+    }
+
+    fn visit_group_by_clause_mut(&mut self, group_by_clause: &mut GroupByClause) {
+        group_by_clause.span = Span::default();
+    }
+
+    fn visit_having_clause_mut(&mut self, having_clause: &mut HavingClause) {
+        having_clause.span = Span::default();
+    }
+
+    fn visit_window_clause_mut(&mut self, window_clause: &mut WindowClause) {
+        window_clause.span = Span::default();
+        walk_window_clause_mut(self, window_clause);
+    }
+
+    fn visit_window_definition_mut(&mut self, window_definition: &mut WindowDefinition) {
+        window_definition.span = Span::default();
+        walk_window_definition_mut(self, window_definition);
+    }
+
+    fn visit_window_specification_mut(&mut self, window_specification: &mut WindowSpecification) {
+        window_specification.span = Span::default();
+        walk_window_specification_mut(self, window_specification);
+    }
+
+    fn visit_window_frame_clause_mut(&mut self, window_frame_clause: &mut WindowFrameClause) {
+        window_frame_clause.span = Span::default();
+        walk_window_frame_clause_mut(self, window_frame_clause);
+    }
+
+    fn visit_order_by_clause_mut(&mut self, order_by_clause: &mut OrderByClause) {
+        order_by_clause.span = Span::default();
+        walk_order_by_clause_mut(self, order_by_clause);
+    }
+
+    fn visit_sort_specification_mut(&mut self, sort_specification: &mut SortSpecification) {
+        sort_specification.span = Span::default();
+        walk_sort_specification_mut(self, sort_specification);
+    }
+
+    fn visit_result_offset_clause_mut(&mut self, result_offset_clause: &mut ResultOffsetClause) {
+        result_offset_clause.span = Span::default();
+        walk_result_offset_clause_mut(self, result_offset_clause);
+    }
+
+    fn visit_fetch_first_clause_mut(&mut self, fetch_first_clause: &mut FetchFirstClause) {
+        fetch_first_clause.span = Span::default();
+        walk_fetch_first_clause_mut(self, fetch_first_clause);
+    }
+
+    fn visit_fetch_first_quantity_mut(&mut self, fetch_first_quantity: &mut FetchFirstQuantity) {
+        fetch_first_quantity.span = Span::default();
+        walk_fetch_first_quantity_mut(self, fetch_first_quantity);
+    }
+
+    fn visit_boolean_expression_mut(&mut self, boolean_expression: &mut BooleanExpression) {
+        match boolean_expression {
+            BooleanExpression::Is { span, .. }
+                | BooleanExpression::Literal(_, span)
+                | BooleanExpression::Not(_, span)
+                | BooleanExpression::Unknown(span) => *span = Span::default(),
+            BooleanExpression::And { .. } | BooleanExpression::Or { .. } | BooleanExpression::Predicate(..) => (),
+        }
+        walk_boolean_expression_mut(self, boolean_expression);
+    }
+
+    fn visit_case_expression_mut(&mut self, case_expression: &mut CaseExpression) {
+        match case_expression {
+            CaseExpression::Coalesce(_, span)
+                | CaseExpression::NullIf { span, .. }
+                | CaseExpression::Searched { span, .. }
+                | CaseExpression::Simple { span, .. } => *span = Span::default(),
+        }
+        walk_case_expression_mut(self, case_expression);
+    }
+
+    fn visit_searched_when_clause_mut(&mut self, searched_when_clause: &mut SearchedWhenClause) {
+        searched_when_clause.span = Span::default();
+        walk_searched_when_clause_mut(self, searched_when_clause);
+    }
+
+    fn visit_simple_when_clause_mut(&mut self, simple_when_clause: &mut SimpleWhenClause) {
+        simple_when_clause.span = Span::default();
+        walk_simple_when_clause_mut(self, simple_when_clause);
+    }
+
+    fn visit_case_result_mut(&mut self, case_result: &mut CaseResult) {
+        if let CaseResult::Null(span) = case_result {
+            *span = Span::default();
+        }
+        walk_case_result_mut(self, case_result);
+    }
+
+    fn visit_cast_specification_mut(&mut self, cast_specification: &mut CastSpecification) {
+        cast_specification.span = Span::default();
+        walk_cast_specification_mut(self, cast_specification);
+    }
+
+    fn visit_numeric_value_expression_mut(&mut self, numeric_value_expression: &mut NumericValueExpression) {
+        match numeric_value_expression {
+            NumericValueExpression::ApproximateNumericLiteral(_, span)
+                | NumericValueExpression::ExactNumericLiteral(_, span)
+                | NumericValueExpression::Negation(_, span)
+                | NumericValueExpression::SimpleI64(_, span)
+                | NumericValueExpression::SimpleU64(_, span) => *span = Span::default(),
+            NumericValueExpression::BinaryOperation { .. } | NumericValueExpression::Function(..) => (),
+        }
+        walk_numeric_value_expression_mut(self, numeric_value_expression);
+    }
+
+    fn visit_numeric_value_function_mut(&mut self, numeric_value_function: &mut NumericValueFunction) {
+        let NumericValueFunction::Position { span, .. } = numeric_value_function;
+        *span = Span::default();
+        walk_numeric_value_function_mut(self, numeric_value_function);
+    }
+
+    fn visit_routine_invocation_mut(&mut self, routine_invocation: &mut RoutineInvocation) {
+        routine_invocation.span = Span::default();
+        walk_routine_invocation_mut(self, routine_invocation);
+    }
+
+    fn visit_set_function_specification_mut(&mut self, set_function_specification: &mut SetFunctionSpecification) {
+        if let SetFunctionSpecification::Count(span) = set_function_specification {
+            *span = Span::default();
+        }
+        walk_set_function_specification_mut(self, set_function_specification);
+    }
+
+    fn visit_general_set_function_mut(&mut self, general_set_function: &mut GeneralSetFunction) {
+        general_set_function.span = Span::default();
+        walk_general_set_function_mut(self, general_set_function);
+    }
+
+    fn visit_string_value_expression_mut(&mut self, string_value_expression: &mut StringValueExpression) {
+        match string_value_expression {
+            StringValueExpression::BinaryLiteral(_, span)
+                | StringValueExpression::BitLiteral(_, span)
+                | StringValueExpression::Literal(_, _, span) => *span = Span::default(),
+            StringValueExpression::Concatenation { .. } | StringValueExpression::Function(..) => (),
+        }
+        walk_string_value_expression_mut(self, string_value_expression);
+    }
+
+    fn visit_string_value_function_mut(&mut self, string_value_function: &mut StringValueFunction) {
+        match string_value_function {
+            StringValueFunction::Overlay { span, .. }
+                | StringValueFunction::Substring { span, .. }
+                | StringValueFunction::Trim { span, .. } => *span = Span::default(),
+        }
+        walk_string_value_function_mut(self, string_value_function);
+    }
+
+    fn visit_window_function_mut(&mut self, window_function: &mut WindowFunction) {
+        window_function.span = Span::default();
+        walk_window_function_mut(self, window_function);
+    }
+
+    fn visit_between_predicate_mut(&mut self, between_predicate: &mut BetweenPredicate) {
+        between_predicate.span = Span::default();
+        walk_between_predicate_mut(self, between_predicate);
+    }
+
+    fn visit_comparison_predicate_mut(&mut self, comparison_predicate: &mut ComparisonPredicate) {
+        comparison_predicate.span = Span::default();
+        walk_comparison_predicate_mut(self, comparison_predicate);
+    }
+
+    fn visit_distinct_predicate_mut(&mut self, distinct_predicate: &mut DistinctPredicate) {
+        distinct_predicate.span = Span::default();
+        walk_distinct_predicate_mut(self, distinct_predicate);
+    }
+
+    fn visit_in_predicate_mut(&mut self, in_predicate: &mut InPredicate) {
+        in_predicate.span = Span::default();
+        walk_in_predicate_mut(self, in_predicate);
+    }
+
+    fn visit_like_predicate_mut(&mut self, like_predicate: &mut LikePredicate) {
+        like_predicate.span = Span::default();
+        walk_like_predicate_mut(self, like_predicate);
+    }
+
+    fn visit_null_predicate_mut(&mut self, null_predicate: &mut NullPredicate) {
+        null_predicate.span = Span::default();
+        walk_null_predicate_mut(self, null_predicate);
+    }
+
+    fn visit_quantified_comparison_predicate_mut(&mut self, quantified_comparison_predicate: &mut QuantifiedComparisonPredicate) {
+        quantified_comparison_predicate.span = Span::default();
+        walk_quantified_comparison_predicate_mut(self, quantified_comparison_predicate);
+    }
+
+    fn visit_similar_predicate_mut(&mut self, similar_predicate: &mut SimilarPredicate) {
+        similar_predicate.span = Span::default();
+        walk_similar_predicate_mut(self, similar_predicate);
+    }
+
+    fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+        identifier.span = Span::default();
+    }
+}
+
+/// A syntax node, of which the [`Span`] in the input is known.
+pub trait Spanned {
+    /// Returns the part of the input this node was parsed from.
+    fn span(&self) -> Span;
+}
+
+impl<T: Spanned> Spanned for Box<T> {
+    fn span(&self) -> Span {
+        self.as_ref().span()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Parser;
+
+    use super::*;
+
+    #[test]
+    fn span_to() {
+        assert_eq!(Span::new(7, 12).to(Span::new(18, 25)).range(), 7..25);
+        assert_eq!(Span::new(18, 25).to(Span::new(7, 12)).range(), 7..25);
+    }
+
+    #[test]
+    fn span_is_compared_structurally() {
+        assert_eq!(Span::new(5, 9), Span::new(5, 9));
+        assert_ne!(Span::new(0, 1), Span::new(5, 9));
+        assert!(Span::new(0, 1) < Span::new(5, 9));
+    }
+
+    #[test]
+    fn statement_eq_ignoring_spans() {
+        let parser = Parser::new();
+        let statement = parser.parse_statement("SELECT a + 1 AS b FROM t WHERE a IS NOT NULL").unwrap();
+        let reformatted = parser.parse_statement("SELECT  a+1  AS b\nFROM t\nWHERE a IS NOT NULL").unwrap();
+        let other = parser.parse_statement("SELECT a + 2 AS b FROM t WHERE a IS NOT NULL").unwrap();
+
+        assert_ne!(statement, reformatted);
+        assert!(statement.eq_ignoring_spans(&reformatted));
+        assert!(!statement.eq_ignoring_spans(&other));
+    }
+}
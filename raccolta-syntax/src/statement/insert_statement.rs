@@ -58,44 +58,56 @@
 use crate::{
     common::TableName,
     expression::table_value_constructor::ContextuallyTypedTableValueConstructor,
+    Span,
+    Spanned,
 };
 
 /// ```text
-//// <insert columns and source> ::=
-////      <from subquery>
-////    | <from constructor>
-////    | <from default>
+/// <insert columns and source> ::=
+///      <from subquery>
+///    | <from constructor>
+///    | <from default>
 ///
-//// <from subquery> ::=
-////    [ <left paren> <insert column list> <right paren> ]
-////    [ override clause> ]
-////    <query expression>
+/// <from subquery> ::=
+///    [ <left paren> <insert column list> <right paren> ]
+///    [ override clause> ]
+///    <query expression>
 ///
-//// <from constructor> ::=
-////    [ <left paren> <insert column list> <right paren> ]
-////    [ <override clause> ]
-////    <contextually typed table value constructor>
+/// <from constructor> ::=
+///    [ <left paren> <insert column list> <right paren> ]
+///    [ <override clause> ]
+///    <contextually typed table value constructor>
 ///
-//// <override clause> ::=
-////      OVERRIDING USER VALUE
-////    | OVERRIDING SYSTEM VALUE
+/// <override clause> ::=
+///      OVERRIDING USER VALUE
+///    | OVERRIDING SYSTEM VALUE
 ///
-//// <from default> ::=
-////    DEFAULT VALUES
+/// <from default> ::=
+///    DEFAULT VALUES
 ///
-//// <insert column list> ::= <column name list>
+/// <insert column list> ::= <column name list>
 ///
 /// <column name list> ::=
 ///     <column name> [ { <comma> <column name> }... ]
 ///
 /// <column name> ::=
-////    <identifier>
+///    <identifier>
 ///  ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InsertColumnsAndSource {
     FromConstructor {
         insert_column_list: Option<Vec<String>>,
         constructor: ContextuallyTypedTableValueConstructor,
+        span: Span,
+    }
+}
+
+impl Spanned for InsertColumnsAndSource {
+    fn span(&self) -> Span {
+        match self {
+            Self::FromConstructor { span, .. } => *span,
+        }
     }
 }
 
@@ -105,7 +117,7 @@ pub enum InsertColumnsAndSource {
 ///     <insert columns and source>
 ///
 /// <insertion target> ::=
-////    <table name>
+///    <table name>
 /// ```
 ///
 /// # Example
@@ -163,6 +175,7 @@ pub enum InsertColumnsAndSource {
 ///                             <right paren>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertStatement {
     /// The table name wherein data must be inserted.
     ///
@@ -176,4 +189,12 @@ pub struct InsertStatement {
     /// 1. `<insert column list>`
     /// 2. `<table value constructor>`
     pub insert_columns_and_source: InsertColumnsAndSource,
+
+    pub span: Span,
+}
+
+impl Spanned for InsertStatement {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
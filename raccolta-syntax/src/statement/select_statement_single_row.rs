@@ -1,10 +1,22 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::set_function::SetQuantifier;
+use crate::{
+    set_function::SetQuantifier,
+    Span,
+    Spanned,
+};
 
 /// The `<select statement: single row>` statement.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStatementSingleRow {
     pub set_quantifier: SetQuantifier,
+    pub span: Span,
+}
+
+impl Spanned for SelectStatementSingleRow {
+    fn span(&self) -> Span {
+        self.span
+    }
 }
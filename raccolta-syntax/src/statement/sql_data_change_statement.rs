@@ -1,6 +1,8 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
 use super::InsertStatement;
 
 /// ```text
@@ -12,6 +14,7 @@ use super::InsertStatement;
 ///     | <update statement: searched>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlDataChangeStatement {
     /// The `INSERT INTO` statement, i.e. `<insert statement>`:
     ///
@@ -22,3 +25,11 @@ pub enum SqlDataChangeStatement {
     /// ```
     Insert(InsertStatement),
 }
+
+impl Spanned for SqlDataChangeStatement {
+    fn span(&self) -> Span {
+        match self {
+            Self::Insert(statement) => statement.span(),
+        }
+    }
+}
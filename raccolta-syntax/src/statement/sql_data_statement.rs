@@ -1,7 +1,11 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::expression::QueryExpression;
+use crate::{
+    expression::QueryExpression,
+    Span,
+    Spanned,
+};
 
 use super::{
     SelectStatementSingleRow,
@@ -19,9 +23,20 @@ use super::{
 ///     | <SQL data change statement>
 /// ```
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlDataStatement {
     /// `<SQL data change statement>`
     ChangeStatement(SqlDataChangeStatement),
-    SelectStatement(QueryExpression),
+    SelectStatement(Box<QueryExpression>),
     SelectStatementSingleRow(SelectStatementSingleRow),
 }
+
+impl Spanned for SqlDataStatement {
+    fn span(&self) -> Span {
+        match self {
+            Self::ChangeStatement(statement) => statement.span(),
+            Self::SelectStatement(statement) => statement.span(),
+            Self::SelectStatementSingleRow(statement) => statement.span(),
+        }
+    }
+}
@@ -1,13 +1,25 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
 use super::{
     SqlDataStatement,
     SqlSchemaStatement,
 };
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlExecutableStatement {
     Schema(SqlSchemaStatement),
     SqlDataStatement(SqlDataStatement),
 }
+
+impl Spanned for SqlExecutableStatement {
+    fn span(&self) -> Span {
+        match self {
+            Self::Schema(statement) => statement.span(),
+            Self::SqlDataStatement(statement) => statement.span(),
+        }
+    }
+}
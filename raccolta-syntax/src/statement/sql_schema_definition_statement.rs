@@ -1,9 +1,22 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
-use crate::schema::definition::TableDefinition;
+use crate::{
+    schema::definition::TableDefinition,
+    Span,
+    Spanned,
+};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlSchemaDefinitionStatement {
     Table(TableDefinition),
 }
+
+impl Spanned for SqlSchemaDefinitionStatement {
+    fn span(&self) -> Span {
+        match self {
+            Self::Table(statement) => statement.span(),
+        }
+    }
+}
@@ -1,9 +1,20 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{Span, Spanned};
+
 use super::SqlSchemaDefinitionStatement;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SqlSchemaStatement {
     Definition(SqlSchemaDefinitionStatement),
 }
+
+impl Spanned for SqlSchemaStatement {
+    fn span(&self) -> Span {
+        match self {
+            Self::Definition(statement) => statement.span(),
+        }
+    }
+}
@@ -1,12 +1,17 @@
 // Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
 // All Rights Reserved.
 
+use crate::{
+    common::{Identifier, IdentifierDelimiter},
+    Span,
+};
 use crate::keyword::{
     NonReservedWord,
     ReservedWord, VendorReservedWord,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
     pub first_character_byte_idx: usize,
     pub last_character_byte_idx: usize,
@@ -35,6 +40,30 @@ impl Token {
         }
     }
 
+    /// Converts this token to an [`Identifier`] if applicable. Non-reserved
+    /// words are regular identifiers too.
+    pub fn as_identifier(&self, input: &str) -> Option<Identifier> {
+        match self.kind {
+            TokenKind::Identifier | TokenKind::NonReservedWord(..) => {
+                Some(Identifier::regular(self.as_string(input)).with_span(self.span()))
+            }
+
+            TokenKind::DelimitedIdentifier { first_character_byte_idx, last_character_byte_idx } => {
+                let body = &input[first_character_byte_idx..last_character_byte_idx];
+                let closing_delimiter = &input[last_character_byte_idx..self.last_character_byte_idx];
+                let escaped_delimiter = closing_delimiter.repeat(2);
+                let delimiter = match closing_delimiter {
+                    "`" => IdentifierDelimiter::Backtick,
+                    "]" => IdentifierDelimiter::SquareBrackets,
+                    _ => IdentifierDelimiter::DoubleQuote,
+                };
+                Some(Identifier::delimited_by(body.replace(&escaped_delimiter, closing_delimiter), delimiter).with_span(self.span()))
+            }
+
+            _ => None,
+        }
+    }
+
     /// Returns the string representation as provided by the `input`.
     pub fn as_string<'a>(&self, input: &'a str) -> &'a str {
         &input[self.first_character_byte_idx..self.last_character_byte_idx]
@@ -44,11 +73,81 @@ impl Token {
     pub fn kind(&self) -> TokenKind {
         self.kind
     }
+
+    /// Returns the part of the input this token spans.
+    pub fn span(&self) -> Span {
+        Span::new(self.first_character_byte_idx, self.last_character_byte_idx)
+    }
+}
+
+/// The reason the [`Lexer`](crate::Lexer) produced a
+/// [`TokenKind::IllegalToken`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IllegalTokenReason {
+    /// A delimited identifier without any characters, e.g. `""`.
+    EmptyDelimitedIdentifier,
+
+    /// A `<binary string literal>` containing something other than pairs of
+    /// hexits and spaces, e.g. `X'ABC'` or `X'GG'`.
+    InvalidBinaryStringLiteral,
+
+    /// An `<unsigned integer>` that doesn't fit in an [`u64`].
+    IntegerOutOfRange,
+
+    /// A `<bit string literal>` containing something other than bits, e.g.
+    /// `B'102'`.
+    InvalidBitStringLiteral,
+
+    /// A numeric literal without exponent digits, e.g. `1E+`, or one that is
+    /// directly followed by identifier characters, e.g. `123abc`.
+    InvalidNumericLiteral,
+
+    /// A `UESCAPE` clause with an escape character that isn't allowed, e.g.
+    /// `UESCAPE '+'`, or that isn't followed by a quoted character.
+    InvalidUnicodeEscapeCharacter,
+
+    /// A `<Unicode character string literal>` with an escape value that isn't
+    /// followed by enough hexits, or doesn't denote a Unicode scalar value,
+    /// e.g. `U&'\00G1'`.
+    InvalidUnicodeEscapeValue,
+
+    /// A character that doesn't start any token.
+    UnexpectedCharacter(char),
+
+    /// A `<bracketed comment>` that isn't closed by `*/`. Block comments can
+    /// be nested, so every `/*` needs its own `*/`.
+    UnterminatedBracketedComment,
+
+    /// A delimited identifier that isn't closed by its delimiter, e.g. `"`.
+    UnterminatedDelimitedIdentifier,
+
+    /// A string literal that isn't closed by a quote `'`. The position of
+    /// the opening quote is given, since the illegal token spans the rest of
+    /// the input.
+    UnterminatedStringLiteral {
+        opening_quote_byte_idx: usize,
+    },
+}
+
+/// The kind of a [`TokenKind::StringLiteral`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StringLiteralKind {
+    /// A `<character string literal>`, e.g. `'Hello'`.
+    Character,
+
+    /// A `<national character string literal>`, e.g. `N'Hello'`.
+    National,
+
+    /// A `<Unicode character string literal>`, e.g. `U&'\0048ello'`.
+    Unicode,
 }
 
 /// A token is a lexical constituent.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[derive(strum::Display)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenKind {
     /// The '&' token
     Ampersand,
@@ -56,9 +155,27 @@ pub enum TokenKind {
     /// The '*' token
     Asterisk,
 
+    /// An `<approximate numeric literal>`, e.g. `6.02E23`. The value is kept
+    /// as the source text, see [`Token::as_string`].
+    ApproximateNumericLiteral,
+
     /// The '@' token
     AtSign,
 
+    /// A binary string literal, e.g. X'DEADBEEF'. The byte indices point to
+    /// the hexits between the quotes.
+    BinaryStringLiteral {
+        first_character_byte_idx: usize,
+        last_character_byte_idx: usize,
+    },
+
+    /// A bit string literal, e.g. B'1010'. The byte indices point to the bits
+    /// between the quotes.
+    BitStringLiteral {
+        first_character_byte_idx: usize,
+        last_character_byte_idx: usize,
+    },
+
     /// The '^' token
     Circumflex,
 
@@ -68,9 +185,23 @@ pub enum TokenKind {
     /// The ',' character
     Comma,
 
+    /// A `<simple comment>` such as `-- comment`, or a `<bracketed comment>`
+    /// such as `/* comment */`. These are only produced when enabled using
+    /// [`Lexer::with_comments`](crate::Lexer::with_comments), and skipped as
+    /// whitespace otherwise.
+    Comment,
+
     /// The `||` token
     ConcatenationOperator,
 
+    /// A delimited identifier, e.g. "Name". The byte indices point to the
+    /// body between the delimiters, in which escaped delimiters aren't
+    /// resolved yet. See [`Token::as_identifier`].
+    DelimitedIdentifier {
+        first_character_byte_idx: usize,
+        last_character_byte_idx: usize,
+    },
+
     /// The `$` token
     DollarSign,
 
@@ -83,13 +214,17 @@ pub enum TokenKind {
     /// The '=' character
     EqualsSign,
 
-    /// The `! token
+    /// An `<exact numeric literal>` with a period, e.g. `3.14`, `.5` or `1.`.
+    /// The value is kept as the source text, see [`Token::as_string`].
+    ExactNumericLiteral,
+
+    /// The `!` token
     ExclamationMark,
 
     /// The '.' character
     FullStop,
 
-    /// The `<` token
+    /// The `>` token
     GreaterThanOperator,
 
     /// The `>=` token
@@ -97,8 +232,8 @@ pub enum TokenKind {
 
     Identifier,
 
-    /// Illegal portion
-    IllegalToken,
+    /// Illegal portion, after which the lexer stops producing tokens.
+    IllegalToken(IllegalTokenReason),
 
     /// The `{` token
     LeftBrace,
@@ -160,13 +295,16 @@ pub enum TokenKind {
     /// The `/` token
     Solidus,
 
-    /// A string literal, e.g. 'Hello, world!'.
+    /// A string literal, e.g. 'Hello, world!'. The token spans the complete
+    /// literal, including the quotes and continuing literals, so the value
+    /// needs to be decoded before use.
     StringLiteral {
-        first_character_byte_idx: usize,
-        last_character_byte_idx: usize,
+        kind: StringLiteralKind,
     },
 
-    /// An unsigned integer [`u64`].
+    /// An `<unsigned integer>`, i.e. an `<exact numeric literal>` without a
+    /// period, e.g. `42`. With vendor keywords, hexadecimal integers such as
+    /// `0x1F` are lexed as well.
     UnsignedInteger(u64),
 
     VendorReservedWord(VendorReservedWord),
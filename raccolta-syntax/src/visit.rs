@@ -0,0 +1,1279 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Read-only traversal of syntax trees. Implementations of [`Visit`] override
+//! the methods of the nodes they are interested in, while the default methods
+//! walk into the children of the other nodes. For example, the tables a
+//! statement depends on can be collected as follows:
+//!
+//! ```
+//! use raccolta_syntax::{
+//!     common::TableName,
+//!     expression::table_reference::TablePrimaryKind,
+//!     visit::Visit,
+//!     Parser,
+//! };
+//!
+//! #[derive(Default)]
+//! struct TableNames(Vec<String>);
+//!
+//! impl Visit for TableNames {
+//!     fn visit_table_primary_kind(&mut self, table_primary_kind: &TablePrimaryKind) {
+//!         let TablePrimaryKind::TableOrQueryName(name) = table_primary_kind;
+//!         self.0.push(name.value.clone());
+//!     }
+//!
+//!     fn visit_table_name(&mut self, table_name: &TableName) {
+//!         self.0.push(table_name.table_qualifier.value.clone());
+//!     }
+//! }
+//!
+//! let statement = Parser::new().parse_statement("SELECT * FROM person, pet").unwrap();
+//!
+//! let mut table_names = TableNames::default();
+//! table_names.visit_sql_executable_statement(&statement);
+//! assert_eq!(table_names.0, ["person", "pet"]);
+//! ```
+//!
+//! An overriding method can call the corresponding `walk_*` function of this
+//! module to continue into the children of the node, or leave it out to skip
+//! them. For mutating syntax trees, see [`VisitMut`](crate::visit_mut::VisitMut).
+
+use crate::{
+    clause::{
+        fetch_first_clause::{
+            FetchFirstClause,
+            FetchFirstClauseOrigin,
+            FetchFirstQuantity,
+        },
+        order_by_clause::{
+            OrderByClause,
+            OrderingSpecification,
+            SortSpecification,
+        },
+        result_offset_clause::ResultOffsetClause,
+        window_clause::{
+            WindowDefinition,
+            WindowFrameBound,
+            WindowFrameClause,
+            WindowFrameUnits,
+            WindowSpecification,
+        },
+        FromClause,
+        GroupByClause,
+        HavingClause,
+        WhereClause,
+        WindowClause,
+    },
+    common::{
+        character_set::{
+            CharacterSetSpecification,
+            CharacterSetSpecificationKind,
+        },
+        Identifier,
+        TableName,
+    },
+    expression::{
+        boolean_expression::TruthValue,
+        case_expression::{
+            CaseResult,
+            SearchedWhenClause,
+            SimpleWhenClause,
+        },
+        cast_specification::CastOperand,
+        data_type::{
+            BinaryStringType,
+            BitStringType,
+            CharacterStringType,
+            DataType,
+            LargeObjectLength,
+            LargeObjectLengthMultiplier,
+            NationalCharacterStringType,
+            NumericType,
+            PredefinedType,
+        },
+        numeric_value_function::NumericValueFunction,
+        query_expression::{
+            NonJoinQueryExpression,
+            NonJoinQueryPrimary,
+            NonJoinQueryTerm,
+            QueryExpressionBody,
+            QueryPrimary,
+            SimpleTable,
+        },
+        query_specification::{
+            DerivedColumn,
+            SelectList,
+            SelectSublist,
+        },
+        row_value_constructor::{
+            ContextuallyTypedRowValueConstructor,
+            ContextuallyTypedRowValueConstructorElement,
+        },
+        row_value_expression::ContextuallyTypedRowValueExpression,
+        string_value_expression::StringValueExpression,
+        string_value_function::{
+            StringValueFunction,
+            TrimSpecification,
+        },
+        table_reference::{
+            TablePrimary,
+            TablePrimaryKind,
+        },
+        table_value_constructor::ContextuallyTypedTableValueConstructor,
+        window_function::{
+            RankFunctionType,
+            WindowFunctionType,
+            WindowNameOrSpecification,
+        },
+        BooleanExpression,
+        CaseExpression,
+        CastSpecification,
+        ColumnReference,
+        NumericOperator,
+        NumericValueExpression,
+        QueryExpression,
+        QuerySpecification,
+        RoutineInvocation,
+        SimpleValueSpecification,
+        TableExpression,
+        TableReference,
+        ValueExpression,
+        WindowFunction,
+    },
+    predicate::{
+        between_predicate::BetweenPredicate,
+        comparison_predicate::{
+            ComparisonOperator,
+            ComparisonPredicate,
+        },
+        distinct_predicate::DistinctPredicate,
+        in_predicate::InPredicate,
+        like_predicate::LikePredicate,
+        null_predicate::NullPredicate,
+        quantified_comparison_predicate::{
+            QuantifiedComparisonPredicate,
+            Quantifier,
+        },
+        similar_predicate::SimilarPredicate,
+        Predicate,
+    },
+    schema::definition::{
+        table_definition::{
+            ColumnConstraintDefinition,
+            ColumnDefinition,
+            TableElement,
+        },
+        TableDefinition,
+        UniqueSpecification,
+    },
+    set_function::{
+        ComputationalOperation,
+        GeneralSetFunction,
+        SetFunctionSpecification,
+        SetQuantifier,
+    },
+    statement::{
+        insert_statement::InsertColumnsAndSource,
+        InsertStatement,
+        SelectStatementSingleRow,
+        SqlDataChangeStatement,
+        SqlDataStatement,
+        SqlExecutableStatement,
+        SqlSchemaDefinitionStatement,
+        SqlSchemaStatement,
+    },
+};
+
+/// Visits the nodes of a syntax tree by reference. Every method defaults to
+/// walking the children of the node, which are visited in the order of the
+/// node's fields. Leaf nodes, such as [`Identifier`], have nothing to walk.
+pub trait Visit {
+    fn visit_sql_executable_statement(&mut self, sql_executable_statement: &SqlExecutableStatement) {
+        walk_sql_executable_statement(self, sql_executable_statement);
+    }
+
+    fn visit_sql_schema_statement(&mut self, sql_schema_statement: &SqlSchemaStatement) {
+        walk_sql_schema_statement(self, sql_schema_statement);
+    }
+
+    fn visit_sql_schema_definition_statement(&mut self, sql_schema_definition_statement: &SqlSchemaDefinitionStatement) {
+        walk_sql_schema_definition_statement(self, sql_schema_definition_statement);
+    }
+
+    fn visit_sql_data_statement(&mut self, sql_data_statement: &SqlDataStatement) {
+        walk_sql_data_statement(self, sql_data_statement);
+    }
+
+    fn visit_sql_data_change_statement(&mut self, sql_data_change_statement: &SqlDataChangeStatement) {
+        walk_sql_data_change_statement(self, sql_data_change_statement);
+    }
+
+    fn visit_insert_statement(&mut self, insert_statement: &InsertStatement) {
+        walk_insert_statement(self, insert_statement);
+    }
+
+    fn visit_insert_columns_and_source(&mut self, insert_columns_and_source: &InsertColumnsAndSource) {
+        walk_insert_columns_and_source(self, insert_columns_and_source);
+    }
+
+    fn visit_contextually_typed_table_value_constructor(&mut self, contextually_typed_table_value_constructor: &ContextuallyTypedTableValueConstructor) {
+        walk_contextually_typed_table_value_constructor(self, contextually_typed_table_value_constructor);
+    }
+
+    fn visit_contextually_typed_row_value_expression(&mut self, contextually_typed_row_value_expression: &ContextuallyTypedRowValueExpression) {
+        walk_contextually_typed_row_value_expression(self, contextually_typed_row_value_expression);
+    }
+
+    fn visit_contextually_typed_row_value_constructor(&mut self, contextually_typed_row_value_constructor: &ContextuallyTypedRowValueConstructor) {
+        walk_contextually_typed_row_value_constructor(self, contextually_typed_row_value_constructor);
+    }
+
+    fn visit_contextually_typed_row_value_constructor_element(&mut self, contextually_typed_row_value_constructor_element: &ContextuallyTypedRowValueConstructorElement) {
+        walk_contextually_typed_row_value_constructor_element(self, contextually_typed_row_value_constructor_element);
+    }
+
+    fn visit_select_statement_single_row(&mut self, select_statement_single_row: &SelectStatementSingleRow) {
+        walk_select_statement_single_row(self, select_statement_single_row);
+    }
+
+    fn visit_table_definition(&mut self, table_definition: &TableDefinition) {
+        walk_table_definition(self, table_definition);
+    }
+
+    fn visit_table_element(&mut self, table_element: &TableElement) {
+        walk_table_element(self, table_element);
+    }
+
+    fn visit_column_definition(&mut self, column_definition: &ColumnDefinition) {
+        walk_column_definition(self, column_definition);
+    }
+
+    fn visit_column_constraint_definition(&mut self, column_constraint_definition: &ColumnConstraintDefinition) {
+        walk_column_constraint_definition(self, column_constraint_definition);
+    }
+
+    fn visit_unique_specification(&mut self, _unique_specification: &UniqueSpecification) {}
+
+    fn visit_query_expression(&mut self, query_expression: &QueryExpression) {
+        walk_query_expression(self, query_expression);
+    }
+
+    fn visit_query_expression_body(&mut self, query_expression_body: &QueryExpressionBody) {
+        walk_query_expression_body(self, query_expression_body);
+    }
+
+    fn visit_non_join_query_expression(&mut self, non_join_query_expression: &NonJoinQueryExpression) {
+        walk_non_join_query_expression(self, non_join_query_expression);
+    }
+
+    fn visit_non_join_query_term(&mut self, non_join_query_term: &NonJoinQueryTerm) {
+        walk_non_join_query_term(self, non_join_query_term);
+    }
+
+    fn visit_non_join_query_primary(&mut self, non_join_query_primary: &NonJoinQueryPrimary) {
+        walk_non_join_query_primary(self, non_join_query_primary);
+    }
+
+    fn visit_query_primary(&mut self, query_primary: &QueryPrimary) {
+        walk_query_primary(self, query_primary);
+    }
+
+    fn visit_simple_table(&mut self, simple_table: &SimpleTable) {
+        walk_simple_table(self, simple_table);
+    }
+
+    fn visit_query_specification(&mut self, query_specification: &QuerySpecification) {
+        walk_query_specification(self, query_specification);
+    }
+
+    fn visit_select_list(&mut self, select_list: &SelectList) {
+        walk_select_list(self, select_list);
+    }
+
+    fn visit_select_sublist(&mut self, select_sublist: &SelectSublist) {
+        walk_select_sublist(self, select_sublist);
+    }
+
+    fn visit_derived_column(&mut self, derived_column: &DerivedColumn) {
+        walk_derived_column(self, derived_column);
+    }
+
+    fn visit_table_expression(&mut self, table_expression: &TableExpression) {
+        walk_table_expression(self, table_expression);
+    }
+
+    fn visit_table_reference(&mut self, table_reference: &TableReference) {
+        walk_table_reference(self, table_reference);
+    }
+
+    fn visit_table_primary(&mut self, table_primary: &TablePrimary) {
+        walk_table_primary(self, table_primary);
+    }
+
+    fn visit_table_primary_kind(&mut self, table_primary_kind: &TablePrimaryKind) {
+        walk_table_primary_kind(self, table_primary_kind);
+    }
+
+    fn visit_from_clause(&mut self, from_clause: &FromClause) {
+        walk_from_clause(self, from_clause);
+    }
+
+    fn visit_where_clause(&mut self, where_clause: &WhereClause) {
+        walk_where_clause(self, where_clause);
+    }
+
+    fn visit_group_by_clause(&mut self, _group_by_clause: &GroupByClause) {}
+
+    fn visit_having_clause(&mut self, _having_clause: &HavingClause) {}
+
+    fn visit_window_clause(&mut self, window_clause: &WindowClause) {
+        walk_window_clause(self, window_clause);
+    }
+
+    fn visit_window_definition(&mut self, window_definition: &WindowDefinition) {
+        walk_window_definition(self, window_definition);
+    }
+
+    fn visit_window_specification(&mut self, window_specification: &WindowSpecification) {
+        walk_window_specification(self, window_specification);
+    }
+
+    fn visit_window_frame_clause(&mut self, window_frame_clause: &WindowFrameClause) {
+        walk_window_frame_clause(self, window_frame_clause);
+    }
+
+    fn visit_window_frame_units(&mut self, _window_frame_units: &WindowFrameUnits) {}
+
+    fn visit_window_frame_bound(&mut self, window_frame_bound: &WindowFrameBound) {
+        walk_window_frame_bound(self, window_frame_bound);
+    }
+
+    fn visit_order_by_clause(&mut self, order_by_clause: &OrderByClause) {
+        walk_order_by_clause(self, order_by_clause);
+    }
+
+    fn visit_sort_specification(&mut self, sort_specification: &SortSpecification) {
+        walk_sort_specification(self, sort_specification);
+    }
+
+    fn visit_ordering_specification(&mut self, _ordering_specification: &OrderingSpecification) {}
+
+    fn visit_result_offset_clause(&mut self, result_offset_clause: &ResultOffsetClause) {
+        walk_result_offset_clause(self, result_offset_clause);
+    }
+
+    fn visit_fetch_first_clause(&mut self, fetch_first_clause: &FetchFirstClause) {
+        walk_fetch_first_clause(self, fetch_first_clause);
+    }
+
+    fn visit_fetch_first_quantity(&mut self, fetch_first_quantity: &FetchFirstQuantity) {
+        walk_fetch_first_quantity(self, fetch_first_quantity);
+    }
+
+    fn visit_fetch_first_clause_origin(&mut self, _fetch_first_clause_origin: &FetchFirstClauseOrigin) {}
+
+    fn visit_value_expression(&mut self, value_expression: &ValueExpression) {
+        walk_value_expression(self, value_expression);
+    }
+
+    fn visit_boolean_expression(&mut self, boolean_expression: &BooleanExpression) {
+        walk_boolean_expression(self, boolean_expression);
+    }
+
+    fn visit_truth_value(&mut self, _truth_value: &TruthValue) {}
+
+    fn visit_case_expression(&mut self, case_expression: &CaseExpression) {
+        walk_case_expression(self, case_expression);
+    }
+
+    fn visit_searched_when_clause(&mut self, searched_when_clause: &SearchedWhenClause) {
+        walk_searched_when_clause(self, searched_when_clause);
+    }
+
+    fn visit_simple_when_clause(&mut self, simple_when_clause: &SimpleWhenClause) {
+        walk_simple_when_clause(self, simple_when_clause);
+    }
+
+    fn visit_case_result(&mut self, case_result: &CaseResult) {
+        walk_case_result(self, case_result);
+    }
+
+    fn visit_cast_specification(&mut self, cast_specification: &CastSpecification) {
+        walk_cast_specification(self, cast_specification);
+    }
+
+    fn visit_cast_operand(&mut self, cast_operand: &CastOperand) {
+        walk_cast_operand(self, cast_operand);
+    }
+
+    fn visit_column_reference(&mut self, column_reference: &ColumnReference) {
+        walk_column_reference(self, column_reference);
+    }
+
+    fn visit_numeric_value_expression(&mut self, numeric_value_expression: &NumericValueExpression) {
+        walk_numeric_value_expression(self, numeric_value_expression);
+    }
+
+    fn visit_numeric_operator(&mut self, _numeric_operator: &NumericOperator) {}
+
+    fn visit_numeric_value_function(&mut self, numeric_value_function: &NumericValueFunction) {
+        walk_numeric_value_function(self, numeric_value_function);
+    }
+
+    fn visit_routine_invocation(&mut self, routine_invocation: &RoutineInvocation) {
+        walk_routine_invocation(self, routine_invocation);
+    }
+
+    fn visit_set_function_specification(&mut self, set_function_specification: &SetFunctionSpecification) {
+        walk_set_function_specification(self, set_function_specification);
+    }
+
+    fn visit_general_set_function(&mut self, general_set_function: &GeneralSetFunction) {
+        walk_general_set_function(self, general_set_function);
+    }
+
+    fn visit_computational_operation(&mut self, _computational_operation: &ComputationalOperation) {}
+
+    fn visit_set_quantifier(&mut self, _set_quantifier: &SetQuantifier) {}
+
+    fn visit_simple_value_specification(&mut self, _simple_value_specification: &SimpleValueSpecification) {}
+
+    fn visit_string_value_expression(&mut self, string_value_expression: &StringValueExpression) {
+        walk_string_value_expression(self, string_value_expression);
+    }
+
+    fn visit_string_value_function(&mut self, string_value_function: &StringValueFunction) {
+        walk_string_value_function(self, string_value_function);
+    }
+
+    fn visit_trim_specification(&mut self, _trim_specification: &TrimSpecification) {}
+
+    fn visit_window_function(&mut self, window_function: &WindowFunction) {
+        walk_window_function(self, window_function);
+    }
+
+    fn visit_window_function_type(&mut self, window_function_type: &WindowFunctionType) {
+        walk_window_function_type(self, window_function_type);
+    }
+
+    fn visit_rank_function_type(&mut self, _rank_function_type: &RankFunctionType) {}
+
+    fn visit_window_name_or_specification(&mut self, window_name_or_specification: &WindowNameOrSpecification) {
+        walk_window_name_or_specification(self, window_name_or_specification);
+    }
+
+    fn visit_predicate(&mut self, predicate: &Predicate) {
+        walk_predicate(self, predicate);
+    }
+
+    fn visit_between_predicate(&mut self, between_predicate: &BetweenPredicate) {
+        walk_between_predicate(self, between_predicate);
+    }
+
+    fn visit_comparison_predicate(&mut self, comparison_predicate: &ComparisonPredicate) {
+        walk_comparison_predicate(self, comparison_predicate);
+    }
+
+    fn visit_comparison_operator(&mut self, _comparison_operator: &ComparisonOperator) {}
+
+    fn visit_distinct_predicate(&mut self, distinct_predicate: &DistinctPredicate) {
+        walk_distinct_predicate(self, distinct_predicate);
+    }
+
+    fn visit_in_predicate(&mut self, in_predicate: &InPredicate) {
+        walk_in_predicate(self, in_predicate);
+    }
+
+    fn visit_like_predicate(&mut self, like_predicate: &LikePredicate) {
+        walk_like_predicate(self, like_predicate);
+    }
+
+    fn visit_null_predicate(&mut self, null_predicate: &NullPredicate) {
+        walk_null_predicate(self, null_predicate);
+    }
+
+    fn visit_quantified_comparison_predicate(&mut self, quantified_comparison_predicate: &QuantifiedComparisonPredicate) {
+        walk_quantified_comparison_predicate(self, quantified_comparison_predicate);
+    }
+
+    fn visit_quantifier(&mut self, _quantifier: &Quantifier) {}
+
+    fn visit_similar_predicate(&mut self, similar_predicate: &SimilarPredicate) {
+        walk_similar_predicate(self, similar_predicate);
+    }
+
+    fn visit_data_type(&mut self, data_type: &DataType) {
+        walk_data_type(self, data_type);
+    }
+
+    fn visit_predefined_type(&mut self, predefined_type: &PredefinedType) {
+        walk_predefined_type(self, predefined_type);
+    }
+
+    fn visit_character_string_type(&mut self, character_string_type: &CharacterStringType) {
+        walk_character_string_type(self, character_string_type);
+    }
+
+    fn visit_national_character_string_type(&mut self, national_character_string_type: &NationalCharacterStringType) {
+        walk_national_character_string_type(self, national_character_string_type);
+    }
+
+    fn visit_binary_string_type(&mut self, binary_string_type: &BinaryStringType) {
+        walk_binary_string_type(self, binary_string_type);
+    }
+
+    fn visit_bit_string_type(&mut self, _bit_string_type: &BitStringType) {}
+
+    fn visit_numeric_type(&mut self, _numeric_type: &NumericType) {}
+
+    fn visit_large_object_length(&mut self, large_object_length: &LargeObjectLength) {
+        walk_large_object_length(self, large_object_length);
+    }
+
+    fn visit_large_object_length_multiplier(&mut self, _large_object_length_multiplier: &LargeObjectLengthMultiplier) {}
+
+    fn visit_character_set_specification(&mut self, character_set_specification: &CharacterSetSpecification) {
+        walk_character_set_specification(self, character_set_specification);
+    }
+
+    fn visit_character_set_specification_kind(&mut self, _character_set_specification_kind: &CharacterSetSpecificationKind) {}
+
+    fn visit_table_name(&mut self, table_name: &TableName) {
+        walk_table_name(self, table_name);
+    }
+
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+}
+
+pub fn walk_sql_executable_statement<V: Visit + ?Sized>(visitor: &mut V, sql_executable_statement: &SqlExecutableStatement) {
+    match sql_executable_statement {
+        SqlExecutableStatement::Schema(statement) => visitor.visit_sql_schema_statement(statement),
+        SqlExecutableStatement::SqlDataStatement(statement) => visitor.visit_sql_data_statement(statement),
+    }
+}
+
+pub fn walk_sql_schema_statement<V: Visit + ?Sized>(visitor: &mut V, sql_schema_statement: &SqlSchemaStatement) {
+    match sql_schema_statement {
+        SqlSchemaStatement::Definition(statement) => visitor.visit_sql_schema_definition_statement(statement),
+    }
+}
+
+pub fn walk_sql_schema_definition_statement<V: Visit + ?Sized>(visitor: &mut V, sql_schema_definition_statement: &SqlSchemaDefinitionStatement) {
+    match sql_schema_definition_statement {
+        SqlSchemaDefinitionStatement::Table(definition) => visitor.visit_table_definition(definition),
+    }
+}
+
+pub fn walk_sql_data_statement<V: Visit + ?Sized>(visitor: &mut V, sql_data_statement: &SqlDataStatement) {
+    match sql_data_statement {
+        SqlDataStatement::ChangeStatement(statement) => visitor.visit_sql_data_change_statement(statement),
+        SqlDataStatement::SelectStatement(query_expression) => visitor.visit_query_expression(query_expression),
+        SqlDataStatement::SelectStatementSingleRow(statement) => visitor.visit_select_statement_single_row(statement),
+    }
+}
+
+pub fn walk_sql_data_change_statement<V: Visit + ?Sized>(visitor: &mut V, sql_data_change_statement: &SqlDataChangeStatement) {
+    match sql_data_change_statement {
+        SqlDataChangeStatement::Insert(statement) => visitor.visit_insert_statement(statement),
+    }
+}
+
+pub fn walk_insert_statement<V: Visit + ?Sized>(visitor: &mut V, insert_statement: &InsertStatement) {
+    visitor.visit_table_name(&insert_statement.table_name);
+    visitor.visit_insert_columns_and_source(&insert_statement.insert_columns_and_source);
+}
+
+pub fn walk_insert_columns_and_source<V: Visit + ?Sized>(visitor: &mut V, insert_columns_and_source: &InsertColumnsAndSource) {
+    match insert_columns_and_source {
+        InsertColumnsAndSource::FromConstructor { constructor, .. } => visitor.visit_contextually_typed_table_value_constructor(constructor),
+    }
+}
+
+pub fn walk_contextually_typed_table_value_constructor<V: Visit + ?Sized>(visitor: &mut V, contextually_typed_table_value_constructor: &ContextuallyTypedTableValueConstructor) {
+    for contextually_typed_row_value_expression in &contextually_typed_table_value_constructor.values {
+        visitor.visit_contextually_typed_row_value_expression(contextually_typed_row_value_expression);
+    }
+}
+
+pub fn walk_contextually_typed_row_value_expression<V: Visit + ?Sized>(visitor: &mut V, contextually_typed_row_value_expression: &ContextuallyTypedRowValueExpression) {
+    match contextually_typed_row_value_expression {
+        ContextuallyTypedRowValueExpression::ContextuallyTypedRowValueConstructor(constructor) => visitor.visit_contextually_typed_row_value_constructor(constructor),
+    }
+}
+
+pub fn walk_contextually_typed_row_value_constructor<V: Visit + ?Sized>(visitor: &mut V, contextually_typed_row_value_constructor: &ContextuallyTypedRowValueConstructor) {
+    for contextually_typed_row_value_constructor_element in &contextually_typed_row_value_constructor.elements {
+        visitor.visit_contextually_typed_row_value_constructor_element(contextually_typed_row_value_constructor_element);
+    }
+}
+
+pub fn walk_contextually_typed_row_value_constructor_element<V: Visit + ?Sized>(visitor: &mut V, contextually_typed_row_value_constructor_element: &ContextuallyTypedRowValueConstructorElement) {
+    match contextually_typed_row_value_constructor_element {
+        ContextuallyTypedRowValueConstructorElement::ValueExpression(expression) => visitor.visit_value_expression(expression),
+    }
+}
+
+pub fn walk_select_statement_single_row<V: Visit + ?Sized>(visitor: &mut V, select_statement_single_row: &SelectStatementSingleRow) {
+    visitor.visit_set_quantifier(&select_statement_single_row.set_quantifier);
+}
+
+pub fn walk_table_definition<V: Visit + ?Sized>(visitor: &mut V, table_definition: &TableDefinition) {
+    visitor.visit_identifier(&table_definition.table_name);
+
+    for table_element in &table_definition.elements {
+        visitor.visit_table_element(table_element);
+    }
+}
+
+pub fn walk_table_element<V: Visit + ?Sized>(visitor: &mut V, table_element: &TableElement) {
+    match table_element {
+        TableElement::ColumnDefinition(definition) => visitor.visit_column_definition(definition),
+    }
+}
+
+pub fn walk_column_definition<V: Visit + ?Sized>(visitor: &mut V, column_definition: &ColumnDefinition) {
+    visitor.visit_identifier(&column_definition.column_name);
+    visitor.visit_data_type(&column_definition.data_type);
+
+    for column_constraint_definition in &column_definition.column_constraint_definitions {
+        visitor.visit_column_constraint_definition(column_constraint_definition);
+    }
+}
+
+pub fn walk_column_constraint_definition<V: Visit + ?Sized>(visitor: &mut V, column_constraint_definition: &ColumnConstraintDefinition) {
+    match column_constraint_definition {
+        ColumnConstraintDefinition::UniqueSpecification(specification) => visitor.visit_unique_specification(specification),
+        ColumnConstraintDefinition::NotNull => (),
+    }
+}
+
+pub fn walk_query_expression<V: Visit + ?Sized>(visitor: &mut V, query_expression: &QueryExpression) {
+    visitor.visit_query_expression_body(&query_expression.body);
+
+    if let Some(order_by) = &query_expression.order_by {
+        visitor.visit_order_by_clause(order_by);
+    }
+
+    if let Some(offset) = &query_expression.offset {
+        visitor.visit_result_offset_clause(offset);
+    }
+
+    if let Some(fetch) = &query_expression.fetch {
+        visitor.visit_fetch_first_clause(fetch);
+    }
+}
+
+pub fn walk_query_expression_body<V: Visit + ?Sized>(visitor: &mut V, query_expression_body: &QueryExpressionBody) {
+    match query_expression_body {
+        QueryExpressionBody::NonJoinQueryExpression(expression) => visitor.visit_non_join_query_expression(expression),
+        QueryExpressionBody::SimpleTable(simple_table) => visitor.visit_simple_table(simple_table),
+    }
+}
+
+pub fn walk_non_join_query_expression<V: Visit + ?Sized>(visitor: &mut V, non_join_query_expression: &NonJoinQueryExpression) {
+    match non_join_query_expression {
+        NonJoinQueryExpression::NonJoinQueryTerm(term) => visitor.visit_non_join_query_term(term),
+    }
+}
+
+pub fn walk_non_join_query_term<V: Visit + ?Sized>(visitor: &mut V, non_join_query_term: &NonJoinQueryTerm) {
+    match non_join_query_term {
+        NonJoinQueryTerm::NonJoinQueryPrimary(primary) => visitor.visit_non_join_query_primary(primary),
+    }
+}
+
+pub fn walk_non_join_query_primary<V: Visit + ?Sized>(visitor: &mut V, non_join_query_primary: &NonJoinQueryPrimary) {
+    match non_join_query_primary {
+        NonJoinQueryPrimary::SimpleTable(simple_table) => visitor.visit_simple_table(simple_table),
+    }
+}
+
+pub fn walk_query_primary<V: Visit + ?Sized>(visitor: &mut V, query_primary: &QueryPrimary) {
+    match query_primary {
+        QueryPrimary::NonJoinQueryPrimary(primary) => visitor.visit_non_join_query_primary(primary),
+    }
+}
+
+pub fn walk_simple_table<V: Visit + ?Sized>(visitor: &mut V, simple_table: &SimpleTable) {
+    match simple_table {
+        SimpleTable::QuerySpecification(specification) => visitor.visit_query_specification(specification),
+    }
+}
+
+pub fn walk_query_specification<V: Visit + ?Sized>(visitor: &mut V, query_specification: &QuerySpecification) {
+    visitor.visit_set_quantifier(&query_specification.set_quantifier);
+    visitor.visit_select_list(&query_specification.select_list);
+
+    if let Some(table_expression) = &query_specification.table_expression {
+        visitor.visit_table_expression(table_expression);
+    }
+}
+
+pub fn walk_select_list<V: Visit + ?Sized>(visitor: &mut V, select_list: &SelectList) {
+    match select_list {
+        SelectList::Sublist(sublist) => {
+            for select_sublist in sublist {
+                visitor.visit_select_sublist(select_sublist);
+            }
+        }
+
+        SelectList::Asterisk => (),
+    }
+}
+
+pub fn walk_select_sublist<V: Visit + ?Sized>(visitor: &mut V, select_sublist: &SelectSublist) {
+    match select_sublist {
+        SelectSublist::DerivedColumn(derived_column) => visitor.visit_derived_column(derived_column),
+    }
+}
+
+pub fn walk_derived_column<V: Visit + ?Sized>(visitor: &mut V, derived_column: &DerivedColumn) {
+    visitor.visit_value_expression(&derived_column.value_expression);
+
+    if let Some(alias) = &derived_column.alias {
+        visitor.visit_identifier(alias);
+    }
+}
+
+pub fn walk_table_expression<V: Visit + ?Sized>(visitor: &mut V, table_expression: &TableExpression) {
+    visitor.visit_from_clause(&table_expression.from_clause);
+
+    if let Some(where_clause) = &table_expression.where_clause {
+        visitor.visit_where_clause(where_clause);
+    }
+
+    if let Some(group_by_clause) = &table_expression.group_by_clause {
+        visitor.visit_group_by_clause(group_by_clause);
+    }
+
+    if let Some(having_clause) = &table_expression.having_clause {
+        visitor.visit_having_clause(having_clause);
+    }
+
+    if let Some(window_clause) = &table_expression.window_clause {
+        visitor.visit_window_clause(window_clause);
+    }
+}
+
+pub fn walk_table_reference<V: Visit + ?Sized>(visitor: &mut V, table_reference: &TableReference) {
+    match table_reference {
+        TableReference::Primary(primary) => visitor.visit_table_primary(primary),
+    }
+}
+
+pub fn walk_table_primary<V: Visit + ?Sized>(visitor: &mut V, table_primary: &TablePrimary) {
+    visitor.visit_table_primary_kind(&table_primary.kind);
+}
+
+pub fn walk_table_primary_kind<V: Visit + ?Sized>(visitor: &mut V, table_primary_kind: &TablePrimaryKind) {
+    match table_primary_kind {
+        TablePrimaryKind::TableOrQueryName(name) => visitor.visit_identifier(name),
+    }
+}
+
+pub fn walk_from_clause<V: Visit + ?Sized>(visitor: &mut V, from_clause: &FromClause) {
+    for table_reference in &from_clause.table_references {
+        visitor.visit_table_reference(table_reference);
+    }
+}
+
+pub fn walk_where_clause<V: Visit + ?Sized>(visitor: &mut V, where_clause: &WhereClause) {
+    visitor.visit_value_expression(&where_clause.search_condition);
+}
+
+pub fn walk_window_clause<V: Visit + ?Sized>(visitor: &mut V, window_clause: &WindowClause) {
+    for window_definition in &window_clause.window_definitions {
+        visitor.visit_window_definition(window_definition);
+    }
+}
+
+pub fn walk_window_definition<V: Visit + ?Sized>(visitor: &mut V, window_definition: &WindowDefinition) {
+    visitor.visit_identifier(&window_definition.name);
+    visitor.visit_window_specification(&window_definition.specification);
+}
+
+pub fn walk_window_specification<V: Visit + ?Sized>(visitor: &mut V, window_specification: &WindowSpecification) {
+    if let Some(existing_window_name) = &window_specification.existing_window_name {
+        visitor.visit_identifier(existing_window_name);
+    }
+
+    for column_reference in &window_specification.partition_clause {
+        visitor.visit_column_reference(column_reference);
+    }
+
+    if let Some(order_clause) = &window_specification.order_clause {
+        visitor.visit_order_by_clause(order_clause);
+    }
+
+    if let Some(frame_clause) = &window_specification.frame_clause {
+        visitor.visit_window_frame_clause(frame_clause);
+    }
+}
+
+pub fn walk_window_frame_clause<V: Visit + ?Sized>(visitor: &mut V, window_frame_clause: &WindowFrameClause) {
+    visitor.visit_window_frame_units(&window_frame_clause.units);
+    visitor.visit_window_frame_bound(&window_frame_clause.start);
+
+    if let Some(end) = &window_frame_clause.end {
+        visitor.visit_window_frame_bound(end);
+    }
+}
+
+pub fn walk_window_frame_bound<V: Visit + ?Sized>(visitor: &mut V, window_frame_bound: &WindowFrameBound) {
+    match window_frame_bound {
+        WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value) => visitor.visit_simple_value_specification(value),
+        WindowFrameBound::UnboundedPreceding | WindowFrameBound::CurrentRow | WindowFrameBound::UnboundedFollowing => (),
+    }
+}
+
+pub fn walk_order_by_clause<V: Visit + ?Sized>(visitor: &mut V, order_by_clause: &OrderByClause) {
+    for sort_specification in &order_by_clause.sort_specification_list {
+        visitor.visit_sort_specification(sort_specification);
+    }
+}
+
+pub fn walk_sort_specification<V: Visit + ?Sized>(visitor: &mut V, sort_specification: &SortSpecification) {
+    visitor.visit_column_reference(&sort_specification.sort_key);
+
+    if let Some(ordering_specification) = &sort_specification.ordering_specification {
+        visitor.visit_ordering_specification(ordering_specification);
+    }
+}
+
+pub fn walk_result_offset_clause<V: Visit + ?Sized>(visitor: &mut V, result_offset_clause: &ResultOffsetClause) {
+    visitor.visit_simple_value_specification(&result_offset_clause.offset_row_count);
+}
+
+pub fn walk_fetch_first_clause<V: Visit + ?Sized>(visitor: &mut V, fetch_first_clause: &FetchFirstClause) {
+    visitor.visit_fetch_first_quantity(&fetch_first_clause.quantity);
+    visitor.visit_fetch_first_clause_origin(&fetch_first_clause.origin);
+}
+
+pub fn walk_fetch_first_quantity<V: Visit + ?Sized>(visitor: &mut V, fetch_first_quantity: &FetchFirstQuantity) {
+    visitor.visit_simple_value_specification(&fetch_first_quantity.value);
+}
+
+pub fn walk_value_expression<V: Visit + ?Sized>(visitor: &mut V, value_expression: &ValueExpression) {
+    match value_expression {
+        ValueExpression::Boolean(expression) => visitor.visit_boolean_expression(expression),
+        ValueExpression::Case(expression) => visitor.visit_case_expression(expression),
+        ValueExpression::Cast(specification) => visitor.visit_cast_specification(specification),
+        ValueExpression::ColumnReference(reference) => visitor.visit_column_reference(reference),
+        ValueExpression::Numeric(expression) => visitor.visit_numeric_value_expression(expression),
+        ValueExpression::RoutineInvocation(invocation) => visitor.visit_routine_invocation(invocation),
+        ValueExpression::SetFunctionSpecification(specification) => visitor.visit_set_function_specification(specification),
+        ValueExpression::StringValueExpression(expression) => visitor.visit_string_value_expression(expression),
+        ValueExpression::WindowFunction(function) => visitor.visit_window_function(function),
+    }
+}
+
+pub fn walk_boolean_expression<V: Visit + ?Sized>(visitor: &mut V, boolean_expression: &BooleanExpression) {
+    match boolean_expression {
+        BooleanExpression::And { left_hand_side, right_hand_side } | BooleanExpression::Or { left_hand_side, right_hand_side } => {
+            visitor.visit_value_expression(left_hand_side);
+            visitor.visit_value_expression(right_hand_side);
+        }
+
+        BooleanExpression::Is { operand, truth_value, .. } => {
+            visitor.visit_value_expression(operand);
+            visitor.visit_truth_value(truth_value);
+        }
+
+        BooleanExpression::Not(operand, _) => visitor.visit_value_expression(operand),
+        BooleanExpression::Predicate(predicate) => visitor.visit_predicate(predicate),
+        BooleanExpression::Literal(..) | BooleanExpression::Unknown(..) => (),
+    }
+}
+
+pub fn walk_case_expression<V: Visit + ?Sized>(visitor: &mut V, case_expression: &CaseExpression) {
+    match case_expression {
+        CaseExpression::Coalesce(operands, _) => {
+            for value_expression in operands {
+                visitor.visit_value_expression(value_expression);
+            }
+        }
+
+        CaseExpression::NullIf { left_hand_side, right_hand_side, .. } => {
+            visitor.visit_value_expression(left_hand_side);
+            visitor.visit_value_expression(right_hand_side);
+        }
+
+        CaseExpression::Searched { when_clauses, else_clause, .. } => {
+            for searched_when_clause in when_clauses {
+                visitor.visit_searched_when_clause(searched_when_clause);
+            }
+
+            if let Some(else_clause) = else_clause {
+                visitor.visit_case_result(else_clause);
+            }
+        }
+
+        CaseExpression::Simple { operand, when_clauses, else_clause, .. } => {
+            visitor.visit_value_expression(operand);
+
+            for simple_when_clause in when_clauses {
+                visitor.visit_simple_when_clause(simple_when_clause);
+            }
+
+            if let Some(else_clause) = else_clause {
+                visitor.visit_case_result(else_clause);
+            }
+        }
+    }
+}
+
+pub fn walk_searched_when_clause<V: Visit + ?Sized>(visitor: &mut V, searched_when_clause: &SearchedWhenClause) {
+    visitor.visit_value_expression(&searched_when_clause.search_condition);
+    visitor.visit_case_result(&searched_when_clause.result);
+}
+
+pub fn walk_simple_when_clause<V: Visit + ?Sized>(visitor: &mut V, simple_when_clause: &SimpleWhenClause) {
+    visitor.visit_value_expression(&simple_when_clause.when_operand);
+    visitor.visit_case_result(&simple_when_clause.result);
+}
+
+pub fn walk_case_result<V: Visit + ?Sized>(visitor: &mut V, case_result: &CaseResult) {
+    match case_result {
+        CaseResult::ValueExpression(expression) => visitor.visit_value_expression(expression),
+        CaseResult::Null(..) => (),
+    }
+}
+
+pub fn walk_cast_specification<V: Visit + ?Sized>(visitor: &mut V, cast_specification: &CastSpecification) {
+    visitor.visit_cast_operand(&cast_specification.operand);
+    visitor.visit_data_type(&cast_specification.target);
+}
+
+pub fn walk_cast_operand<V: Visit + ?Sized>(visitor: &mut V, cast_operand: &CastOperand) {
+    match cast_operand {
+        CastOperand::ValueExpression(expression) => visitor.visit_value_expression(expression),
+        CastOperand::Null => (),
+    }
+}
+
+pub fn walk_column_reference<V: Visit + ?Sized>(visitor: &mut V, column_reference: &ColumnReference) {
+    match column_reference {
+        ColumnReference::BasicIdentifierChain(identifiers) => {
+            for identifier in identifiers {
+                visitor.visit_identifier(identifier);
+            }
+        }
+    }
+}
+
+pub fn walk_numeric_value_expression<V: Visit + ?Sized>(visitor: &mut V, numeric_value_expression: &NumericValueExpression) {
+    match numeric_value_expression {
+        NumericValueExpression::BinaryOperation { left_hand_side, operator, right_hand_side } => {
+            visitor.visit_value_expression(left_hand_side);
+            visitor.visit_numeric_operator(operator);
+            visitor.visit_value_expression(right_hand_side);
+        }
+
+        NumericValueExpression::Function(function) => visitor.visit_numeric_value_function(function),
+        NumericValueExpression::Negation(operand, _) => visitor.visit_value_expression(operand),
+        NumericValueExpression::ApproximateNumericLiteral(..) | NumericValueExpression::ExactNumericLiteral(..) | NumericValueExpression::SimpleI64(..) | NumericValueExpression::SimpleU64(..) => (),
+    }
+}
+
+pub fn walk_numeric_value_function<V: Visit + ?Sized>(visitor: &mut V, numeric_value_function: &NumericValueFunction) {
+    match numeric_value_function {
+        NumericValueFunction::Position { needle, haystack, .. } => {
+            visitor.visit_value_expression(needle);
+            visitor.visit_value_expression(haystack);
+        }
+    }
+}
+
+pub fn walk_routine_invocation<V: Visit + ?Sized>(visitor: &mut V, routine_invocation: &RoutineInvocation) {
+    visitor.visit_identifier(&routine_invocation.name);
+
+    for value_expression in &routine_invocation.arguments {
+        visitor.visit_value_expression(value_expression);
+    }
+}
+
+pub fn walk_set_function_specification<V: Visit + ?Sized>(visitor: &mut V, set_function_specification: &SetFunctionSpecification) {
+    match set_function_specification {
+        SetFunctionSpecification::General(function) => visitor.visit_general_set_function(function),
+        SetFunctionSpecification::Count(..) => (),
+    }
+}
+
+pub fn walk_general_set_function<V: Visit + ?Sized>(visitor: &mut V, general_set_function: &GeneralSetFunction) {
+    visitor.visit_computational_operation(&general_set_function.function_type);
+    visitor.visit_set_quantifier(&general_set_function.set_quantifier);
+    visitor.visit_value_expression(&general_set_function.value_expression);
+}
+
+pub fn walk_string_value_expression<V: Visit + ?Sized>(visitor: &mut V, string_value_expression: &StringValueExpression) {
+    match string_value_expression {
+        StringValueExpression::Concatenation { left_hand_side, right_hand_side } => {
+            visitor.visit_value_expression(left_hand_side);
+            visitor.visit_value_expression(right_hand_side);
+        }
+
+        StringValueExpression::Function(function) => visitor.visit_string_value_function(function),
+        StringValueExpression::BinaryLiteral(..) | StringValueExpression::BitLiteral(..) | StringValueExpression::Literal(..) => (),
+    }
+}
+
+pub fn walk_string_value_function<V: Visit + ?Sized>(visitor: &mut V, string_value_function: &StringValueFunction) {
+    match string_value_function {
+        StringValueFunction::Overlay { operand, placing, start_position, string_length, .. } => {
+            visitor.visit_value_expression(operand);
+            visitor.visit_value_expression(placing);
+            visitor.visit_value_expression(start_position);
+
+            if let Some(string_length) = string_length {
+                visitor.visit_value_expression(string_length);
+            }
+        }
+
+        StringValueFunction::Substring { operand, start_position, string_length, .. } => {
+            visitor.visit_value_expression(operand);
+            visitor.visit_value_expression(start_position);
+
+            if let Some(string_length) = string_length {
+                visitor.visit_value_expression(string_length);
+            }
+        }
+
+        StringValueFunction::Trim { specification, character, source, .. } => {
+            visitor.visit_trim_specification(specification);
+
+            if let Some(character) = character {
+                visitor.visit_value_expression(character);
+            }
+
+            visitor.visit_value_expression(source);
+        }
+    }
+}
+
+pub fn walk_window_function<V: Visit + ?Sized>(visitor: &mut V, window_function: &WindowFunction) {
+    visitor.visit_window_function_type(&window_function.function_type);
+    visitor.visit_window_name_or_specification(&window_function.window);
+}
+
+pub fn walk_window_function_type<V: Visit + ?Sized>(visitor: &mut V, window_function_type: &WindowFunctionType) {
+    match window_function_type {
+        WindowFunctionType::Aggregate(specification) => visitor.visit_set_function_specification(specification),
+        WindowFunctionType::Rank(function_type) => visitor.visit_rank_function_type(function_type),
+        WindowFunctionType::RowNumber => (),
+    }
+}
+
+pub fn walk_window_name_or_specification<V: Visit + ?Sized>(visitor: &mut V, window_name_or_specification: &WindowNameOrSpecification) {
+    match window_name_or_specification {
+        WindowNameOrSpecification::Name(name) => visitor.visit_identifier(name),
+        WindowNameOrSpecification::Specification(specification) => visitor.visit_window_specification(specification),
+    }
+}
+
+pub fn walk_predicate<V: Visit + ?Sized>(visitor: &mut V, predicate: &Predicate) {
+    match predicate {
+        Predicate::Between(predicate) => visitor.visit_between_predicate(predicate),
+        Predicate::Comparison(predicate) => visitor.visit_comparison_predicate(predicate),
+        Predicate::Distinct(predicate) => visitor.visit_distinct_predicate(predicate),
+        Predicate::In(predicate) => visitor.visit_in_predicate(predicate),
+        Predicate::Like(predicate) => visitor.visit_like_predicate(predicate),
+        Predicate::Null(predicate) => visitor.visit_null_predicate(predicate),
+        Predicate::QuantifiedComparison(predicate) => visitor.visit_quantified_comparison_predicate(predicate),
+        Predicate::Similar(predicate) => visitor.visit_similar_predicate(predicate),
+    }
+}
+
+pub fn walk_between_predicate<V: Visit + ?Sized>(visitor: &mut V, between_predicate: &BetweenPredicate) {
+    visitor.visit_value_expression(&between_predicate.operand);
+    visitor.visit_value_expression(&between_predicate.lower_bound);
+    visitor.visit_value_expression(&between_predicate.upper_bound);
+}
+
+pub fn walk_comparison_predicate<V: Visit + ?Sized>(visitor: &mut V, comparison_predicate: &ComparisonPredicate) {
+    visitor.visit_value_expression(&comparison_predicate.left_hand_side);
+    visitor.visit_comparison_operator(&comparison_predicate.operator);
+    visitor.visit_value_expression(&comparison_predicate.right_hand_side);
+}
+
+pub fn walk_distinct_predicate<V: Visit + ?Sized>(visitor: &mut V, distinct_predicate: &DistinctPredicate) {
+    visitor.visit_value_expression(&distinct_predicate.left_hand_side);
+    visitor.visit_value_expression(&distinct_predicate.right_hand_side);
+}
+
+pub fn walk_in_predicate<V: Visit + ?Sized>(visitor: &mut V, in_predicate: &InPredicate) {
+    visitor.visit_value_expression(&in_predicate.operand);
+
+    for value_expression in &in_predicate.values {
+        visitor.visit_value_expression(value_expression);
+    }
+}
+
+pub fn walk_like_predicate<V: Visit + ?Sized>(visitor: &mut V, like_predicate: &LikePredicate) {
+    visitor.visit_value_expression(&like_predicate.operand);
+    visitor.visit_value_expression(&like_predicate.pattern);
+
+    if let Some(escape) = &like_predicate.escape {
+        visitor.visit_value_expression(escape);
+    }
+}
+
+pub fn walk_null_predicate<V: Visit + ?Sized>(visitor: &mut V, null_predicate: &NullPredicate) {
+    visitor.visit_value_expression(&null_predicate.operand);
+}
+
+pub fn walk_quantified_comparison_predicate<V: Visit + ?Sized>(visitor: &mut V, quantified_comparison_predicate: &QuantifiedComparisonPredicate) {
+    visitor.visit_value_expression(&quantified_comparison_predicate.left_hand_side);
+    visitor.visit_comparison_operator(&quantified_comparison_predicate.operator);
+    visitor.visit_quantifier(&quantified_comparison_predicate.quantifier);
+
+    for value_expression in &quantified_comparison_predicate.values {
+        visitor.visit_value_expression(value_expression);
+    }
+}
+
+pub fn walk_similar_predicate<V: Visit + ?Sized>(visitor: &mut V, similar_predicate: &SimilarPredicate) {
+    visitor.visit_value_expression(&similar_predicate.operand);
+    visitor.visit_value_expression(&similar_predicate.pattern);
+
+    if let Some(escape) = &similar_predicate.escape {
+        visitor.visit_value_expression(escape);
+    }
+}
+
+pub fn walk_data_type<V: Visit + ?Sized>(visitor: &mut V, data_type: &DataType) {
+    match data_type {
+        DataType::Predefined(predefined_type) => visitor.visit_predefined_type(predefined_type),
+    }
+}
+
+pub fn walk_predefined_type<V: Visit + ?Sized>(visitor: &mut V, predefined_type: &PredefinedType) {
+    match predefined_type {
+        PredefinedType::CharacterString { definition, character_set } => {
+            visitor.visit_character_string_type(definition);
+
+            if let Some(character_set) = character_set {
+                visitor.visit_character_set_specification(character_set);
+            }
+        }
+
+        PredefinedType::NationalCharacterString(definition) => visitor.visit_national_character_string_type(definition),
+        PredefinedType::BinaryString(definition) => visitor.visit_binary_string_type(definition),
+        PredefinedType::BitString(definition) => visitor.visit_bit_string_type(definition),
+        PredefinedType::Numeric(definition) => visitor.visit_numeric_type(definition),
+        PredefinedType::Boolean => (),
+    }
+}
+
+pub fn walk_character_string_type<V: Visit + ?Sized>(visitor: &mut V, character_string_type: &CharacterStringType) {
+    match character_string_type {
+        CharacterStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length(length);
+            }
+        }
+
+        CharacterStringType::Fixed { .. } | CharacterStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_national_character_string_type<V: Visit + ?Sized>(visitor: &mut V, national_character_string_type: &NationalCharacterStringType) {
+    match national_character_string_type {
+        NationalCharacterStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length(length);
+            }
+        }
+
+        NationalCharacterStringType::Fixed { .. } | NationalCharacterStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_binary_string_type<V: Visit + ?Sized>(visitor: &mut V, binary_string_type: &BinaryStringType) {
+    match binary_string_type {
+        BinaryStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length(length);
+            }
+        }
+
+        BinaryStringType::Fixed { .. } | BinaryStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_large_object_length<V: Visit + ?Sized>(visitor: &mut V, large_object_length: &LargeObjectLength) {
+    if let Some(multiplier) = &large_object_length.multiplier {
+        visitor.visit_large_object_length_multiplier(multiplier);
+    }
+}
+
+pub fn walk_character_set_specification<V: Visit + ?Sized>(visitor: &mut V, character_set_specification: &CharacterSetSpecification) {
+    visitor.visit_character_set_specification_kind(&character_set_specification.kind);
+}
+
+pub fn walk_table_name<V: Visit + ?Sized>(visitor: &mut V, table_name: &TableName) {
+    visitor.visit_identifier(&table_name.table_qualifier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+    use rstest::rstest;
+
+    use pretty_assertions::assert_eq;
+
+    /// Collects the column references, qualified names joined by a period.
+    #[derive(Default)]
+    struct ColumnNames {
+        names: Vec<String>,
+
+        /// Whether or not the `WHERE` clauses are skipped.
+        skip_where_clauses: bool,
+    }
+
+    impl Visit for ColumnNames {
+        fn visit_column_reference(&mut self, column_reference: &ColumnReference) {
+            let ColumnReference::BasicIdentifierChain(chain) = column_reference;
+            self.names.push(chain.iter().map(AsRef::as_ref).collect::<Vec<&str>>().join("."));
+        }
+
+        fn visit_where_clause(&mut self, where_clause: &WhereClause) {
+            if !self.skip_where_clauses {
+                walk_where_clause(self, where_clause);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("SELECT a, t.b FROM t WHERE c > 1 ORDER BY d", &["a", "t.b", "c", "d"])]
+    #[case("SELECT COUNT(*), SUM(a) FROM t WHERE b BETWEEN c AND 5", &["a", "b", "c"])]
+    #[case("SELECT CASE a WHEN b THEN c ELSE d END, NULLIF(e, f), COALESCE(g, h) FROM t", &["a", "b", "c", "d", "e", "f", "g", "h"])]
+    #[case("SELECT CAST(a AS INT), TRIM(LEADING b FROM c), SUBSTRING(d FROM e FOR f), POSITION(g IN h)", &["a", "b", "c", "d", "e", "f", "g", "h"])]
+    #[case("SELECT a FROM t WHERE b IN (c, d) AND e LIKE f ESCAPE g OR h IS NOT NULL", &["a", "b", "c", "d", "e", "f", "g", "h"])]
+    #[case("SELECT a FROM t WHERE b = ANY (c, d) AND NOT e IS DISTINCT FROM f", &["a", "b", "c", "d", "e", "f"])]
+    #[case("SELECT RANK() OVER (PARTITION BY a ORDER BY b), SUM(c) OVER w FROM t WINDOW w AS (PARTITION BY d)", &["a", "b", "c", "d"])]
+    #[case("INSERT INTO t VALUES (1, 'a')", &[])]
+    #[case("CREATE TABLE t (a INT)", &[])]
+    fn visit_column_references(#[case] input: &str, #[case] expected: &[&str]) {
+        let statement = Parser::new().parse_statement(input).unwrap();
+
+        let mut column_names = ColumnNames::default();
+        column_names.visit_sql_executable_statement(&statement);
+
+        assert_eq!(column_names.names, expected);
+    }
+
+    #[test]
+    fn visit_without_walking_skips_children() {
+        let statement = Parser::new().parse_statement("SELECT a FROM t WHERE b > c ORDER BY d").unwrap();
+
+        let mut column_names = ColumnNames {
+            skip_where_clauses: true,
+            ..Default::default()
+        };
+        column_names.visit_sql_executable_statement(&statement);
+
+        assert_eq!(column_names.names, ["a", "d"]);
+    }
+}
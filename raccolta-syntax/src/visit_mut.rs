@@ -0,0 +1,1269 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! Mutable traversal of syntax trees, the counterpart of
+//! [`Visit`](crate::visit::Visit). For example, a column can be renamed
+//! throughout a statement as follows:
+//!
+//! ```
+//! use raccolta_syntax::{
+//!     common::Identifier,
+//!     expression::ColumnReference,
+//!     visit_mut::VisitMut,
+//!     Parser,
+//! };
+//!
+//! struct RenameColumn<'a> {
+//!     from: Identifier,
+//!     to: &'a str,
+//! }
+//!
+//! impl VisitMut for RenameColumn<'_> {
+//!     fn visit_column_reference_mut(&mut self, column_reference: &mut ColumnReference) {
+//!         if let ColumnReference::BasicIdentifierChain(chain) = column_reference {
+//!             if let Some(column_name) = chain.last_mut().filter(|name| name.matches(&self.from)) {
+//!                 column_name.value = self.to.into();
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! let mut statement = Parser::new().parse_statement("SELECT age FROM person ORDER BY age").unwrap();
+//! RenameColumn { from: Identifier::regular("age"), to: "years" }.visit_sql_executable_statement_mut(&mut statement);
+//! assert_eq!(statement.to_string(), "SELECT years FROM person ORDER BY years");
+//! ```
+//!
+//! An overriding method can call the corresponding `walk_*_mut` function of
+//! this module to continue into the children of the node, or leave it out to
+//! skip them. Since the [`Span`](crate::Span)s of the nodes are left as-is,
+//! they might no longer correspond to the source text after the tree has been
+//! modified.
+
+use crate::{
+    clause::{
+        fetch_first_clause::{
+            FetchFirstClause,
+            FetchFirstClauseOrigin,
+            FetchFirstQuantity,
+        },
+        order_by_clause::{
+            OrderByClause,
+            OrderingSpecification,
+            SortSpecification,
+        },
+        result_offset_clause::ResultOffsetClause,
+        window_clause::{
+            WindowDefinition,
+            WindowFrameBound,
+            WindowFrameClause,
+            WindowFrameUnits,
+            WindowSpecification,
+        },
+        FromClause,
+        GroupByClause,
+        HavingClause,
+        WhereClause,
+        WindowClause,
+    },
+    common::{
+        character_set::{
+            CharacterSetSpecification,
+            CharacterSetSpecificationKind,
+        },
+        Identifier,
+        TableName,
+    },
+    expression::{
+        boolean_expression::TruthValue,
+        case_expression::{
+            CaseResult,
+            SearchedWhenClause,
+            SimpleWhenClause,
+        },
+        cast_specification::CastOperand,
+        data_type::{
+            BinaryStringType,
+            BitStringType,
+            CharacterStringType,
+            DataType,
+            LargeObjectLength,
+            LargeObjectLengthMultiplier,
+            NationalCharacterStringType,
+            NumericType,
+            PredefinedType,
+        },
+        numeric_value_function::NumericValueFunction,
+        query_expression::{
+            NonJoinQueryExpression,
+            NonJoinQueryPrimary,
+            NonJoinQueryTerm,
+            QueryExpressionBody,
+            QueryPrimary,
+            SimpleTable,
+        },
+        query_specification::{
+            DerivedColumn,
+            SelectList,
+            SelectSublist,
+        },
+        row_value_constructor::{
+            ContextuallyTypedRowValueConstructor,
+            ContextuallyTypedRowValueConstructorElement,
+        },
+        row_value_expression::ContextuallyTypedRowValueExpression,
+        string_value_expression::StringValueExpression,
+        string_value_function::{
+            StringValueFunction,
+            TrimSpecification,
+        },
+        table_reference::{
+            TablePrimary,
+            TablePrimaryKind,
+        },
+        table_value_constructor::ContextuallyTypedTableValueConstructor,
+        window_function::{
+            RankFunctionType,
+            WindowFunctionType,
+            WindowNameOrSpecification,
+        },
+        BooleanExpression,
+        CaseExpression,
+        CastSpecification,
+        ColumnReference,
+        NumericOperator,
+        NumericValueExpression,
+        QueryExpression,
+        QuerySpecification,
+        RoutineInvocation,
+        SimpleValueSpecification,
+        TableExpression,
+        TableReference,
+        ValueExpression,
+        WindowFunction,
+    },
+    predicate::{
+        between_predicate::BetweenPredicate,
+        comparison_predicate::{
+            ComparisonOperator,
+            ComparisonPredicate,
+        },
+        distinct_predicate::DistinctPredicate,
+        in_predicate::InPredicate,
+        like_predicate::LikePredicate,
+        null_predicate::NullPredicate,
+        quantified_comparison_predicate::{
+            QuantifiedComparisonPredicate,
+            Quantifier,
+        },
+        similar_predicate::SimilarPredicate,
+        Predicate,
+    },
+    schema::definition::{
+        table_definition::{
+            ColumnConstraintDefinition,
+            ColumnDefinition,
+            TableElement,
+        },
+        TableDefinition,
+        UniqueSpecification,
+    },
+    set_function::{
+        ComputationalOperation,
+        GeneralSetFunction,
+        SetFunctionSpecification,
+        SetQuantifier,
+    },
+    statement::{
+        insert_statement::InsertColumnsAndSource,
+        InsertStatement,
+        SelectStatementSingleRow,
+        SqlDataChangeStatement,
+        SqlDataStatement,
+        SqlExecutableStatement,
+        SqlSchemaDefinitionStatement,
+        SqlSchemaStatement,
+    },
+};
+
+/// Visits the nodes of a syntax tree by mutable reference. Every method
+/// defaults to walking the children of the node, which are visited in the
+/// order of the node's fields. Leaf nodes, such as [`Identifier`], have
+/// nothing to walk.
+pub trait VisitMut {
+    fn visit_sql_executable_statement_mut(&mut self, sql_executable_statement: &mut SqlExecutableStatement) {
+        walk_sql_executable_statement_mut(self, sql_executable_statement);
+    }
+
+    fn visit_sql_schema_statement_mut(&mut self, sql_schema_statement: &mut SqlSchemaStatement) {
+        walk_sql_schema_statement_mut(self, sql_schema_statement);
+    }
+
+    fn visit_sql_schema_definition_statement_mut(&mut self, sql_schema_definition_statement: &mut SqlSchemaDefinitionStatement) {
+        walk_sql_schema_definition_statement_mut(self, sql_schema_definition_statement);
+    }
+
+    fn visit_sql_data_statement_mut(&mut self, sql_data_statement: &mut SqlDataStatement) {
+        walk_sql_data_statement_mut(self, sql_data_statement);
+    }
+
+    fn visit_sql_data_change_statement_mut(&mut self, sql_data_change_statement: &mut SqlDataChangeStatement) {
+        walk_sql_data_change_statement_mut(self, sql_data_change_statement);
+    }
+
+    fn visit_insert_statement_mut(&mut self, insert_statement: &mut InsertStatement) {
+        walk_insert_statement_mut(self, insert_statement);
+    }
+
+    fn visit_insert_columns_and_source_mut(&mut self, insert_columns_and_source: &mut InsertColumnsAndSource) {
+        walk_insert_columns_and_source_mut(self, insert_columns_and_source);
+    }
+
+    fn visit_contextually_typed_table_value_constructor_mut(&mut self, contextually_typed_table_value_constructor: &mut ContextuallyTypedTableValueConstructor) {
+        walk_contextually_typed_table_value_constructor_mut(self, contextually_typed_table_value_constructor);
+    }
+
+    fn visit_contextually_typed_row_value_expression_mut(&mut self, contextually_typed_row_value_expression: &mut ContextuallyTypedRowValueExpression) {
+        walk_contextually_typed_row_value_expression_mut(self, contextually_typed_row_value_expression);
+    }
+
+    fn visit_contextually_typed_row_value_constructor_mut(&mut self, contextually_typed_row_value_constructor: &mut ContextuallyTypedRowValueConstructor) {
+        walk_contextually_typed_row_value_constructor_mut(self, contextually_typed_row_value_constructor);
+    }
+
+    fn visit_contextually_typed_row_value_constructor_element_mut(&mut self, contextually_typed_row_value_constructor_element: &mut ContextuallyTypedRowValueConstructorElement) {
+        walk_contextually_typed_row_value_constructor_element_mut(self, contextually_typed_row_value_constructor_element);
+    }
+
+    fn visit_select_statement_single_row_mut(&mut self, select_statement_single_row: &mut SelectStatementSingleRow) {
+        walk_select_statement_single_row_mut(self, select_statement_single_row);
+    }
+
+    fn visit_table_definition_mut(&mut self, table_definition: &mut TableDefinition) {
+        walk_table_definition_mut(self, table_definition);
+    }
+
+    fn visit_table_element_mut(&mut self, table_element: &mut TableElement) {
+        walk_table_element_mut(self, table_element);
+    }
+
+    fn visit_column_definition_mut(&mut self, column_definition: &mut ColumnDefinition) {
+        walk_column_definition_mut(self, column_definition);
+    }
+
+    fn visit_column_constraint_definition_mut(&mut self, column_constraint_definition: &mut ColumnConstraintDefinition) {
+        walk_column_constraint_definition_mut(self, column_constraint_definition);
+    }
+
+    fn visit_unique_specification_mut(&mut self, _unique_specification: &mut UniqueSpecification) {}
+
+    fn visit_query_expression_mut(&mut self, query_expression: &mut QueryExpression) {
+        walk_query_expression_mut(self, query_expression);
+    }
+
+    fn visit_query_expression_body_mut(&mut self, query_expression_body: &mut QueryExpressionBody) {
+        walk_query_expression_body_mut(self, query_expression_body);
+    }
+
+    fn visit_non_join_query_expression_mut(&mut self, non_join_query_expression: &mut NonJoinQueryExpression) {
+        walk_non_join_query_expression_mut(self, non_join_query_expression);
+    }
+
+    fn visit_non_join_query_term_mut(&mut self, non_join_query_term: &mut NonJoinQueryTerm) {
+        walk_non_join_query_term_mut(self, non_join_query_term);
+    }
+
+    fn visit_non_join_query_primary_mut(&mut self, non_join_query_primary: &mut NonJoinQueryPrimary) {
+        walk_non_join_query_primary_mut(self, non_join_query_primary);
+    }
+
+    fn visit_query_primary_mut(&mut self, query_primary: &mut QueryPrimary) {
+        walk_query_primary_mut(self, query_primary);
+    }
+
+    fn visit_simple_table_mut(&mut self, simple_table: &mut SimpleTable) {
+        walk_simple_table_mut(self, simple_table);
+    }
+
+    fn visit_query_specification_mut(&mut self, query_specification: &mut QuerySpecification) {
+        walk_query_specification_mut(self, query_specification);
+    }
+
+    fn visit_select_list_mut(&mut self, select_list: &mut SelectList) {
+        walk_select_list_mut(self, select_list);
+    }
+
+    fn visit_select_sublist_mut(&mut self, select_sublist: &mut SelectSublist) {
+        walk_select_sublist_mut(self, select_sublist);
+    }
+
+    fn visit_derived_column_mut(&mut self, derived_column: &mut DerivedColumn) {
+        walk_derived_column_mut(self, derived_column);
+    }
+
+    fn visit_table_expression_mut(&mut self, table_expression: &mut TableExpression) {
+        walk_table_expression_mut(self, table_expression);
+    }
+
+    fn visit_table_reference_mut(&mut self, table_reference: &mut TableReference) {
+        walk_table_reference_mut(self, table_reference);
+    }
+
+    fn visit_table_primary_mut(&mut self, table_primary: &mut TablePrimary) {
+        walk_table_primary_mut(self, table_primary);
+    }
+
+    fn visit_table_primary_kind_mut(&mut self, table_primary_kind: &mut TablePrimaryKind) {
+        walk_table_primary_kind_mut(self, table_primary_kind);
+    }
+
+    fn visit_from_clause_mut(&mut self, from_clause: &mut FromClause) {
+        walk_from_clause_mut(self, from_clause);
+    }
+
+    fn visit_where_clause_mut(&mut self, where_clause: &mut WhereClause) {
+        walk_where_clause_mut(self, where_clause);
+    }
+
+    fn visit_group_by_clause_mut(&mut self, _group_by_clause: &mut GroupByClause) {}
+
+    fn visit_having_clause_mut(&mut self, _having_clause: &mut HavingClause) {}
+
+    fn visit_window_clause_mut(&mut self, window_clause: &mut WindowClause) {
+        walk_window_clause_mut(self, window_clause);
+    }
+
+    fn visit_window_definition_mut(&mut self, window_definition: &mut WindowDefinition) {
+        walk_window_definition_mut(self, window_definition);
+    }
+
+    fn visit_window_specification_mut(&mut self, window_specification: &mut WindowSpecification) {
+        walk_window_specification_mut(self, window_specification);
+    }
+
+    fn visit_window_frame_clause_mut(&mut self, window_frame_clause: &mut WindowFrameClause) {
+        walk_window_frame_clause_mut(self, window_frame_clause);
+    }
+
+    fn visit_window_frame_units_mut(&mut self, _window_frame_units: &mut WindowFrameUnits) {}
+
+    fn visit_window_frame_bound_mut(&mut self, window_frame_bound: &mut WindowFrameBound) {
+        walk_window_frame_bound_mut(self, window_frame_bound);
+    }
+
+    fn visit_order_by_clause_mut(&mut self, order_by_clause: &mut OrderByClause) {
+        walk_order_by_clause_mut(self, order_by_clause);
+    }
+
+    fn visit_sort_specification_mut(&mut self, sort_specification: &mut SortSpecification) {
+        walk_sort_specification_mut(self, sort_specification);
+    }
+
+    fn visit_ordering_specification_mut(&mut self, _ordering_specification: &mut OrderingSpecification) {}
+
+    fn visit_result_offset_clause_mut(&mut self, result_offset_clause: &mut ResultOffsetClause) {
+        walk_result_offset_clause_mut(self, result_offset_clause);
+    }
+
+    fn visit_fetch_first_clause_mut(&mut self, fetch_first_clause: &mut FetchFirstClause) {
+        walk_fetch_first_clause_mut(self, fetch_first_clause);
+    }
+
+    fn visit_fetch_first_quantity_mut(&mut self, fetch_first_quantity: &mut FetchFirstQuantity) {
+        walk_fetch_first_quantity_mut(self, fetch_first_quantity);
+    }
+
+    fn visit_fetch_first_clause_origin_mut(&mut self, _fetch_first_clause_origin: &mut FetchFirstClauseOrigin) {}
+
+    fn visit_value_expression_mut(&mut self, value_expression: &mut ValueExpression) {
+        walk_value_expression_mut(self, value_expression);
+    }
+
+    fn visit_boolean_expression_mut(&mut self, boolean_expression: &mut BooleanExpression) {
+        walk_boolean_expression_mut(self, boolean_expression);
+    }
+
+    fn visit_truth_value_mut(&mut self, _truth_value: &mut TruthValue) {}
+
+    fn visit_case_expression_mut(&mut self, case_expression: &mut CaseExpression) {
+        walk_case_expression_mut(self, case_expression);
+    }
+
+    fn visit_searched_when_clause_mut(&mut self, searched_when_clause: &mut SearchedWhenClause) {
+        walk_searched_when_clause_mut(self, searched_when_clause);
+    }
+
+    fn visit_simple_when_clause_mut(&mut self, simple_when_clause: &mut SimpleWhenClause) {
+        walk_simple_when_clause_mut(self, simple_when_clause);
+    }
+
+    fn visit_case_result_mut(&mut self, case_result: &mut CaseResult) {
+        walk_case_result_mut(self, case_result);
+    }
+
+    fn visit_cast_specification_mut(&mut self, cast_specification: &mut CastSpecification) {
+        walk_cast_specification_mut(self, cast_specification);
+    }
+
+    fn visit_cast_operand_mut(&mut self, cast_operand: &mut CastOperand) {
+        walk_cast_operand_mut(self, cast_operand);
+    }
+
+    fn visit_column_reference_mut(&mut self, column_reference: &mut ColumnReference) {
+        walk_column_reference_mut(self, column_reference);
+    }
+
+    fn visit_numeric_value_expression_mut(&mut self, numeric_value_expression: &mut NumericValueExpression) {
+        walk_numeric_value_expression_mut(self, numeric_value_expression);
+    }
+
+    fn visit_numeric_operator_mut(&mut self, _numeric_operator: &mut NumericOperator) {}
+
+    fn visit_numeric_value_function_mut(&mut self, numeric_value_function: &mut NumericValueFunction) {
+        walk_numeric_value_function_mut(self, numeric_value_function);
+    }
+
+    fn visit_routine_invocation_mut(&mut self, routine_invocation: &mut RoutineInvocation) {
+        walk_routine_invocation_mut(self, routine_invocation);
+    }
+
+    fn visit_set_function_specification_mut(&mut self, set_function_specification: &mut SetFunctionSpecification) {
+        walk_set_function_specification_mut(self, set_function_specification);
+    }
+
+    fn visit_general_set_function_mut(&mut self, general_set_function: &mut GeneralSetFunction) {
+        walk_general_set_function_mut(self, general_set_function);
+    }
+
+    fn visit_computational_operation_mut(&mut self, _computational_operation: &mut ComputationalOperation) {}
+
+    fn visit_set_quantifier_mut(&mut self, _set_quantifier: &mut SetQuantifier) {}
+
+    fn visit_simple_value_specification_mut(&mut self, _simple_value_specification: &mut SimpleValueSpecification) {}
+
+    fn visit_string_value_expression_mut(&mut self, string_value_expression: &mut StringValueExpression) {
+        walk_string_value_expression_mut(self, string_value_expression);
+    }
+
+    fn visit_string_value_function_mut(&mut self, string_value_function: &mut StringValueFunction) {
+        walk_string_value_function_mut(self, string_value_function);
+    }
+
+    fn visit_trim_specification_mut(&mut self, _trim_specification: &mut TrimSpecification) {}
+
+    fn visit_window_function_mut(&mut self, window_function: &mut WindowFunction) {
+        walk_window_function_mut(self, window_function);
+    }
+
+    fn visit_window_function_type_mut(&mut self, window_function_type: &mut WindowFunctionType) {
+        walk_window_function_type_mut(self, window_function_type);
+    }
+
+    fn visit_rank_function_type_mut(&mut self, _rank_function_type: &mut RankFunctionType) {}
+
+    fn visit_window_name_or_specification_mut(&mut self, window_name_or_specification: &mut WindowNameOrSpecification) {
+        walk_window_name_or_specification_mut(self, window_name_or_specification);
+    }
+
+    fn visit_predicate_mut(&mut self, predicate: &mut Predicate) {
+        walk_predicate_mut(self, predicate);
+    }
+
+    fn visit_between_predicate_mut(&mut self, between_predicate: &mut BetweenPredicate) {
+        walk_between_predicate_mut(self, between_predicate);
+    }
+
+    fn visit_comparison_predicate_mut(&mut self, comparison_predicate: &mut ComparisonPredicate) {
+        walk_comparison_predicate_mut(self, comparison_predicate);
+    }
+
+    fn visit_comparison_operator_mut(&mut self, _comparison_operator: &mut ComparisonOperator) {}
+
+    fn visit_distinct_predicate_mut(&mut self, distinct_predicate: &mut DistinctPredicate) {
+        walk_distinct_predicate_mut(self, distinct_predicate);
+    }
+
+    fn visit_in_predicate_mut(&mut self, in_predicate: &mut InPredicate) {
+        walk_in_predicate_mut(self, in_predicate);
+    }
+
+    fn visit_like_predicate_mut(&mut self, like_predicate: &mut LikePredicate) {
+        walk_like_predicate_mut(self, like_predicate);
+    }
+
+    fn visit_null_predicate_mut(&mut self, null_predicate: &mut NullPredicate) {
+        walk_null_predicate_mut(self, null_predicate);
+    }
+
+    fn visit_quantified_comparison_predicate_mut(&mut self, quantified_comparison_predicate: &mut QuantifiedComparisonPredicate) {
+        walk_quantified_comparison_predicate_mut(self, quantified_comparison_predicate);
+    }
+
+    fn visit_quantifier_mut(&mut self, _quantifier: &mut Quantifier) {}
+
+    fn visit_similar_predicate_mut(&mut self, similar_predicate: &mut SimilarPredicate) {
+        walk_similar_predicate_mut(self, similar_predicate);
+    }
+
+    fn visit_data_type_mut(&mut self, data_type: &mut DataType) {
+        walk_data_type_mut(self, data_type);
+    }
+
+    fn visit_predefined_type_mut(&mut self, predefined_type: &mut PredefinedType) {
+        walk_predefined_type_mut(self, predefined_type);
+    }
+
+    fn visit_character_string_type_mut(&mut self, character_string_type: &mut CharacterStringType) {
+        walk_character_string_type_mut(self, character_string_type);
+    }
+
+    fn visit_national_character_string_type_mut(&mut self, national_character_string_type: &mut NationalCharacterStringType) {
+        walk_national_character_string_type_mut(self, national_character_string_type);
+    }
+
+    fn visit_binary_string_type_mut(&mut self, binary_string_type: &mut BinaryStringType) {
+        walk_binary_string_type_mut(self, binary_string_type);
+    }
+
+    fn visit_bit_string_type_mut(&mut self, _bit_string_type: &mut BitStringType) {}
+
+    fn visit_numeric_type_mut(&mut self, _numeric_type: &mut NumericType) {}
+
+    fn visit_large_object_length_mut(&mut self, large_object_length: &mut LargeObjectLength) {
+        walk_large_object_length_mut(self, large_object_length);
+    }
+
+    fn visit_large_object_length_multiplier_mut(&mut self, _large_object_length_multiplier: &mut LargeObjectLengthMultiplier) {}
+
+    fn visit_character_set_specification_mut(&mut self, character_set_specification: &mut CharacterSetSpecification) {
+        walk_character_set_specification_mut(self, character_set_specification);
+    }
+
+    fn visit_character_set_specification_kind_mut(&mut self, _character_set_specification_kind: &mut CharacterSetSpecificationKind) {}
+
+    fn visit_table_name_mut(&mut self, table_name: &mut TableName) {
+        walk_table_name_mut(self, table_name);
+    }
+
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier) {}
+}
+
+pub fn walk_sql_executable_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, sql_executable_statement: &mut SqlExecutableStatement) {
+    match sql_executable_statement {
+        SqlExecutableStatement::Schema(statement) => visitor.visit_sql_schema_statement_mut(statement),
+        SqlExecutableStatement::SqlDataStatement(statement) => visitor.visit_sql_data_statement_mut(statement),
+    }
+}
+
+pub fn walk_sql_schema_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, sql_schema_statement: &mut SqlSchemaStatement) {
+    match sql_schema_statement {
+        SqlSchemaStatement::Definition(statement) => visitor.visit_sql_schema_definition_statement_mut(statement),
+    }
+}
+
+pub fn walk_sql_schema_definition_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, sql_schema_definition_statement: &mut SqlSchemaDefinitionStatement) {
+    match sql_schema_definition_statement {
+        SqlSchemaDefinitionStatement::Table(definition) => visitor.visit_table_definition_mut(definition),
+    }
+}
+
+pub fn walk_sql_data_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, sql_data_statement: &mut SqlDataStatement) {
+    match sql_data_statement {
+        SqlDataStatement::ChangeStatement(statement) => visitor.visit_sql_data_change_statement_mut(statement),
+        SqlDataStatement::SelectStatement(query_expression) => visitor.visit_query_expression_mut(query_expression),
+        SqlDataStatement::SelectStatementSingleRow(statement) => visitor.visit_select_statement_single_row_mut(statement),
+    }
+}
+
+pub fn walk_sql_data_change_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, sql_data_change_statement: &mut SqlDataChangeStatement) {
+    match sql_data_change_statement {
+        SqlDataChangeStatement::Insert(statement) => visitor.visit_insert_statement_mut(statement),
+    }
+}
+
+pub fn walk_insert_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, insert_statement: &mut InsertStatement) {
+    visitor.visit_table_name_mut(&mut insert_statement.table_name);
+    visitor.visit_insert_columns_and_source_mut(&mut insert_statement.insert_columns_and_source);
+}
+
+pub fn walk_insert_columns_and_source_mut<V: VisitMut + ?Sized>(visitor: &mut V, insert_columns_and_source: &mut InsertColumnsAndSource) {
+    match insert_columns_and_source {
+        InsertColumnsAndSource::FromConstructor { constructor, .. } => visitor.visit_contextually_typed_table_value_constructor_mut(constructor),
+    }
+}
+
+pub fn walk_contextually_typed_table_value_constructor_mut<V: VisitMut + ?Sized>(visitor: &mut V, contextually_typed_table_value_constructor: &mut ContextuallyTypedTableValueConstructor) {
+    for contextually_typed_row_value_expression in &mut contextually_typed_table_value_constructor.values {
+        visitor.visit_contextually_typed_row_value_expression_mut(contextually_typed_row_value_expression);
+    }
+}
+
+pub fn walk_contextually_typed_row_value_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, contextually_typed_row_value_expression: &mut ContextuallyTypedRowValueExpression) {
+    match contextually_typed_row_value_expression {
+        ContextuallyTypedRowValueExpression::ContextuallyTypedRowValueConstructor(constructor) => visitor.visit_contextually_typed_row_value_constructor_mut(constructor),
+    }
+}
+
+pub fn walk_contextually_typed_row_value_constructor_mut<V: VisitMut + ?Sized>(visitor: &mut V, contextually_typed_row_value_constructor: &mut ContextuallyTypedRowValueConstructor) {
+    for contextually_typed_row_value_constructor_element in &mut contextually_typed_row_value_constructor.elements {
+        visitor.visit_contextually_typed_row_value_constructor_element_mut(contextually_typed_row_value_constructor_element);
+    }
+}
+
+pub fn walk_contextually_typed_row_value_constructor_element_mut<V: VisitMut + ?Sized>(visitor: &mut V, contextually_typed_row_value_constructor_element: &mut ContextuallyTypedRowValueConstructorElement) {
+    match contextually_typed_row_value_constructor_element {
+        ContextuallyTypedRowValueConstructorElement::ValueExpression(expression) => visitor.visit_value_expression_mut(expression),
+    }
+}
+
+pub fn walk_select_statement_single_row_mut<V: VisitMut + ?Sized>(visitor: &mut V, select_statement_single_row: &mut SelectStatementSingleRow) {
+    visitor.visit_set_quantifier_mut(&mut select_statement_single_row.set_quantifier);
+}
+
+pub fn walk_table_definition_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_definition: &mut TableDefinition) {
+    visitor.visit_identifier_mut(&mut table_definition.table_name);
+
+    for table_element in &mut table_definition.elements {
+        visitor.visit_table_element_mut(table_element);
+    }
+}
+
+pub fn walk_table_element_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_element: &mut TableElement) {
+    match table_element {
+        TableElement::ColumnDefinition(definition) => visitor.visit_column_definition_mut(definition),
+    }
+}
+
+pub fn walk_column_definition_mut<V: VisitMut + ?Sized>(visitor: &mut V, column_definition: &mut ColumnDefinition) {
+    visitor.visit_identifier_mut(&mut column_definition.column_name);
+    visitor.visit_data_type_mut(&mut column_definition.data_type);
+
+    for column_constraint_definition in &mut column_definition.column_constraint_definitions {
+        visitor.visit_column_constraint_definition_mut(column_constraint_definition);
+    }
+}
+
+pub fn walk_column_constraint_definition_mut<V: VisitMut + ?Sized>(visitor: &mut V, column_constraint_definition: &mut ColumnConstraintDefinition) {
+    match column_constraint_definition {
+        ColumnConstraintDefinition::UniqueSpecification(specification) => visitor.visit_unique_specification_mut(specification),
+        ColumnConstraintDefinition::NotNull => (),
+    }
+}
+
+pub fn walk_query_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, query_expression: &mut QueryExpression) {
+    visitor.visit_query_expression_body_mut(&mut query_expression.body);
+
+    if let Some(order_by) = &mut query_expression.order_by {
+        visitor.visit_order_by_clause_mut(order_by);
+    }
+
+    if let Some(offset) = &mut query_expression.offset {
+        visitor.visit_result_offset_clause_mut(offset);
+    }
+
+    if let Some(fetch) = &mut query_expression.fetch {
+        visitor.visit_fetch_first_clause_mut(fetch);
+    }
+}
+
+pub fn walk_query_expression_body_mut<V: VisitMut + ?Sized>(visitor: &mut V, query_expression_body: &mut QueryExpressionBody) {
+    match query_expression_body {
+        QueryExpressionBody::NonJoinQueryExpression(expression) => visitor.visit_non_join_query_expression_mut(expression),
+        QueryExpressionBody::SimpleTable(simple_table) => visitor.visit_simple_table_mut(simple_table),
+    }
+}
+
+pub fn walk_non_join_query_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, non_join_query_expression: &mut NonJoinQueryExpression) {
+    match non_join_query_expression {
+        NonJoinQueryExpression::NonJoinQueryTerm(term) => visitor.visit_non_join_query_term_mut(term),
+    }
+}
+
+pub fn walk_non_join_query_term_mut<V: VisitMut + ?Sized>(visitor: &mut V, non_join_query_term: &mut NonJoinQueryTerm) {
+    match non_join_query_term {
+        NonJoinQueryTerm::NonJoinQueryPrimary(primary) => visitor.visit_non_join_query_primary_mut(primary),
+    }
+}
+
+pub fn walk_non_join_query_primary_mut<V: VisitMut + ?Sized>(visitor: &mut V, non_join_query_primary: &mut NonJoinQueryPrimary) {
+    match non_join_query_primary {
+        NonJoinQueryPrimary::SimpleTable(simple_table) => visitor.visit_simple_table_mut(simple_table),
+    }
+}
+
+pub fn walk_query_primary_mut<V: VisitMut + ?Sized>(visitor: &mut V, query_primary: &mut QueryPrimary) {
+    match query_primary {
+        QueryPrimary::NonJoinQueryPrimary(primary) => visitor.visit_non_join_query_primary_mut(primary),
+    }
+}
+
+pub fn walk_simple_table_mut<V: VisitMut + ?Sized>(visitor: &mut V, simple_table: &mut SimpleTable) {
+    match simple_table {
+        SimpleTable::QuerySpecification(specification) => visitor.visit_query_specification_mut(specification),
+    }
+}
+
+pub fn walk_query_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, query_specification: &mut QuerySpecification) {
+    visitor.visit_set_quantifier_mut(&mut query_specification.set_quantifier);
+    visitor.visit_select_list_mut(&mut query_specification.select_list);
+
+    if let Some(table_expression) = &mut query_specification.table_expression {
+        visitor.visit_table_expression_mut(table_expression);
+    }
+}
+
+pub fn walk_select_list_mut<V: VisitMut + ?Sized>(visitor: &mut V, select_list: &mut SelectList) {
+    match select_list {
+        SelectList::Sublist(sublist) => {
+            for select_sublist in sublist {
+                visitor.visit_select_sublist_mut(select_sublist);
+            }
+        }
+
+        SelectList::Asterisk => (),
+    }
+}
+
+pub fn walk_select_sublist_mut<V: VisitMut + ?Sized>(visitor: &mut V, select_sublist: &mut SelectSublist) {
+    match select_sublist {
+        SelectSublist::DerivedColumn(derived_column) => visitor.visit_derived_column_mut(derived_column),
+    }
+}
+
+pub fn walk_derived_column_mut<V: VisitMut + ?Sized>(visitor: &mut V, derived_column: &mut DerivedColumn) {
+    visitor.visit_value_expression_mut(&mut derived_column.value_expression);
+
+    if let Some(alias) = &mut derived_column.alias {
+        visitor.visit_identifier_mut(alias);
+    }
+}
+
+pub fn walk_table_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_expression: &mut TableExpression) {
+    visitor.visit_from_clause_mut(&mut table_expression.from_clause);
+
+    if let Some(where_clause) = &mut table_expression.where_clause {
+        visitor.visit_where_clause_mut(where_clause);
+    }
+
+    if let Some(group_by_clause) = &mut table_expression.group_by_clause {
+        visitor.visit_group_by_clause_mut(group_by_clause);
+    }
+
+    if let Some(having_clause) = &mut table_expression.having_clause {
+        visitor.visit_having_clause_mut(having_clause);
+    }
+
+    if let Some(window_clause) = &mut table_expression.window_clause {
+        visitor.visit_window_clause_mut(window_clause);
+    }
+}
+
+pub fn walk_table_reference_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_reference: &mut TableReference) {
+    match table_reference {
+        TableReference::Primary(primary) => visitor.visit_table_primary_mut(primary),
+    }
+}
+
+pub fn walk_table_primary_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_primary: &mut TablePrimary) {
+    visitor.visit_table_primary_kind_mut(&mut table_primary.kind);
+}
+
+pub fn walk_table_primary_kind_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_primary_kind: &mut TablePrimaryKind) {
+    match table_primary_kind {
+        TablePrimaryKind::TableOrQueryName(name) => visitor.visit_identifier_mut(name),
+    }
+}
+
+pub fn walk_from_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, from_clause: &mut FromClause) {
+    for table_reference in &mut from_clause.table_references {
+        visitor.visit_table_reference_mut(table_reference);
+    }
+}
+
+pub fn walk_where_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, where_clause: &mut WhereClause) {
+    visitor.visit_value_expression_mut(&mut where_clause.search_condition);
+}
+
+pub fn walk_window_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_clause: &mut WindowClause) {
+    for window_definition in &mut window_clause.window_definitions {
+        visitor.visit_window_definition_mut(window_definition);
+    }
+}
+
+pub fn walk_window_definition_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_definition: &mut WindowDefinition) {
+    visitor.visit_identifier_mut(&mut window_definition.name);
+    visitor.visit_window_specification_mut(&mut window_definition.specification);
+}
+
+pub fn walk_window_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_specification: &mut WindowSpecification) {
+    if let Some(existing_window_name) = &mut window_specification.existing_window_name {
+        visitor.visit_identifier_mut(existing_window_name);
+    }
+
+    for column_reference in &mut window_specification.partition_clause {
+        visitor.visit_column_reference_mut(column_reference);
+    }
+
+    if let Some(order_clause) = &mut window_specification.order_clause {
+        visitor.visit_order_by_clause_mut(order_clause);
+    }
+
+    if let Some(frame_clause) = &mut window_specification.frame_clause {
+        visitor.visit_window_frame_clause_mut(frame_clause);
+    }
+}
+
+pub fn walk_window_frame_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_frame_clause: &mut WindowFrameClause) {
+    visitor.visit_window_frame_units_mut(&mut window_frame_clause.units);
+    visitor.visit_window_frame_bound_mut(&mut window_frame_clause.start);
+
+    if let Some(end) = &mut window_frame_clause.end {
+        visitor.visit_window_frame_bound_mut(end);
+    }
+}
+
+pub fn walk_window_frame_bound_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_frame_bound: &mut WindowFrameBound) {
+    match window_frame_bound {
+        WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value) => visitor.visit_simple_value_specification_mut(value),
+        WindowFrameBound::UnboundedPreceding | WindowFrameBound::CurrentRow | WindowFrameBound::UnboundedFollowing => (),
+    }
+}
+
+pub fn walk_order_by_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, order_by_clause: &mut OrderByClause) {
+    for sort_specification in &mut order_by_clause.sort_specification_list {
+        visitor.visit_sort_specification_mut(sort_specification);
+    }
+}
+
+pub fn walk_sort_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, sort_specification: &mut SortSpecification) {
+    visitor.visit_column_reference_mut(&mut sort_specification.sort_key);
+
+    if let Some(ordering_specification) = &mut sort_specification.ordering_specification {
+        visitor.visit_ordering_specification_mut(ordering_specification);
+    }
+}
+
+pub fn walk_result_offset_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, result_offset_clause: &mut ResultOffsetClause) {
+    visitor.visit_simple_value_specification_mut(&mut result_offset_clause.offset_row_count);
+}
+
+pub fn walk_fetch_first_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, fetch_first_clause: &mut FetchFirstClause) {
+    visitor.visit_fetch_first_quantity_mut(&mut fetch_first_clause.quantity);
+    visitor.visit_fetch_first_clause_origin_mut(&mut fetch_first_clause.origin);
+}
+
+pub fn walk_fetch_first_quantity_mut<V: VisitMut + ?Sized>(visitor: &mut V, fetch_first_quantity: &mut FetchFirstQuantity) {
+    visitor.visit_simple_value_specification_mut(&mut fetch_first_quantity.value);
+}
+
+pub fn walk_value_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, value_expression: &mut ValueExpression) {
+    match value_expression {
+        ValueExpression::Boolean(expression) => visitor.visit_boolean_expression_mut(expression),
+        ValueExpression::Case(expression) => visitor.visit_case_expression_mut(expression),
+        ValueExpression::Cast(specification) => visitor.visit_cast_specification_mut(specification),
+        ValueExpression::ColumnReference(reference) => visitor.visit_column_reference_mut(reference),
+        ValueExpression::Numeric(expression) => visitor.visit_numeric_value_expression_mut(expression),
+        ValueExpression::RoutineInvocation(invocation) => visitor.visit_routine_invocation_mut(invocation),
+        ValueExpression::SetFunctionSpecification(specification) => visitor.visit_set_function_specification_mut(specification),
+        ValueExpression::StringValueExpression(expression) => visitor.visit_string_value_expression_mut(expression),
+        ValueExpression::WindowFunction(function) => visitor.visit_window_function_mut(function),
+    }
+}
+
+pub fn walk_boolean_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, boolean_expression: &mut BooleanExpression) {
+    match boolean_expression {
+        BooleanExpression::And { left_hand_side, right_hand_side } | BooleanExpression::Or { left_hand_side, right_hand_side } => {
+            visitor.visit_value_expression_mut(left_hand_side);
+            visitor.visit_value_expression_mut(right_hand_side);
+        }
+
+        BooleanExpression::Is { operand, truth_value, .. } => {
+            visitor.visit_value_expression_mut(operand);
+            visitor.visit_truth_value_mut(truth_value);
+        }
+
+        BooleanExpression::Not(operand, _) => visitor.visit_value_expression_mut(operand),
+        BooleanExpression::Predicate(predicate) => visitor.visit_predicate_mut(predicate),
+        BooleanExpression::Literal(..) | BooleanExpression::Unknown(..) => (),
+    }
+}
+
+pub fn walk_case_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, case_expression: &mut CaseExpression) {
+    match case_expression {
+        CaseExpression::Coalesce(operands, _) => {
+            for value_expression in operands {
+                visitor.visit_value_expression_mut(value_expression);
+            }
+        }
+
+        CaseExpression::NullIf { left_hand_side, right_hand_side, .. } => {
+            visitor.visit_value_expression_mut(left_hand_side);
+            visitor.visit_value_expression_mut(right_hand_side);
+        }
+
+        CaseExpression::Searched { when_clauses, else_clause, .. } => {
+            for searched_when_clause in when_clauses {
+                visitor.visit_searched_when_clause_mut(searched_when_clause);
+            }
+
+            if let Some(else_clause) = else_clause {
+                visitor.visit_case_result_mut(else_clause);
+            }
+        }
+
+        CaseExpression::Simple { operand, when_clauses, else_clause, .. } => {
+            visitor.visit_value_expression_mut(operand);
+
+            for simple_when_clause in when_clauses {
+                visitor.visit_simple_when_clause_mut(simple_when_clause);
+            }
+
+            if let Some(else_clause) = else_clause {
+                visitor.visit_case_result_mut(else_clause);
+            }
+        }
+    }
+}
+
+pub fn walk_searched_when_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, searched_when_clause: &mut SearchedWhenClause) {
+    visitor.visit_value_expression_mut(&mut searched_when_clause.search_condition);
+    visitor.visit_case_result_mut(&mut searched_when_clause.result);
+}
+
+pub fn walk_simple_when_clause_mut<V: VisitMut + ?Sized>(visitor: &mut V, simple_when_clause: &mut SimpleWhenClause) {
+    visitor.visit_value_expression_mut(&mut simple_when_clause.when_operand);
+    visitor.visit_case_result_mut(&mut simple_when_clause.result);
+}
+
+pub fn walk_case_result_mut<V: VisitMut + ?Sized>(visitor: &mut V, case_result: &mut CaseResult) {
+    match case_result {
+        CaseResult::ValueExpression(expression) => visitor.visit_value_expression_mut(expression),
+        CaseResult::Null(..) => (),
+    }
+}
+
+pub fn walk_cast_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, cast_specification: &mut CastSpecification) {
+    visitor.visit_cast_operand_mut(&mut cast_specification.operand);
+    visitor.visit_data_type_mut(&mut cast_specification.target);
+}
+
+pub fn walk_cast_operand_mut<V: VisitMut + ?Sized>(visitor: &mut V, cast_operand: &mut CastOperand) {
+    match cast_operand {
+        CastOperand::ValueExpression(expression) => visitor.visit_value_expression_mut(expression),
+        CastOperand::Null => (),
+    }
+}
+
+pub fn walk_column_reference_mut<V: VisitMut + ?Sized>(visitor: &mut V, column_reference: &mut ColumnReference) {
+    match column_reference {
+        ColumnReference::BasicIdentifierChain(identifiers) => {
+            for identifier in identifiers {
+                visitor.visit_identifier_mut(identifier);
+            }
+        }
+    }
+}
+
+pub fn walk_numeric_value_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, numeric_value_expression: &mut NumericValueExpression) {
+    match numeric_value_expression {
+        NumericValueExpression::BinaryOperation { left_hand_side, operator, right_hand_side } => {
+            visitor.visit_value_expression_mut(left_hand_side);
+            visitor.visit_numeric_operator_mut(operator);
+            visitor.visit_value_expression_mut(right_hand_side);
+        }
+
+        NumericValueExpression::Function(function) => visitor.visit_numeric_value_function_mut(function),
+        NumericValueExpression::Negation(operand, _) => visitor.visit_value_expression_mut(operand),
+        NumericValueExpression::ApproximateNumericLiteral(..) | NumericValueExpression::ExactNumericLiteral(..) | NumericValueExpression::SimpleI64(..) | NumericValueExpression::SimpleU64(..) => (),
+    }
+}
+
+pub fn walk_numeric_value_function_mut<V: VisitMut + ?Sized>(visitor: &mut V, numeric_value_function: &mut NumericValueFunction) {
+    match numeric_value_function {
+        NumericValueFunction::Position { needle, haystack, .. } => {
+            visitor.visit_value_expression_mut(needle);
+            visitor.visit_value_expression_mut(haystack);
+        }
+    }
+}
+
+pub fn walk_routine_invocation_mut<V: VisitMut + ?Sized>(visitor: &mut V, routine_invocation: &mut RoutineInvocation) {
+    visitor.visit_identifier_mut(&mut routine_invocation.name);
+
+    for value_expression in &mut routine_invocation.arguments {
+        visitor.visit_value_expression_mut(value_expression);
+    }
+}
+
+pub fn walk_set_function_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, set_function_specification: &mut SetFunctionSpecification) {
+    match set_function_specification {
+        SetFunctionSpecification::General(function) => visitor.visit_general_set_function_mut(function),
+        SetFunctionSpecification::Count(..) => (),
+    }
+}
+
+pub fn walk_general_set_function_mut<V: VisitMut + ?Sized>(visitor: &mut V, general_set_function: &mut GeneralSetFunction) {
+    visitor.visit_computational_operation_mut(&mut general_set_function.function_type);
+    visitor.visit_set_quantifier_mut(&mut general_set_function.set_quantifier);
+    visitor.visit_value_expression_mut(&mut general_set_function.value_expression);
+}
+
+pub fn walk_string_value_expression_mut<V: VisitMut + ?Sized>(visitor: &mut V, string_value_expression: &mut StringValueExpression) {
+    match string_value_expression {
+        StringValueExpression::Concatenation { left_hand_side, right_hand_side } => {
+            visitor.visit_value_expression_mut(left_hand_side);
+            visitor.visit_value_expression_mut(right_hand_side);
+        }
+
+        StringValueExpression::Function(function) => visitor.visit_string_value_function_mut(function),
+        StringValueExpression::BinaryLiteral(..) | StringValueExpression::BitLiteral(..) | StringValueExpression::Literal(..) => (),
+    }
+}
+
+pub fn walk_string_value_function_mut<V: VisitMut + ?Sized>(visitor: &mut V, string_value_function: &mut StringValueFunction) {
+    match string_value_function {
+        StringValueFunction::Overlay { operand, placing, start_position, string_length, .. } => {
+            visitor.visit_value_expression_mut(operand);
+            visitor.visit_value_expression_mut(placing);
+            visitor.visit_value_expression_mut(start_position);
+
+            if let Some(string_length) = string_length {
+                visitor.visit_value_expression_mut(string_length);
+            }
+        }
+
+        StringValueFunction::Substring { operand, start_position, string_length, .. } => {
+            visitor.visit_value_expression_mut(operand);
+            visitor.visit_value_expression_mut(start_position);
+
+            if let Some(string_length) = string_length {
+                visitor.visit_value_expression_mut(string_length);
+            }
+        }
+
+        StringValueFunction::Trim { specification, character, source, .. } => {
+            visitor.visit_trim_specification_mut(specification);
+
+            if let Some(character) = character {
+                visitor.visit_value_expression_mut(character);
+            }
+
+            visitor.visit_value_expression_mut(source);
+        }
+    }
+}
+
+pub fn walk_window_function_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_function: &mut WindowFunction) {
+    visitor.visit_window_function_type_mut(&mut window_function.function_type);
+    visitor.visit_window_name_or_specification_mut(&mut window_function.window);
+}
+
+pub fn walk_window_function_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_function_type: &mut WindowFunctionType) {
+    match window_function_type {
+        WindowFunctionType::Aggregate(specification) => visitor.visit_set_function_specification_mut(specification),
+        WindowFunctionType::Rank(function_type) => visitor.visit_rank_function_type_mut(function_type),
+        WindowFunctionType::RowNumber => (),
+    }
+}
+
+pub fn walk_window_name_or_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, window_name_or_specification: &mut WindowNameOrSpecification) {
+    match window_name_or_specification {
+        WindowNameOrSpecification::Name(name) => visitor.visit_identifier_mut(name),
+        WindowNameOrSpecification::Specification(specification) => visitor.visit_window_specification_mut(specification),
+    }
+}
+
+pub fn walk_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, predicate: &mut Predicate) {
+    match predicate {
+        Predicate::Between(predicate) => visitor.visit_between_predicate_mut(predicate),
+        Predicate::Comparison(predicate) => visitor.visit_comparison_predicate_mut(predicate),
+        Predicate::Distinct(predicate) => visitor.visit_distinct_predicate_mut(predicate),
+        Predicate::In(predicate) => visitor.visit_in_predicate_mut(predicate),
+        Predicate::Like(predicate) => visitor.visit_like_predicate_mut(predicate),
+        Predicate::Null(predicate) => visitor.visit_null_predicate_mut(predicate),
+        Predicate::QuantifiedComparison(predicate) => visitor.visit_quantified_comparison_predicate_mut(predicate),
+        Predicate::Similar(predicate) => visitor.visit_similar_predicate_mut(predicate),
+    }
+}
+
+pub fn walk_between_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, between_predicate: &mut BetweenPredicate) {
+    visitor.visit_value_expression_mut(&mut between_predicate.operand);
+    visitor.visit_value_expression_mut(&mut between_predicate.lower_bound);
+    visitor.visit_value_expression_mut(&mut between_predicate.upper_bound);
+}
+
+pub fn walk_comparison_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, comparison_predicate: &mut ComparisonPredicate) {
+    visitor.visit_value_expression_mut(&mut comparison_predicate.left_hand_side);
+    visitor.visit_comparison_operator_mut(&mut comparison_predicate.operator);
+    visitor.visit_value_expression_mut(&mut comparison_predicate.right_hand_side);
+}
+
+pub fn walk_distinct_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, distinct_predicate: &mut DistinctPredicate) {
+    visitor.visit_value_expression_mut(&mut distinct_predicate.left_hand_side);
+    visitor.visit_value_expression_mut(&mut distinct_predicate.right_hand_side);
+}
+
+pub fn walk_in_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, in_predicate: &mut InPredicate) {
+    visitor.visit_value_expression_mut(&mut in_predicate.operand);
+
+    for value_expression in &mut in_predicate.values {
+        visitor.visit_value_expression_mut(value_expression);
+    }
+}
+
+pub fn walk_like_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, like_predicate: &mut LikePredicate) {
+    visitor.visit_value_expression_mut(&mut like_predicate.operand);
+    visitor.visit_value_expression_mut(&mut like_predicate.pattern);
+
+    if let Some(escape) = &mut like_predicate.escape {
+        visitor.visit_value_expression_mut(escape);
+    }
+}
+
+pub fn walk_null_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, null_predicate: &mut NullPredicate) {
+    visitor.visit_value_expression_mut(&mut null_predicate.operand);
+}
+
+pub fn walk_quantified_comparison_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, quantified_comparison_predicate: &mut QuantifiedComparisonPredicate) {
+    visitor.visit_value_expression_mut(&mut quantified_comparison_predicate.left_hand_side);
+    visitor.visit_comparison_operator_mut(&mut quantified_comparison_predicate.operator);
+    visitor.visit_quantifier_mut(&mut quantified_comparison_predicate.quantifier);
+
+    for value_expression in &mut quantified_comparison_predicate.values {
+        visitor.visit_value_expression_mut(value_expression);
+    }
+}
+
+pub fn walk_similar_predicate_mut<V: VisitMut + ?Sized>(visitor: &mut V, similar_predicate: &mut SimilarPredicate) {
+    visitor.visit_value_expression_mut(&mut similar_predicate.operand);
+    visitor.visit_value_expression_mut(&mut similar_predicate.pattern);
+
+    if let Some(escape) = &mut similar_predicate.escape {
+        visitor.visit_value_expression_mut(escape);
+    }
+}
+
+pub fn walk_data_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, data_type: &mut DataType) {
+    match data_type {
+        DataType::Predefined(predefined_type) => visitor.visit_predefined_type_mut(predefined_type),
+    }
+}
+
+pub fn walk_predefined_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, predefined_type: &mut PredefinedType) {
+    match predefined_type {
+        PredefinedType::CharacterString { definition, character_set } => {
+            visitor.visit_character_string_type_mut(definition);
+
+            if let Some(character_set) = character_set {
+                visitor.visit_character_set_specification_mut(character_set);
+            }
+        }
+
+        PredefinedType::NationalCharacterString(definition) => visitor.visit_national_character_string_type_mut(definition),
+        PredefinedType::BinaryString(definition) => visitor.visit_binary_string_type_mut(definition),
+        PredefinedType::BitString(definition) => visitor.visit_bit_string_type_mut(definition),
+        PredefinedType::Numeric(definition) => visitor.visit_numeric_type_mut(definition),
+        PredefinedType::Boolean => (),
+    }
+}
+
+pub fn walk_character_string_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, character_string_type: &mut CharacterStringType) {
+    match character_string_type {
+        CharacterStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length_mut(length);
+            }
+        }
+
+        CharacterStringType::Fixed { .. } | CharacterStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_national_character_string_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, national_character_string_type: &mut NationalCharacterStringType) {
+    match national_character_string_type {
+        NationalCharacterStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length_mut(length);
+            }
+        }
+
+        NationalCharacterStringType::Fixed { .. } | NationalCharacterStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_binary_string_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, binary_string_type: &mut BinaryStringType) {
+    match binary_string_type {
+        BinaryStringType::LargeObject { length } => {
+            if let Some(length) = length {
+                visitor.visit_large_object_length_mut(length);
+            }
+        }
+
+        BinaryStringType::Fixed { .. } | BinaryStringType::Varying { .. } => (),
+    }
+}
+
+pub fn walk_large_object_length_mut<V: VisitMut + ?Sized>(visitor: &mut V, large_object_length: &mut LargeObjectLength) {
+    if let Some(multiplier) = &mut large_object_length.multiplier {
+        visitor.visit_large_object_length_multiplier_mut(multiplier);
+    }
+}
+
+pub fn walk_character_set_specification_mut<V: VisitMut + ?Sized>(visitor: &mut V, character_set_specification: &mut CharacterSetSpecification) {
+    visitor.visit_character_set_specification_kind_mut(&mut character_set_specification.kind);
+}
+
+pub fn walk_table_name_mut<V: VisitMut + ?Sized>(visitor: &mut V, table_name: &mut TableName) {
+    visitor.visit_identifier_mut(&mut table_name.table_qualifier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::IdentifierDelimiter, Parser};
+    use rstest::rstest;
+
+    use pretty_assertions::assert_eq;
+
+    /// Replaces the column references with the `NULL` literal.
+    struct ReplaceColumnsWithNull;
+
+    impl VisitMut for ReplaceColumnsWithNull {
+        fn visit_value_expression_mut(&mut self, value_expression: &mut ValueExpression) {
+            if let ValueExpression::ColumnReference(..) = value_expression {
+                *value_expression = ValueExpression::Boolean(BooleanExpression::Unknown(Default::default()));
+                return;
+            }
+
+            walk_value_expression_mut(self, value_expression);
+        }
+    }
+
+    /// Makes every identifier case-sensitive, keeping its value.
+    struct DelimitIdentifiers;
+
+    impl VisitMut for DelimitIdentifiers {
+        fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+            identifier.delimiter = Some(IdentifierDelimiter::DoubleQuote);
+        }
+    }
+
+    #[rstest]
+    #[case("SELECT a, b + 1 FROM t WHERE c IN (d, 2)", "SELECT UNKNOWN, UNKNOWN + 1 FROM t WHERE UNKNOWN IN (UNKNOWN, 2)")]
+    #[case("SELECT CASE WHEN a THEN b END, UPPER(c) FROM t ORDER BY d", "SELECT CASE WHEN UNKNOWN THEN UNKNOWN END, UPPER(UNKNOWN) FROM t ORDER BY d")]
+    #[case("INSERT INTO t VALUES (a, 1)", "INSERT INTO t VALUES (UNKNOWN, 1)")]
+    fn visit_mut_replace_value_expressions(#[case] input: &str, #[case] expected: &str) {
+        let mut statement = Parser::new().parse_statement(input).unwrap();
+        ReplaceColumnsWithNull.visit_sql_executable_statement_mut(&mut statement);
+        assert_eq!(statement.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("SELECT a FROM t AS u ORDER BY b", r#"SELECT "a" FROM "t" AS u ORDER BY "b""#)]
+    #[case("SELECT RANK() OVER w FROM t WINDOW w AS (PARTITION BY a)", r#"SELECT RANK() OVER "w" FROM "t" WINDOW "w" AS (PARTITION BY "a")"#)]
+    #[case("INSERT INTO t VALUES (1)", r#"INSERT INTO "t" VALUES (1)"#)]
+    fn visit_mut_identifiers(#[case] input: &str, #[case] expected: &str) {
+        let mut statement = Parser::new().parse_statement(input).unwrap();
+        DelimitIdentifiers.visit_sql_executable_statement_mut(&mut statement);
+        assert_eq!(statement.to_string(), expected);
+    }
+}
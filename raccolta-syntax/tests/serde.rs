@@ -0,0 +1,266 @@
+// Copyright (C) 2023 Tristan Gerritsen <tristan@thewoosh.org>
+// All Rights Reserved.
+
+//! The serialized syntax trees are stored and shared between processes, so
+//! these tests pin their JSON representation. Changing any of the expected
+//! values below breaks previously serialized statements.
+
+#![cfg(feature = "serde")]
+
+use raccolta_syntax::{
+    statement::SqlExecutableStatement,
+    Lexer,
+    Parser,
+    Token,
+};
+use rstest::rstest;
+use serde_json::{json, Value};
+
+use pretty_assertions::assert_eq;
+
+fn serialize_statement(input: &str) -> Value {
+    let statement = Parser::new().parse_statement(input).unwrap();
+    serde_json::to_value(statement).unwrap()
+}
+
+#[test]
+fn serialize_select_statement() {
+    let identifier = |value: &str, start: usize, end: usize| json!({
+        "value": value,
+        "delimiter": null,
+        "span": { "start": start, "end": end },
+    });
+
+    let expected = json!({
+        "SqlDataStatement": {
+            "SelectStatement": {
+                "body": {
+                    "SimpleTable": {
+                        "QuerySpecification": {
+                            "set_quantifier": "All",
+                            "select_list": {
+                                "Sublist": [
+                                    {
+                                        "DerivedColumn": {
+                                            "value_expression": {
+                                                "ColumnReference": {
+                                                    "BasicIdentifierChain": [identifier("name", 7, 11)],
+                                                },
+                                            },
+                                            "alias": null,
+                                            "span": { "start": 7, "end": 11 },
+                                        },
+                                    },
+                                ],
+                            },
+                            "table_expression": {
+                                "from_clause": {
+                                    "table_references": [
+                                        {
+                                            "Primary": {
+                                                "kind": { "TableOrQueryName": identifier("person", 17, 23) },
+                                                "correlation_name": null,
+                                                "span": { "start": 17, "end": 23 },
+                                            },
+                                        },
+                                    ],
+                                    "span": { "start": 12, "end": 23 },
+                                },
+                                "where_clause": {
+                                    "search_condition": {
+                                        "Boolean": {
+                                            "Predicate": {
+                                                "Comparison": {
+                                                    "operator": "GreaterThan",
+                                                    "left_hand_side": {
+                                                        "ColumnReference": {
+                                                            "BasicIdentifierChain": [identifier("age", 30, 33)],
+                                                        },
+                                                    },
+                                                    "right_hand_side": {
+                                                        "Numeric": {
+                                                            "SimpleU64": [18, { "start": 36, "end": 38 }],
+                                                        },
+                                                    },
+                                                    "span": { "start": 30, "end": 38 },
+                                                },
+                                            },
+                                        },
+                                    },
+                                    "span": { "start": 24, "end": 38 },
+                                },
+                                "group_by_clause": null,
+                                "having_clause": null,
+                                "window_clause": null,
+                                "span": { "start": 12, "end": 38 },
+                            },
+                            "span": { "start": 0, "end": 38 },
+                        },
+                    },
+                },
+                "order_by": null,
+                "offset": null,
+                "fetch": null,
+                "span": { "start": 0, "end": 38 },
+            },
+        },
+    });
+
+    assert_eq!(serialize_statement("SELECT name FROM person WHERE age > 18"), expected);
+}
+
+#[test]
+fn serialize_select_statement_clauses() {
+    let value = serialize_statement("SELECT DISTINCT a FROM t ORDER BY a DESC FETCH FIRST 5 ROWS ONLY");
+    let query = &value["SqlDataStatement"]["SelectStatement"];
+
+    assert_eq!(query["body"]["SimpleTable"]["QuerySpecification"]["set_quantifier"], json!("Distinct"));
+    assert_eq!(query["order_by"]["sort_specification_list"][0]["ordering_specification"], json!("Descending"));
+    assert_eq!(query["fetch"], json!({
+        "quantity": {
+            "value": { "LiteralUnsigned": 5 },
+            "is_percent": false,
+            "span": { "start": 53, "end": 54 },
+        },
+        "origin": "FetchFirst",
+        "with_ties": false,
+        "span": { "start": 41, "end": 64 },
+    }));
+}
+
+#[test]
+fn serialize_create_table_statement() {
+    let identifier = |value: &str, start: usize, end: usize| json!({
+        "value": value,
+        "delimiter": null,
+        "span": { "start": start, "end": end },
+    });
+
+    let expected = json!({
+        "Schema": {
+            "Definition": {
+                "Table": {
+                    "table_name": identifier("t", 13, 14),
+                    "elements": [
+                        {
+                            "ColumnDefinition": {
+                                "column_name": identifier("id", 16, 18),
+                                "data_type": { "Predefined": { "Numeric": "Integer" } },
+                                "column_constraint_definitions": [],
+                                "span": { "start": 16, "end": 22 },
+                            },
+                        },
+                        {
+                            "ColumnDefinition": {
+                                "column_name": identifier("name", 24, 28),
+                                "data_type": {
+                                    "Predefined": {
+                                        "CharacterString": {
+                                            "definition": { "Varying": { "length": 10 } },
+                                            "character_set": null,
+                                        },
+                                    },
+                                },
+                                "column_constraint_definitions": [],
+                                "span": { "start": 24, "end": 40 },
+                            },
+                        },
+                    ],
+                    "span": { "start": 0, "end": 41 },
+                },
+            },
+        },
+    });
+
+    assert_eq!(serialize_statement("CREATE TABLE t (id INT, name VARCHAR(10))"), expected);
+}
+
+#[test]
+fn serialize_insert_statement() {
+    let expected = json!({
+        "SqlDataStatement": {
+            "ChangeStatement": {
+                "Insert": {
+                    "table_name": {
+                        "table_qualifier": {
+                            "value": "t",
+                            "delimiter": null,
+                            "span": { "start": 12, "end": 13 },
+                        },
+                    },
+                    "insert_columns_and_source": {
+                        "FromConstructor": {
+                            "insert_column_list": null,
+                            "constructor": {
+                                "values": [
+                                    {
+                                        "ContextuallyTypedRowValueConstructor": {
+                                            "elements": [
+                                                {
+                                                    "ValueExpression": {
+                                                        "Numeric": { "SimpleU64": [1, { "start": 22, "end": 23 }] },
+                                                    },
+                                                },
+                                                {
+                                                    "ValueExpression": {
+                                                        "StringValueExpression": { "Literal": ["a", "Character", { "start": 25, "end": 28 }] },
+                                                    },
+                                                },
+                                            ],
+                                            "span": { "start": 21, "end": 29 },
+                                        },
+                                    },
+                                ],
+                                "span": { "start": 14, "end": 29 },
+                            },
+                            "span": { "start": 14, "end": 29 },
+                        },
+                    },
+                    "span": { "start": 0, "end": 29 },
+                },
+            },
+        },
+    });
+
+    assert_eq!(serialize_statement("INSERT INTO t VALUES (1, 'a')"), expected);
+}
+
+/// Keywords are serialized as they are spelled in SQL, rather than by the
+/// names of their variants.
+#[test]
+fn serialize_tokens() {
+    let tokens: Vec<Token> = Lexer::new("SELECT x, 'a' LIMIT 1").collect();
+
+    let expected = json!([
+        { "first_character_byte_idx": 0, "last_character_byte_idx": 6, "kind": { "ReservedWord": "SELECT" } },
+        { "first_character_byte_idx": 7, "last_character_byte_idx": 8, "kind": "Identifier" },
+        { "first_character_byte_idx": 8, "last_character_byte_idx": 9, "kind": "Comma" },
+        { "first_character_byte_idx": 10, "last_character_byte_idx": 13, "kind": { "StringLiteral": { "kind": "Character" } } },
+        { "first_character_byte_idx": 14, "last_character_byte_idx": 19, "kind": { "VendorReservedWord": "LIMIT" } },
+        { "first_character_byte_idx": 20, "last_character_byte_idx": 21, "kind": { "UnsignedInteger": 1 } },
+    ]);
+
+    assert_eq!(serde_json::to_value(&tokens).unwrap(), expected);
+    assert_eq!(serde_json::from_value::<Vec<Token>>(expected).unwrap(), tokens);
+}
+
+#[rstest]
+#[case("SELECT name FROM person WHERE age > 18")]
+#[case("SELECT \"Name\", -x, 1.5, 6.02E23, TRUE IS NOT UNKNOWN FROM t AS u")]
+#[case("SELECT X'0AFF', B'101', 'it''s' || N'national', TRIM(LEADING '-' FROM x), POSITION('a' IN x)")]
+#[case("SELECT CASE WHEN a BETWEEN 1 AND 2 THEN NULL ELSE CAST(a AS VARCHAR(5)) END, COALESCE(a, b), NULLIF(a, b)")]
+#[case("SELECT a FROM t WHERE a IN (1, 2) AND b LIKE 'x%' ESCAPE '!' OR c IS NOT DISTINCT FROM d")]
+#[case("SELECT COUNT(*), SUM(DISTINCT a), RANK() OVER (PARTITION BY a ORDER BY b ROWS UNBOUNDED PRECEDING) FROM t WINDOW w AS (ORDER BY a)")]
+#[case("SELECT a FROM t ORDER BY a OFFSET 1 ROW FETCH FIRST 50 PERCENT ROWS WITH TIES")]
+#[case("SELECT TOP 5 a FROM t")]
+#[case("SELECT a FROM t LIMIT 5 OFFSET 10")]
+#[case("CREATE TABLE t (id INT, data BLOB(2M), bits BIT VARYING(8))")]
+#[case("INSERT INTO t VALUES (1, 'a'), (2, 'b')")]
+fn deserialize_serialized_statement(#[case] input: &str) {
+    let statement = Parser::new().parse_statement(input).unwrap();
+
+    let json = serde_json::to_string(&statement).unwrap();
+    let deserialized: SqlExecutableStatement = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized, statement);
+}